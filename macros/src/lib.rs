@@ -1,8 +1,8 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{DeriveInput, Error, Path, parse_macro_input};
+use syn::{DeriveInput, Error, Expr, Path, Token, parse_macro_input, punctuated::Punctuated};
 
-#[proc_macro_derive(InputAction, attributes(action_output))]
+#[proc_macro_derive(InputAction, attributes(action_output, action_bindings, action_group))]
 pub fn input_action_derive(item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as DeriveInput);
 
@@ -21,6 +21,41 @@ pub fn input_action_derive(item: TokenStream) -> TokenStream {
         Err(e) => return e.to_compile_error().into(),
     };
 
+    let default_bindings = match input
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("action_bindings"))
+        .map(|attr| attr.parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated))
+        .transpose()
+    {
+        Ok(Some(bindings)) => {
+            let bindings = bindings.iter();
+            Some(quote! {
+                fn default_bindings() -> impl IntoIterator<Item = ::bevy_enhanced_input::prelude::Binding> {
+                    [#(::core::convert::Into::<::bevy_enhanced_input::prelude::Binding>::into(#bindings)),*]
+                }
+            })
+        }
+        Ok(None) => None,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let group = match input
+        .attrs
+        .iter()
+        .find(|a| a.path().is_ident("action_group"))
+        .map(|attr| attr.parse_args::<Expr>())
+        .transpose()
+    {
+        Ok(Some(group)) => Some(quote! {
+            fn group() -> ::core::option::Option<::bevy_enhanced_input::prelude::ActionGroup> {
+                ::core::option::Option::Some(#group)
+            }
+        }),
+        Ok(None) => None,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
     let trait_name = quote! { ::bevy_enhanced_input::prelude::InputAction };
     let struct_name = input.ident;
     let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
@@ -28,6 +63,10 @@ pub fn input_action_derive(item: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #trait_name for #struct_name #type_generics #where_clause {
             type Output = #output_ty;
+
+            #default_bindings
+
+            #group
         }
     })
 }
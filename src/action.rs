@@ -44,21 +44,45 @@
 //!
 //! This is useful for simulating input during cutscenes,
 //! testing, networked replication, AI-controlled players, game replays, or other scenarios where you want to control the action state directly.
+//!
+//! To drive an action through its conditions and modifiers instead, use [`InjectedValue`].
+//!
+//! # Runtime-defined actions
+//!
+//! When the set of actions isn't known at compile time, like for mods or scripts, spawn
+//! [`DynAction`] instead. See the [`dynamic`] module for details.
 
+pub mod conflicts;
+pub mod dynamic;
 pub mod events;
 pub mod fns;
+pub mod group;
+pub mod history;
+pub mod inject;
 pub mod mock;
+pub mod monitor;
 pub mod relationship;
+pub mod timeline;
 pub mod value;
+pub mod watchdog;
 
+use alloc::vec::Vec;
+#[cfg(feature = "reflect")]
+use core::any::TypeId;
 use core::{any, fmt::Debug};
 
-use bevy::prelude::*;
+use bevy::{ecs::spawn::SpawnIter, prelude::*};
+#[cfg(feature = "reflect")]
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    reflect::{GetTypeRegistration, Typed, utility::GenericTypePathCell},
+};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::*;
+use crate::{context::input_reader::DeviceSource, prelude::*};
 use fns::ActionFns;
+use group::GroupCanceled;
 
 /// Component that represents a user action.
 ///
@@ -69,7 +93,17 @@ use fns::ActionFns;
 ///
 /// See the required components for other data associated with the action
 /// that can be accessed without static typing.
+///
+/// With the `reflect` feature, the type is registered automatically when the component
+/// is first added. To load actions from scenes before any of them were spawned,
+/// register the type manually with `app.register_type::<Action<A>>()`.
 #[derive(Component, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Default, PartialEq, type_path = false),
+    component(on_add = register_reflect::<A>)
+)]
 #[require(
     Name::new(any::type_name::<A>()),
     ActionFns::new::<A>(),
@@ -79,6 +113,10 @@ use fns::ActionFns;
     ActionEvents,
     ActionTime,
     ActionMock,
+    InjectedValue,
+    ActionBinding,
+    ClaimedDevice,
+    GroupCanceled,
 )]
 pub struct Action<A: InputAction>(A::Output);
 
@@ -106,6 +144,76 @@ impl<A: InputAction> Action<A> {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns a bundle with the action and [`InputAction::default_bindings`] spawned as its [`Bindings`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut world = World::new();
+    /// world.spawn((
+    ///     Player,
+    ///     actions!(Player[Action::<Jump>::with_default_bindings()]),
+    /// ));
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// #[action_bindings(KeyCode::Space, GamepadButton::South)]
+    /// struct Jump;
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// ```
+    pub fn with_default_bindings() -> impl Bundle {
+        let bindings: Vec<_> = A::default_bindings().into_iter().collect();
+        (
+            Self::new(),
+            Bindings::spawn(SpawnIter(bindings.into_iter())),
+        )
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl<A: InputAction> TypePath for Action<A> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                concat!(module_path!(), "::Action<{}>"),
+                any::type_name::<A>()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("Action<{}>", any::type_name::<A>()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("Action")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some(module_path!())
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some(module_path!().split(':').next().unwrap())
+    }
+}
+
+/// Registers [`Action<A>`] for reflection since generic types can't be registered automatically.
+#[cfg(feature = "reflect")]
+fn register_reflect<A: InputAction>(world: DeferredWorld, _ctx: HookContext) {
+    let Some(registry) = world.get_resource::<AppTypeRegistry>() else {
+        return;
+    };
+
+    if !registry.read().contains(TypeId::of::<Action<A>>()) {
+        registry.write().register::<Action<A>>();
+    }
 }
 
 /// A gameplay-related action which can be bound to inputs.
@@ -129,11 +237,51 @@ pub trait InputAction: 'static {
     /// - Use [`f32`] for single-axis actions (e.g., `Zoom`).
     /// - For multi-axis actions, like `Movement`, use [`Vec2`] or [`Vec3`].
     type Output: ActionOutput;
+
+    /// Bindings spawned by [`Action::with_default_bindings`] and
+    /// [`RebindEntityCommandsExt::bind_defaults`].
+    ///
+    /// With the derive, can be specified using the `action_bindings` attribute.
+    /// Accepts anything that converts into [`Binding`]:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// #[action_bindings(KeyCode::Space, GamepadButton::South)]
+    /// struct Jump;
+    /// ```
+    ///
+    /// Empty by default.
+    fn default_bindings() -> impl IntoIterator<Item = Binding> {
+        []
+    }
+
+    /// Group of mutually exclusive actions this action belongs to.
+    ///
+    /// With the derive, can be specified using the `action_group` attribute:
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// #[action_group(ActionGroup(0))]
+    /// struct SelectSlot1;
+    /// ```
+    ///
+    /// Can be overridden per action by inserting [`ActionGroup`]. See its documentation for details.
+    ///
+    /// [`None`] by default.
+    fn group() -> Option<ActionGroup> {
+        None
+    }
 }
 
 /// Type which can be used as [`InputAction::Output`].
 pub trait ActionOutput:
-    From<ActionValue> + Default + Send + Sync + Debug + Clone + Copy + PartialEq
+    From<ActionValue> + Default + Send + Sync + Debug + Clone + Copy + PartialEq + ReflectOutput
 {
     /// Dimension of this output.
     ///
@@ -141,6 +289,24 @@ pub trait ActionOutput:
     const DIM: ActionValueDim;
 }
 
+/// Reflection bounds for [`ActionOutput`].
+///
+/// Required to reflect [`Action<C>`] when the `reflect` feature is enabled, empty otherwise.
+#[cfg(feature = "reflect")]
+pub trait ReflectOutput: FromReflect + Typed + GetTypeRegistration {}
+
+#[cfg(feature = "reflect")]
+impl<T: FromReflect + Typed + GetTypeRegistration> ReflectOutput for T {}
+
+/// Reflection bounds for [`ActionOutput`].
+///
+/// Required to reflect [`Action<C>`] when the `reflect` feature is enabled, empty otherwise.
+#[cfg(not(feature = "reflect"))]
+pub trait ReflectOutput {}
+
+#[cfg(not(feature = "reflect"))]
+impl<T> ReflectOutput for T {}
+
 impl ActionOutput for bool {
     const DIM: ActionValueDim = ActionValueDim::Bool;
 }
@@ -158,6 +324,44 @@ impl ActionOutput for Vec3 {
 }
 
 /// Behavior configuration for [`Action<C>`].
+///
+/// Stored on each action entity, so the same action type can be configured differently per context.
+///
+/// # Examples
+///
+/// Consume input only while on foot:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut world = World::new();
+/// world.spawn((
+///     OnFoot,
+///     actions!(OnFoot[(
+///         Action::<Interact>::new(),
+///         ActionSettings {
+///             consume_input: true,
+///             ..Default::default()
+///         },
+///         bindings![KeyCode::KeyE],
+///     )]),
+/// ));
+/// world.spawn((
+///     InCar,
+///     actions!(InCar[(Action::<Interact>::new(), bindings![KeyCode::KeyE])]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct OnFoot;
+///
+/// #[derive(Component)]
+/// struct InCar;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Interact;
+/// ```
 #[derive(Component, Default, Debug, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
@@ -188,19 +392,44 @@ pub struct ActionSettings {
     /// Specifies whether this action should swallow any [`Bindings`]
     /// bound to it or allow them to pass through to affect actions that evaluated later.
     ///
-    /// Actions are ordered by the maximum number of [`ModKeys`] in their bindings.
-    /// For example, an action with a `Ctrl + C` binding is evaluated before one with just
-    /// a `C` binding. If actions have the same modifier count, they are ordered by their
-    /// spawn order.
+    /// Actions are ordered by their [`Self::priority`] and then by the maximum number of
+    /// [`ModKeys`] in their bindings. For example, an action with a `Ctrl + C` binding is
+    /// evaluated before one with just a `C` binding. If actions have the same priority and
+    /// modifier count, they are ordered by their spawn order.
     ///
     /// Consuming is global and affect actions in all contexts. Importantly, this does
     /// **not** affect the underlying Bevy input - only the action evaluation logic is impacted.
+    /// Use [`Actions::conflicts`] and [`Actions::conflicts_with`] to find bindings affected by it.
     ///
     /// Inputs are consumed only when the action state is not equal to
     /// [`TriggerState::None`].
     ///
     /// By default set to `false`.
     pub consume_input: bool,
+
+    /// Evaluation priority of the action within its context.
+    ///
+    /// Actions with a higher priority are evaluated first, so with [`Self::consume_input`]
+    /// they take precedence over other actions bound to the same input, regardless of
+    /// their spawn order or the number of modifiers in their bindings.
+    ///
+    /// Doesn't affect the order between contexts, see [`ContextPriority`] for this.
+    ///
+    /// By default set to 0.
+    pub priority: usize,
+
+    /// Makes the action exclusively owned by the first [`InputDevice`] that actuates it
+    /// until its state returns to [`TriggerState::None`].
+    ///
+    /// While owned, bindings from other devices are ignored. Useful for critical analog actions,
+    /// like steering, to prevent keyboard keys and a gamepad stick from fighting each other.
+    /// Gamepads are claimed individually, so with [`GamepadDevice::Any`] only the gamepad
+    /// that actuated the action is read, and input from other gamepads is ignored.
+    /// Bindings that don't correspond to a single device, such as [`Binding::AnyKey`],
+    /// are never ignored and never claim the action.
+    ///
+    /// By default set to `false`.
+    pub exclusive_device: bool,
 }
 
 /// Defines how [`ActionValue`] is calculated when multiple inputs are evaluated with the
@@ -226,6 +455,16 @@ pub enum Accumulation {
     ///
     /// For example, given values of 0.5 and -1.5, the input action's value would be -1.5.
     MaxAbs,
+    /// For each axis, take the value from the first mapping in the order of [`Bindings`]
+    /// that has a non-zero value on this axis.
+    ///
+    /// For example, given values of 0.5 and -1.5, the input action's value would be 0.5.
+    /// Given (0.5, 0.0) and (-1.0, 1.0), the value would be (0.5, 1.0).
+    ///
+    /// Axes are handled independently because presets like [`Cardinal`] spawn a separate
+    /// mapping for each direction. Useful when bindings are listed by preference, like a gamepad stick
+    /// followed by keyboard keys, and the inputs should not be mixed on the same axis.
+    First,
 }
 
 /// State for [`Action<C>`].
@@ -306,3 +545,40 @@ impl ActionTime {
         }
     }
 }
+
+/// Binding that last contributed to the state of [`Action<C>`].
+///
+/// Updated during evaluation to the binding with the highest [`TriggerState`]. If multiple bindings
+/// share the highest state, the first one is used. Keeps the previous value while no binding is active,
+/// so events like [`Complete`] still report the binding that drove the action.
+/// For gamepad bindings, also stores the gamepad from which the binding was read.
+///
+/// Set to [`None`] when the action is driven by [`ActionMock`].
+#[derive(Component, Deref, Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+pub struct ActionBinding {
+    #[deref]
+    pub(crate) binding: Option<Binding>,
+    pub(crate) gamepad: Option<Entity>,
+}
+
+impl ActionBinding {
+    /// Returns the gamepad from which the binding was read.
+    ///
+    /// With [`GamepadDevice::Any`], this is the first gamepad that actuated the binding.
+    /// Returns [`None`] for bindings from other devices.
+    #[must_use]
+    pub fn gamepad(&self) -> Option<Entity> {
+        self.gamepad
+    }
+}
+
+/// Device that currently owns [`Action<C>`].
+///
+/// Used only if [`ActionSettings::exclusive_device`] is set.
+#[derive(Component, Deref, DerefMut, Default)]
+pub(crate) struct ClaimedDevice(Option<DeviceSource>);
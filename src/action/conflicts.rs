@@ -0,0 +1,324 @@
+//! Detection of actions bound to the same inputs.
+//!
+//! See [`Actions::conflicts`] and [`Actions::conflicts_with`] for details.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+impl<C: Component> Actions<C> {
+    /// Returns pairs of actions whose bindings activate from the same input.
+    ///
+    /// Bindings conflict if they read the same input and the [`ModKeys`] of one binding contain
+    /// the modifiers of the other. For example, `Ctrl + S` conflicts with `S` and `Ctrl`, since pressing
+    /// it also activates the other binding, but not with `Alt + S`. See [`ConflictSeverity`] for the levels.
+    ///
+    /// Each pair of conflicting bindings is reported separately, with actions in spawn order.
+    /// Only actions of this context are checked. [`Binding::AnyKey`], [`Binding::AnyInput`] and [`Binding::None`]
+    /// never conflict, and [`Binding::LogicalKey`] conflicts only with other logical keys, since the
+    /// physical key depends on the layout.
+    ///
+    /// # Examples
+    ///
+    /// Warn about a broken keymap before saving it.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// fn check_keymap(
+    ///     player: Single<&Actions<Player>>,
+    ///     actions: Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+    ///     bindings: Query<&Binding>,
+    /// ) {
+    ///     for conflict in player.conflicts(&actions, &bindings) {
+    ///         if conflict.severity >= ConflictSeverity::Overlapping {
+    ///             let [first, second] = conflict.bindings;
+    ///             warn!("`{first}` conflicts with `{second}`");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    /// ```
+    #[must_use]
+    pub fn conflicts(
+        &self,
+        actions: &Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+        bindings: &Query<&Binding>,
+    ) -> Vec<BindingConflict> {
+        let entries = collect_entries(self, actions, bindings);
+
+        let mut conflicts = Vec::new();
+        for (index, first) in entries.iter().enumerate() {
+            for second in &entries[index + 1..] {
+                push_conflicts(&mut conflicts, first, second, |ordering| match ordering {
+                    Ordering::Equal => ConflictSeverity::Identical,
+                    Ordering::Greater if first.consumes_before(second, true) => {
+                        ConflictSeverity::Resolved
+                    }
+                    Ordering::Less if second.consumes_before(first, false) => {
+                        ConflictSeverity::Resolved
+                    }
+                    Ordering::Greater | Ordering::Less => ConflictSeverity::Overlapping,
+                });
+            }
+        }
+
+        conflicts
+    }
+
+    /// Returns pairs of actions from this context and context `O` whose bindings activate from the same input.
+    ///
+    /// Useful when an entity has multiple contexts, like `Actions<OnFoot>` and `Actions<InCar>`,
+    /// since [`Self::conflicts`] checks only actions within a single context.
+    ///
+    /// Assumes that this context is evaluated before `O`, for example because it has a higher
+    /// [`ContextPriority`]. Since all actions of this context are evaluated first, an overlap is
+    /// [`ConflictSeverity::Resolved`] only if the action from this context requires additional
+    /// modifiers and consumes the input. See [`ActionSettings::consume_input`] for details.
+    ///
+    /// Actions of this context come first in each returned pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// fn check_keymap(
+    ///     player: Single<(&Actions<InCar>, &Actions<OnFoot>)>,
+    ///     actions: Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+    ///     bindings: Query<&Binding>,
+    /// ) {
+    ///     let (in_car, on_foot) = *player;
+    ///     for conflict in in_car.conflicts_with(on_foot, &actions, &bindings) {
+    ///         if conflict.severity >= ConflictSeverity::Overlapping {
+    ///             let [first, second] = conflict.bindings;
+    ///             warn!("`{first}` from `InCar` shadows `{second}` from `OnFoot`");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct OnFoot;
+    ///
+    /// #[derive(Component)]
+    /// struct InCar;
+    /// ```
+    #[must_use]
+    pub fn conflicts_with<O: Component>(
+        &self,
+        other: &Actions<O>,
+        actions: &Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+        bindings: &Query<&Binding>,
+    ) -> Vec<BindingConflict> {
+        let entries = collect_entries(self, actions, bindings);
+        let other_entries = collect_entries(other, actions, bindings);
+
+        let mut conflicts = Vec::new();
+        for first in &entries {
+            for second in &other_entries {
+                push_conflicts(&mut conflicts, first, second, |ordering| match ordering {
+                    Ordering::Equal => ConflictSeverity::Identical,
+                    Ordering::Greater if first.consume_input => ConflictSeverity::Resolved,
+                    Ordering::Greater | Ordering::Less => ConflictSeverity::Overlapping,
+                });
+            }
+        }
+
+        conflicts
+    }
+}
+
+fn collect_entries<C: Component>(
+    context: &Actions<C>,
+    actions: &Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+    bindings: &Query<&Binding>,
+) -> Vec<ActionEntry> {
+    context
+        .iter()
+        .filter_map(|action| {
+            let (action_bindings, settings) = actions.get(action).ok()?;
+            let action_bindings: Vec<_> = bindings
+                .iter_many(action_bindings.into_iter().flatten())
+                .copied()
+                .collect();
+            let mods_count = action_bindings
+                .iter()
+                .map(|binding| binding.mod_keys_count())
+                .max()
+                .unwrap_or(0);
+            let consume_input = settings.is_some_and(|s| s.consume_input);
+            let priority = settings.map(|s| s.priority).unwrap_or_default();
+
+            Some(ActionEntry {
+                entity: action,
+                bindings: action_bindings,
+                priority,
+                mods_count,
+                consume_input,
+            })
+        })
+        .collect()
+}
+
+/// Pushes conflicts between all bindings of two actions with the severity for each overlap.
+fn push_conflicts(
+    conflicts: &mut Vec<BindingConflict>,
+    first: &ActionEntry,
+    second: &ActionEntry,
+    severity: impl Fn(Ordering) -> ConflictSeverity,
+) {
+    for &first_binding in &first.bindings {
+        for &second_binding in &second.bindings {
+            let Some(ordering) = overlap(first_binding, second_binding) else {
+                continue;
+            };
+
+            conflicts.push(BindingConflict {
+                actions: [first.entity, second.entity],
+                bindings: [first_binding, second_binding],
+                severity: severity(ordering),
+            });
+        }
+    }
+}
+
+struct ActionEntry {
+    entity: Entity,
+    bindings: Vec<Binding>,
+    priority: usize,
+    mods_count: usize,
+    consume_input: bool,
+}
+
+impl ActionEntry {
+    /// Returns `true` if the action consumes inputs before the other action is evaluated.
+    ///
+    /// Actions with the same priority and modifier count are evaluated in spawn order.
+    fn consumes_before(&self, other: &Self, spawned_first: bool) -> bool {
+        let order = (self.priority, self.mods_count);
+        let other_order = (other.priority, other.mods_count);
+        self.consume_input && (order > other_order || (spawned_first && order == other_order))
+    }
+}
+
+/// Compares two bindings that activate from the same input.
+///
+/// Returns [`Ordering::Greater`] if the first binding requires additional modifiers,
+/// [`Ordering::Less`] if the second one does, and [`Ordering::Equal`] if they are identical.
+pub(crate) fn overlap(first: Binding, second: Binding) -> Option<Ordering> {
+    if matches!(first, Binding::AnyKey | Binding::AnyInput | Binding::None)
+        || matches!(second, Binding::AnyKey | Binding::AnyInput | Binding::None)
+    {
+        return None;
+    }
+
+    let first_mods = first.mod_keys();
+    let second_mods = second.mod_keys();
+    if strip_mod_keys(first) == strip_mod_keys(second) {
+        return if first_mods == second_mods {
+            Some(Ordering::Equal)
+        } else if first_mods.contains(second_mods) {
+            Some(Ordering::Greater)
+        } else if second_mods.contains(first_mods) {
+            Some(Ordering::Less)
+        } else {
+            None
+        };
+    }
+
+    // Modifier keys can also be bound directly.
+    if modifier(second).is_some_and(|mod_key| first_mods.contains(mod_key)) {
+        Some(Ordering::Greater)
+    } else if modifier(first).is_some_and(|mod_key| second_mods.contains(mod_key)) {
+        Some(Ordering::Less)
+    } else {
+        None
+    }
+}
+
+fn strip_mod_keys(binding: Binding) -> Binding {
+    if binding.mod_keys().is_empty() {
+        binding
+    } else {
+        binding.without_mod_keys()
+    }
+}
+
+/// Returns the modifier if the binding is a modifier key without other modifiers.
+fn modifier(binding: Binding) -> Option<ModKeys> {
+    let Binding::Keyboard { key, mod_keys } = binding else {
+        return None;
+    };
+
+    let mod_key = ModKeys::from(key);
+    (mod_keys.is_empty() && !mod_key.is_empty()).then_some(mod_key)
+}
+
+/// Two bindings of different actions that activate from the same input.
+///
+/// Returned by [`Actions::conflicts`] and [`Actions::conflicts_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BindingConflict {
+    /// Conflicting action entities in spawn order or, for [`Actions::conflicts_with`], in context order.
+    pub actions: [Entity; 2],
+
+    /// Conflicting bindings of the corresponding actions.
+    pub bindings: [Binding; 2],
+
+    pub severity: ConflictSeverity,
+}
+
+/// How much a [`BindingConflict`] affects the actions, from least to most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ConflictSeverity {
+    /// One binding requires additional modifiers, but its action is evaluated first and consumes
+    /// the input, so the other action doesn't activate.
+    ///
+    /// See [`ActionSettings::consume_input`] for the evaluation order.
+    Resolved,
+
+    /// One binding requires additional modifiers, so activating it also activates the other action.
+    ///
+    /// Can be resolved by enabling [`ActionSettings::consume_input`] on the action with more modifiers
+    /// or by inserting [`ModKeysDisambiguation`] on the context entity.
+    Overlapping,
+
+    /// Both bindings read the same input with the same modifiers.
+    ///
+    /// Both actions activate together, or one of them never activates if the other consumes the input.
+    Identical,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overlaps() {
+        let s = Binding::from(KeyCode::KeyS);
+        let ctrl_s = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL);
+        let alt_s = KeyCode::KeyS.with_mod_keys(ModKeys::ALT);
+        let ctrl_alt_s = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL | ModKeys::ALT);
+
+        assert_eq!(overlap(s, s), Some(Ordering::Equal));
+        assert_eq!(overlap(ctrl_s, s), Some(Ordering::Greater));
+        assert_eq!(overlap(s, ctrl_s), Some(Ordering::Less));
+        assert_eq!(overlap(ctrl_alt_s, alt_s), Some(Ordering::Greater));
+        assert_eq!(overlap(ctrl_s, alt_s), None);
+        assert_eq!(overlap(s, KeyCode::KeyD.into()), None);
+        assert_eq!(
+            overlap(ctrl_s, KeyCode::ControlRight.into()),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(overlap(alt_s, KeyCode::ControlLeft.into()), None);
+        assert_eq!(overlap(s, Binding::AnyKey), None);
+        assert_eq!(overlap(Binding::None, Binding::None), None);
+    }
+}
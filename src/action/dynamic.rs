@@ -0,0 +1,207 @@
+//! Actions defined at runtime.
+//!
+//! Typed actions require a type for each action known at compile time. For modding or
+//! scripting, the set of actions may only be known after loading data, so [`DynAction`]
+//! can be spawned instead of [`Action<A>`] and evaluated alongside typed actions in the same
+//! context.
+//!
+//! Instead of typed [action events](crate::action::events), [`DynActionEvent`] is triggered
+//! for each state transition.
+
+use alloc::borrow::Cow;
+
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+use log::{debug, warn};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use super::{ClaimedDevice, fns::ActionFns, group::GroupCanceled};
+use crate::prelude::*;
+
+/// Component that represents a user action defined at runtime.
+///
+/// A dynamically typed counterpart of [`Action<A>`]: it has the same required components,
+/// so [`TriggerState`], [`ActionValue`], [`ActionEvents`], and [`ActionTime`] can be used to
+/// poll it. The value will be converted to [`Self::dim`].
+///
+/// If the entity has no [`Name`], it will be inserted with [`Self::name`].
+///
+/// # Examples
+///
+/// Spawn actions from a mod manifest:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_observer(run_script);
+///
+/// let manifest = [("jump", KeyCode::Space), ("dash", KeyCode::ShiftLeft)];
+/// app.world_mut().spawn((
+///     Player,
+///     Actions::<Player>::spawn(SpawnWith(move |context: &mut ActionSpawner<_>| {
+///         for (name, key) in manifest {
+///             context.spawn((
+///                 DynAction::new(name, ActionValueDim::Bool),
+///                 bindings![key],
+///             ));
+///         }
+///     })),
+/// ));
+///
+/// fn run_script(event: On<DynActionEvent>, actions: Query<&DynAction>) {
+///     if event.event == ActionEvents::START {
+///         let action = actions.get(event.action).unwrap();
+///         info!("calling `on_{}`", action.name());
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+#[component(immutable, on_add = init)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[require(
+    ActionFns::dynamic(),
+    ActionValue::zero(ActionValueDim::Bool),
+    ActionSettings,
+    TriggerState,
+    ActionEvents,
+    ActionTime,
+    ActionMock,
+    InjectedValue,
+    ActionBinding,
+    ClaimedDevice,
+    GroupCanceled
+)]
+pub struct DynAction {
+    name: Cow<'static, str>,
+    dim: ActionValueDim,
+}
+
+impl DynAction {
+    /// Creates a new action with the given name and output dimension.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>, dim: ActionValueDim) -> Self {
+        Self {
+            name: name.into(),
+            dim,
+        }
+    }
+
+    /// Returns the name of the action.
+    ///
+    /// Used only for identification, names don't have to be unique.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the dimension of the action value.
+    #[must_use]
+    pub fn dim(&self) -> ActionValueDim {
+        self.dim
+    }
+}
+
+/// Initializes the value with the action dimension and the name.
+fn init(mut world: DeferredWorld, ctx: HookContext) {
+    let action = world.get::<DynAction>(ctx.entity).unwrap();
+    let dim = action.dim;
+    let name =
+        (!world.entity(ctx.entity).contains::<Name>()).then(|| Name::new(action.name.clone()));
+
+    *world.get_mut::<ActionValue>(ctx.entity).unwrap() = ActionValue::zero(dim);
+    if let Some(name) = name {
+        world.commands().entity(ctx.entity).insert_if_new(name);
+    }
+}
+
+/// Triggered for each flag of [`ActionEvents`] for [`DynAction`].
+///
+/// Follows the same transitions and order as the typed [action events](crate::action::events).
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct DynActionEvent {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Action that triggered this event.
+    pub action: Entity,
+
+    /// Single event flag, like [`ActionEvents::START`].
+    pub event: ActionEvents,
+
+    /// Current action value.
+    pub value: ActionValue,
+
+    /// Current action state.
+    pub state: TriggerState,
+
+    /// Binding that produced the action value, if any.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
+    /// Time that this action was in [`TriggerState::Ongoing`] or [`TriggerState::Fired`] state.
+    pub elapsed_secs: f32,
+
+    /// Time that this action was in [`TriggerState::Fired`] state.
+    pub fired_secs: f32,
+}
+
+pub(super) fn store_value(action: &mut EntityMut, value: ActionValue) {
+    let dyn_action = action
+        .get::<DynAction>()
+        .expect("entity should be a dynamic action");
+
+    let dim = value.dim();
+    if dim != dyn_action.dim {
+        warn!(
+            "action `{}` (`{}`) expects `{:?}`, but got `{dim:?}`",
+            dyn_action.name,
+            action.id(),
+            dyn_action.dim
+        );
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn trigger(
+    commands: &mut Commands,
+    context: Entity,
+    action: Entity,
+    state: TriggerState,
+    events: ActionEvents,
+    value: ActionValue,
+    time: ActionTime,
+    binding: Option<Binding>,
+) {
+    for (name, event) in events.iter_names() {
+        debug!("triggering `{name}` for dynamic action `{action}` for context `{context}`");
+        commands.trigger(DynActionEvent {
+            context,
+            action,
+            event,
+            value,
+            state,
+            binding,
+            elapsed_secs: time.elapsed_secs,
+            fired_secs: time.fired_secs,
+        });
+    }
+}
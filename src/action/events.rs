@@ -137,6 +137,11 @@ pub struct Start<A: InputAction> {
 
     /// Current action state.
     pub state: TriggerState,
+
+    /// Binding that last contributed to the action state.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
 }
 
 impl<A: InputAction> Debug for Start<A> {
@@ -144,6 +149,7 @@ impl<A: InputAction> Debug for Start<A> {
         f.debug_struct("Started")
             .field("value", &self.value)
             .field("state", &self.state)
+            .field("binding", &self.binding)
             .finish()
     }
 }
@@ -215,6 +221,11 @@ pub struct Ongoing<A: InputAction> {
     /// Current action state.
     pub state: TriggerState,
 
+    /// Binding that last contributed to the action state.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
     /// Time that this action has been in [`TriggerState::Ongoing`] state.
     pub elapsed_secs: f32,
 }
@@ -224,6 +235,7 @@ impl<A: InputAction> Debug for Ongoing<A> {
         f.debug_struct("Ongoing")
             .field("value", &self.value)
             .field("state", &self.state)
+            .field("binding", &self.binding)
             .field("elapsed_secs", &self.elapsed_secs)
             .finish()
     }
@@ -286,6 +298,11 @@ pub struct Fire<A: InputAction> {
     /// Current action state.
     pub state: TriggerState,
 
+    /// Binding that last contributed to the action state.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
     /// Time that this action has been in [`TriggerState::Fired`] state.
     pub fired_secs: f32,
 
@@ -298,6 +315,7 @@ impl<A: InputAction> Debug for Fire<A> {
         f.debug_struct("Fired")
             .field("value", &self.value)
             .field("state", &self.state)
+            .field("binding", &self.binding)
             .field("fired_secs", &self.fired_secs)
             .field("elapsed_secs", &self.elapsed_secs)
             .finish()
@@ -369,6 +387,11 @@ pub struct Cancel<A: InputAction> {
     /// Current action state.
     pub state: TriggerState,
 
+    /// Binding that last contributed to the action state.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
     /// Time that this action has been in [`TriggerState::Ongoing`] state.
     pub elapsed_secs: f32,
 }
@@ -378,6 +401,7 @@ impl<A: InputAction> Debug for Cancel<A> {
         f.debug_struct("Canceled")
             .field("value", &self.value)
             .field("state", &self.state)
+            .field("binding", &self.binding)
             .field("elapsed_secs", &self.elapsed_secs)
             .finish()
     }
@@ -467,6 +491,11 @@ pub struct Complete<A: InputAction> {
     /// Current action state.
     pub state: TriggerState,
 
+    /// Binding that last contributed to the action state.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
     /// Time that this action has been in [`TriggerState::Fired`] state.
     pub fired_secs: f32,
 
@@ -479,6 +508,7 @@ impl<A: InputAction> Debug for Complete<A> {
         f.debug_struct("Completed")
             .field("value", &self.value)
             .field("state", &self.state)
+            .field("binding", &self.binding)
             .field("fired_secs", &self.fired_secs)
             .field("elapsed_secs", &self.elapsed_secs)
             .finish()
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use bevy::prelude::*;
 use log::{debug, warn};
 
@@ -10,7 +12,18 @@ use crate::prelude::{Cancel, *};
 #[component(immutable)]
 pub(crate) struct ActionFns {
     store_value: fn(&mut EntityMut, ActionValue),
-    trigger: fn(&mut Commands, Entity, Entity, TriggerState, ActionEvents, ActionValue, ActionTime),
+    trigger: fn(
+        &mut Commands,
+        Entity,
+        Entity,
+        TriggerState,
+        ActionEvents,
+        ActionValue,
+        ActionTime,
+        Option<Binding>,
+    ),
+    group: Option<ActionGroup>,
+    default_bindings: fn() -> Vec<Binding>,
 }
 
 impl ActionFns {
@@ -19,9 +32,31 @@ impl ActionFns {
         Self {
             store_value: store_value::<A>,
             trigger: trigger::<A>,
+            group: A::group(),
+            default_bindings: default_bindings::<A>,
+        }
+    }
+
+    /// Creates a new instance with function pointers for [`DynAction`](super::dynamic::DynAction).
+    pub(super) fn dynamic() -> Self {
+        Self {
+            store_value: super::dynamic::store_value,
+            trigger: super::dynamic::trigger,
+            group: None,
+            default_bindings: Vec::new,
         }
     }
 
+    /// Returns [`InputAction::group`] for the action marker `A` for which this instance was created.
+    pub(crate) fn group(&self) -> Option<ActionGroup> {
+        self.group
+    }
+
+    /// Returns [`InputAction::default_bindings`] for the action marker `A` for which this instance was created.
+    pub(crate) fn default_bindings(&self) -> Vec<Binding> {
+        (self.default_bindings)()
+    }
+
     /// Stores the given value in the entity's [`Action<A>`] component for which this instance was created.
     pub(crate) fn store_value(&self, action: &mut EntityMut, value: ActionValue) {
         (self.store_value)(action, value);
@@ -38,11 +73,18 @@ impl ActionFns {
         events: ActionEvents,
         value: ActionValue,
         time: ActionTime,
+        binding: Option<Binding>,
     ) {
-        (self.trigger)(commands, context, action, state, events, value, time);
+        (self.trigger)(
+            commands, context, action, state, events, value, time, binding,
+        );
     }
 }
 
+fn default_bindings<A: InputAction>() -> Vec<Binding> {
+    A::default_bindings().into_iter().collect()
+}
+
 fn store_value<A: InputAction>(action: &mut EntityMut, value: ActionValue) {
     let dim = value.dim();
     if dim != A::Output::DIM {
@@ -61,6 +103,7 @@ fn store_value<A: InputAction>(action: &mut EntityMut, value: ActionValue) {
     **action = value.into();
 }
 
+#[allow(clippy::too_many_arguments)]
 fn trigger<A: InputAction>(
     commands: &mut Commands,
     context: Entity,
@@ -69,6 +112,7 @@ fn trigger<A: InputAction>(
     events: ActionEvents,
     value: ActionValue,
     time: ActionTime,
+    binding: Option<Binding>,
 ) {
     for (name, event) in events.iter_names() {
         debug!(
@@ -83,6 +127,7 @@ fn trigger<A: InputAction>(
                     action,
                     value: value.into(),
                     state,
+                    binding,
                 };
                 commands.trigger(event);
             }
@@ -92,6 +137,7 @@ fn trigger<A: InputAction>(
                     action,
                     value: value.into(),
                     state,
+                    binding,
                     elapsed_secs: time.elapsed_secs,
                 };
                 commands.trigger(event);
@@ -102,6 +148,7 @@ fn trigger<A: InputAction>(
                     action,
                     value: value.into(),
                     state,
+                    binding,
                     fired_secs: time.fired_secs,
                     elapsed_secs: time.elapsed_secs,
                 };
@@ -113,6 +160,7 @@ fn trigger<A: InputAction>(
                     action,
                     value: value.into(),
                     state,
+                    binding,
                     elapsed_secs: time.elapsed_secs,
                 };
                 commands.trigger(event);
@@ -123,6 +171,7 @@ fn trigger<A: InputAction>(
                     action,
                     value: value.into(),
                     state,
+                    binding,
                     fired_secs: time.fired_secs,
                     elapsed_secs: time.elapsed_secs,
                 };
@@ -228,6 +277,7 @@ mod tests {
             events,
             false.into(),
             Default::default(),
+            None,
         );
 
         world.flush();
@@ -0,0 +1,74 @@
+//! Mutually exclusive actions.
+
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Makes actions of the same group within a context mutually exclusive.
+///
+/// When an action transitions to [`TriggerState::Fired`](crate::prelude::TriggerState::Fired),
+/// other actions from its group in the same context are transitioned to
+/// [`TriggerState::None`](crate::prelude::TriggerState::None), triggering
+/// [`Cancel`](crate::prelude::Cancel) if they were active. Canceled actions stay inactive until
+/// their inputs would evaluate them to [`TriggerState::None`](crate::prelude::TriggerState::None),
+/// so holding the input of a canceled action doesn't take the group back. If multiple actions
+/// start firing on the same frame, the first evaluated one wins.
+///
+/// Can be declared for all actions of a type via [`InputAction::group`](crate::prelude::InputAction::group)
+/// or inserted on an action entity, which overrides the group from the trait.
+///
+/// # Examples
+///
+/// Switching between weapon slots, where only the last selected slot stays active:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// const WEAPON_SLOTS: ActionGroup = ActionGroup(0);
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (Action::<Primary>::new(), bindings![KeyCode::Digit1]),
+///         (Action::<Secondary>::new(), bindings![KeyCode::Digit2]),
+///         (Action::<Melee>::new(), WEAPON_SLOTS, bindings![KeyCode::Digit3]),
+///     ]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// #[action_group(WEAPON_SLOTS)]
+/// struct Primary;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// #[action_group(WEAPON_SLOTS)]
+/// struct Secondary;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Melee;
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq, Hash)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ActionGroup(pub u32);
+
+/// Whether the action was canceled by another action from its [`ActionGroup`].
+///
+/// Reset once the action evaluates to [`TriggerState::None`](crate::prelude::TriggerState::None).
+#[derive(Component, Deref, DerefMut, Default)]
+pub(crate) struct GroupCanceled(bool);
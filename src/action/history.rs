@@ -0,0 +1,315 @@
+//! Recent values of an action, such as for input displays in training modes.
+
+use alloc::collections::VecDeque;
+use core::fmt::{self, Display, Formatter};
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Records the values of an action for each evaluation of its context.
+///
+/// Consecutive evaluations with the same value are merged into a single [`HistoryEntry`]
+/// with the number of frames it was held, which is the form fighting-game input displays use.
+/// Only the last [`Self::capacity`] entries are kept.
+///
+/// For directional actions, [`Self::numpad`] returns the entries in numpad notation.
+///
+/// Should be inserted on an action entity.
+///
+/// # Examples
+///
+/// Display the inputs in a training mode:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Move>::new(),
+///             ActionHistory::default(),
+///             Bindings::spawn((Cardinal::wasd_keys(), Axial::left_stick())),
+///         ),
+///         (
+///             Action::<Punch>::new(),
+///             ActionHistory::default(),
+///             bindings![KeyCode::KeyJ, GamepadButton::West],
+///         ),
+///     ]),
+/// ));
+///
+/// fn display_inputs(
+///     directions: Single<&ActionHistory, With<Action<Move>>>,
+///     punches: Single<&ActionHistory, With<Action<Punch>>>,
+/// ) {
+///     for (direction, frames) in directions.numpad(0.5).take(10) {
+///         info!("{direction} ({frames}f)");
+///     }
+///     if let Some(entry) = punches.iter().next()
+///         && entry.value.as_bool()
+///     {
+///         info!("P ({}f)", entry.frames);
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Move;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Punch;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct ActionHistory {
+    /// Maximum number of entries to keep.
+    ///
+    /// By default set to 32.
+    pub capacity: usize,
+
+    entries: VecDeque<HistoryEntry>,
+}
+
+impl ActionHistory {
+    /// Sets [`Self::capacity`].
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Records the value for a single frame.
+    pub fn record(&mut self, value: ActionValue) {
+        if let Some(last) = self.entries.front_mut()
+            && last.value == value
+        {
+            last.frames = last.frames.saturating_add(1);
+            return;
+        }
+
+        self.entries.push_front(HistoryEntry { value, frames: 1 });
+        self.entries.truncate(self.capacity);
+    }
+
+    /// Returns the entries from the most recent to the oldest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &HistoryEntry> + ExactSizeIterator {
+        self.entries.iter()
+    }
+
+    /// Returns the entries in numpad notation from the most recent to the oldest.
+    ///
+    /// Values are converted with [`NumpadDirection::new`] using the given threshold.
+    /// Consecutive entries with the same direction are merged, summing their frames.
+    pub fn numpad(&self, threshold: f32) -> impl Iterator<Item = (NumpadDirection, u32)> {
+        let mut entries = self
+            .entries
+            .iter()
+            .map(move |entry| {
+                (
+                    NumpadDirection::new(entry.value.as_axis2d(), threshold),
+                    entry.frames,
+                )
+            })
+            .peekable();
+
+        core::iter::from_fn(move || {
+            let (direction, mut frames) = entries.next()?;
+            while let Some((_, next_frames)) = entries.next_if(|&(next, _)| next == direction) {
+                frames = frames.saturating_add(next_frames);
+            }
+            Some((direction, frames))
+        })
+    }
+
+    /// Forgets all recorded entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Default for ActionHistory {
+    fn default() -> Self {
+        Self {
+            capacity: 32,
+            entries: Default::default(),
+        }
+    }
+}
+
+/// Value of an action held for consecutive frames in [`ActionHistory`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+pub struct HistoryEntry {
+    /// Recorded value.
+    pub value: ActionValue,
+
+    /// Number of context evaluations with this value.
+    pub frames: u32,
+}
+
+/// Direction in numpad notation, as used by fighting-game communities.
+///
+/// Digits correspond to the numpad layout for a character facing right,
+/// so 6 is forward, 2 is down and 5 is neutral. For a character facing left, use [`Self::mirrored`]
+/// or negate the X axis of the action with [`Negate`].
+///
+/// [`Display`] prints the digit.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+pub enum NumpadDirection {
+    /// 1
+    DownBack = 1,
+    /// 2
+    Down = 2,
+    /// 3
+    DownForward = 3,
+    /// 4
+    Back = 4,
+    /// 5
+    Neutral = 5,
+    /// 6
+    Forward = 6,
+    /// 7
+    UpBack = 7,
+    /// 8
+    Up = 8,
+    /// 9
+    UpForward = 9,
+}
+
+impl NumpadDirection {
+    /// Converts a direction where positive X is forward and positive Y is up.
+    ///
+    /// Each axis counts as pressed when its absolute value exceeds `threshold`.
+    #[must_use]
+    pub fn new(direction: Vec2, threshold: f32) -> Self {
+        let axis = |value: f32| {
+            if value > threshold {
+                1
+            } else if value < -threshold {
+                -1
+            } else {
+                0
+            }
+        };
+
+        match (axis(direction.x), axis(direction.y)) {
+            (-1, -1) => Self::DownBack,
+            (0, -1) => Self::Down,
+            (1, -1) => Self::DownForward,
+            (-1, 0) => Self::Back,
+            (1, 0) => Self::Forward,
+            (-1, 1) => Self::UpBack,
+            (0, 1) => Self::Up,
+            (1, 1) => Self::UpForward,
+            _ => Self::Neutral,
+        }
+    }
+
+    /// Returns the direction for a character facing the other way.
+    #[must_use]
+    pub fn mirrored(self) -> Self {
+        match self {
+            Self::DownBack => Self::DownForward,
+            Self::DownForward => Self::DownBack,
+            Self::Back => Self::Forward,
+            Self::Forward => Self::Back,
+            Self::UpBack => Self::UpForward,
+            Self::UpForward => Self::UpBack,
+            direction => direction,
+        }
+    }
+
+    /// Returns the numpad digit.
+    #[must_use]
+    pub fn digit(self) -> u8 {
+        self as u8
+    }
+}
+
+impl Display for NumpadDirection {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.digit())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn merging() {
+        let mut history = ActionHistory::default().with_capacity(2);
+        history.record(true.into());
+        history.record(true.into());
+        history.record(false.into());
+        history.record(true.into());
+
+        let entries: Vec<_> = history.iter().copied().collect();
+        assert_eq!(
+            entries,
+            [
+                HistoryEntry {
+                    value: true.into(),
+                    frames: 1
+                },
+                HistoryEntry {
+                    value: false.into(),
+                    frames: 1
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn numpad() {
+        let mut history = ActionHistory::default();
+        for value in [
+            Vec2::ZERO,
+            Vec2::NEG_Y,
+            Vec2::new(0.9, -0.9),
+            Vec2::new(1.0, -0.8),
+            Vec2::X,
+        ] {
+            history.record(value.into());
+        }
+
+        let notation: Vec<_> = history
+            .numpad(0.5)
+            .map(|(direction, frames)| (direction.digit(), frames))
+            .collect();
+        assert_eq!(notation, [(6, 1), (3, 2), (2, 1), (5, 1)]);
+    }
+
+    #[test]
+    fn mirrored() {
+        assert_eq!(
+            NumpadDirection::new(Vec2::new(-1.0, -1.0), 0.5),
+            NumpadDirection::DownBack
+        );
+        assert_eq!(
+            NumpadDirection::DownBack.mirrored(),
+            NumpadDirection::DownForward
+        );
+        assert_eq!(NumpadDirection::Up.mirrored(), NumpadDirection::Up);
+        assert_eq!(NumpadDirection::Forward.to_string(), "6");
+    }
+}
@@ -0,0 +1,173 @@
+//! Provides functionality for injecting values into actions from game code.
+//!
+//! Unlike [mocking](super::mock), which replaces the action state directly, injected values
+//! are evaluated like input from an additional binding: they pass through action-level
+//! [modifiers](crate::modifier) and [conditions](crate::condition), so a scripted jump
+//! still respects a cooldown and a scripted movement still gets the same scaling as the player's.
+//!
+//! Could be useful for:
+//!
+//! - Driving cutscenes and tutorials.
+//! - AI-controlled players that share actions with human players.
+
+use bevy::{ecs::error::warn, prelude::*};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Value injected into [`Action<C>`] for the next evaluation.
+///
+/// You can simply insert this component on the action entity or use either [`InjectEntityWorldMutExt`]
+/// or [`InjectEntityCommandsExt`] for a command-based API on the context entity.
+///
+/// During the next evaluation, the value is combined with the values from [`Bindings`] as if it came
+/// from an additional binding without modifiers and conditions, and then cleared. To drive the action
+/// continuously, inject a value every update.
+///
+/// Values are ignored while the action is mocked with [`ActionMock`] or its context is inactive.
+///
+/// # Examples
+///
+/// Make an AI-controlled player jump during the next update:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// fn jump(mut commands: Commands, jump: Single<Entity, With<Action<Jump>>>) {
+///     commands.entity(*jump).insert(InjectedValue::new(true));
+/// }
+/// # #[derive(InputAction)]
+/// # #[action_output(bool)]
+/// # struct Jump;
+/// ```
+#[derive(Component, Default, Debug, Clone, Copy, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct InjectedValue(pub Option<ActionValue>);
+
+impl InjectedValue {
+    /// Creates a new instance that injects the value during the next evaluation.
+    #[must_use]
+    pub fn new(value: impl Into<ActionValue>) -> Self {
+        Self(Some(value.into()))
+    }
+}
+
+/// Extension trait for [`EntityWorldMut`] that provides methods for injecting values into actions.
+pub trait InjectEntityWorldMutExt {
+    /// Injects a value into action `A` for the context `C` on the entity.
+    ///
+    /// See [`InjectEntityCommandsExt::inject`] for more details.
+    fn inject<C: Component, A: InputAction>(self, value: impl Into<ActionValue>) -> Result<()>;
+}
+
+impl InjectEntityWorldMutExt for EntityWorldMut<'_> {
+    fn inject<C: Component, A: InputAction>(self, value: impl Into<ActionValue>) -> Result<()> {
+        inject::<C, A>(value).apply(self)
+    }
+}
+
+/// Extension trait for [`EntityCommands`] that provides methods for injecting values into actions.
+pub trait InjectEntityCommandsExt {
+    /// Searches for an entity with [`Action<A>`] in [`Actions<C>`] and inserts [`InjectedValue`] to it
+    /// with the given value.
+    ///
+    /// This will emit a warning if the entity does not exist, does not have [`Actions<C>`], or those actions do not contain an [`Action<A>`].
+    ///
+    /// See also [`InjectEntityWorldMutExt::inject`].
+    ///
+    /// # Examples
+    ///
+    /// Walk forward during a cutscene. Conditions and modifiers of the action still apply.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut app = App::new();
+    /// app.world_mut().spawn((
+    ///     Player,
+    ///     actions!(Player[(
+    ///         Action::<Movement>::new(),
+    ///         Scale::splat(5.0),
+    ///         Bindings::spawn(Cardinal::wasd_keys()),
+    ///     )]),
+    /// ));
+    ///
+    /// fn walk_forward(mut commands: Commands, player: Single<Entity, With<Player>>) {
+    ///     commands
+    ///         .entity(*player)
+    ///         .inject::<Player, Movement>(Vec2::Y);
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(InputAction)]
+    /// # #[action_output(Vec2)]
+    /// # struct Movement;
+    /// ```
+    fn inject<C: Component, A: InputAction>(&mut self, value: impl Into<ActionValue>) -> &mut Self;
+
+    /// Like [`Self::inject`], but will not emit a warning in case of failure.
+    fn try_inject<C: Component, A: InputAction>(
+        &mut self,
+        value: impl Into<ActionValue>,
+    ) -> &mut Self;
+}
+
+impl InjectEntityCommandsExt for EntityCommands<'_> {
+    fn inject<C: Component, A: InputAction>(&mut self, value: impl Into<ActionValue>) -> &mut Self {
+        self.queue_handled(inject::<C, A>(value), warn)
+    }
+
+    fn try_inject<C: Component, A: InputAction>(
+        &mut self,
+        value: impl Into<ActionValue>,
+    ) -> &mut Self {
+        self.queue_silenced(inject::<C, A>(value))
+    }
+}
+
+/// Injects a value into action `A` for the context `C` on the entity.
+///
+/// See also [`InjectEntityCommandsExt::inject`] and [`InjectEntityWorldMutExt::inject`].
+pub fn inject<C: Component, A: InputAction>(
+    value: impl Into<ActionValue>,
+) -> impl EntityCommand<Result<()>> {
+    let value = value.into();
+    move |entity: EntityWorldMut| -> Result<()> {
+        let context = entity.id();
+        let actions = entity.get::<Actions<C>>().ok_or_else(|| {
+            format!(
+                "entity {} has no `{}`",
+                context,
+                ShortName::of::<Actions<C>>(),
+            )
+        })?;
+
+        let action = actions
+            .iter()
+            .find(|&a| entity.world().get::<Action<A>>(a).is_some())
+            .ok_or_else(|| {
+                format!(
+                    "entity {} has no `{}` in its `{}`",
+                    context,
+                    ShortName::of::<Action<A>>(),
+                    ShortName::of::<Actions<C>>(),
+                )
+            })?;
+
+        // Not an archetype move: `Action` requires `InjectedValue`.
+        let world = entity.into_world_mut();
+        world.entity_mut(action).insert(InjectedValue::new(value));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,237 @@
+//! Detection of inhuman input patterns, such as macros and bots.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy::prelude::*;
+use log::debug;
+
+/// Checks presses of an action for inhuman patterns and triggers [`InputAnomaly`] when detected.
+///
+/// Presses are counted on [`ActionEvents::START`](crate::prelude::ActionEvents::START) using
+/// real time. Once [`Self::samples`] presses are collected, the following heuristics are checked:
+/// - The average press rate exceeds [`Self::max_rate`].
+/// - The difference between the longest and the shortest interval between presses is below
+///   [`Self::min_jitter`], meaning the timing is too uniform for a human.
+///
+/// After an anomaly is reported, collected presses are cleared, so each report is based on new samples.
+///
+/// These heuristics can produce false positives, for example with turbo buttons.
+/// Treat the events as a signal for further analysis, such as on an authoritative server,
+/// rather than as proof of cheating.
+///
+/// Should be inserted on an action entity.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[(
+///         Action::<Fire>::new(),
+///         InputMonitor::default(),
+///         bindings![MouseButton::Left],
+///     )]),
+/// ));
+///
+/// fn report(anomaly: On<InputAnomaly>) {
+///     warn!("`{}` fired suspiciously: {:?}", anomaly.action, anomaly.kind);
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Fire;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct InputMonitor {
+    /// Maximum average number of presses per second.
+    ///
+    /// By default set to 15.0.
+    pub max_rate: f32,
+
+    /// Minimum spread of intervals between presses.
+    ///
+    /// By default set to 2 milliseconds.
+    pub min_jitter: Duration,
+
+    /// Number of presses to collect before checking.
+    ///
+    /// By default set to 10.
+    pub samples: usize,
+
+    presses: VecDeque<Duration>,
+}
+
+impl InputMonitor {
+    /// Sets [`Self::max_rate`].
+    #[must_use]
+    pub fn with_max_rate(mut self, max_rate: f32) -> Self {
+        self.max_rate = max_rate;
+        self
+    }
+
+    /// Sets [`Self::min_jitter`].
+    #[must_use]
+    pub fn with_min_jitter(mut self, min_jitter: Duration) -> Self {
+        self.min_jitter = min_jitter;
+        self
+    }
+
+    /// Sets [`Self::samples`].
+    #[must_use]
+    pub fn with_samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Records a press at the given time and returns the detected anomaly, if any.
+    pub fn record(&mut self, now: Duration) -> Option<AnomalyKind> {
+        self.presses.push_back(now);
+        while self.presses.len() > self.samples.max(2) {
+            self.presses.pop_front();
+        }
+        if self.presses.len() < self.samples.max(2) {
+            return None;
+        }
+
+        let first = *self.presses.front().unwrap();
+        let elapsed = now.saturating_sub(first).as_secs_f32();
+        let intervals = (self.presses.len() - 1) as f32;
+        let rate = if elapsed > 0.0 {
+            intervals / elapsed
+        } else {
+            f32::INFINITY
+        };
+
+        let kind = if rate > self.max_rate {
+            Some(AnomalyKind::Rate { rate })
+        } else {
+            let (min, max) = self
+                .presses
+                .iter()
+                .zip(self.presses.iter().skip(1))
+                .map(|(&previous, &next)| next - previous)
+                .fold((Duration::MAX, Duration::ZERO), |(min, max), interval| {
+                    (min.min(interval), max.max(interval))
+                });
+            let jitter = max - min;
+            (jitter < self.min_jitter).then_some(AnomalyKind::UniformTiming { jitter })
+        };
+
+        if kind.is_some() {
+            self.presses.clear();
+        }
+
+        kind
+    }
+}
+
+impl Default for InputMonitor {
+    fn default() -> Self {
+        Self {
+            max_rate: 15.0,
+            min_jitter: Duration::from_millis(2),
+            samples: 10,
+            presses: Default::default(),
+        }
+    }
+}
+
+/// Heuristic that detected an [`InputAnomaly`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+pub enum AnomalyKind {
+    /// The action was pressed faster than [`InputMonitor::max_rate`].
+    Rate {
+        /// Average presses per second.
+        rate: f32,
+    },
+    /// The intervals between presses varied less than [`InputMonitor::min_jitter`].
+    UniformTiming {
+        /// Difference between the longest and the shortest interval.
+        jitter: Duration,
+    },
+}
+
+/// Triggered when [`InputMonitor`] detects an inhuman input pattern.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct InputAnomaly {
+    /// Action that triggered the event.
+    #[event_target]
+    pub action: Entity,
+
+    /// Entity with the context of the action.
+    pub context: Entity,
+
+    /// Detected anomaly.
+    pub kind: AnomalyKind,
+}
+
+impl InputAnomaly {
+    pub(crate) fn new(action: Entity, context: Entity, kind: AnomalyKind) -> Self {
+        debug!("detected `{kind:?}` for `{action}`");
+        Self {
+            action,
+            context,
+            kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate() {
+        let mut monitor = InputMonitor::default().with_max_rate(5.0).with_samples(3);
+
+        assert_eq!(monitor.record(Duration::from_millis(0)), None);
+        assert_eq!(monitor.record(Duration::from_millis(100)), None);
+        assert_eq!(
+            monitor.record(Duration::from_millis(250)),
+            Some(AnomalyKind::Rate { rate: 8.0 })
+        );
+        assert!(monitor.presses.is_empty());
+    }
+
+    #[test]
+    fn uniform_timing() {
+        let mut monitor = InputMonitor::default().with_samples(3);
+
+        assert_eq!(monitor.record(Duration::from_millis(0)), None);
+        assert_eq!(monitor.record(Duration::from_millis(200)), None);
+        assert_eq!(
+            monitor.record(Duration::from_millis(401)),
+            Some(AnomalyKind::UniformTiming {
+                jitter: Duration::from_millis(1)
+            })
+        );
+    }
+
+    #[test]
+    fn human() {
+        let mut monitor = InputMonitor::default().with_samples(3);
+
+        assert_eq!(monitor.record(Duration::from_millis(0)), None);
+        assert_eq!(monitor.record(Duration::from_millis(180)), None);
+        assert_eq!(monitor.record(Duration::from_millis(410)), None);
+        assert_eq!(
+            monitor.record(Duration::from_millis(600)),
+            None,
+            "should check only the latest samples"
+        );
+    }
+}
@@ -36,6 +36,7 @@ pub struct ActionOf<C: Component> {
     #[relationship]
     entity: Entity,
     #[cfg_attr(feature = "reflect", reflect(ignore))]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     marker: PhantomData<C>,
 }
 
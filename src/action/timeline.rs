@@ -0,0 +1,115 @@
+//! Assertions for action states over multiple frames.
+//!
+//! Tests for conditions usually update the app in a loop and check the state after each update.
+//! [`ActionTimelineExt::assert_timeline`] does the same from a list of expected state changes.
+
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Extension trait for [`App`] to check action states over multiple frames.
+pub trait ActionTimelineExt {
+    /// Updates the app frame by frame and checks the [`TriggerState`] of the [`Action<A>`] after each update.
+    ///
+    /// Each item is a frame index and the state expected from this frame onward, until the next item.
+    /// Frame 0 is the first update performed by this method, and updates stop after the last listed frame.
+    /// Frames before the first item are not checked.
+    ///
+    /// Inputs are not changed, so press or release them between calls. For time-based conditions,
+    /// insert [`TimeUpdateStrategy::ManualDuration`](bevy::time::TimeUpdateStrategy::ManualDuration)
+    /// to advance time by a fixed step each frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state doesn't match, if frames are not in ascending order,
+    /// or if there isn't exactly one [`Action<A>`] in the world.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    ///
+    /// use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+    ///     .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(100)))
+    ///     .add_input_context::<Player>()
+    ///     .finish();
+    ///
+    /// app.world_mut().spawn((
+    ///     Player,
+    ///     actions!(Player[(Action::<Jump>::new(), Hold::new(0.5), bindings![KeyCode::Space])]),
+    /// ));
+    ///
+    /// app.update();
+    ///
+    /// app.press(KeyCode::Space).assert_timeline::<Jump>([
+    ///     (0, TriggerState::Ongoing),
+    ///     (4, TriggerState::Fired),
+    /// ]);
+    ///
+    /// app.release(KeyCode::Space)
+    ///     .assert_timeline::<Jump>([(0, TriggerState::None)]);
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct Jump;
+    /// ```
+    fn assert_timeline<A: InputAction>(
+        &mut self,
+        timeline: impl IntoIterator<Item = (usize, TriggerState)>,
+    ) -> &mut Self;
+}
+
+impl ActionTimelineExt for App {
+    #[track_caller]
+    fn assert_timeline<A: InputAction>(
+        &mut self,
+        timeline: impl IntoIterator<Item = (usize, TriggerState)>,
+    ) -> &mut Self {
+        let timeline: Vec<_> = timeline.into_iter().collect();
+        assert!(
+            timeline.is_sorted_by(|(a, _), (b, _)| a < b),
+            "frames should be in ascending order"
+        );
+        let Some(&(last_frame, _)) = timeline.last() else {
+            return self;
+        };
+
+        let mut expected = None;
+        let mut keyframes = timeline.into_iter().peekable();
+        for frame in 0..=last_frame {
+            self.update();
+
+            if let Some((_, state)) = keyframes.next_if(|&(keyframe, _)| keyframe == frame) {
+                expected = Some(state);
+            }
+
+            if let Some(expected) = expected {
+                let state = *self
+                    .world_mut()
+                    .query_filtered::<&TriggerState, With<Action<A>>>()
+                    .single(self.world())
+                    .unwrap_or_else(|e| {
+                        panic!("should have a single `{}`: {e}", ShortName::of::<A>())
+                    });
+
+                assert_eq!(
+                    state,
+                    expected,
+                    "`{}` should be in state `{expected:?}` at frame {frame}",
+                    ShortName::of::<A>()
+                );
+            }
+        }
+
+        self
+    }
+}
@@ -0,0 +1,190 @@
+//! Detection of actions stuck in an active state.
+
+use alloc::vec::Vec;
+
+use bevy::{ecs::entity::EntityHashSet, prelude::*};
+use log::warn;
+
+use crate::{action::fns::ActionFns, prelude::*};
+
+/// Reports actions that stay in [`TriggerState::Ongoing`] or [`TriggerState::Fired`] for suspiciously long.
+///
+/// Not present by default. Insert this resource to trigger [`ActionStuck`] once
+/// [`ActionTime::elapsed_secs`] of an action exceeds [`Self::threshold`].
+/// Each action is reported once until it returns to [`TriggerState::None`].
+///
+/// Intended for development to catch stuck-input bugs, such as a key released while the window
+/// was unfocused. Actions that are legitimately held for a long time, like movement, will also
+/// be reported, so pick a threshold above their usual duration.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+///     .insert_resource(ActionWatchdog::new(60.0))
+///     .add_observer(report);
+///
+/// fn report(stuck: On<ActionStuck>) {
+///     error!(
+///         "`{}` is stuck from `{:?}` with conditions {:?}",
+///         stuck.action, stuck.binding, stuck.conditions
+///     );
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct ActionWatchdog {
+    /// Time in seconds after which an active action is considered stuck.
+    ///
+    /// By default set to 30.0.
+    pub threshold: f32,
+
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    reported: EntityHashSet,
+}
+
+impl ActionWatchdog {
+    /// Creates a new instance with the given threshold in seconds.
+    #[must_use]
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            threshold,
+            reported: Default::default(),
+        }
+    }
+
+    /// Returns `true` if the action should be reported as stuck.
+    pub(crate) fn check(&mut self, action: Entity, state: TriggerState, time: ActionTime) -> bool {
+        if state == TriggerState::None {
+            self.reported.remove(&action);
+            return false;
+        }
+
+        time.elapsed_secs >= self.threshold && self.reported.insert(action)
+    }
+}
+
+impl Default for ActionWatchdog {
+    fn default() -> Self {
+        Self::new(30.0)
+    }
+}
+
+pub(crate) fn forget_action(
+    remove: On<Remove, ActionFns>,
+    watchdog: Option<ResMut<ActionWatchdog>>,
+) {
+    if let Some(mut watchdog) = watchdog {
+        watchdog.reported.remove(&remove.entity);
+    }
+}
+
+/// Triggered when [`ActionWatchdog`] detects a stuck action.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct ActionStuck {
+    /// Action that triggered the event.
+    #[event_target]
+    pub action: Entity,
+
+    /// Entity with the context of the action.
+    pub context: Entity,
+
+    /// Current state of the action.
+    pub state: TriggerState,
+
+    /// Time the action was in [`TriggerState::Ongoing`] and [`TriggerState::Fired`] states.
+    pub elapsed_secs: f32,
+
+    /// Binding that last contributed to the state of the action.
+    ///
+    /// See [`ActionBinding`] for details.
+    pub binding: Option<Binding>,
+
+    /// Names of conditions on the responsible binding followed by the conditions on the action.
+    pub conditions: Vec<ShortName<'static>>,
+}
+
+impl ActionStuck {
+    pub(crate) fn new(
+        action: Entity,
+        context: Entity,
+        state: TriggerState,
+        time: ActionTime,
+        binding: Option<Binding>,
+        conditions: Vec<ShortName<'static>>,
+    ) -> Self {
+        warn!(
+            "`{action}` is stuck in `{state:?}` for {:.1} seconds from `{binding:?}` with conditions {conditions:?}",
+            time.elapsed_secs
+        );
+        Self {
+            action,
+            context,
+            state,
+            elapsed_secs: time.elapsed_secs,
+            binding,
+            conditions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check() {
+        let mut watchdog = ActionWatchdog::new(1.0);
+        let action = Entity::PLACEHOLDER;
+        let stuck = ActionTime {
+            elapsed_secs: 1.5,
+            fired_secs: 1.5,
+        };
+
+        assert!(!watchdog.check(action, TriggerState::Fired, ActionTime::default()));
+        assert!(watchdog.check(action, TriggerState::Fired, stuck));
+        assert!(
+            !watchdog.check(action, TriggerState::Fired, stuck),
+            "should report only once"
+        );
+
+        assert!(!watchdog.check(action, TriggerState::None, ActionTime::default()));
+        assert!(
+            watchdog.check(action, TriggerState::Ongoing, stuck),
+            "should report again after release"
+        );
+    }
+
+    #[test]
+    fn forget() {
+        let mut world = World::new();
+        world.add_observer(forget_action);
+
+        let action = world.spawn(ActionFns::new::<Test>()).id();
+        let mut watchdog = ActionWatchdog::new(1.0);
+        let stuck = ActionTime {
+            elapsed_secs: 1.5,
+            fired_secs: 1.5,
+        };
+        assert!(watchdog.check(action, TriggerState::Fired, stuck));
+        world.insert_resource(watchdog);
+
+        world.despawn(action);
+        assert!(
+            world.resource::<ActionWatchdog>().reported.is_empty(),
+            "despawned actions should be forgotten"
+        );
+    }
+
+    #[derive(InputAction)]
+    #[action_output(bool)]
+    struct Test;
+}
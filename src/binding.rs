@@ -9,10 +9,38 @@
 //! When defining input bindings, you may find the collection of [preset bindings](crate::preset) useful
 //! to reduce boilerplate and demonstrate common input patterns and transformations.
 //!
+//! Since bindings are regular entities, they are visible to inspectors and scenes, and can be overridden
+//! per context entity by despawning and spawning [`BindingOf`] entities. See [`rebind`] for a command-based
+//! API and [`patch`] for applying a set of changes. Bindings of different actions can be linked with
+//! [`BindingGroup`] to rebind them together.
+//!
 //! For an exhaustive list of available input devices, see the [`Binding`] enum.
 
+#[cfg(feature = "asset")]
+pub mod asset;
+pub mod capture;
+pub mod default_profile;
+pub mod display_map;
+pub mod fallback;
+pub mod glyphs;
+pub mod group;
+pub mod history;
+pub mod lock_keys;
+pub mod logical_keys;
+#[cfg(feature = "macro_pad")]
+pub mod macro_pad;
+pub mod media_keys;
+pub mod mock_input;
 pub mod mod_keys;
+pub mod mouse_drag;
+pub mod patch;
+pub mod rebind;
 pub mod relationship;
+pub mod scroll_steps;
+pub mod ui_buttons;
+pub mod virtual_inputs;
+#[cfg(feature = "voice")]
+pub mod voice;
 
 use core::fmt::{self, Display, Formatter};
 
@@ -24,7 +52,7 @@ use log::{Level, error, log_enabled, warn};
 #[cfg(feature = "serialize")]
 use serde::{Deserialize, Serialize};
 
-use crate::prelude::*;
+use crate::{context::fixed_rate::StepInterpolation, prelude::*};
 
 /// A an input bound to an [`Action<C>`].
 ///
@@ -35,11 +63,11 @@ use crate::prelude::*;
 ///
 /// If the action's dimension differs from the captured input, it will be converted using
 /// [`ActionValue::convert`](crate::action::value::ActionValue::convert).
-#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Component, Debug, PartialEq)
+    reflect(Clone, Component, Debug, Hash, PartialEq)
 )]
 #[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[cfg_attr(
@@ -47,10 +75,62 @@ use crate::prelude::*;
     reflect(Serialize, Deserialize)
 )]
 #[component(on_insert = on_insert, immutable)]
-#[require(FirstActivation)]
+#[require(FirstActivation, StepInterpolation)]
 pub enum Binding {
     /// Keyboard button, captured as [`ActionValue::Bool`].
+    ///
+    /// Modifier keys, like [`KeyCode::ShiftLeft`], can be used as `key` to bind them as regular buttons
+    /// (e.g. Shift for sprint). Such bindings and bindings that use the same key in `mod_keys`
+    /// don't consume each other, so both actions will trigger.
+    ///
+    /// Media and consumer keys, like [`KeyCode::MediaPlayPause`], are also supported.
+    /// See [`media_keys`] for platform details.
     Keyboard { key: KeyCode, mod_keys: ModKeys },
+    /// Keyboard key identified by the character it types, captured as [`ActionValue::Bool`].
+    ///
+    /// Unlike [`Self::Keyboard`], follows the keyboard layout. Use it for shortcuts that are
+    /// remembered by their letter and keep [`Self::Keyboard`] for positional controls, like movement.
+    /// See [`logical_keys`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Undo with the key labeled Z on any layout, but move with keys at the WASD location.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// # let mut world = World::new();
+    /// world.spawn((
+    ///     Editor,
+    ///     actions!(Editor[
+    ///         (
+    ///             Action::<Undo>::new(),
+    ///             bindings!['z'.with_mod_keys(ModKeys::CONTROL)],
+    ///         ),
+    ///         (
+    ///             Action::<Pan>::new(),
+    ///             Bindings::spawn(Cardinal::wasd_keys()),
+    ///         ),
+    ///     ]),
+    /// ));
+    ///
+    /// #[derive(Component)]
+    /// struct Editor;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct Undo;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(Vec2)]
+    /// struct Pan;
+    /// ```
+    LogicalKey { character: char, mod_keys: ModKeys },
+    /// Lock state of a keyboard key from [`LockKeys`], captured as [`ActionValue::Bool`].
+    ///
+    /// Unlike [`Self::Keyboard`] with the same key, stays active while the lock is enabled.
+    LockKey(LockKey),
     /// Mouse button, captured as [`ActionValue::Bool`].
     MouseButton {
         button: MouseButton,
@@ -58,6 +138,38 @@ pub enum Binding {
     },
     /// Mouse movement, captured as [`ActionValue::Axis2D`].
     MouseMotion { mod_keys: ModKeys },
+    /// Mouse movement since the button was pressed, captured as [`ActionValue::Axis2D`].
+    ///
+    /// Zero while the button is released. See [`mouse_drag`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Select units with a box while the left button is held.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// # let mut world = World::new();
+    /// world.spawn((
+    ///     Player,
+    ///     actions!(Player[(
+    ///         Action::<BoxSelect>::new(),
+    ///         bindings![Binding::mouse_drag(MouseButton::Left)],
+    ///     )]),
+    /// ));
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(Vec2)]
+    /// struct BoxSelect;
+    /// ```
+    MouseDrag {
+        button: MouseButton,
+        mod_keys: ModKeys,
+    },
     /// Mouse wheel, captured as [`ActionValue::Axis2D`].
     ///
     /// <div class="warning">
@@ -92,6 +204,103 @@ pub enum Binding {
     /// struct PlayerCam;
     /// ```
     MouseWheel { mod_keys: ModKeys },
+    /// Mouse wheel step in a direction, captured as [`ActionValue::Bool`].
+    ///
+    /// Active for a single frame when the wheel scrolls by at least one whole line in the direction.
+    /// [`ScrollDirection::Left`] and [`ScrollDirection::Right`] correspond to tilting the wheel.
+    /// See [`scroll_steps`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Page through an inventory by wheel notches.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// actions!(Inventory[
+    ///     (
+    ///         Action::<NextPage>::new(),
+    ///         bindings![ScrollDirection::Down, ScrollDirection::Right],
+    ///     ),
+    ///     (
+    ///         Action::<PrevPage>::new(),
+    ///         bindings![ScrollDirection::Up, ScrollDirection::Left],
+    ///     ),
+    /// ]);
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct NextPage;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct PrevPage;
+    ///
+    /// #[derive(Component)]
+    /// struct Inventory;
+    /// ```
+    MouseWheelStep {
+        direction: ScrollDirection,
+        mod_keys: ModKeys,
+    },
+    /// Vertical mouse wheel delta in lines, captured as [`ActionValue::Axis1D`].
+    ///
+    /// Unlike [`Self::MouseWheel`], deltas reported in pixels are converted to lines,
+    /// so the value is consistent between wheels and touchpads. High-resolution wheels
+    /// report fractions of a line. See [`scroll_steps`] for details.
+    MouseWheelPrecise { mod_keys: ModKeys },
+    /// Cursor position in logical pixels, captured as [`ActionValue::Axis2D`].
+    ///
+    /// Read from [`CursorPosition`], which is updated from the primary window with the `window` feature.
+    /// The origin is at the top-left corner of the window. When the cursor is outside the window,
+    /// the value is zero.
+    ///
+    /// Use the `ScreenToWorld` modifier from the `camera` feature to get world coordinates.
+    MousePosition { mod_keys: ModKeys },
+    /// UI node, captured as [`ActionValue::Bool`].
+    ///
+    /// Active while the node is pressed. Requires the `ui` feature, see [`ui_buttons`] for details.
+    ///
+    /// # Examples
+    ///
+    /// Trigger the same action by a hotkey and an on-screen button.
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// # let mut world = World::new();
+    /// // Usually a `Button` node.
+    /// let button = world.spawn_empty().id();
+    /// world.spawn((
+    ///     Player,
+    ///     actions!(Player[(
+    ///         Action::<OpenInventory>::new(),
+    ///         bindings![KeyCode::KeyI, Binding::UiButton(button)],
+    ///     )]),
+    /// ));
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct OpenInventory;
+    /// ```
+    UiButton(Entity),
+    /// Input driven by application events, captured as the value provided by the event mapping.
+    ///
+    /// Active for a single update after the event. See [`virtual_inputs`] for details.
+    Virtual(VirtualInput),
+    /// Macro pad key, captured as [`ActionValue::Bool`].
+    ///
+    /// Read from [`MacroPadKeys`], which is updated by an external backend. See [`macro_pad`] for details.
+    /// Doesn't support keyboard modifiers and has no [`InputDevice`].
+    ///
+    /// Requires the `macro_pad` feature.
+    #[cfg(feature = "macro_pad")]
+    MacroPadKey(MacroPadKey),
     /// Gamepad button, captured as [`ActionValue::Axis1D`].
     GamepadButton(GamepadButton),
     /// Gamepad stick axis, captured as [`ActionValue::Axis1D`].
@@ -105,6 +314,16 @@ pub enum Binding {
     /// inputs, not just the one that activated it. To have an action with this binding
     /// evaluated first, place it in a higher-priority context.
     AnyKey,
+    /// Any key, button, gamepad stick or mouse wheel step, captured as [`ActionValue::Bool`].
+    ///
+    /// Like [`Self::AnyKey`], but also activates when a gamepad axis is beyond
+    /// [`ACTUATION_THRESHOLD`](capture::ACTUATION_THRESHOLD) or the mouse wheel steps.
+    /// Useful for "press any key to continue" prompts that should also react to sticks.
+    /// Mouse motion is ignored, since it's too easy to trigger accidentally.
+    ///
+    /// Consumes inputs the same way as [`Self::AnyKey`]. To get the actuated inputs
+    /// themselves, use [`InputCapture`].
+    AnyInput,
     /// Doesn't correspond to any input, captured as [`ActionValue::Bool`] with `false`.
     ///
     /// Useful for expressing empty bindings in [presets](crate::preset).
@@ -112,6 +331,15 @@ pub enum Binding {
 }
 
 impl Binding {
+    /// Returns [`Self::LogicalKey`] without keyboard modifiers.
+    #[must_use]
+    pub const fn logical_key(character: char) -> Self {
+        Self::LogicalKey {
+            character,
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
     /// Returns [`Self::MouseMotion`] without keyboard modifiers.
     #[must_use]
     pub const fn mouse_motion() -> Self {
@@ -120,6 +348,15 @@ impl Binding {
         }
     }
 
+    /// Returns [`Self::MouseDrag`] without keyboard modifiers.
+    #[must_use]
+    pub const fn mouse_drag(button: MouseButton) -> Self {
+        Self::MouseDrag {
+            button,
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
     /// Returns [`Self::MouseWheel`] without keyboard modifiers.
     #[must_use]
     pub const fn mouse_wheel() -> Self {
@@ -128,6 +365,31 @@ impl Binding {
         }
     }
 
+    /// Returns [`Self::MouseWheelStep`] without keyboard modifiers.
+    #[must_use]
+    pub const fn mouse_wheel_step(direction: ScrollDirection) -> Self {
+        Self::MouseWheelStep {
+            direction,
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
+    /// Returns [`Self::MouseWheelPrecise`] without keyboard modifiers.
+    #[must_use]
+    pub const fn mouse_wheel_precise() -> Self {
+        Self::MouseWheelPrecise {
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
+    /// Returns [`Self::MousePosition`] without keyboard modifiers.
+    #[must_use]
+    pub const fn mouse_position() -> Self {
+        Self::MousePosition {
+            mod_keys: ModKeys::empty(),
+        }
+    }
+
     /// Returns the amount of associated keyboard modifiers.
     #[must_use]
     pub fn mod_keys_count(self) -> usize {
@@ -139,16 +401,55 @@ impl Binding {
     pub const fn mod_keys(self) -> ModKeys {
         match self {
             Binding::Keyboard { mod_keys, .. }
+            | Binding::LogicalKey { mod_keys, .. }
             | Binding::MouseButton { mod_keys, .. }
             | Binding::MouseMotion { mod_keys }
-            | Binding::MouseWheel { mod_keys } => mod_keys,
-            Binding::GamepadButton(_)
+            | Binding::MouseDrag { mod_keys, .. }
+            | Binding::MouseWheel { mod_keys }
+            | Binding::MouseWheelStep { mod_keys, .. }
+            | Binding::MouseWheelPrecise { mod_keys }
+            | Binding::MousePosition { mod_keys } => mod_keys,
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(_) => ModKeys::empty(),
+            Binding::LockKey(_)
+            | Binding::UiButton(_)
+            | Binding::Virtual(_)
+            | Binding::GamepadButton(_)
             | Binding::GamepadAxis(_)
             | Binding::AnyKey
+            | Binding::AnyInput
             | Binding::None => ModKeys::empty(),
         }
     }
 
+    /// Returns the device that produces input for this binding.
+    ///
+    /// Returns `None` for [`Self::UiButton`], [`Self::Virtual`], [`Self::AnyKey`],
+    /// [`Self::AnyInput`] and [`Self::None`], since they don't correspond to a single device.
+    #[must_use]
+    pub const fn device(self) -> Option<InputDevice> {
+        match self {
+            Binding::Keyboard { .. } | Binding::LogicalKey { .. } | Binding::LockKey(_) => {
+                Some(InputDevice::Keyboard)
+            }
+            Binding::MouseButton { .. }
+            | Binding::MouseMotion { .. }
+            | Binding::MouseDrag { .. }
+            | Binding::MouseWheel { .. }
+            | Binding::MouseWheelStep { .. }
+            | Binding::MouseWheelPrecise { .. }
+            | Binding::MousePosition { .. } => Some(InputDevice::Mouse),
+            Binding::GamepadButton(_) | Binding::GamepadAxis(_) => Some(InputDevice::Gamepad),
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(_) => None,
+            Binding::UiButton(_)
+            | Binding::Virtual(_)
+            | Binding::AnyKey
+            | Binding::AnyInput
+            | Binding::None => None,
+        }
+    }
+
     /// Returns new instance without any keyboard modifiers.
     ///
     /// # Panics
@@ -169,12 +470,25 @@ impl Display for Binding {
 
         match self {
             Binding::Keyboard { key, .. } => write!(f, "{key:?}"),
+            Binding::LogicalKey { character, .. } => {
+                write!(f, "{}", character.to_uppercase())
+            }
+            Binding::LockKey(key) => write!(f, "{key}"),
             Binding::MouseButton { button, .. } => write!(f, "Mouse {button:?}"),
             Binding::MouseMotion { .. } => write!(f, "Mouse Motion"),
+            Binding::MouseDrag { button, .. } => write!(f, "Mouse {button:?} Drag"),
             Binding::MouseWheel { .. } => write!(f, "Scroll Wheel"),
+            Binding::MouseWheelStep { direction, .. } => write!(f, "{direction}"),
+            Binding::MouseWheelPrecise { .. } => write!(f, "Precise Scroll Wheel"),
+            Binding::MousePosition { .. } => write!(f, "Mouse Position"),
+            Binding::UiButton(_) => write!(f, "UI Button"),
+            Binding::Virtual(input) => write!(f, "{input}"),
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(key) => write!(f, "{key}"),
             Binding::GamepadButton(gamepad_button) => write!(f, "{gamepad_button:?}"),
             Binding::GamepadAxis(gamepad_axis) => write!(f, "{gamepad_axis:?}"),
             Binding::AnyKey => write!(f, "Any Key"),
+            Binding::AnyInput => write!(f, "Any Input"),
             Binding::None => write!(f, "None"),
         }
     }
@@ -189,6 +503,18 @@ impl From<KeyCode> for Binding {
     }
 }
 
+impl From<char> for Binding {
+    fn from(character: char) -> Self {
+        Self::logical_key(character)
+    }
+}
+
+impl From<LockKey> for Binding {
+    fn from(value: LockKey) -> Self {
+        Self::LockKey(value)
+    }
+}
+
 impl From<MouseButton> for Binding {
     fn from(button: MouseButton) -> Self {
         Self::MouseButton {
@@ -198,6 +524,12 @@ impl From<MouseButton> for Binding {
     }
 }
 
+impl From<ScrollDirection> for Binding {
+    fn from(direction: ScrollDirection) -> Self {
+        Self::mouse_wheel_step(direction)
+    }
+}
+
 impl From<GamepadButton> for Binding {
     fn from(value: GamepadButton) -> Self {
         Self::GamepadButton(value)
@@ -210,6 +542,43 @@ impl From<GamepadAxis> for Binding {
     }
 }
 
+impl From<VirtualInput> for Binding {
+    fn from(value: VirtualInput) -> Self {
+        Self::Virtual(value)
+    }
+}
+
+#[cfg(feature = "macro_pad")]
+impl From<MacroPadKey> for Binding {
+    fn from(value: MacroPadKey) -> Self {
+        Self::MacroPadKey(value)
+    }
+}
+
+/// Kind of device that produces input for a [`Binding`].
+///
+/// Used by [`ActionSettings::exclusive_device`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum InputDevice {
+    /// Corresponds to [`Binding::Keyboard`], [`Binding::LogicalKey`] and [`Binding::LockKey`].
+    Keyboard,
+    /// Corresponds to [`Binding::MouseButton`], [`Binding::MouseMotion`], [`Binding::MouseDrag`], [`Binding::MouseWheel`],
+    /// [`Binding::MouseWheelStep`], [`Binding::MouseWheelPrecise`] and [`Binding::MousePosition`].
+    Mouse,
+    /// Corresponds to [`Binding::GamepadButton`] and [`Binding::GamepadAxis`].
+    Gamepad,
+}
+
 /// A trait to ergonomically assign keyboard modifiers to any type that can be converted into a [`Binding`].
 pub trait InputModKeys {
     /// Returns a binding with assigned keyboard modifiers.
@@ -220,19 +589,40 @@ pub trait InputModKeys {
 impl<I: Into<Binding>> InputModKeys for I {
     /// Returns new instance with the replaced keyboard modifiers.
     ///
-    /// Prints error and does nothing when called on [`Binding::GamepadButton`],
-    /// [`Binding::GamepadAxis`], [`Binding::AnyKey`] or [`Binding::None`].
+    /// Prints error and does nothing when called on [`Binding::LockKey`], [`Binding::UiButton`],
+    /// [`Binding::Virtual`], [`Binding::GamepadButton`], [`Binding::GamepadAxis`], [`Binding::AnyKey`],
+    /// [`Binding::AnyInput`] or [`Binding::None`].
     fn with_mod_keys(self, mod_keys: ModKeys) -> Binding {
         let binding = self.into();
         match binding {
             Binding::Keyboard { key, .. } => Binding::Keyboard { key, mod_keys },
+            Binding::LogicalKey { character, .. } => Binding::LogicalKey {
+                character,
+                mod_keys,
+            },
             Binding::MouseButton { button, .. } => Binding::MouseButton { button, mod_keys },
             Binding::MouseMotion { .. } => Binding::MouseMotion { mod_keys },
+            Binding::MouseDrag { button, .. } => Binding::MouseDrag { button, mod_keys },
             Binding::MouseWheel { .. } => Binding::MouseWheel { mod_keys },
-            Binding::GamepadButton { .. }
+            Binding::MouseWheelStep { direction, .. } => Binding::MouseWheelStep {
+                direction,
+                mod_keys,
+            },
+            Binding::MouseWheelPrecise { .. } => Binding::MouseWheelPrecise { mod_keys },
+            Binding::MousePosition { .. } => Binding::MousePosition { mod_keys },
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(_) => {
+                error!("can't add `{mod_keys:?}` to `{binding:?}`");
+                binding
+            }
+            Binding::LockKey(_)
+            | Binding::UiButton(_)
+            | Binding::Virtual(_)
+            | Binding::GamepadButton { .. }
             | Binding::GamepadAxis { .. }
             | Binding::None
-            | Binding::AnyKey => {
+            | Binding::AnyKey
+            | Binding::AnyInput => {
                 error!("can't add `{mod_keys:?}` to `{binding:?}`");
                 binding
             }
@@ -298,6 +688,7 @@ mod tests {
             .to_string(),
             "Ctrl + KeyA"
         );
+        assert_eq!('z'.with_mod_keys(ModKeys::CONTROL).to_string(), "Ctrl + Z");
         assert_eq!(
             Binding::MouseButton {
                 button: MouseButton::Left,
@@ -313,6 +704,10 @@ mod tests {
             .to_string(),
             "Mouse Motion"
         );
+        assert_eq!(
+            Binding::mouse_drag(MouseButton::Right).to_string(),
+            "Mouse Right Drag"
+        );
         assert_eq!(
             Binding::MouseWheel {
                 mod_keys: ModKeys::empty()
@@ -320,6 +715,15 @@ mod tests {
             .to_string(),
             "Scroll Wheel"
         );
+        assert_eq!(
+            Binding::mouse_wheel_step(ScrollDirection::Left).to_string(),
+            "Scroll Left"
+        );
+        assert_eq!(
+            Binding::mouse_wheel_precise().to_string(),
+            "Precise Scroll Wheel"
+        );
+        assert_eq!(Binding::mouse_position().to_string(), "Mouse Position");
         assert_eq!(
             Binding::GamepadAxis(GamepadAxis::LeftStickX).to_string(),
             "LeftStickX"
@@ -0,0 +1,161 @@
+//! Loading bindings from asset files with hot reload.
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::marker::PhantomData;
+
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use log::debug;
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Bindings for actions of a context, loaded from a `.input.ron` file.
+///
+/// Maps action type names without module paths to their bindings. Actions that are not
+/// mentioned in the file keep their bindings.
+///
+/// Assign it to a context entity with [`ContextBindings`].
+///
+/// Requires the `asset` feature and [`AssetPlugin`] added before [`crate::EnhancedInputPlugin`].
+///
+/// # Examples
+///
+/// File `player.input.ron`:
+///
+/// ```ron
+/// {
+///     "Jump": [Keyboard(key: Space, mod_keys: ""), GamepadButton(South)],
+///     "Fire": [MouseButton(button: Left, mod_keys: "")],
+/// }
+/// ```
+#[derive(Asset, TypePath, Debug, Default, Clone, Deref, DerefMut, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputBindings(pub BTreeMap<String, Vec<Binding>>);
+
+/// Loads [`InputBindings`] from files with the `.input.ron` extension.
+#[derive(TypePath, Default)]
+pub struct InputBindingsLoader;
+
+impl AssetLoader for InputBindingsLoader {
+    type Asset = InputBindings;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let bindings = ron::de::from_bytes(&bytes)?;
+        Ok(bindings)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["input.ron"]
+    }
+}
+
+/// Replaces bindings of actions from context `C` with bindings from an [`InputBindings`] asset.
+///
+/// Bindings are applied once the asset is loaded and re-applied each time it's modified,
+/// so with Bevy's `file_watcher` feature keymaps can be iterated on without recompiling.
+/// Modifiers and conditions attached to the replaced binding entities are despawned with them.
+///
+/// Applied during [`EnhancedInputSystems::Prepare`] for each context registered with
+/// [`InputContextAppExt::add_input_context`].
+///
+/// Should be inserted on the context entity.
+///
+/// Requires the `asset` feature and [`AssetPlugin`] added before [`crate::EnhancedInputPlugin`].
+/// Without [`AssetPlugin`], [`InputBindings`] is not registered and this component is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn spawn_player(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands.spawn((
+///         Player,
+///         ContextBindings::<Player>::new(asset_server.load("player.input.ron")),
+///         actions!(Player[
+///             // Default bindings, replaced once the asset is loaded.
+///             (Action::<Jump>::new(), bindings![KeyCode::Space]),
+///         ]),
+///     ));
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(Component, Debug, Clone)]
+pub struct ContextBindings<C: Component> {
+    /// Asset with bindings to apply.
+    pub handle: Handle<InputBindings>,
+    marker: PhantomData<C>,
+}
+
+impl<C: Component> ContextBindings<C> {
+    /// Creates a new instance that applies bindings from the given asset.
+    #[must_use]
+    pub fn new(handle: Handle<InputBindings>) -> Self {
+        Self {
+            handle,
+            marker: PhantomData,
+        }
+    }
+}
+
+pub(crate) fn apply_bindings<C: Component>(
+    mut commands: Commands,
+    mut asset_events: MessageReader<AssetEvent<InputBindings>>,
+    assets: Res<Assets<InputBindings>>,
+    contexts: Query<(Entity, Ref<ContextBindings<C>>, &Actions<C>)>,
+    actions: Query<&Name>,
+) {
+    let changed: Vec<_> = asset_events
+        .read()
+        .filter_map(|&event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(id),
+            _ => None,
+        })
+        .collect();
+
+    for (context, context_bindings, context_actions) in &contexts {
+        if !context_bindings.is_changed() && !changed.contains(&context_bindings.handle.id()) {
+            continue;
+        }
+        let Some(bindings) = assets.get(&context_bindings.handle) else {
+            continue;
+        };
+
+        debug!("applying bindings asset to `{context}`");
+        for (action, name) in context_actions
+            .iter()
+            .filter_map(|action| actions.get(action).ok().map(|name| (action, name)))
+        {
+            let short_name = ShortName(name).to_string();
+            if let Some(action_bindings) = bindings.get(&short_name) {
+                commands.entity(action).despawn_related::<Bindings>();
+                for &binding in action_bindings {
+                    commands.spawn((BindingOf(action), binding));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,138 @@
+//! Raw input capture for binding screens and "press any key" prompts.
+//!
+//! [`InputCapture`] reports every input that was actuated during the current frame as a [`Binding`],
+//! regardless of contexts, consumption or [`ActionSources`]. Keys, mouse buttons and gamepad buttons
+//! are reported on the frame they are pressed, gamepad axes when they cross [`ACTUATION_THRESHOLD`] and
+//! mouse wheel steps on each step. Mouse motion and cursor position are not reported, since they change
+//! too easily to be meaningful for binding.
+//!
+//! Actuations are detected during [`EnhancedInputSystems::Prepare`] by comparing with the previous frame,
+//! so inputs from [`MockInputExt`] are also reported.
+//!
+//! For actions, use [`Binding::AnyInput`] instead.
+
+use alloc::vec::Vec;
+use core::mem;
+
+use bevy::{ecs::system::SystemParam, platform::collections::HashSet, prelude::*};
+use log::trace;
+
+use crate::{context::pause::InputPause, prelude::*};
+
+/// Minimum absolute value of a gamepad axis to be considered actuated.
+///
+/// Used by [`InputCapture`] and [`Binding::AnyInput`].
+pub const ACTUATION_THRESHOLD: f32 = 0.5;
+
+/// System parameter that provides inputs actuated during the current frame.
+///
+/// See the [module documentation](self) for details.
+///
+/// # Examples
+///
+/// Rebind an action to the next pressed input.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn rebind_jump(
+///     mut commands: Commands,
+///     capture: InputCapture,
+///     player: Single<Entity, With<Player>>,
+/// ) {
+///     if let Some(&binding) = capture.inputs().first() {
+///         commands.entity(*player).rebind::<Player, Jump>([binding]);
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(SystemParam)]
+pub struct InputCapture<'w> {
+    captured: Res<'w, CapturedInputs>,
+}
+
+impl InputCapture<'_> {
+    /// Returns inputs actuated during the current frame.
+    ///
+    /// Keyboard inputs are reported without modifiers, since modifier keys are reported themselves.
+    #[must_use]
+    pub fn inputs(&self) -> &[Binding] {
+        &self.captured.inputs
+    }
+
+    /// Returns `true` if no input was actuated during the current frame.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.captured.inputs.is_empty()
+    }
+}
+
+/// Inputs actuated during the current frame for [`InputCapture`].
+#[derive(Resource, Default)]
+pub(crate) struct CapturedInputs {
+    inputs: Vec<Binding>,
+
+    /// Inputs that were active in the previous frame.
+    ///
+    /// Gamepad inputs are stored per gamepad.
+    active: HashSet<(Option<Entity>, Binding)>,
+}
+
+pub(crate) fn capture_inputs(
+    mut captured: ResMut<CapturedInputs>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    scroll_steps: Res<ScrollSteps>,
+    gamepads: Query<(Entity, &Gamepad)>,
+    pause: Res<InputPause>,
+) {
+    let CapturedInputs { inputs, active } = &mut *captured;
+    let last_active = mem::take(active);
+    inputs.clear();
+
+    // Steps are already discrete per frame.
+    inputs.extend(scroll_steps.directions().map(Binding::from));
+
+    let mut activate = |device, binding| {
+        active.insert((device, binding));
+        if !last_active.contains(&(device, binding)) && !inputs.contains(&binding) {
+            trace!("captured `{binding}`");
+            inputs.push(binding);
+        }
+    };
+
+    for &key in keys.iter().flat_map(|k| k.get_pressed()) {
+        activate(None, key.into());
+    }
+
+    for &button in mouse_buttons.iter().flat_map(|b| b.get_pressed()) {
+        activate(None, button.into());
+    }
+
+    for (entity, gamepad) in &gamepads {
+        for &button in gamepad.get_pressed() {
+            activate(Some(entity), button.into());
+        }
+
+        for axis in GamepadAxis::all() {
+            if gamepad
+                .get(axis)
+                .is_some_and(|value| value.abs() >= ACTUATION_THRESHOLD)
+            {
+                activate(Some(entity), axis.into());
+            }
+        }
+    }
+
+    if pause.is_paused() {
+        // Keep tracking active inputs to avoid reporting inputs held through the pause.
+        inputs.clear();
+    }
+}
@@ -0,0 +1,507 @@
+//! Picking default bindings from the detected hardware on the first run.
+//!
+//! Games usually ship several binding profiles, such as keyboard-first and gamepad-first,
+//! or variants for different keyboard layouts. Instead of asking the player on the first run,
+//! a [`DefaultProfileSelector`] can pick the profile from [`InputHardware`] once enough is known,
+//! for example after the first input on a "press any button" screen.
+//!
+//! Register a selector with [`DefaultProfileAppExt::select_default_profile`]. The result is inserted
+//! as [`SelectedProfile`]. Save it with the rest of the settings, and on later runs insert
+//! [`SelectedProfile`] before the first update to skip the detection.
+//!
+//! # Keyboard layouts
+//!
+//! [`KeyCode`] is a physical key, so [`Cardinal::wasd_keys`](crate::prelude::Cardinal::wasd_keys)
+//! is already ZQSD on AZERTY. The layout matters for keys that are picked by their letter,
+//! like `I` for the inventory, and for showing the bindings to the player.
+//! Use [`KeyboardLayout::key`] to find the physical key for a letter.
+
+use core::fmt::{self, Display, Formatter};
+
+use bevy::{
+    ecs::message::MessageCursor,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+use log::debug;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EnhancedInputSystems, prelude::*};
+
+/// An extension trait for [`App`] to pick default bindings on the first run.
+pub trait DefaultProfileAppExt {
+    /// Selects the default profile with the given selector.
+    ///
+    /// The selector is evaluated during [`EnhancedInputSystems::Prepare`] until it returns a profile
+    /// or until [`SelectedProfile`] is inserted manually.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+    ///     .select_default_profile(FirstInputSelector)
+    ///     .add_systems(
+    ///         Update,
+    ///         spawn_player.run_if(resource_added::<SelectedProfile<DeviceProfile>>),
+    ///     );
+    ///
+    /// fn spawn_player(mut commands: Commands, profile: Res<SelectedProfile<DeviceProfile>>) {
+    ///     let bindings = match **profile {
+    ///         DeviceProfile::KeyboardMouse { layout } => {
+    ///             // Open the inventory by the key labeled `I` on any layout.
+    ///             let key = layout.key('i').unwrap_or(KeyCode::KeyI);
+    ///             Binding::from(key)
+    ///         }
+    ///         DeviceProfile::Gamepad => GamepadButton::North.into(),
+    ///     };
+    ///
+    ///     commands.spawn((
+    ///         Player,
+    ///         actions!(Player[(Action::<OpenInventory>::new(), bindings![bindings])]),
+    ///     ));
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct OpenInventory;
+    /// ```
+    fn select_default_profile<S: DefaultProfileSelector>(&mut self, selector: S) -> &mut Self;
+}
+
+impl DefaultProfileAppExt for App {
+    fn select_default_profile<S: DefaultProfileSelector>(&mut self, selector: S) -> &mut Self {
+        self.insert_resource(ProfileSelector(selector)).add_systems(
+            PreUpdate,
+            select_profile::<S>
+                .run_if(not(resource_exists::<SelectedProfile<S::Profile>>))
+                .after(update_hardware)
+                .in_set(EnhancedInputSystems::Prepare),
+        )
+    }
+}
+
+/// Picks a profile from the detected hardware.
+///
+/// Implemented for closures that take [`InputHardware`] and return an optional profile.
+/// See [`FirstInputSelector`] for a built-in implementation.
+pub trait DefaultProfileSelector: Send + Sync + 'static {
+    /// Profile type, usually an enum of binding sets provided by the game.
+    type Profile: Send + Sync + 'static;
+
+    /// Returns the profile for the hardware, or [`None`] to wait for more information.
+    fn select(&self, hardware: &InputHardware) -> Option<Self::Profile>;
+}
+
+impl<F, P> DefaultProfileSelector for F
+where
+    F: Fn(&InputHardware) -> Option<P> + Send + Sync + 'static,
+    P: Send + Sync + 'static,
+{
+    type Profile = P;
+
+    fn select(&self, hardware: &InputHardware) -> Option<Self::Profile> {
+        (self)(hardware)
+    }
+}
+
+/// Selects a [`DeviceProfile`] based on the device of the first input.
+///
+/// Waits for the first input, so it's best used with a "press any button" screen.
+/// If the first input is from a gamepad, selects [`DeviceProfile::Gamepad`].
+/// Otherwise selects [`DeviceProfile::KeyboardMouse`] with the detected layout,
+/// or [`KeyboardLayout::Qwerty`] if the layout is unknown.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FirstInputSelector;
+
+impl DefaultProfileSelector for FirstInputSelector {
+    type Profile = DeviceProfile;
+
+    fn select(&self, hardware: &InputHardware) -> Option<Self::Profile> {
+        match hardware.first_device()? {
+            InputDevice::Keyboard | InputDevice::Mouse => Some(DeviceProfile::KeyboardMouse {
+                layout: hardware.keyboard_layout.unwrap_or_default(),
+            }),
+            InputDevice::Gamepad => Some(DeviceProfile::Gamepad),
+        }
+    }
+}
+
+/// Profile returned by [`FirstInputSelector`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum DeviceProfile {
+    /// Keyboard and mouse bindings for the given layout.
+    KeyboardMouse { layout: KeyboardLayout },
+    /// Gamepad-first bindings.
+    Gamepad,
+}
+
+/// Profile picked by [`DefaultProfileSelector`].
+///
+/// Inserted once the selector returns a profile. Remove it to run the selection again.
+#[derive(Resource, Deref, DerefMut, Debug, Clone, Copy)]
+pub struct SelectedProfile<P: Send + Sync + 'static>(pub P);
+
+#[derive(Resource)]
+struct ProfileSelector<S>(S);
+
+/// Input hardware detected at runtime.
+///
+/// Bevy can't list connected keyboards and mice, and doesn't expose the OS keyboard layout,
+/// so both are inferred from the input the player produces.
+///
+/// Updated during [`EnhancedInputSystems::Prepare`].
+///
+/// Initialized by [`crate::EnhancedInputPlugin`].
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct InputHardware {
+    /// Layout of the keyboard.
+    ///
+    /// Detected from characters typed by letter keys, usually after a few presses.
+    /// Can be set manually, for example from a platform API. Detection stops once the layout is known.
+    pub keyboard_layout: Option<KeyboardLayout>,
+
+    /// Layouts that match all typed characters so far.
+    layout_candidates: u8,
+
+    first_device: Option<InputDevice>,
+    gamepads: usize,
+}
+
+impl InputHardware {
+    /// Returns the device of the first pressed key or button since startup.
+    #[must_use]
+    pub fn first_device(&self) -> Option<InputDevice> {
+        self.first_device
+    }
+
+    /// Returns the number of connected gamepads.
+    #[must_use]
+    pub fn gamepads(&self) -> usize {
+        self.gamepads
+    }
+
+    /// Narrows the layout down by a character typed with the key.
+    ///
+    /// Only Latin letters are considered, since other characters depend on modifiers and regional variants.
+    fn detect_layout(&mut self, key: KeyCode, c: char) {
+        if !c.is_ascii_alphabetic() {
+            return;
+        }
+
+        let letter = c.to_ascii_lowercase();
+        for (index, layout) in KeyboardLayout::ALL.into_iter().enumerate() {
+            if layout.letter(key) != Some(letter) {
+                self.layout_candidates &= !(1 << index);
+            }
+        }
+
+        if self.layout_candidates.count_ones() == 1 {
+            let layout = KeyboardLayout::ALL[self.layout_candidates.trailing_zeros() as usize];
+            debug!("detected keyboard layout `{layout}`");
+            self.keyboard_layout = Some(layout);
+        }
+    }
+}
+
+impl Default for InputHardware {
+    fn default() -> Self {
+        Self {
+            keyboard_layout: None,
+            layout_candidates: (1 << KeyboardLayout::ALL.len()) - 1,
+            first_device: None,
+            gamepads: 0,
+        }
+    }
+}
+
+/// Keyboard layouts recognized by [`InputHardware`].
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum KeyboardLayout {
+    #[default]
+    Qwerty,
+    /// French layout.
+    Azerty,
+    /// German layout.
+    Qwertz,
+    Dvorak,
+}
+
+impl KeyboardLayout {
+    const ALL: [Self; 4] = [Self::Qwerty, Self::Azerty, Self::Qwertz, Self::Dvorak];
+
+    /// Keys that have a Latin letter on at least one layout.
+    const LETTER_KEYS: [KeyCode; 30] = [
+        KeyCode::KeyA,
+        KeyCode::KeyB,
+        KeyCode::KeyC,
+        KeyCode::KeyD,
+        KeyCode::KeyE,
+        KeyCode::KeyF,
+        KeyCode::KeyG,
+        KeyCode::KeyH,
+        KeyCode::KeyI,
+        KeyCode::KeyJ,
+        KeyCode::KeyK,
+        KeyCode::KeyL,
+        KeyCode::KeyM,
+        KeyCode::KeyN,
+        KeyCode::KeyO,
+        KeyCode::KeyP,
+        KeyCode::KeyQ,
+        KeyCode::KeyR,
+        KeyCode::KeyS,
+        KeyCode::KeyT,
+        KeyCode::KeyU,
+        KeyCode::KeyV,
+        KeyCode::KeyW,
+        KeyCode::KeyX,
+        KeyCode::KeyY,
+        KeyCode::KeyZ,
+        KeyCode::Semicolon,
+        KeyCode::Comma,
+        KeyCode::Period,
+        KeyCode::Slash,
+    ];
+
+    /// Returns the lowercase Latin letter printed on the physical key in this layout.
+    #[must_use]
+    pub fn letter(self, key: KeyCode) -> Option<char> {
+        match (self, key) {
+            (Self::Azerty, KeyCode::KeyQ) => Some('a'),
+            (Self::Azerty, KeyCode::KeyW) => Some('z'),
+            (Self::Azerty, KeyCode::KeyA) => Some('q'),
+            (Self::Azerty, KeyCode::Semicolon) => Some('m'),
+            (Self::Azerty, KeyCode::KeyZ) => Some('w'),
+            (Self::Azerty, KeyCode::KeyM) => None,
+            (Self::Qwertz, KeyCode::KeyY) => Some('z'),
+            (Self::Qwertz, KeyCode::KeyZ) => Some('y'),
+            (Self::Dvorak, key) => match key {
+                KeyCode::KeyR => Some('p'),
+                KeyCode::KeyT => Some('y'),
+                KeyCode::KeyY => Some('f'),
+                KeyCode::KeyU => Some('g'),
+                KeyCode::KeyI => Some('c'),
+                KeyCode::KeyO => Some('r'),
+                KeyCode::KeyP => Some('l'),
+                KeyCode::KeyA => Some('a'),
+                KeyCode::KeyS => Some('o'),
+                KeyCode::KeyD => Some('e'),
+                KeyCode::KeyF => Some('u'),
+                KeyCode::KeyG => Some('i'),
+                KeyCode::KeyH => Some('d'),
+                KeyCode::KeyJ => Some('h'),
+                KeyCode::KeyK => Some('t'),
+                KeyCode::KeyL => Some('n'),
+                KeyCode::Semicolon => Some('s'),
+                KeyCode::KeyX => Some('q'),
+                KeyCode::KeyC => Some('j'),
+                KeyCode::KeyV => Some('k'),
+                KeyCode::KeyB => Some('x'),
+                KeyCode::KeyN => Some('b'),
+                KeyCode::KeyM => Some('m'),
+                KeyCode::Comma => Some('w'),
+                KeyCode::Period => Some('v'),
+                KeyCode::Slash => Some('z'),
+                _ => None,
+            },
+            (_, key) => Self::qwerty_letter(key),
+        }
+    }
+
+    /// Returns the physical key with the given Latin letter in this layout.
+    #[must_use]
+    pub fn key(self, letter: char) -> Option<KeyCode> {
+        let letter = letter.to_ascii_lowercase();
+        Self::LETTER_KEYS
+            .into_iter()
+            .find(|&key| self.letter(key) == Some(letter))
+    }
+
+    fn qwerty_letter(key: KeyCode) -> Option<char> {
+        let letter = match key {
+            KeyCode::KeyA => 'a',
+            KeyCode::KeyB => 'b',
+            KeyCode::KeyC => 'c',
+            KeyCode::KeyD => 'd',
+            KeyCode::KeyE => 'e',
+            KeyCode::KeyF => 'f',
+            KeyCode::KeyG => 'g',
+            KeyCode::KeyH => 'h',
+            KeyCode::KeyI => 'i',
+            KeyCode::KeyJ => 'j',
+            KeyCode::KeyK => 'k',
+            KeyCode::KeyL => 'l',
+            KeyCode::KeyM => 'm',
+            KeyCode::KeyN => 'n',
+            KeyCode::KeyO => 'o',
+            KeyCode::KeyP => 'p',
+            KeyCode::KeyQ => 'q',
+            KeyCode::KeyR => 'r',
+            KeyCode::KeyS => 's',
+            KeyCode::KeyT => 't',
+            KeyCode::KeyU => 'u',
+            KeyCode::KeyV => 'v',
+            KeyCode::KeyW => 'w',
+            KeyCode::KeyX => 'x',
+            KeyCode::KeyY => 'y',
+            KeyCode::KeyZ => 'z',
+            _ => return None,
+        };
+
+        Some(letter)
+    }
+}
+
+impl Display for KeyboardLayout {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            KeyboardLayout::Qwerty => write!(f, "QWERTY"),
+            KeyboardLayout::Azerty => write!(f, "AZERTY"),
+            KeyboardLayout::Qwertz => write!(f, "QWERTZ"),
+            KeyboardLayout::Dvorak => write!(f, "Dvorak"),
+        }
+    }
+}
+
+pub(crate) fn update_hardware(
+    mut hardware: ResMut<InputHardware>,
+    mut input_cursor: Local<MessageCursor<KeyboardInput>>,
+    keyboard_inputs: Option<Res<Messages<KeyboardInput>>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    gamepads: Query<&Gamepad>,
+) {
+    if let Some(keyboard_inputs) = keyboard_inputs {
+        for input in input_cursor.read(&keyboard_inputs) {
+            if hardware.keyboard_layout.is_none()
+                && hardware.layout_candidates != 0
+                && let Key::Character(text) = &input.logical_key
+                && let Some(c) = text.chars().next()
+            {
+                hardware.detect_layout(input.key_code, c);
+            }
+        }
+    }
+
+    if hardware.first_device.is_none() {
+        let device = if keys.is_some_and(|keys| keys.get_pressed().next().is_some()) {
+            Some(InputDevice::Keyboard)
+        } else if mouse_buttons.is_some_and(|buttons| buttons.get_pressed().next().is_some()) {
+            Some(InputDevice::Mouse)
+        } else if gamepads
+            .iter()
+            .any(|gamepad| gamepad.get_pressed().next().is_some())
+        {
+            Some(InputDevice::Gamepad)
+        } else {
+            None
+        };
+
+        if let Some(device) = device {
+            debug!("detected first input device `{device:?}`");
+            hardware.first_device = Some(device);
+        }
+    }
+
+    let count = gamepads.iter().len();
+    if hardware.gamepads != count {
+        hardware.gamepads = count;
+    }
+}
+
+fn select_profile<S: DefaultProfileSelector>(
+    mut commands: Commands,
+    selector: Res<ProfileSelector<S>>,
+    hardware: Res<InputHardware>,
+) {
+    if let Some(profile) = selector.0.select(&hardware) {
+        debug!(
+            "selected default profile `{}`",
+            ShortName::of::<S::Profile>()
+        );
+        commands.insert_resource(SelectedProfile(profile));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout_detection() {
+        let mut hardware = InputHardware::default();
+
+        hardware.detect_layout(KeyCode::Space, ' ');
+        assert_eq!(hardware.keyboard_layout, None);
+
+        hardware.detect_layout(KeyCode::KeyE, 'e');
+        assert_eq!(
+            hardware.keyboard_layout, None,
+            "key matches several layouts"
+        );
+
+        hardware.detect_layout(KeyCode::KeyZ, 'Y');
+        assert_eq!(hardware.keyboard_layout, Some(KeyboardLayout::Qwertz));
+    }
+
+    #[test]
+    fn unknown_layout() {
+        let mut hardware = InputHardware::default();
+
+        hardware.detect_layout(KeyCode::KeyQ, 'й');
+        assert_eq!(
+            hardware.layout_candidates.count_ones(),
+            4,
+            "non-Latin characters should be ignored"
+        );
+
+        // Colemak.
+        hardware.detect_layout(KeyCode::KeyE, 'f');
+        assert_eq!(hardware.layout_candidates, 0);
+        assert_eq!(hardware.keyboard_layout, None);
+    }
+
+    #[test]
+    fn letters() {
+        for layout in KeyboardLayout::ALL {
+            for letter in 'a'..='z' {
+                let key = layout
+                    .key(letter)
+                    .unwrap_or_else(|| panic!("`{letter}` should exist on `{layout}`"));
+                assert_eq!(layout.letter(key), Some(letter));
+            }
+        }
+
+        assert_eq!(KeyboardLayout::Azerty.key('z'), Some(KeyCode::KeyW));
+        assert_eq!(KeyboardLayout::Azerty.key('m'), Some(KeyCode::Semicolon));
+        assert_eq!(KeyboardLayout::Dvorak.key('I'), Some(KeyCode::KeyG));
+    }
+}
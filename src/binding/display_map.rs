@@ -0,0 +1,192 @@
+//! User-facing names for bindings and actions.
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+};
+use core::{
+    any,
+    fmt::{self, Display, Formatter},
+};
+
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::prelude::*;
+
+/// Maps bindings and actions to user-facing names, such as localized or layout-aware labels.
+///
+/// The [`Display`] implementations of [`Binding`] and [`ModKeys`] produce fixed English names.
+/// Use [`Self::display`] in binding menus to apply the names from this map instead.
+///
+/// Names are resolved in the following order:
+/// 1. The resolver set by [`Self::set_resolver`], if any.
+/// 2. Names added by [`Self::insert`].
+/// 3. The [`Display`] implementation of [`Binding`].
+///
+/// Keyboard modifiers are always displayed separately, so names should be
+/// added for bindings without them.
+///
+/// Initialized by [`crate::EnhancedInputPlugin`] as empty.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut map = InputDisplayMap::default();
+/// map.insert(GamepadButton::South, "Croix (✕)");
+/// map.insert_mod_key(ModKeys::CONTROL, "Strg");
+/// map.insert_action::<Jump>("Sauter");
+///
+/// assert_eq!(map.display(GamepadButton::South).to_string(), "Croix (✕)");
+/// assert_eq!(
+///     map.display(KeyCode::KeyA.with_mod_keys(ModKeys::CONTROL)).to_string(),
+///     "Strg + KeyA"
+/// );
+/// assert_eq!(map.action_name::<Jump>(), "Sauter");
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(Resource, Default)]
+pub struct InputDisplayMap {
+    bindings: HashMap<Binding, String>,
+    mod_keys: HashMap<ModKeys, String>,
+    actions: HashMap<&'static str, String>,
+    resolver: Option<Box<dyn Fn(Binding) -> Option<String> + Send + Sync>>,
+}
+
+impl InputDisplayMap {
+    /// Sets the name for a binding and returns the previous name, if any.
+    ///
+    /// Keyboard modifiers of the binding are ignored.
+    pub fn insert(
+        &mut self,
+        binding: impl Into<Binding>,
+        name: impl Into<String>,
+    ) -> Option<String> {
+        self.bindings
+            .insert(strip_mod_keys(binding.into()), name.into())
+    }
+
+    /// Sets the name for a single keyboard modifier and returns the previous name, if any.
+    pub fn insert_mod_key(&mut self, mod_key: ModKeys, name: impl Into<String>) -> Option<String> {
+        self.mod_keys.insert(mod_key, name.into())
+    }
+
+    /// Sets the name for an action and returns the previous name, if any.
+    pub fn insert_action<A: InputAction>(&mut self, name: impl Into<String>) -> Option<String> {
+        self.actions.insert(any::type_name::<A>(), name.into())
+    }
+
+    /// Sets a function that resolves binding names before the inserted names.
+    ///
+    /// Useful for names that depend on external state, such as the keyboard layout.
+    /// Receives bindings without keyboard modifiers. Return [`None`] to fall back to inserted names.
+    pub fn set_resolver(
+        &mut self,
+        resolver: impl Fn(Binding) -> Option<String> + Send + Sync + 'static,
+    ) {
+        self.resolver = Some(Box::new(resolver));
+    }
+
+    /// Returns a value that displays the binding using names from this map.
+    #[must_use]
+    pub fn display(&self, binding: impl Into<Binding>) -> BindingDisplay<'_> {
+        BindingDisplay {
+            map: self,
+            binding: binding.into(),
+        }
+    }
+
+    /// Returns the name of the action.
+    ///
+    /// Falls back to the type name of the action.
+    #[must_use]
+    pub fn action_name<A: InputAction>(&self) -> String {
+        self.actions
+            .get(any::type_name::<A>())
+            .cloned()
+            .unwrap_or_else(|| ShortName::of::<A>().to_string())
+    }
+
+    /// Returns the name of an action from the [`Name`] of its entity.
+    ///
+    /// Like [`Self::action_name`], but for action entities whose type is not known statically.
+    /// Relies on the [`Name`] that is automatically inserted with [`Action<A>`].
+    #[must_use]
+    pub fn entity_action_name(&self, name: &Name) -> String {
+        self.actions
+            .get(name.as_str())
+            .cloned()
+            .unwrap_or_else(|| ShortName(name).to_string())
+    }
+}
+
+/// Displays a [`Binding`] using names from [`InputDisplayMap`].
+///
+/// Returned by [`InputDisplayMap::display`].
+pub struct BindingDisplay<'a> {
+    map: &'a InputDisplayMap,
+    binding: Binding,
+}
+
+impl Display for BindingDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for mod_key in self.binding.mod_keys().iter() {
+            match self.map.mod_keys.get(&mod_key) {
+                Some(name) => write!(f, "{name} + ")?,
+                None => write!(f, "{mod_key} + ")?,
+            }
+        }
+
+        let binding = strip_mod_keys(self.binding);
+        if let Some(name) = self
+            .map
+            .resolver
+            .as_ref()
+            .and_then(|resolve| resolve(binding))
+        {
+            return write!(f, "{name}");
+        }
+
+        match self.map.bindings.get(&binding) {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{binding}"),
+        }
+    }
+}
+
+/// Like [`Binding::without_mod_keys`], but doesn't log an error for bindings without modifiers.
+fn strip_mod_keys(binding: Binding) -> Binding {
+    if binding.mod_keys().is_empty() {
+        binding
+    } else {
+        binding.without_mod_keys()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn resolver() {
+        let mut map = InputDisplayMap::default();
+        map.insert(KeyCode::KeyQ, "Q");
+        map.insert(KeyCode::KeyW, "W");
+        map.set_resolver(|binding| (binding == KeyCode::KeyQ.into()).then(|| "A".to_string()));
+
+        assert_eq!(map.display(KeyCode::KeyQ).to_string(), "A");
+        assert_eq!(map.display(KeyCode::KeyW).to_string(), "W");
+        assert_eq!(
+            map.display(KeyCode::KeyE.with_mod_keys(ModKeys::SHIFT))
+                .to_string(),
+            "Shift + KeyE"
+        );
+    }
+}
@@ -0,0 +1,87 @@
+//! Bindings that replace a disconnected gamepad.
+//!
+//! Bindings marked with [`FallbackBinding`] are ignored until the gamepad assigned to the context
+//! entity via [`GamepadDevice::Single`](crate::context::GamepadDevice::Single) disconnects. While it's disconnected, [`FallbackActive`] is
+//! present on the entity and fallback bindings are evaluated like regular ones. Once the gamepad
+//! reconnects or a replacement is assigned via [`GamepadFallback::Replace`](crate::context::GamepadFallback::Replace), fallback bindings are
+//! ignored again.
+//!
+//! Fallback isn't activated for [`GamepadFallback::Any`](crate::context::GamepadFallback::Any), since input from other gamepads is used instead.
+//!
+//! [`FallbackChanged`] is triggered on the entity on each transition, which is useful for notifying the player.
+//!
+//! # Examples
+//!
+//! Move with the left stick and switch to WASD while the gamepad is disconnected:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_enhanced_input::prelude::*;
+//!
+//! # let mut world = World::new();
+//! # let gamepad = world.spawn(Gamepad::default()).id();
+//! world.spawn((
+//!     Player,
+//!     GamepadDevice::Single(gamepad),
+//!     actions!(Player[
+//!         (
+//!             Action::<Movement>::new(),
+//!             Bindings::spawn((
+//!                 Axial::left_stick(),
+//!                 Cardinal::wasd_keys().with(FallbackBinding),
+//!             )),
+//!         ),
+//!     ]),
+//! ));
+//!
+//! fn notify(changed: On<FallbackChanged>) {
+//!     if changed.active {
+//!         info!("gamepad disconnected, switching to keyboard");
+//!     } else {
+//!         info!("gamepad connected, switching back");
+//!     }
+//! }
+//!
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! #[derive(InputAction)]
+//! #[action_output(Vec2)]
+//! struct Movement;
+//! ```
+
+use bevy::prelude::*;
+
+/// Marks a binding as a fallback that is evaluated only while [`FallbackActive`] is present
+/// on the context entity.
+///
+/// See the [module documentation](self) for more details.
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct FallbackBinding;
+
+/// Present on an entity while the gamepad assigned to it via [`GamepadDevice::Single`](crate::context::GamepadDevice::Single) is disconnected.
+///
+/// Inserted and removed automatically. Enables bindings with [`FallbackBinding`].
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct FallbackActive;
+
+/// Triggered on an entity when its [`FallbackActive`] is inserted or removed.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct FallbackChanged {
+    /// Entity with [`GamepadDevice`](crate::context::GamepadDevice) on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Whether fallback bindings are now evaluated.
+    pub active: bool,
+}
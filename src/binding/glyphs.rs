@@ -0,0 +1,190 @@
+//! Gamepad kind detection and button icon lookup for UI prompts.
+
+use alloc::string::String;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+use log::debug;
+
+use crate::prelude::*;
+
+/// Brand of a gamepad, detected from its vendor ID.
+///
+/// Automatically inserted on entities with [`Gamepad`].
+/// Use it to pick button icons from [`GamepadGlyphs`].
+#[derive(Component, Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Hash, PartialEq)
+)]
+pub enum GamepadKind {
+    /// Microsoft Xbox controllers.
+    Xbox,
+    /// Sony DualShock and DualSense controllers.
+    PlayStation,
+    /// Nintendo Switch controllers.
+    Switch,
+    /// Any other or unknown controller.
+    #[default]
+    Generic,
+}
+
+impl GamepadKind {
+    /// Returns the kind for the given USB vendor ID.
+    #[must_use]
+    pub fn from_vendor_id(vendor_id: u16) -> Self {
+        match vendor_id {
+            0x045E => Self::Xbox,
+            0x054C => Self::PlayStation,
+            0x057E => Self::Switch,
+            _ => Self::Generic,
+        }
+    }
+
+    /// Returns the kind of the gamepad.
+    ///
+    /// Returns [`Self::Generic`] if the vendor ID is unknown.
+    #[must_use]
+    pub fn detect(gamepad: &Gamepad) -> Self {
+        gamepad
+            .vendor_id()
+            .map(Self::from_vendor_id)
+            .unwrap_or_default()
+    }
+}
+
+pub(crate) fn detect_kind(
+    insert: On<Insert, Gamepad>,
+    mut commands: Commands,
+    gamepads: Query<&Gamepad>,
+) {
+    let gamepad = gamepads.get(insert.entity).unwrap();
+    let kind = GamepadKind::detect(gamepad);
+    debug!("detected `{kind:?}` for gamepad `{}`", insert.entity);
+    commands.entity(insert.entity).insert(kind);
+}
+
+/// Icon for a gamepad input in a UI prompt.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug, PartialEq))]
+pub enum Glyph {
+    /// Index in a texture atlas.
+    Index(usize),
+    /// Path to an asset.
+    Path(String),
+}
+
+impl From<usize> for Glyph {
+    fn from(value: usize) -> Self {
+        Self::Index(value)
+    }
+}
+
+impl From<&str> for Glyph {
+    fn from(value: &str) -> Self {
+        Self::Path(value.into())
+    }
+}
+
+impl From<String> for Glyph {
+    fn from(value: String) -> Self {
+        Self::Path(value)
+    }
+}
+
+/// Maps gamepad inputs to icons for each [`GamepadKind`].
+///
+/// Initialized by [`crate::EnhancedInputPlugin`] as empty.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut glyphs = GamepadGlyphs::default();
+/// glyphs
+///     .insert(GamepadKind::Generic, GamepadButton::South, 0)
+///     .insert(GamepadKind::Xbox, GamepadButton::South, "icons/xbox/a.png")
+///     .insert(GamepadKind::PlayStation, GamepadButton::South, "icons/ps/cross.png");
+///
+/// assert_eq!(
+///     glyphs.get(GamepadKind::PlayStation, GamepadButton::South),
+///     Some(&Glyph::Path("icons/ps/cross.png".into()))
+/// );
+/// assert_eq!(
+///     glyphs.get(GamepadKind::Switch, GamepadButton::South),
+///     Some(&Glyph::Index(0)),
+///     "should fall back to generic glyphs"
+/// );
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct GamepadGlyphs {
+    glyphs: HashMap<(GamepadKind, Binding), Glyph>,
+}
+
+impl GamepadGlyphs {
+    /// Sets the icon for the gamepad input.
+    ///
+    /// The input is expected to be [`Binding::GamepadButton`] or [`Binding::GamepadAxis`].
+    pub fn insert(
+        &mut self,
+        kind: GamepadKind,
+        input: impl Into<Binding>,
+        glyph: impl Into<Glyph>,
+    ) -> &mut Self {
+        self.glyphs.insert((kind, input.into()), glyph.into());
+        self
+    }
+
+    /// Returns the icon for the gamepad input.
+    ///
+    /// Falls back to the icon for [`GamepadKind::Generic`].
+    #[must_use]
+    pub fn get(&self, kind: GamepadKind, input: impl Into<Binding>) -> Option<&Glyph> {
+        let input = input.into();
+        self.glyphs
+            .get(&(kind, input))
+            .or_else(|| self.glyphs.get(&(GamepadKind::Generic, input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::{
+        InputPlugin,
+        gamepad::{GamepadConnection, GamepadConnectionEvent},
+    };
+
+    use super::*;
+
+    #[test]
+    fn detection() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin).add_observer(detect_kind);
+
+        let xbox = app.world_mut().spawn_empty().id();
+        let unknown = app.world_mut().spawn_empty().id();
+        for (gamepad, vendor_id) in [(xbox, Some(0x045E)), (unknown, None)] {
+            app.world_mut().write_message(GamepadConnectionEvent::new(
+                gamepad,
+                GamepadConnection::Connected {
+                    name: "Test".into(),
+                    vendor_id,
+                    product_id: None,
+                },
+            ));
+        }
+
+        app.update();
+
+        assert_eq!(
+            app.world().get::<GamepadKind>(xbox),
+            Some(&GamepadKind::Xbox)
+        );
+        assert_eq!(
+            app.world().get::<GamepadKind>(unknown),
+            Some(&GamepadKind::Generic)
+        );
+    }
+}
@@ -0,0 +1,183 @@
+//! Bindings of different actions that should be rebound together.
+//!
+//! Some actions come in pairs, like leaning left and right or strafing. Their bindings are usually
+//! mirrored, so rebinding one of them without the other leaves the controls inconsistent. Linking such
+//! bindings with the same [`BindingGroup`] lets rebinding UIs offer to rebind the whole set via
+//! [`RebindEntityCommandsExt::rebind_group`] and check that the new set is consistent with
+//! [`BindingGroup::asymmetry`].
+//!
+//! # Examples
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_enhanced_input::prelude::*;
+//!
+//! const LEAN: BindingGroup = BindingGroup(0);
+//!
+//! # let mut world = World::new();
+//! world.spawn((
+//!     Player,
+//!     actions!(Player[
+//!         (Action::<LeanLeft>::new(), bindings![(KeyCode::KeyQ, LEAN)]),
+//!         (Action::<LeanRight>::new(), bindings![(KeyCode::KeyE, LEAN)]),
+//!     ]),
+//! ));
+//!
+//! fn rebind_lean(mut commands: Commands, player: Single<Entity, With<Player>>) {
+//!     let bindings = [KeyCode::KeyZ.into(), KeyCode::KeyC.into()];
+//!     if let Some(asymmetry) = BindingGroup::asymmetry(&bindings) {
+//!         warn!("lean bindings are inconsistent: {asymmetry:?}");
+//!         return;
+//!     }
+//!
+//!     commands.entity(*player).rebind_group::<Player>(LEAN, bindings);
+//! }
+//!
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! #[derive(InputAction)]
+//! #[action_output(bool)]
+//! struct LeanLeft;
+//!
+//! #[derive(InputAction)]
+//! #[action_output(bool)]
+//! struct LeanRight;
+//! ```
+
+use alloc::vec::Vec;
+
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Links bindings of different actions within a context that should be rebound as a set.
+///
+/// Has no effect on evaluation. See the [module documentation](self) for more details.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq, Hash)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct BindingGroup(pub u32);
+
+impl BindingGroup {
+    /// Returns the first inconsistency between bindings of a group.
+    ///
+    /// Bindings are considered symmetric if they are all distinct and use the same
+    /// [`InputDevice`] and [`ModKeys`].
+    #[must_use]
+    pub fn asymmetry(bindings: &[Binding]) -> Option<GroupAsymmetry> {
+        for (index, &first) in bindings.iter().enumerate() {
+            for &second in &bindings[index + 1..] {
+                if first == second {
+                    return Some(GroupAsymmetry::Duplicate(first));
+                }
+                if first.device() != second.device() {
+                    return Some(GroupAsymmetry::Devices([first, second]));
+                }
+                if first.mod_keys() != second.mod_keys() {
+                    return Some(GroupAsymmetry::ModKeys([first, second]));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Inconsistency between bindings of a [`BindingGroup`].
+///
+/// Returned by [`BindingGroup::asymmetry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupAsymmetry {
+    /// The same binding is used more than once.
+    Duplicate(Binding),
+    /// Bindings read from different devices.
+    Devices([Binding; 2]),
+    /// Bindings require different keyboard modifiers.
+    ModKeys([Binding; 2]),
+}
+
+impl<C: Component> Actions<C> {
+    /// Returns binding entities from the group with their bindings.
+    ///
+    /// Bindings are returned in the order of actions in this context, then in the order
+    /// of bindings within each action. [`RebindEntityCommandsExt::rebind_group`] uses the same order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// const LEAN: BindingGroup = BindingGroup(0);
+    ///
+    /// fn show_lean(
+    ///     player: Single<&Actions<Player>>,
+    ///     actions: Query<&Bindings>,
+    ///     bindings: Query<(&Binding, &BindingGroup)>,
+    /// ) {
+    ///     for (_, binding) in player.binding_group(LEAN, &actions, &bindings) {
+    ///         info!("lean: `{binding}`");
+    ///     }
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    /// ```
+    #[must_use]
+    pub fn binding_group(
+        &self,
+        group: BindingGroup,
+        actions: &Query<&Bindings>,
+        bindings: &Query<(&Binding, &BindingGroup)>,
+    ) -> Vec<(Entity, Binding)> {
+        actions
+            .iter_many(self.iter())
+            .flat_map(|action_bindings| action_bindings.iter())
+            .filter_map(|entity| {
+                let (&binding, &binding_group) = bindings.get(entity).ok()?;
+                (binding_group == group).then_some((entity, binding))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asymmetry() {
+        let q = Binding::from(KeyCode::KeyQ);
+        let e = Binding::from(KeyCode::KeyE);
+
+        assert_eq!(BindingGroup::asymmetry(&[]), None);
+        assert_eq!(BindingGroup::asymmetry(&[q, e]), None);
+        assert_eq!(
+            BindingGroup::asymmetry(&[q, q]),
+            Some(GroupAsymmetry::Duplicate(q))
+        );
+
+        let south = Binding::from(GamepadButton::South);
+        assert_eq!(
+            BindingGroup::asymmetry(&[q, south]),
+            Some(GroupAsymmetry::Devices([q, south]))
+        );
+
+        let ctrl_e = KeyCode::KeyE.with_mod_keys(ModKeys::CONTROL);
+        assert_eq!(
+            BindingGroup::asymmetry(&[q, e, ctrl_e]),
+            Some(GroupAsymmetry::ModKeys([q, ctrl_e]))
+        );
+    }
+}
@@ -0,0 +1,313 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Undo and redo log for edits of a [`BindingSet`].
+///
+/// Each edit is stored as a [`BindingPatch`] that reverts it, so the history stays small
+/// regardless of the number of actions. Edits are applied with [`BindingSet::apply_patch`],
+/// which means the set must not be modified outside of the history while it's in use.
+/// Otherwise [`Self::undo`] and [`Self::redo`] return [`PatchError::VersionMismatch`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut defaults = BindingSet::default();
+/// defaults.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+///
+/// let mut user = defaults.clone();
+/// let mut history = BindingHistory::default();
+///
+/// history.set(&mut user, "jump", 0, KeyCode::KeyJ.into())?;
+/// assert_eq!(user.get("jump").unwrap()[0], KeyCode::KeyJ.into());
+///
+/// history.undo(&mut user)?;
+/// assert_eq!(user.get("jump").unwrap()[0], KeyCode::Space.into());
+///
+/// history.redo(&mut user)?;
+/// assert_eq!(user.get("jump").unwrap()[0], KeyCode::KeyJ.into());
+///
+/// // Reverting is also an edit, so it can be undone.
+/// history.revert_to(&mut user, &defaults)?;
+/// assert_eq!(user.actions, defaults.actions);
+/// # Ok::<(), PatchError>(())
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct BindingHistory {
+    /// Patches that revert the applied edits, the last one is the most recent.
+    undo: Vec<BindingPatch>,
+
+    /// Patches that re-apply the undone edits, the last one is the most recently undone.
+    redo: Vec<BindingPatch>,
+
+    /// [`BindingSet::version`] after the last recorded operation.
+    version: u32,
+}
+
+impl BindingHistory {
+    /// Replaces the binding in the slot of the given action and records the edit.
+    ///
+    /// If the slot doesn't exist, it will be added. Slots in between are filled with [`Binding::None`].
+    /// Does nothing if the slot already contains this binding.
+    pub fn set(
+        &mut self,
+        set: &mut BindingSet,
+        action: impl Into<String>,
+        slot: usize,
+        binding: Binding,
+    ) -> Result<(), PatchError> {
+        let action = action.into();
+        let bindings = set.get(&action).unwrap_or_default();
+        let action_changes = match bindings.get(slot) {
+            Some(&old) if old == binding => return Ok(()),
+            Some(&old) => alloc::vec![BindingChange::Changed {
+                slot,
+                old,
+                new: binding,
+            }],
+            None => {
+                // Add the skipped slots explicitly to remove them on undo.
+                let mut action_changes: Vec<_> = (bindings.len()..slot)
+                    .map(|slot| BindingChange::Added {
+                        slot,
+                        binding: Binding::None,
+                    })
+                    .collect();
+                action_changes.push(BindingChange::Added { slot, binding });
+                action_changes
+            }
+        };
+
+        let mut changes = BTreeMap::new();
+        changes.insert(action, action_changes);
+
+        self.apply(
+            set,
+            BindingPatch {
+                base_version: set.version,
+                changes,
+            },
+        )
+    }
+
+    /// Changes all bindings to match `defaults` and records it as a single edit.
+    ///
+    /// Does nothing if the bindings already match.
+    pub fn revert_to(
+        &mut self,
+        set: &mut BindingSet,
+        defaults: &BindingSet,
+    ) -> Result<(), PatchError> {
+        self.apply(set, set.diff(defaults))
+    }
+
+    /// Applies the patch and records it as a single edit.
+    ///
+    /// Clears the redo log. Empty patches are not recorded.
+    pub fn apply(&mut self, set: &mut BindingSet, patch: BindingPatch) -> Result<(), PatchError> {
+        if patch.is_empty() {
+            return Ok(());
+        }
+
+        set.apply_patch(&patch)?;
+        self.undo.push(patch.inverse(set.version));
+        self.redo.clear();
+        self.version = set.version;
+
+        Ok(())
+    }
+
+    /// Reverts the most recent edit.
+    ///
+    /// Returns `false` if there is nothing to undo.
+    pub fn undo(&mut self, set: &mut BindingSet) -> Result<bool, PatchError> {
+        self.check_version(set)?;
+        let Some(patch) = self.undo.pop() else {
+            return Ok(false);
+        };
+
+        let redo_patch = self.apply_recorded(set, patch);
+        self.redo.push(redo_patch);
+
+        Ok(true)
+    }
+
+    /// Re-applies the most recently undone edit.
+    ///
+    /// Returns `false` if there is nothing to redo.
+    pub fn redo(&mut self, set: &mut BindingSet) -> Result<bool, PatchError> {
+        self.check_version(set)?;
+        let Some(patch) = self.redo.pop() else {
+            return Ok(false);
+        };
+
+        let undo_patch = self.apply_recorded(set, patch);
+        self.undo.push(undo_patch);
+
+        Ok(true)
+    }
+
+    /// Returns `true` if there is an edit to undo.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an edit to redo.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Returns an error if the set was modified outside of the history.
+    fn check_version(&self, set: &BindingSet) -> Result<(), PatchError> {
+        if (self.can_undo() || self.can_redo()) && set.version != self.version {
+            return Err(PatchError::VersionMismatch {
+                expected: self.version,
+                found: set.version,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Applies a patch from the log and returns its inverse.
+    fn apply_recorded(&mut self, set: &mut BindingSet, mut patch: BindingPatch) -> BindingPatch {
+        // Recorded patches are rebased since each undo and redo increments the version.
+        patch.base_version = set.version;
+        set.apply_patch(&patch)
+            .expect("recorded patch should match the current version");
+        self.version = set.version;
+
+        patch.inverse(set.version)
+    }
+
+    /// Forgets all recorded edits.
+    ///
+    /// Call it after saving the bindings if undo shouldn't go past the saved state.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn undo_redo() {
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::Space.into()]);
+        let original = set.clone();
+
+        let mut history = BindingHistory::default();
+        assert!(!history.can_undo());
+
+        history
+            .set(&mut set, "jump", 1, GamepadButton::South.into())
+            .unwrap();
+        history
+            .set(&mut set, "fire", 2, MouseButton::Left.into())
+            .unwrap();
+        assert_eq!(
+            set.get("fire").unwrap(),
+            [Binding::None, Binding::None, MouseButton::Left.into()]
+        );
+        let edited = set.clone();
+
+        assert!(history.undo(&mut set).unwrap());
+        assert!(history.undo(&mut set).unwrap());
+        assert!(!history.undo(&mut set).unwrap());
+        assert_eq!(set.actions, original.actions);
+
+        assert!(history.redo(&mut set).unwrap());
+        assert!(history.redo(&mut set).unwrap());
+        assert!(!history.can_redo());
+        assert_eq!(set.actions, edited.actions);
+    }
+
+    #[test]
+    fn same_binding() {
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::Space.into()]);
+
+        let mut history = BindingHistory::default();
+        history
+            .set(&mut set, "jump", 0, KeyCode::Space.into())
+            .unwrap();
+
+        assert!(!history.can_undo());
+        assert_eq!(set.version, 0);
+    }
+
+    #[test]
+    fn edit_clears_redo() {
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::Space.into()]);
+
+        let mut history = BindingHistory::default();
+        history
+            .set(&mut set, "jump", 0, KeyCode::KeyJ.into())
+            .unwrap();
+        history.undo(&mut set).unwrap();
+        assert!(history.can_redo());
+
+        history
+            .set(&mut set, "jump", 0, KeyCode::KeyK.into())
+            .unwrap();
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn revert_to() {
+        let mut defaults = BindingSet::default();
+        defaults.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+        defaults.insert("fire", [MouseButton::Left.into()]);
+
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::KeyJ.into()]);
+        set.insert("crouch", [KeyCode::ControlLeft.into()]);
+        let original = set.clone();
+
+        let mut history = BindingHistory::default();
+        history.revert_to(&mut set, &defaults).unwrap();
+        assert_eq!(set.actions, defaults.actions);
+
+        history.undo(&mut set).unwrap();
+        assert_eq!(set.actions, original.actions);
+    }
+
+    #[test]
+    fn external_change() {
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::Space.into()]);
+
+        let mut history = BindingHistory::default();
+        history
+            .set(&mut set, "jump", 0, KeyCode::KeyJ.into())
+            .unwrap();
+
+        let mut other = set.clone();
+        other.insert("jump", [KeyCode::KeyK.into()]);
+        set.apply_patch(&set.diff(&other)).unwrap();
+
+        assert_eq!(
+            history.undo(&mut set),
+            Err(PatchError::VersionMismatch {
+                expected: 1,
+                found: 2
+            })
+        );
+        assert!(history.can_undo(), "failed edit should be kept");
+    }
+}
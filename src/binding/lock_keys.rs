@@ -0,0 +1,191 @@
+use core::fmt::{self, Display, Formatter};
+
+use bevy::{
+    ecs::message::MessageCursor,
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use log::debug;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Keyboard key with a toggled lock state, such as Num Lock.
+///
+/// Used by [`Binding::LockKey`](super::Binding::LockKey) and
+/// [`LockState`](crate::prelude::LockState).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum LockKey {
+    /// Corresponds to [`KeyCode::CapsLock`].
+    CapsLock,
+    /// Corresponds to [`KeyCode::NumLock`].
+    NumLock,
+    /// Corresponds to [`KeyCode::ScrollLock`].
+    ScrollLock,
+}
+
+impl LockKey {
+    /// Returns the key that toggles the lock state.
+    #[must_use]
+    pub const fn key_code(self) -> KeyCode {
+        match self {
+            LockKey::CapsLock => KeyCode::CapsLock,
+            LockKey::NumLock => KeyCode::NumLock,
+            LockKey::ScrollLock => KeyCode::ScrollLock,
+        }
+    }
+}
+
+impl Display for LockKey {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            LockKey::CapsLock => write!(f, "Caps Lock"),
+            LockKey::NumLock => write!(f, "Num Lock"),
+            LockKey::ScrollLock => write!(f, "Scroll Lock"),
+        }
+    }
+}
+
+/// Lock states of keyboard keys for [`Binding::LockKey`](super::Binding::LockKey)
+/// and [`LockState`](crate::prelude::LockState).
+///
+/// Bevy doesn't expose the lock states reported by the OS, so they are tracked by
+/// toggling on each press of the corresponding key during [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare).
+/// The states are also corrected from the logical keys reported by the platform in [`KeyboardInput`]:
+/// Num Lock from numpad digits and Caps Lock from the case of typed letters.
+/// Inference is skipped while Shift is held, since platforms handle it differently.
+/// Scroll Lock can only be tracked by toggling.
+///
+/// All locks start disabled. You can assign the actual states if they are known from other sources.
+///
+/// Initialized by [`crate::EnhancedInputPlugin`].
+#[derive(Resource, Default, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct LockKeys {
+    pub caps_lock: bool,
+    pub num_lock: bool,
+    pub scroll_lock: bool,
+}
+
+impl LockKeys {
+    /// Returns `true` if the lock is enabled.
+    #[must_use]
+    pub fn get(&self, key: LockKey) -> bool {
+        match key {
+            LockKey::CapsLock => self.caps_lock,
+            LockKey::NumLock => self.num_lock,
+            LockKey::ScrollLock => self.scroll_lock,
+        }
+    }
+
+    /// Enables or disables the lock.
+    pub fn set(&mut self, key: LockKey, enabled: bool) {
+        match key {
+            LockKey::CapsLock => self.caps_lock = enabled,
+            LockKey::NumLock => self.num_lock = enabled,
+            LockKey::ScrollLock => self.scroll_lock = enabled,
+        }
+    }
+
+    /// Corrects lock states from the logical key of a keyboard press.
+    ///
+    /// Skipped while Shift is held because its effect depends on the platform. For example,
+    /// on Windows Shift turns numpad digits into navigation keys when Num Lock is enabled,
+    /// and on macOS it doesn't invert the case of letters when Caps Lock is enabled.
+    fn infer(&mut self, input: &KeyboardInput, shift: bool) {
+        if input.state != ButtonState::Pressed || shift {
+            return;
+        }
+
+        let character = match &input.logical_key {
+            Key::Character(text) => {
+                let mut chars = text.chars();
+                chars.next().filter(|_| chars.next().is_none())
+            }
+            _ => None,
+        };
+
+        if is_numpad_digit(input.key_code) {
+            let num_lock = character.is_some();
+            if self.num_lock != num_lock {
+                debug!("inferred `{:?}` as `{num_lock}`", LockKey::NumLock);
+                self.num_lock = num_lock;
+            }
+        } else if let Some(character) = character
+            && (character.is_lowercase() || character.is_uppercase())
+        {
+            let caps_lock = character.is_uppercase();
+            if self.caps_lock != caps_lock {
+                debug!("inferred `{:?}` as `{caps_lock}`", LockKey::CapsLock);
+                self.caps_lock = caps_lock;
+            }
+        }
+    }
+}
+
+fn is_numpad_digit(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::Numpad0
+            | KeyCode::Numpad1
+            | KeyCode::Numpad2
+            | KeyCode::Numpad3
+            | KeyCode::Numpad4
+            | KeyCode::Numpad5
+            | KeyCode::Numpad6
+            | KeyCode::Numpad7
+            | KeyCode::Numpad8
+            | KeyCode::Numpad9
+            | KeyCode::NumpadDecimal
+    )
+}
+
+pub(crate) fn update_lock_keys(
+    mut lock_keys: ResMut<LockKeys>,
+    mut last_pressed: Local<[bool; 3]>,
+    mut cursor: Local<MessageCursor<KeyboardInput>>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    keyboard_inputs: Option<Res<Messages<KeyboardInput>>>,
+) {
+    let Some(keys) = keys else {
+        return;
+    };
+
+    // Compare with the previous run instead of using `just_pressed`
+    // to also react to presses from `MockInputExt`.
+    for (key, last_pressed) in [LockKey::CapsLock, LockKey::NumLock, LockKey::ScrollLock]
+        .into_iter()
+        .zip(&mut *last_pressed)
+    {
+        let pressed = keys.pressed(key.key_code());
+        if pressed && !*last_pressed {
+            let enabled = !lock_keys.get(key);
+            debug!("toggling `{key:?}` to `{enabled}`");
+            lock_keys.set(key, enabled);
+        }
+        *last_pressed = pressed;
+    }
+
+    if let Some(keyboard_inputs) = keyboard_inputs {
+        let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+        for input in cursor.read(&keyboard_inputs) {
+            lock_keys.infer(input, shift);
+        }
+    }
+}
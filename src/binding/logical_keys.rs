@@ -0,0 +1,132 @@
+//! Keys identified by the character they type for [`Binding::LogicalKey`](super::Binding::LogicalKey).
+//!
+//! [`KeyCode`] is a physical key location, which is what you want for movement and other positional
+//! controls. But text-centric shortcuts, like Ctrl + Z for undo, are expected to follow the letters
+//! printed on the keyboard. On AZERTY, Z is located where QWERTY has W.
+//!
+//! Characters are read from [`KeyboardInput::logical_key`] during
+//! [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare) and compared
+//! case-insensitively, so Shift affects only characters without a case, like digits.
+//! Keys that don't type a character, such as Escape, should be bound by [`KeyCode`].
+
+use bevy::{
+    ecs::message::MessageCursor,
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardFocusLost, KeyboardInput},
+    },
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use log::trace;
+
+/// Characters of the currently pressed keys.
+#[derive(Resource, Default)]
+pub(crate) struct LogicalKeys {
+    /// Characters typed by physical keys when they were pressed.
+    ///
+    /// Stored per key because the release can report a different character
+    /// if the modifiers changed in between.
+    keys: HashMap<KeyCode, char>,
+
+    /// Characters pressed by [`MockInputExt`](super::mock_input::MockInputExt).
+    pub(crate) mocked: HashSet<char>,
+}
+
+impl LogicalKeys {
+    /// Returns `true` if a key with the character is pressed.
+    pub(crate) fn pressed(&self, character: char) -> bool {
+        let character = fold_case(character);
+        self.mocked.contains(&character) || self.keys.values().any(|&c| c == character)
+    }
+
+    /// Returns the character typed by the pressed physical key.
+    pub(crate) fn character(&self, key: KeyCode) -> Option<char> {
+        self.keys.get(&key).copied()
+    }
+
+    /// Returns physical keys that typed the character.
+    pub(crate) fn keys(&self, character: char) -> impl Iterator<Item = KeyCode> + '_ {
+        let character = fold_case(character);
+        self.keys
+            .iter()
+            .filter(move |&(_, &c)| c == character)
+            .map(|(&key, _)| key)
+    }
+}
+
+/// Converts the character to lowercase if it maps to a single character.
+pub(crate) fn fold_case(character: char) -> char {
+    let mut lowercase = character.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(c), None) => c,
+        _ => character,
+    }
+}
+
+pub(crate) fn update_logical_keys(
+    mut logical_keys: ResMut<LogicalKeys>,
+    mut input_cursor: Local<MessageCursor<KeyboardInput>>,
+    mut focus_cursor: Local<MessageCursor<KeyboardFocusLost>>,
+    keyboard_inputs: Option<Res<Messages<KeyboardInput>>>,
+    focus_lost: Option<Res<Messages<KeyboardFocusLost>>>,
+) {
+    let Some(keyboard_inputs) = keyboard_inputs else {
+        return;
+    };
+
+    // Release all keys like Bevy does for `ButtonInput<KeyCode>`.
+    if let Some(focus_lost) = focus_lost
+        && focus_cursor.read(&focus_lost).last().is_some()
+        && !logical_keys.keys.is_empty()
+    {
+        logical_keys.keys.clear();
+    }
+
+    for input in input_cursor.read(&keyboard_inputs) {
+        match input.state {
+            ButtonState::Pressed => {
+                let Key::Character(text) = &input.logical_key else {
+                    continue;
+                };
+                let mut chars = text.chars();
+                let (Some(character), None) = (chars.next(), chars.next()) else {
+                    continue;
+                };
+
+                let character = fold_case(character);
+                trace!("mapping `{:?}` to `{character}`", input.key_code);
+                logical_keys.keys.insert(input.key_code, character);
+            }
+            ButtonState::Released => {
+                logical_keys.keys.remove(&input.key_code);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn case() {
+        assert_eq!(fold_case('Z'), 'z');
+        assert_eq!(fold_case('Ä'), 'ä');
+        assert_eq!(fold_case('1'), '1');
+        assert_eq!(fold_case('İ'), 'İ', "multi-char lowercase should be kept");
+    }
+
+    #[test]
+    fn pressed() {
+        let mut logical_keys = LogicalKeys::default();
+        logical_keys.keys.insert(KeyCode::KeyW, 'z');
+
+        assert!(logical_keys.pressed('Z'));
+        assert!(!logical_keys.pressed('w'));
+        assert_eq!(logical_keys.character(KeyCode::KeyW), Some('z'));
+        assert_eq!(logical_keys.keys('z').collect::<Vec<_>>(), [KeyCode::KeyW]);
+    }
+}
@@ -0,0 +1,146 @@
+//! Hook for macro pad backends, providing keys as input for [`Binding::MacroPadKey`].
+//!
+//! Macro pads, like the Stream Deck, are HID devices with programmable keys, often with
+//! an image on each key instead of a printed label. Reading them requires a platform HID library,
+//! so no HID backend ships with this crate and nothing here talks to the devices directly.
+//! Instead, an external backend (usually a separate crate) presses and releases keys in [`MacroPadKeys`],
+//! making them available for bindings like any other button. Without a backend, the keys are never pressed.
+//!
+//! Requires the `macro_pad` feature.
+//!
+//! [`MacroPadGlyphs`] stores the key images for UI prompts and for backends that upload
+//! them to the device.
+
+use core::fmt::{self, Display, Formatter};
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Key on a macro pad, numbered by the backend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MacroPadKey(pub u8);
+
+impl Display for MacroPadKey {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Macro Pad {}", self.0)
+    }
+}
+
+/// Pressed keys of connected macro pads.
+///
+/// Should be updated by the backend before [`EnhancedInputSystems::Prepare`].
+/// Keys from all connected pads share the same numbering.
+///
+/// Initialized by [`crate::EnhancedInputPlugin`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.add_systems(PreUpdate, read_pad.before(EnhancedInputSystems::Prepare));
+///
+/// fn read_pad(mut keys: ResMut<MacroPadKeys>) {
+///     // Usually read from the HID device.
+///     let reports = [(0, true), (1, false)];
+///     for (key, pressed) in reports {
+///         keys.set(MacroPadKey(key), pressed);
+///     }
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct MacroPadKeys {
+    pressed: HashSet<MacroPadKey>,
+}
+
+impl MacroPadKeys {
+    /// Returns `true` if the key is pressed.
+    #[must_use]
+    pub fn pressed(&self, key: MacroPadKey) -> bool {
+        self.pressed.contains(&key)
+    }
+
+    /// Sets the pressed state of the key.
+    pub fn set(&mut self, key: MacroPadKey, pressed: bool) {
+        if pressed {
+            self.pressed.insert(key);
+        } else {
+            self.pressed.remove(&key);
+        }
+    }
+
+    /// Releases all keys.
+    ///
+    /// Should be called by the backend when a pad disconnects.
+    pub fn release_all(&mut self) {
+        self.pressed.clear();
+    }
+}
+
+/// Maps macro pad keys to images.
+///
+/// Initialized by [`crate::EnhancedInputPlugin`] as empty.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut glyphs = MacroPadGlyphs::default();
+/// glyphs
+///     .insert(MacroPadKey(0), "icons/pad/record.png")
+///     .insert(MacroPadKey(1), 3);
+///
+/// assert_eq!(
+///     glyphs.get(MacroPadKey(0)),
+///     Some(&Glyph::Path("icons/pad/record.png".into()))
+/// );
+/// assert_eq!(glyphs.get(MacroPadKey(2)), None);
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct MacroPadGlyphs {
+    glyphs: HashMap<MacroPadKey, Glyph>,
+}
+
+impl MacroPadGlyphs {
+    /// Sets the image for the key.
+    pub fn insert(&mut self, key: MacroPadKey, glyph: impl Into<Glyph>) -> &mut Self {
+        self.glyphs.insert(key, glyph.into());
+        self
+    }
+
+    /// Returns the image for the key.
+    #[must_use]
+    pub fn get(&self, key: MacroPadKey) -> Option<&Glyph> {
+        self.glyphs.get(&key)
+    }
+
+    /// Returns all keys with their images.
+    pub fn iter(&self) -> impl Iterator<Item = (MacroPadKey, &Glyph)> {
+        self.glyphs.iter().map(|(&key, glyph)| (key, glyph))
+    }
+}
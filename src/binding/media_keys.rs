@@ -0,0 +1,114 @@
+//! Media and consumer keys, such as play/pause, volume and browser keys.
+//!
+//! These keys are regular [`KeyCode`]s and can be bound with [`Binding::Keyboard`](super::Binding::Keyboard)
+//! like any other key. Some platforms, such as certain browsers, report them only as logical keys
+//! with [`KeyCode::Unidentified`]. Such presses are mapped back to their key codes during
+//! [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare), so bindings work the same way.
+//!
+//! Keys intercepted by the OS, such as media keys on macOS, are not reported to the app at all.
+
+use bevy::{
+    ecs::message::MessageCursor,
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardFocusLost, KeyboardInput},
+    },
+    platform::collections::HashSet,
+    prelude::*,
+};
+use log::trace;
+
+/// Returns `true` if the key is a media or consumer key.
+#[must_use]
+pub fn is_media_key(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::BrowserBack
+            | KeyCode::BrowserFavorites
+            | KeyCode::BrowserForward
+            | KeyCode::BrowserHome
+            | KeyCode::BrowserRefresh
+            | KeyCode::BrowserSearch
+            | KeyCode::BrowserStop
+            | KeyCode::LaunchApp1
+            | KeyCode::LaunchApp2
+            | KeyCode::LaunchMail
+            | KeyCode::MediaPlayPause
+            | KeyCode::MediaSelect
+            | KeyCode::MediaStop
+            | KeyCode::MediaTrackNext
+            | KeyCode::MediaTrackPrevious
+            | KeyCode::AudioVolumeDown
+            | KeyCode::AudioVolumeMute
+            | KeyCode::AudioVolumeUp
+    )
+}
+
+/// Returns the key code for a logical media or consumer key.
+fn media_key_code(key: &Key) -> Option<KeyCode> {
+    let key_code = match key {
+        Key::BrowserBack => KeyCode::BrowserBack,
+        Key::BrowserFavorites => KeyCode::BrowserFavorites,
+        Key::BrowserForward => KeyCode::BrowserForward,
+        Key::BrowserHome => KeyCode::BrowserHome,
+        Key::BrowserRefresh => KeyCode::BrowserRefresh,
+        Key::BrowserSearch => KeyCode::BrowserSearch,
+        Key::BrowserStop => KeyCode::BrowserStop,
+        Key::LaunchApplication1 => KeyCode::LaunchApp1,
+        Key::LaunchApplication2 => KeyCode::LaunchApp2,
+        Key::LaunchMail => KeyCode::LaunchMail,
+        Key::MediaPlayPause => KeyCode::MediaPlayPause,
+        Key::LaunchMediaPlayer => KeyCode::MediaSelect,
+        Key::MediaStop => KeyCode::MediaStop,
+        Key::MediaTrackNext => KeyCode::MediaTrackNext,
+        Key::MediaTrackPrevious => KeyCode::MediaTrackPrevious,
+        Key::AudioVolumeDown => KeyCode::AudioVolumeDown,
+        Key::AudioVolumeMute => KeyCode::AudioVolumeMute,
+        Key::AudioVolumeUp => KeyCode::AudioVolumeUp,
+        _ => return None,
+    };
+
+    Some(key_code)
+}
+
+/// Media keys that are currently pressed, but reported only as logical keys.
+#[derive(Resource, Default, Deref)]
+pub(crate) struct LogicalMediaKeys(HashSet<KeyCode>);
+
+pub(crate) fn update_media_keys(
+    mut media_keys: ResMut<LogicalMediaKeys>,
+    mut input_cursor: Local<MessageCursor<KeyboardInput>>,
+    mut focus_cursor: Local<MessageCursor<KeyboardFocusLost>>,
+    keyboard_inputs: Option<Res<Messages<KeyboardInput>>>,
+    focus_lost: Option<Res<Messages<KeyboardFocusLost>>>,
+) {
+    let Some(keyboard_inputs) = keyboard_inputs else {
+        return;
+    };
+
+    // Release all keys like Bevy does for `ButtonInput<KeyCode>`.
+    if let Some(focus_lost) = focus_lost
+        && focus_cursor.read(&focus_lost).last().is_some()
+        && !media_keys.is_empty()
+    {
+        media_keys.0.clear();
+    }
+
+    for input in input_cursor.read(&keyboard_inputs) {
+        if !matches!(input.key_code, KeyCode::Unidentified(_)) {
+            continue;
+        }
+        let Some(key_code) = media_key_code(&input.logical_key) else {
+            continue;
+        };
+
+        trace!(
+            "mapping logical `{:?}` to `{key_code:?}`",
+            input.logical_key
+        );
+        match input.state {
+            ButtonState::Pressed => media_keys.0.insert(key_code),
+            ButtonState::Released => media_keys.0.remove(&key_code),
+        };
+    }
+}
@@ -0,0 +1,247 @@
+//! Helpers for simulating physical input in tests.
+//!
+//! Unlike [action mocking](crate::action::mock), which skips evaluation, these helpers write
+//! to the same input resources and [`Gamepad`] components the bindings read from, so the inputs
+//! go through the usual evaluation with all modifiers and conditions.
+//!
+//! Gamepad inputs are applied to the first gamepad entity. If no gamepad exists, one will be spawned.
+
+use bevy::{
+    input::mouse::{MouseMotion, MouseScrollUnit, MouseWheel},
+    prelude::*,
+};
+use log::warn;
+
+use crate::{
+    binding::{
+        logical_keys::{LogicalKeys, fold_case},
+        ui_buttons::PressedUiButtons,
+        virtual_inputs::VirtualInputs,
+    },
+    prelude::*,
+};
+
+/// Extension trait for [`World`] and [`App`] to simulate input.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{input::InputPlugin, prelude::*};
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+///     .add_input_context::<Player>()
+///     .finish();
+///
+/// app.world_mut().spawn((
+///     Player,
+///     actions!(Player[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+/// ));
+///
+/// app.press(KeyCode::Space);
+/// app.update();
+///
+/// let mut jump = app.world_mut().query::<&TriggerState>();
+/// assert_eq!(*jump.single(app.world()).unwrap(), TriggerState::Fired);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+pub trait MockInputExt {
+    /// Presses a keyboard key, mouse button or gamepad button.
+    ///
+    /// Keyboard modifiers from the binding are pressed too, using their left variants.
+    /// The input stays pressed until [`Self::release`] is called.
+    ///
+    /// Logs a warning for bindings that aren't buttons.
+    fn press(&mut self, binding: impl Into<Binding>) -> &mut Self;
+
+    /// Releases a button pressed by [`Self::press`], including its keyboard modifiers.
+    fn release(&mut self, binding: impl Into<Binding>) -> &mut Self;
+
+    /// Moves the mouse by the given delta during the next update.
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self;
+
+    /// Scrolls the mouse wheel by the given number of lines during the next update.
+    fn scroll_mouse(&mut self, delta: Vec2) -> &mut Self;
+
+    /// Sets the value of a gamepad axis.
+    ///
+    /// The value stays until changed.
+    fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self;
+
+    /// Sets the analog value of a gamepad button, such as a partially pulled trigger.
+    ///
+    /// The button is considered pressed while the value is non-zero.
+    /// The value stays until changed.
+    fn set_button_value(&mut self, button: GamepadButton, value: f32) -> &mut Self;
+}
+
+impl MockInputExt for World {
+    fn press(&mut self, binding: impl Into<Binding>) -> &mut Self {
+        set_button(self, binding.into(), true);
+        self
+    }
+
+    fn release(&mut self, binding: impl Into<Binding>) -> &mut Self {
+        set_button(self, binding.into(), false);
+        self
+    }
+
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.write_message(MouseMotion { delta });
+        self
+    }
+
+    fn scroll_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.write_message(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: delta.x,
+            y: delta.y,
+            window: Entity::PLACEHOLDER,
+        });
+        self
+    }
+
+    fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self {
+        gamepad_mut(self).analog_mut().set(axis, value);
+        self
+    }
+
+    fn set_button_value(&mut self, button: GamepadButton, value: f32) -> &mut Self {
+        let mut gamepad = gamepad_mut(self);
+        set_pressed(gamepad.digital_mut(), button, value != 0.0);
+        gamepad.analog_mut().set(button, value);
+        self
+    }
+}
+
+impl MockInputExt for App {
+    fn press(&mut self, binding: impl Into<Binding>) -> &mut Self {
+        self.world_mut().press(binding);
+        self
+    }
+
+    fn release(&mut self, binding: impl Into<Binding>) -> &mut Self {
+        self.world_mut().release(binding);
+        self
+    }
+
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.world_mut().move_mouse(delta);
+        self
+    }
+
+    fn scroll_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.world_mut().scroll_mouse(delta);
+        self
+    }
+
+    fn set_axis(&mut self, axis: GamepadAxis, value: f32) -> &mut Self {
+        self.world_mut().set_axis(axis, value);
+        self
+    }
+
+    fn set_button_value(&mut self, button: GamepadButton, value: f32) -> &mut Self {
+        self.world_mut().set_button_value(button, value);
+        self
+    }
+}
+
+fn set_button(world: &mut World, binding: Binding, pressed: bool) {
+    let mod_keys = binding.mod_keys();
+    if !mod_keys.is_empty() {
+        let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+        for [key, _] in mod_keys.iter_keys() {
+            set_pressed(&mut keys, key, pressed);
+        }
+    }
+
+    match binding {
+        Binding::Keyboard { key, .. } => {
+            let mut keys = world.resource_mut::<ButtonInput<KeyCode>>();
+            set_pressed(&mut keys, key, pressed);
+        }
+        Binding::LogicalKey { character, .. } => {
+            let mut logical_keys = world.resource_mut::<LogicalKeys>();
+            let character = fold_case(character);
+            if pressed {
+                logical_keys.mocked.insert(character);
+            } else {
+                logical_keys.mocked.remove(&character);
+            }
+        }
+        Binding::LockKey(key) => {
+            world.resource_mut::<LockKeys>().set(key, pressed);
+        }
+        Binding::MouseButton { button, .. } => {
+            let mut buttons = world.resource_mut::<ButtonInput<MouseButton>>();
+            set_pressed(&mut buttons, button, pressed);
+        }
+        Binding::UiButton(entity) => {
+            let mut pressed_buttons = world.resource_mut::<PressedUiButtons>();
+            if pressed {
+                pressed_buttons.insert(entity);
+            } else {
+                pressed_buttons.remove(&entity);
+            }
+        }
+        Binding::Virtual(input) => {
+            let mut virtual_inputs = world.resource_mut::<VirtualInputs>();
+            if pressed {
+                virtual_inputs.mocked.insert(input);
+            } else {
+                virtual_inputs.mocked.remove(&input);
+            }
+        }
+        #[cfg(feature = "macro_pad")]
+        Binding::MacroPadKey(key) => {
+            world.resource_mut::<MacroPadKeys>().set(key, pressed);
+        }
+        Binding::GamepadButton(button) => {
+            let mut gamepad = gamepad_mut(world);
+            set_pressed(gamepad.digital_mut(), button, pressed);
+            let value = if pressed { 1.0 } else { 0.0 };
+            gamepad.analog_mut().set(button, value);
+        }
+        Binding::MouseMotion { .. }
+        | Binding::MouseDrag { .. }
+        | Binding::MouseWheel { .. }
+        | Binding::MouseWheelStep { .. }
+        | Binding::MouseWheelPrecise { .. }
+        | Binding::MousePosition { .. }
+        | Binding::GamepadAxis(_)
+        | Binding::AnyKey
+        | Binding::AnyInput
+        | Binding::None => {
+            warn!("`{binding:?}` is not a button and can't be pressed or released");
+        }
+    }
+}
+
+fn set_pressed<T: Copy + Eq + core::hash::Hash + Send + Sync + 'static>(
+    input: &mut ButtonInput<T>,
+    button: T,
+    pressed: bool,
+) {
+    if pressed {
+        input.press(button);
+    } else {
+        input.release(button);
+    }
+}
+
+fn gamepad_mut(world: &mut World) -> Mut<'_, Gamepad> {
+    let mut gamepads = world.query_filtered::<Entity, With<Gamepad>>();
+    let entity = match gamepads.iter(world).next() {
+        Some(entity) => entity,
+        None => world.spawn(Gamepad::default()).id(),
+    };
+
+    world.get_mut::<Gamepad>(entity).unwrap()
+}
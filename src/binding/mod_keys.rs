@@ -11,11 +11,11 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// order in which its action is evaluated. See
 /// [`ActionSettings::consume_input`](crate::prelude::ActionSettings::consume_input)
 /// for more details.
-#[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
     derive(Reflect),
-    reflect(Clone, Debug, Default, PartialEq)
+    reflect(Clone, Debug, Default, Hash, PartialEq)
 )]
 #[cfg_attr(
     all(feature = "reflect", feature = "serialize"),
@@ -0,0 +1,70 @@
+//! Mouse motion accumulated while a mouse button is held.
+//!
+//! [`Binding::MouseMotion`](super::Binding::MouseMotion) reports the motion of the current frame,
+//! regardless of buttons. [`Binding::MouseDrag`](super::Binding::MouseDrag) instead reports the total
+//! displacement since the button was pressed and resets to zero on release. This is useful for box
+//! selection in strategy games or panning a camera with a held button.
+//!
+//! Motion from the frame the button is pressed is not included.
+
+use bevy::{
+    input::mouse::AccumulatedMouseMotion,
+    platform::collections::{HashMap, hash_map::Entry},
+    prelude::*,
+};
+use log::trace;
+
+/// Mouse displacement for each held mouse button.
+///
+/// Updated from [`ButtonInput<MouseButton>`] and [`AccumulatedMouseMotion`] during
+/// [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare).
+///
+/// Initialized by [`crate::EnhancedInputPlugin`].
+#[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct MouseDrags {
+    displacements: HashMap<MouseButton, Vec2>,
+}
+
+impl MouseDrags {
+    /// Returns the total mouse motion since the button was pressed.
+    ///
+    /// Returns zero if the button is not held.
+    #[must_use]
+    pub fn displacement(&self, button: MouseButton) -> Vec2 {
+        self.displacements.get(&button).copied().unwrap_or_default()
+    }
+
+    /// Returns `true` if the button is held.
+    #[must_use]
+    pub fn is_dragging(&self, button: MouseButton) -> bool {
+        self.displacements.contains_key(&button)
+    }
+}
+
+pub(crate) fn update_mouse_drags(
+    mut drags: ResMut<MouseDrags>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+) {
+    let delta = mouse_motion.map(|m| m.delta).unwrap_or_default();
+    drags.displacements.retain(|&button, _| {
+        mouse_buttons
+            .as_ref()
+            .is_some_and(|buttons| buttons.pressed(button))
+    });
+
+    for &button in mouse_buttons.iter().flat_map(|b| b.get_pressed()) {
+        match drags.displacements.entry(button) {
+            Entry::Occupied(mut entry) => *entry.get_mut() += delta,
+            Entry::Vacant(entry) => {
+                trace!("starting drag for `{button:?}`");
+                entry.insert(Vec2::ZERO);
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+};
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Bindings for multiple actions, identified by stable names.
+///
+/// Each action stores its bindings as ordered slots, similar to columns in a keybinding menu.
+/// Unbound slots are usually represented by [`Binding::None`].
+///
+/// Intended for user settings. Instead of rewriting the whole profile, you can compute
+/// a [`BindingPatch`] with [`Self::diff`] and store or sync only the changes,
+/// then restore them using [`Self::apply_patch`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut defaults = BindingSet::default();
+/// defaults.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+/// defaults.insert("fire", [MouseButton::Left.into()]);
+///
+/// let mut user = defaults.clone();
+/// user.insert("jump", [KeyCode::KeyJ.into(), GamepadButton::South.into()]);
+///
+/// // Store only what the user changed.
+/// let patch = defaults.diff(&user);
+/// assert_eq!(patch.changes.len(), 1);
+///
+/// // Later restore user settings on top of the defaults.
+/// let mut restored = defaults.clone();
+/// restored.apply_patch(&patch).unwrap();
+/// assert_eq!(restored.get("jump"), user.get("jump"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct BindingSet {
+    /// Incremented each time a patch is applied.
+    ///
+    /// Patches can only be applied to the version they were computed from.
+    pub version: u32,
+
+    /// Binding slots for each action name.
+    pub actions: BTreeMap<String, Vec<Binding>>,
+}
+
+impl BindingSet {
+    /// Replaces bindings for the given action name.
+    pub fn insert(
+        &mut self,
+        action: impl Into<String>,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) {
+        self.actions
+            .insert(action.into(), bindings.into_iter().collect());
+    }
+
+    /// Returns bindings for the given action name.
+    #[must_use]
+    pub fn get(&self, action: &str) -> Option<&[Binding]> {
+        self.actions.get(action).map(|bindings| &**bindings)
+    }
+
+    /// Returns changes required to turn `self` into `other`.
+    ///
+    /// Actions without changes are not included.
+    /// Actions added without bindings are included with an empty list of changes.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> BindingPatch {
+        let mut changes = BTreeMap::new();
+
+        for (action, old_bindings) in &self.actions {
+            let new_bindings = other.get(action).unwrap_or_default();
+            let action_changes = diff_slots(old_bindings, new_bindings);
+            if !action_changes.is_empty() {
+                changes.insert(action.clone(), action_changes);
+            }
+        }
+
+        for (action, new_bindings) in &other.actions {
+            if !self.actions.contains_key(action) {
+                changes.insert(action.clone(), diff_slots(&[], new_bindings));
+            }
+        }
+
+        BindingPatch {
+            base_version: self.version,
+            changes,
+        }
+    }
+
+    /// Applies changes computed by [`Self::diff`].
+    ///
+    /// Only the slots mentioned in the patch are modified.
+    /// Actions that end up without slots are removed, unless the patch
+    /// lists them without changes, in which case they are created empty.
+    ///
+    /// Returns an error if the patch was computed from a different [`Self::version`].
+    /// On success, the version is incremented.
+    pub fn apply_patch(&mut self, patch: &BindingPatch) -> Result<(), PatchError> {
+        if patch.base_version != self.version {
+            return Err(PatchError::VersionMismatch {
+                expected: patch.base_version,
+                found: self.version,
+            });
+        }
+
+        for (action, action_changes) in &patch.changes {
+            let bindings = self.actions.entry(action.clone()).or_default();
+            for &change in action_changes {
+                match change {
+                    BindingChange::Added { slot, binding }
+                    | BindingChange::Changed {
+                        slot, new: binding, ..
+                    } => {
+                        if bindings.len() <= slot {
+                            bindings.resize(slot + 1, Binding::None);
+                        }
+                        bindings[slot] = binding;
+                    }
+                    BindingChange::Removed { slot, .. } => {
+                        if slot + 1 == bindings.len() {
+                            bindings.pop();
+                        } else if let Some(binding) = bindings.get_mut(slot) {
+                            *binding = Binding::None;
+                        }
+                    }
+                }
+            }
+
+            if bindings.is_empty() && !action_changes.is_empty() {
+                self.actions.remove(action);
+            }
+        }
+
+        self.version += 1;
+
+        Ok(())
+    }
+}
+
+fn diff_slots(old: &[Binding], new: &[Binding]) -> Vec<BindingChange> {
+    let mut changes = Vec::new();
+    for (slot, (&old, &new)) in old.iter().zip(new).enumerate() {
+        if old != new {
+            changes.push(BindingChange::Changed { slot, old, new });
+        }
+    }
+
+    for (slot, &binding) in new.iter().enumerate().skip(old.len()) {
+        changes.push(BindingChange::Added { slot, binding });
+    }
+
+    // Remove from the end to keep the remaining slot indices valid.
+    for (slot, &binding) in old.iter().enumerate().skip(new.len()).rev() {
+        changes.push(BindingChange::Removed { slot, binding });
+    }
+
+    changes
+}
+
+/// Changes between two [`BindingSet`]s.
+///
+/// Created by [`BindingSet::diff`] and applied with [`BindingSet::apply_patch`].
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct BindingPatch {
+    /// [`BindingSet::version`] from which this patch was computed.
+    pub base_version: u32,
+
+    /// Changed slots for each action name.
+    pub changes: BTreeMap<String, Vec<BindingChange>>,
+}
+
+impl BindingPatch {
+    /// Returns `true` if the patch contains no changes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Returns a patch that reverts this one.
+    ///
+    /// `base_version` is the [`BindingSet::version`] after this patch was applied.
+    #[must_use]
+    pub fn inverse(&self, base_version: u32) -> Self {
+        let changes = self
+            .changes
+            .iter()
+            .map(|(action, action_changes)| {
+                // Revert in the opposite order to keep the slot indices valid.
+                let action_changes = action_changes
+                    .iter()
+                    .rev()
+                    .map(|change| change.inverse())
+                    .collect();
+                (action.clone(), action_changes)
+            })
+            .collect();
+
+        Self {
+            base_version,
+            changes,
+        }
+    }
+}
+
+/// A single slot change inside [`BindingPatch`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub enum BindingChange {
+    /// A new slot was added.
+    Added { slot: usize, binding: Binding },
+    /// The binding in the slot was replaced.
+    Changed {
+        slot: usize,
+        old: Binding,
+        new: Binding,
+    },
+    /// The slot was removed.
+    Removed { slot: usize, binding: Binding },
+}
+
+impl BindingChange {
+    /// Returns a change that reverts this one.
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        match self {
+            BindingChange::Added { slot, binding } => BindingChange::Removed { slot, binding },
+            BindingChange::Changed { slot, old, new } => BindingChange::Changed {
+                slot,
+                old: new,
+                new: old,
+            },
+            BindingChange::Removed { slot, binding } => BindingChange::Added { slot, binding },
+        }
+    }
+}
+
+/// Error returned by [`BindingSet::apply_patch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatchError {
+    /// The patch was computed from a different version of the set.
+    VersionMismatch { expected: u32, found: u32 },
+}
+
+impl Display for PatchError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            PatchError::VersionMismatch { expected, found } => write!(
+                f,
+                "patch expects binding set version {expected}, but found {found}"
+            ),
+        }
+    }
+}
+
+impl Error for PatchError {}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn changed() {
+        let mut old = BindingSet::default();
+        old.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+        old.insert("fire", [MouseButton::Left.into()]);
+
+        let mut new = old.clone();
+        new.insert("jump", [KeyCode::KeyJ.into(), GamepadButton::South.into()]);
+
+        let patch = old.diff(&new);
+        assert_eq!(patch.changes.len(), 1);
+        assert_eq!(
+            patch.changes["jump"],
+            [BindingChange::Changed {
+                slot: 0,
+                old: KeyCode::Space.into(),
+                new: KeyCode::KeyJ.into(),
+            }]
+        );
+
+        old.apply_patch(&patch).unwrap();
+        assert_eq!(old.actions, new.actions);
+        assert_eq!(old.version, 1);
+    }
+
+    #[test]
+    fn added_and_removed() {
+        let mut old = BindingSet::default();
+        old.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+        old.insert("fire", [MouseButton::Left.into()]);
+
+        let mut new = BindingSet::default();
+        new.insert("jump", [KeyCode::Space.into()]);
+        new.insert("crouch", [KeyCode::ControlLeft.into()]);
+
+        let patch = old.diff(&new);
+        assert_eq!(patch.changes.len(), 3);
+
+        old.apply_patch(&patch).unwrap();
+        assert_eq!(old.actions, new.actions);
+    }
+
+    #[test]
+    fn added_without_bindings() {
+        let mut old = BindingSet::default();
+        old.insert("jump", [KeyCode::Space.into()]);
+
+        let mut new = old.clone();
+        new.insert("crouch", []);
+
+        let patch = old.diff(&new);
+        assert_eq!(patch.changes.len(), 1);
+        assert!(patch.changes["crouch"].is_empty());
+
+        old.apply_patch(&patch).unwrap();
+        assert_eq!(old.actions, new.actions);
+    }
+
+    #[test]
+    fn inverse() {
+        let mut old = BindingSet::default();
+        old.insert("jump", [KeyCode::Space.into(), GamepadButton::South.into()]);
+        old.insert("fire", [MouseButton::Left.into()]);
+
+        let mut new = BindingSet::default();
+        new.insert("jump", [KeyCode::KeyJ.into()]);
+        new.insert("crouch", [KeyCode::ControlLeft.into()]);
+
+        let mut set = old.clone();
+        let patch = old.diff(&new);
+        set.apply_patch(&patch).unwrap();
+        set.apply_patch(&patch.inverse(set.version)).unwrap();
+        assert_eq!(set.actions, old.actions);
+    }
+
+    #[test]
+    fn no_changes() {
+        let mut set = BindingSet::default();
+        set.insert("jump", [KeyCode::Space.into()]);
+
+        let patch = set.diff(&set.clone());
+        assert!(patch.is_empty());
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let mut old = BindingSet::default();
+        old.insert("jump", [KeyCode::Space.into()]);
+
+        let mut new = old.clone();
+        new.insert("jump", [KeyCode::KeyJ.into()]);
+
+        let patch = old.diff(&new);
+        old.apply_patch(&patch).unwrap();
+        assert_eq!(
+            old.apply_patch(&patch),
+            Err(PatchError::VersionMismatch {
+                expected: 0,
+                found: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,338 @@
+//! Command-based API for replacing action bindings.
+//!
+//! Bindings are regular entities, so you can modify them directly. But locating the right action
+//! entity inside a context is verbose, and despawning bindings while the context is being evaluated
+//! could be surprising. The commands from this module are deferred, so it's safe to use them
+//! in observers for [action events](crate::action::events): changes will be applied after the
+//! current evaluation pass and picked up during the next one.
+
+use alloc::vec::Vec;
+
+use bevy::{ecs::error::warn, prelude::*};
+
+use crate::{action::fns::ActionFns, prelude::*};
+
+/// Extension trait for [`EntityWorldMut`] that provides methods for replacing action bindings.
+pub trait RebindEntityWorldMutExt {
+    /// Replaces bindings of action `A` for the context `C` on the entity.
+    ///
+    /// See [`RebindEntityCommandsExt::rebind`] for more details.
+    fn rebind<C: Component, A: InputAction>(
+        self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> Result<()>;
+
+    /// Replaces bindings from the group in context `C` on the entity.
+    ///
+    /// See [`RebindEntityCommandsExt::rebind_group`] for more details.
+    fn rebind_group<C: Component>(
+        self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> Result<()>;
+
+    /// Replaces bindings of all actions in context `C` on the entity with their defaults.
+    ///
+    /// See [`RebindEntityCommandsExt::bind_defaults`] for more details.
+    fn bind_defaults<C: Component>(self) -> Result<()>;
+}
+
+impl RebindEntityWorldMutExt for EntityWorldMut<'_> {
+    fn rebind<C: Component, A: InputAction>(
+        self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> Result<()> {
+        rebind::<C, A>(bindings).apply(self)
+    }
+
+    fn rebind_group<C: Component>(
+        self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> Result<()> {
+        rebind_group::<C>(group, bindings).apply(self)
+    }
+
+    fn bind_defaults<C: Component>(self) -> Result<()> {
+        bind_defaults::<C>().apply(self)
+    }
+}
+
+/// Extension trait for [`EntityCommands`] that provides methods for replacing action bindings.
+pub trait RebindEntityCommandsExt {
+    /// Searches for an entity with [`Action<A>`] in [`Actions<C>`], despawns all its [`Bindings`]
+    /// and spawns new ones from the given iterator.
+    ///
+    /// Modifiers and conditions attached to the old binding entities will be despawned with them.
+    /// This will emit a warning if the entity does not exist, does not have [`Actions<C>`], or those actions do not contain an [`Action<A>`].
+    ///
+    /// See also [`RebindEntityWorldMutExt::rebind`].
+    ///
+    /// # Examples
+    ///
+    /// Swap to an alternative binding from an observer.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// # let mut app = App::new();
+    /// app.add_observer(swap_hands);
+    ///
+    /// app.world_mut().spawn((
+    ///     Player,
+    ///     actions!(Player[
+    ///         (Action::<SwapHands>::new(), bindings![KeyCode::KeyH]),
+    ///         (Action::<Attack>::new(), bindings![MouseButton::Left]),
+    ///     ])
+    /// ));
+    ///
+    /// fn swap_hands(swap: On<Start<SwapHands>>, mut commands: Commands) {
+    ///     // Will be applied after the current evaluation.
+    ///     commands
+    ///         .entity(swap.context)
+    ///         .rebind::<Player, Attack>([MouseButton::Right.into()]);
+    /// }
+    /// # #[derive(Component)]
+    /// # struct Player;
+    /// # #[derive(InputAction)]
+    /// # #[action_output(bool)]
+    /// # struct SwapHands;
+    /// # #[derive(InputAction)]
+    /// # #[action_output(bool)]
+    /// # struct Attack;
+    /// ```
+    fn rebind<C: Component, A: InputAction>(
+        &mut self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self;
+
+    /// Like [`Self::rebind`], but will not emit a warning in case of failure.
+    fn try_rebind<C: Component, A: InputAction>(
+        &mut self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self;
+
+    /// Replaces bindings of all binding entities with the [`BindingGroup`] in [`Actions<C>`].
+    ///
+    /// Bindings are assigned in the order returned by [`Actions::binding_group`]. Unlike [`Self::rebind`],
+    /// binding entities are preserved, so their modifiers and conditions are kept. Emits a warning
+    /// without changing anything if the number of bindings doesn't match the group size.
+    ///
+    /// See also [`RebindEntityWorldMutExt::rebind_group`] and the [`group`](crate::binding::group) module.
+    fn rebind_group<C: Component>(
+        &mut self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self;
+
+    /// Like [`Self::rebind_group`], but will not emit a warning in case of failure.
+    fn try_rebind_group<C: Component>(
+        &mut self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self;
+
+    /// Replaces bindings of each action in [`Actions<C>`] with its [`InputAction::default_bindings`].
+    ///
+    /// Actions without default bindings are left untouched. Like with [`Self::rebind`], modifiers
+    /// and conditions attached to the old binding entities will be despawned with them.
+    /// This will emit a warning if the entity does not exist or does not have [`Actions<C>`].
+    ///
+    /// Useful to spawn actions without bindings and apply the defaults later, or to implement
+    /// a "reset to defaults" button in a settings menu.
+    ///
+    /// See also [`RebindEntityWorldMutExt::bind_defaults`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_enhanced_input::prelude::*;
+    /// fn spawn_player(mut commands: Commands) {
+    ///     commands
+    ///         .spawn((
+    ///             Player,
+    ///             actions!(Player[Action::<Jump>::new(), Action::<Crouch>::new()]),
+    ///         ))
+    ///         .bind_defaults::<Player>();
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// #[action_bindings(KeyCode::Space, GamepadButton::South)]
+    /// struct Jump;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// #[action_bindings(KeyCode::ControlLeft, GamepadButton::East)]
+    /// struct Crouch;
+    /// ```
+    fn bind_defaults<C: Component>(&mut self) -> &mut Self;
+
+    /// Like [`Self::bind_defaults`], but will not emit a warning in case of failure.
+    fn try_bind_defaults<C: Component>(&mut self) -> &mut Self;
+}
+
+impl RebindEntityCommandsExt for EntityCommands<'_> {
+    fn rebind<C: Component, A: InputAction>(
+        &mut self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self {
+        self.queue_handled(rebind::<C, A>(bindings), warn)
+    }
+
+    fn try_rebind<C: Component, A: InputAction>(
+        &mut self,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self {
+        self.queue_silenced(rebind::<C, A>(bindings))
+    }
+
+    fn rebind_group<C: Component>(
+        &mut self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self {
+        self.queue_handled(rebind_group::<C>(group, bindings), warn)
+    }
+
+    fn try_rebind_group<C: Component>(
+        &mut self,
+        group: BindingGroup,
+        bindings: impl IntoIterator<Item = Binding>,
+    ) -> &mut Self {
+        self.queue_silenced(rebind_group::<C>(group, bindings))
+    }
+
+    fn bind_defaults<C: Component>(&mut self) -> &mut Self {
+        self.queue_handled(bind_defaults::<C>(), warn)
+    }
+
+    fn try_bind_defaults<C: Component>(&mut self) -> &mut Self {
+        self.queue_silenced(bind_defaults::<C>())
+    }
+}
+
+/// Replaces bindings of action `A` for the context `C` on the entity.
+///
+/// See also [`RebindEntityCommandsExt::rebind`] and [`RebindEntityWorldMutExt::rebind`].
+pub fn rebind<C: Component, A: InputAction>(
+    bindings: impl IntoIterator<Item = Binding>,
+) -> impl EntityCommand<Result<()>> {
+    let bindings: Vec<_> = bindings.into_iter().collect();
+    move |entity: EntityWorldMut| -> Result<()> {
+        let context = entity.id();
+        let actions = entity.get::<Actions<C>>().ok_or_else(|| {
+            format!(
+                "entity {} has no `{}`",
+                context,
+                ShortName::of::<Actions<C>>(),
+            )
+        })?;
+
+        let action = actions
+            .iter()
+            .find(|&a| entity.world().get::<Action<A>>(a).is_some())
+            .ok_or_else(|| {
+                format!(
+                    "entity {} has no `{}` in its `{}`",
+                    context,
+                    ShortName::of::<Action<A>>(),
+                    ShortName::of::<Actions<C>>(),
+                )
+            })?;
+
+        let world = entity.into_world_mut();
+        world.entity_mut(action).despawn_related::<Bindings>();
+        for binding in bindings {
+            world.spawn((BindingOf(action), binding));
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces bindings from the group in context `C` on the entity.
+///
+/// See also [`RebindEntityCommandsExt::rebind_group`] and [`RebindEntityWorldMutExt::rebind_group`].
+pub fn rebind_group<C: Component>(
+    group: BindingGroup,
+    bindings: impl IntoIterator<Item = Binding>,
+) -> impl EntityCommand<Result<()>> {
+    let bindings: Vec<_> = bindings.into_iter().collect();
+    move |entity: EntityWorldMut| -> Result<()> {
+        let context = entity.id();
+        let actions = entity.get::<Actions<C>>().ok_or_else(|| {
+            format!(
+                "entity {} has no `{}`",
+                context,
+                ShortName::of::<Actions<C>>(),
+            )
+        })?;
+
+        let world = entity.world();
+        let members: Vec<_> = actions
+            .iter()
+            .filter_map(|action| world.get::<Bindings>(action))
+            .flat_map(|action_bindings| action_bindings.iter())
+            .filter(|&binding| world.get::<BindingGroup>(binding) == Some(&group))
+            .collect();
+
+        if members.len() != bindings.len() {
+            return Err(format!(
+                "`{group:?}` in `{}` of entity {} has {} bindings, but {} were provided",
+                ShortName::of::<Actions<C>>(),
+                context,
+                members.len(),
+                bindings.len(),
+            )
+            .into());
+        }
+
+        let world = entity.into_world_mut();
+        for (member, binding) in members.into_iter().zip(bindings) {
+            world.entity_mut(member).insert(binding);
+        }
+
+        Ok(())
+    }
+}
+
+/// Replaces bindings of all actions in context `C` on the entity with their defaults.
+///
+/// See also [`RebindEntityCommandsExt::bind_defaults`] and [`RebindEntityWorldMutExt::bind_defaults`].
+pub fn bind_defaults<C: Component>() -> impl EntityCommand<Result<()>> {
+    move |entity: EntityWorldMut| -> Result<()> {
+        let context = entity.id();
+        let actions = entity.get::<Actions<C>>().ok_or_else(|| {
+            format!(
+                "entity {} has no `{}`",
+                context,
+                ShortName::of::<Actions<C>>(),
+            )
+        })?;
+
+        let world = entity.world();
+        let defaults: Vec<_> = actions
+            .iter()
+            .filter_map(|action| {
+                let fns = world.get::<ActionFns>(action)?;
+                let bindings = fns.default_bindings();
+                (!bindings.is_empty()).then_some((action, bindings))
+            })
+            .collect();
+
+        let world = entity.into_world_mut();
+        for (action, bindings) in defaults {
+            world.entity_mut(action).despawn_related::<Bindings>();
+            for binding in bindings {
+                world.spawn((BindingOf(action), binding));
+            }
+        }
+
+        Ok(())
+    }
+}
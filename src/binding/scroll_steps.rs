@@ -0,0 +1,235 @@
+//! Mouse wheel input split into discrete steps and precise deltas.
+//!
+//! Wheels report either whole notches, fractions of a line from high-resolution wheels,
+//! or pixels from touchpads. [`Binding::MouseWheel`](super::Binding::MouseWheel) passes
+//! these deltas as is, so the result depends on the device.
+//!
+//! [`Binding::MouseWheelStep`](super::Binding::MouseWheelStep) accumulates the deltas
+//! into whole lines and reports each direction as a button, including tilt-wheel clicks
+//! to the left and right. This is useful for paging through lists.
+//!
+//! [`Binding::MouseWheelPrecise`](super::Binding::MouseWheelPrecise) reports the unquantized
+//! vertical delta in lines, which is useful for smooth zooming.
+
+use core::fmt::{self, Display, Formatter};
+
+use bevy::{
+    input::mouse::{AccumulatedMouseScroll, MouseScrollUnit},
+    prelude::*,
+};
+use log::trace;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Direction of a mouse wheel step for [`Binding::MouseWheelStep`](super::Binding::MouseWheelStep).
+///
+/// Matches the axes of [`Binding::MouseWheel`](super::Binding::MouseWheel):
+/// [`Self::Up`] and [`Self::Right`] are positive.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum ScrollDirection {
+    Up,
+    Down,
+    /// Usually produced by tilting the wheel to the left.
+    Left,
+    /// Usually produced by tilting the wheel to the right.
+    Right,
+}
+
+impl ScrollDirection {
+    /// Returns `true` if the steps contain at least one step in this direction.
+    fn matches(self, steps: IVec2) -> bool {
+        match self {
+            ScrollDirection::Up => steps.y > 0,
+            ScrollDirection::Down => steps.y < 0,
+            ScrollDirection::Left => steps.x < 0,
+            ScrollDirection::Right => steps.x > 0,
+        }
+    }
+}
+
+impl Display for ScrollDirection {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ScrollDirection::Up => write!(f, "Scroll Up"),
+            ScrollDirection::Down => write!(f, "Scroll Down"),
+            ScrollDirection::Left => write!(f, "Scroll Left"),
+            ScrollDirection::Right => write!(f, "Scroll Right"),
+        }
+    }
+}
+
+/// Mouse wheel input for the current frame, normalized to lines and split into whole steps.
+///
+/// Updated from [`AccumulatedMouseScroll`] during [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare).
+///
+/// Initialized by [`crate::EnhancedInputPlugin`].
+#[derive(Resource, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct ScrollSteps {
+    /// Number of pixels that correspond to a single line.
+    ///
+    /// Used to convert deltas from devices that report pixels, such as touchpads.
+    ///
+    /// By default set to 100.0.
+    pub pixels_per_line: f32,
+
+    /// Delta in lines for the current frame.
+    lines: Vec2,
+
+    /// Whole steps for the current frame.
+    steps: IVec2,
+
+    /// Fraction of a step accumulated from previous frames.
+    remainder: Vec2,
+}
+
+impl ScrollSteps {
+    /// Returns the delta in lines for the current frame.
+    ///
+    /// Unlike [`AccumulatedMouseScroll`], pixel deltas are converted using [`Self::pixels_per_line`].
+    #[must_use]
+    pub fn lines(&self) -> Vec2 {
+        self.lines
+    }
+
+    /// Returns the number of whole steps for the current frame on each axis.
+    ///
+    /// Fractions of a step are carried over to the next frames until a whole step is reached,
+    /// or discarded when the direction changes.
+    #[must_use]
+    pub fn steps(&self) -> IVec2 {
+        self.steps
+    }
+
+    /// Returns `true` if there was at least one step in the given direction in the current frame.
+    #[must_use]
+    pub fn stepped(&self, direction: ScrollDirection) -> bool {
+        direction.matches(self.steps)
+    }
+
+    /// Returns directions with at least one step in the current frame.
+    pub fn directions(&self) -> impl Iterator<Item = ScrollDirection> + '_ {
+        [
+            ScrollDirection::Up,
+            ScrollDirection::Down,
+            ScrollDirection::Left,
+            ScrollDirection::Right,
+        ]
+        .into_iter()
+        .filter(|&direction| self.stepped(direction))
+    }
+
+    fn update(&mut self, lines: Vec2) {
+        self.lines = lines;
+
+        // Discard the fraction from the opposite direction.
+        for axis in 0..2 {
+            if lines[axis] * self.remainder[axis] < 0.0 {
+                self.remainder[axis] = 0.0;
+            }
+        }
+
+        self.remainder += lines;
+        let steps = self.remainder.trunc();
+        self.remainder -= steps;
+        self.steps = steps.as_ivec2();
+    }
+}
+
+impl Default for ScrollSteps {
+    fn default() -> Self {
+        Self {
+            pixels_per_line: 100.0,
+            lines: Vec2::ZERO,
+            steps: IVec2::ZERO,
+            remainder: Vec2::ZERO,
+        }
+    }
+}
+
+pub(crate) fn update_scroll_steps(
+    mut scroll_steps: ResMut<ScrollSteps>,
+    mouse_scroll: Option<Res<AccumulatedMouseScroll>>,
+) {
+    let lines = mouse_scroll
+        .map(|scroll| match scroll.unit {
+            MouseScrollUnit::Line => scroll.delta,
+            MouseScrollUnit::Pixel => scroll.delta / scroll_steps.pixels_per_line,
+        })
+        .unwrap_or_default();
+
+    if lines == Vec2::ZERO && scroll_steps.lines == Vec2::ZERO {
+        return;
+    }
+
+    scroll_steps.update(lines);
+    if scroll_steps.steps != IVec2::ZERO {
+        trace!("scrolled by `{}` steps", scroll_steps.steps);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn steps() {
+        let mut scroll_steps = ScrollSteps::default();
+
+        scroll_steps.update(Vec2::new(0.0, 2.0));
+        assert_eq!(scroll_steps.steps(), IVec2::new(0, 2));
+        assert!(scroll_steps.stepped(ScrollDirection::Up));
+        assert!(!scroll_steps.stepped(ScrollDirection::Down));
+
+        scroll_steps.update(Vec2::new(-1.0, 0.0));
+        assert_eq!(
+            scroll_steps.directions().collect::<Vec<_>>(),
+            [ScrollDirection::Left]
+        );
+
+        scroll_steps.update(Vec2::ZERO);
+        assert_eq!(scroll_steps.directions().count(), 0);
+    }
+
+    #[test]
+    fn fractions() {
+        let mut scroll_steps = ScrollSteps::default();
+
+        for _ in 0..3 {
+            scroll_steps.update(Vec2::new(0.0, 0.4));
+        }
+        assert_eq!(scroll_steps.lines(), Vec2::new(0.0, 0.4));
+        assert_eq!(
+            scroll_steps.steps(),
+            IVec2::new(0, 1),
+            "fractions should accumulate"
+        );
+
+        scroll_steps.update(Vec2::new(0.0, -0.4));
+        scroll_steps.update(Vec2::new(0.0, -0.4));
+        assert_eq!(
+            scroll_steps.steps(),
+            IVec2::ZERO,
+            "remainder should be discarded after changing the direction"
+        );
+
+        scroll_steps.update(Vec2::new(0.0, -0.4));
+        assert_eq!(scroll_steps.steps(), IVec2::new(0, -1));
+    }
+}
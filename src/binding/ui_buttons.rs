@@ -0,0 +1,34 @@
+//! UI nodes as input for [`Binding::UiButton`](super::Binding::UiButton).
+//!
+//! With the `ui` feature, nodes are considered pressed while their `Interaction` is
+//! `Interaction::Pressed`. This allows on-screen buttons and hotkeys to trigger the same action
+//! through the regular condition and modifier pipeline.
+//!
+//! Without the feature, bindings to UI nodes are only activated by
+//! [`MockInputExt`](super::mock_input::MockInputExt).
+
+use bevy::{platform::collections::HashSet, prelude::*};
+
+/// UI nodes that are currently pressed.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub(crate) struct PressedUiButtons(HashSet<Entity>);
+
+#[cfg(feature = "ui")]
+pub(crate) fn update_ui_buttons(
+    mut pressed_buttons: ResMut<PressedUiButtons>,
+    mut removed: RemovedComponents<Interaction>,
+    interactions: Query<(Entity, &Interaction), Changed<Interaction>>,
+) {
+    for entity in removed.read() {
+        pressed_buttons.remove(&entity);
+    }
+
+    for (entity, &interaction) in &interactions {
+        if interaction == Interaction::Pressed {
+            log::trace!("UI button `{entity}` pressed");
+            pressed_buttons.insert(entity);
+        } else {
+            pressed_buttons.remove(&entity);
+        }
+    }
+}
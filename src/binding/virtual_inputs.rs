@@ -0,0 +1,145 @@
+//! Application events as input for [`Binding::Virtual`].
+//!
+//! Some inputs don't come from a physical device, like a taunt command received over the network
+//! or a recognized voice command. Mapping such events to a [`VirtualInput`] with
+//! [`VirtualInputAppExt::add_virtual_input`] lets them drive actions through the regular
+//! condition and modifier pipeline, alongside physical bindings.
+//!
+//! Events are collected by observers and become visible to bindings during the next
+//! [`EnhancedInputSystems::Prepare`], where they stay
+//! active for a single update, similar to [`Binding::MouseWheelStep`].
+//! If an input receives multiple values before the update, the last one is used.
+
+use core::{
+    fmt::{self, Display, Formatter},
+    mem,
+};
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+use log::trace;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Identifier of an input driven by application events.
+///
+/// See the [module documentation](self) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct VirtualInput(pub u32);
+
+impl Display for VirtualInput {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "Virtual {}", self.0)
+    }
+}
+
+/// An extension trait for [`App`] to map events to virtual inputs.
+pub trait VirtualInputAppExt {
+    /// Maps event `E` to a virtual input.
+    ///
+    /// Each time `E` is triggered, `convert` is called with it. If it returns a value, the input
+    /// will have this value during the next update. Returning [`None`] ignores the event.
+    ///
+    /// Can be called multiple times to map the same event to different inputs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// const TAUNT: VirtualInput = VirtualInput(0);
+    ///
+    /// # let mut app = App::new();
+    /// app.add_virtual_input::<RemoteCommand>(TAUNT, |command| {
+    ///     (command.name == "taunt").then_some(true.into())
+    /// });
+    ///
+    /// # let mut world = World::new();
+    /// world.spawn((
+    ///     Player,
+    ///     actions!(Player[(
+    ///         Action::<Taunt>::new(),
+    ///         bindings![KeyCode::KeyT, Binding::Virtual(TAUNT)],
+    ///     )]),
+    /// ));
+    ///
+    /// #[derive(Event)]
+    /// struct RemoteCommand {
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct Taunt;
+    /// ```
+    fn add_virtual_input<E: Event>(
+        &mut self,
+        input: VirtualInput,
+        convert: impl Fn(&E) -> Option<ActionValue> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl VirtualInputAppExt for App {
+    fn add_virtual_input<E: Event>(
+        &mut self,
+        input: VirtualInput,
+        convert: impl Fn(&E) -> Option<ActionValue> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.add_observer(move |event: On<E>, mut inputs: ResMut<VirtualInputs>| {
+            if let Some(value) = convert(&event) {
+                trace!("received `{value:?}` for `{input}`");
+                inputs.received.insert(input, value);
+            }
+        })
+    }
+}
+
+/// Values of virtual inputs.
+#[derive(Resource, Default)]
+pub(crate) struct VirtualInputs {
+    /// Values visible to bindings during the current update.
+    values: HashMap<VirtualInput, ActionValue>,
+
+    /// Values received since the last update.
+    received: HashMap<VirtualInput, ActionValue>,
+
+    /// Inputs pressed by [`MockInputExt`].
+    pub(crate) mocked: HashSet<VirtualInput>,
+}
+
+impl VirtualInputs {
+    /// Returns the value of the input if it's active.
+    pub(crate) fn get(&self, input: VirtualInput) -> Option<ActionValue> {
+        if self.mocked.contains(&input) {
+            return Some(true.into());
+        }
+
+        self.values.get(&input).copied()
+    }
+}
+
+pub(crate) fn update_virtual_inputs(mut inputs: ResMut<VirtualInputs>) {
+    let VirtualInputs {
+        values, received, ..
+    } = &mut *inputs;
+    values.clear();
+    mem::swap(values, received);
+}
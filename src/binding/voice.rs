@@ -0,0 +1,88 @@
+//! Hook for speech recognition backends.
+//!
+//! This crate doesn't recognize speech itself. Instead, a backend (usually a separate crate
+//! wrapping a speech-to-text engine) triggers [`RecognizedPhrase`] for each recognized phrase,
+//! and [`VoiceAppExt::add_voice_phrase`] maps phrases to [`VirtualInput`]s that can be bound
+//! to actions like any other input.
+//!
+//! Each phrase is active for a single update with the recognition confidence as its
+//! [`ActionValue::Axis1D`] value. Add [`MinConfidence`] to the binding to ignore uncertain
+//! recognitions.
+
+use alloc::string::String;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// A phrase reported by a speech recognition backend.
+///
+/// Should be triggered by the backend for each recognized phrase.
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct RecognizedPhrase {
+    /// Recognized text.
+    pub phrase: String,
+
+    /// How confident the backend is in the recognition, from 0 to 1.
+    pub confidence: f32,
+}
+
+impl RecognizedPhrase {
+    #[must_use]
+    pub fn new(phrase: impl Into<String>, confidence: f32) -> Self {
+        Self {
+            phrase: phrase.into(),
+            confidence,
+        }
+    }
+}
+
+/// An extension trait for [`App`] to map voice commands to virtual inputs.
+pub trait VoiceAppExt {
+    /// Maps a phrase to a virtual input.
+    ///
+    /// The phrase is matched against [`RecognizedPhrase::phrase`] ignoring ASCII case
+    /// and surrounding whitespace. The input receives the recognition confidence as its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// const RELOAD: VirtualInput = VirtualInput(0);
+    ///
+    /// # let mut app = App::new();
+    /// app.add_voice_phrase("reload", RELOAD);
+    ///
+    /// # let mut world = World::new();
+    /// world.spawn((
+    ///     Player,
+    ///     actions!(Player[(
+    ///         Action::<Reload>::new(),
+    ///         bindings![KeyCode::KeyR, (RELOAD, MinConfidence::new(0.7))],
+    ///     )]),
+    /// ));
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// #[derive(InputAction)]
+    /// #[action_output(bool)]
+    /// struct Reload;
+    /// ```
+    fn add_voice_phrase(&mut self, phrase: impl Into<String>, input: VirtualInput) -> &mut Self;
+}
+
+impl VoiceAppExt for App {
+    fn add_voice_phrase(&mut self, phrase: impl Into<String>, input: VirtualInput) -> &mut Self {
+        let phrase = phrase.into();
+        self.add_virtual_input::<RecognizedPhrase>(input, move |recognized| {
+            recognized
+                .phrase
+                .trim()
+                .eq_ignore_ascii_case(phrase.trim())
+                .then_some(recognized.confidence.into())
+        })
+    }
+}
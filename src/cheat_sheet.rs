@@ -0,0 +1,240 @@
+//! Printable summaries of context bindings for "controls" pages.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::{self, Write};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::prelude::*;
+
+/// Summary of the current bindings of a context.
+///
+/// Created by [`CheatSheets::get`]. Use [`Self::to_html`] to get a printable page,
+/// or iterate over [`Self::actions`] to lay out your own UI, like a pause screen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheatSheet {
+    /// Context name from [`ContextInfo::display_name`] or the type name of the context.
+    pub title: String,
+
+    /// Context description from [`ContextInfo::description`].
+    pub description: String,
+
+    /// Actions in the order they were spawned.
+    pub actions: Vec<CheatSheetAction>,
+}
+
+impl CheatSheet {
+    /// Returns a standalone HTML page with a table of actions and their bindings.
+    ///
+    /// Glyphs from [`Glyph::Path`] are rendered as images with the binding name as the alternative text.
+    /// Atlas indices can't be referenced from HTML, so bindings with [`Glyph::Index`] are displayed by name.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        self.write_html(&mut html)
+            .expect("writing to a string shouldn't fail");
+        html
+    }
+
+    fn write_html(&self, html: &mut String) -> fmt::Result {
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html>")?;
+        writeln!(html, "<head>")?;
+        writeln!(html, "<meta charset=\"utf-8\">")?;
+        writeln!(html, "<title>{}</title>", Escaped(&self.title))?;
+        writeln!(
+            html,
+            "<style>table {{ border-collapse: collapse; }} th, td {{ border: 1px solid #888; padding: 4px 8px; text-align: left; }} img {{ height: 1.5em; vertical-align: middle; }}</style>"
+        )?;
+        writeln!(html, "</head>")?;
+        writeln!(html, "<body>")?;
+        writeln!(html, "<h1>{}</h1>", Escaped(&self.title))?;
+        if !self.description.is_empty() {
+            writeln!(html, "<p>{}</p>", Escaped(&self.description))?;
+        }
+        writeln!(html, "<table>")?;
+        writeln!(html, "<tr><th>Action</th><th>Bindings</th></tr>")?;
+        for action in &self.actions {
+            write!(html, "<tr><td>{}</td><td>", Escaped(&action.name))?;
+            for (index, binding) in action.bindings.iter().enumerate() {
+                if index != 0 {
+                    write!(html, ", ")?;
+                }
+                match &binding.glyph {
+                    Some(Glyph::Path(path)) => write!(
+                        html,
+                        "<img src=\"{}\" alt=\"{}\">",
+                        Escaped(path),
+                        Escaped(&binding.name)
+                    )?,
+                    Some(Glyph::Index(_)) | None => write!(html, "{}", Escaped(&binding.name))?,
+                }
+            }
+            writeln!(html, "</td></tr>")?;
+        }
+        writeln!(html, "</table>")?;
+        writeln!(html, "</body>")?;
+        writeln!(html, "</html>")
+    }
+}
+
+/// An action in a [`CheatSheet`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheatSheetAction {
+    /// Name from [`InputDisplayMap::entity_action_name`].
+    pub name: String,
+
+    /// Bindings in the order they were spawned, excluding [`Binding::None`].
+    pub bindings: Vec<CheatSheetBinding>,
+}
+
+/// A binding in a [`CheatSheetAction`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CheatSheetBinding {
+    pub binding: Binding,
+
+    /// Name from [`InputDisplayMap::display`].
+    pub name: String,
+
+    /// Icon from [`GamepadGlyphs`] for the requested [`GamepadKind`].
+    ///
+    /// Always [`None`] for non-gamepad bindings.
+    pub glyph: Option<Glyph>,
+}
+
+/// Builds [`CheatSheet`]s for context `C` at runtime.
+///
+/// Uses [`ContextInfos`] for the title, [`InputDisplayMap`] for action and binding names,
+/// and [`GamepadGlyphs`] for gamepad icons, so the result reflects the current bindings and settings.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn print_controls(cheat_sheets: CheatSheets<Player>, player: Single<Entity, With<Player>>) {
+///     if let Some(cheat_sheet) = cheat_sheets.get(*player, GamepadKind::Xbox) {
+///         // Save the page or show it in a web view.
+///         info!("{}", cheat_sheet.to_html());
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(SystemParam)]
+pub struct CheatSheets<'w, 's, C: Component> {
+    display_map: Res<'w, InputDisplayMap>,
+    glyphs: Res<'w, GamepadGlyphs>,
+    infos: Res<'w, ContextInfos>,
+    contexts: Query<'w, 's, &'static Actions<C>>,
+    actions: Query<'w, 's, (&'static Name, Option<&'static Bindings>)>,
+    bindings: Query<'w, 's, &'static Binding>,
+}
+
+impl<C: Component> CheatSheets<'_, '_, C> {
+    /// Returns the cheat sheet for the context entity, with gamepad glyphs for the given kind.
+    ///
+    /// Returns [`None`] if the entity doesn't have context `C`.
+    #[must_use]
+    pub fn get(&self, context: Entity, kind: GamepadKind) -> Option<CheatSheet> {
+        let context_actions = self.contexts.get(context).ok()?;
+
+        let (title, description) = match self.infos.get::<C>() {
+            Some(info) => (info.display_name.to_string(), info.description.to_string()),
+            None => (ShortName::of::<C>().to_string(), String::new()),
+        };
+
+        let actions = self
+            .actions
+            .iter_many(context_actions)
+            .map(|(name, bindings)| {
+                let bindings = self
+                    .bindings
+                    .iter_many(bindings.into_iter().flatten())
+                    .filter(|&&binding| binding != Binding::None)
+                    .map(|&binding| CheatSheetBinding {
+                        binding,
+                        name: self.display_map.display(binding).to_string(),
+                        glyph: matches!(binding.device(), Some(InputDevice::Gamepad))
+                            .then(|| self.glyphs.get(kind, binding).cloned())
+                            .flatten(),
+                    })
+                    .collect();
+
+                CheatSheetAction {
+                    name: self.display_map.entity_action_name(name),
+                    bindings,
+                }
+            })
+            .collect();
+
+        Some(CheatSheet {
+            title,
+            description,
+            actions,
+        })
+    }
+}
+
+/// Escapes special HTML characters.
+struct Escaped<'a>(&'a str);
+
+impl fmt::Display for Escaped<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&#39;")?,
+                _ => f.write_char(c)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html() {
+        let cheat_sheet = CheatSheet {
+            title: "On <Foot>".into(),
+            description: String::new(),
+            actions: alloc::vec![CheatSheetAction {
+                name: "Jump".into(),
+                bindings: alloc::vec![
+                    CheatSheetBinding {
+                        binding: KeyCode::Space.into(),
+                        name: "Space".into(),
+                        glyph: None,
+                    },
+                    CheatSheetBinding {
+                        binding: GamepadButton::South.into(),
+                        name: "South".into(),
+                        glyph: Some("icons/a.png".into()),
+                    },
+                ],
+            }],
+        };
+
+        let html = cheat_sheet.to_html();
+        assert!(html.contains("<h1>On &lt;Foot&gt;</h1>"));
+        assert!(
+            !html.contains("<p>"),
+            "empty description shouldn't be written"
+        );
+        assert!(html.contains(
+            "<tr><td>Jump</td><td>Space, <img src=\"icons/a.png\" alt=\"South\"></td></tr>"
+        ));
+    }
+}
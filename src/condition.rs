@@ -48,19 +48,49 @@ world.spawn((
     ])
 ));
 ```
+
+# Reading timers
+
+Conditions are regular components, so their state can be read from the action or binding entity.
+Time-based conditions expose their progress, which is useful for UI like progress rings.
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+fn update_progress_rings(holds: Query<&Hold, With<Action<Interact>>>) {
+    for hold in &holds {
+        let progress = hold.progress();
+        // Render `progress` from 0.0 to 1.0.
+    }
+}
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Interact;
+```
+
+See also [`Pulse::remaining_secs`] and [`Cooldown::remaining_secs`].
 */
 
 pub mod block_by;
+pub mod buffer;
 pub mod chord;
 pub mod combo;
 pub mod cooldown;
 pub mod down;
 pub mod fns;
+pub mod gesture;
 pub mod hold;
 pub mod hold_and_release;
+pub mod layer;
+pub mod lock_state;
 pub mod press;
+pub mod pressure_threshold;
 pub mod pulse;
 pub mod release;
+pub mod speed;
+pub mod step;
 pub mod tap;
 pub mod toggle;
 
@@ -96,6 +126,12 @@ pub trait InputCondition: Debug {
     fn kind(&self) -> ConditionKind {
         ConditionKind::Explicit
     }
+
+    /// Called after all conditions on the same entity are evaluated, with their combined state.
+    ///
+    /// Allows the condition to react to the outcome, such as being overridden by a blocker.
+    /// Does nothing by default.
+    fn post_evaluate(&mut self, _state: TriggerState) {}
 }
 
 /// Determines how a condition contributes to the final [`TriggerState`].
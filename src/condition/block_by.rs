@@ -6,6 +6,8 @@ use crate::prelude::*;
 
 /// Returns [`TriggerState::None`] when specific actions are active.
 ///
+/// The inverse of [`Chord`].
+///
 /// # Examples
 ///
 /// To get action entities during spawning, you could use [`SpawnWith`].
@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/// Like [`Press`], but remembers presses that couldn't trigger the action and fires them later.
+///
+/// If the action is blocked when the input is pressed, for example by [`BlockBy`],
+/// the press stays pending for [`Self::window`] seconds and returns [`TriggerState::Fired`]
+/// as soon as the blocker clears, even if the input was already released.
+/// This makes inputs pressed slightly too early, like jumping just before landing, still count.
+///
+/// Only sees blockers on the same entity, so it should usually be added to the action together with them.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{ecs::spawn::SpawnWith, prelude::*};
+/// use bevy_enhanced_input::prelude::*;
+///
+/// Actions::<Player>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+///     let airborne = context.spawn(Action::<Airborne>::new()).id();
+///
+///     context.spawn((
+///         Action::<Jump>::new(),
+///         BlockBy::single(airborne),
+///         Buffer::new(0.2),
+///         bindings![KeyCode::Space],
+///     ));
+/// }));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// /// Mocked by the game while the player is in the air.
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Airborne;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct Buffer {
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// The type of time used to advance the timer.
+    pub time_kind: TimeKind,
+
+    timer: Timer,
+
+    actuated: bool,
+
+    pending: bool,
+}
+
+impl Buffer {
+    /// Creates a new instance that remembers presses for the given time in seconds.
+    #[must_use]
+    pub fn new(window: f32) -> Self {
+        Self {
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            timer: Timer::from_seconds(window, TimerMode::Once),
+            actuated: false,
+            pending: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the time in seconds for which presses are remembered.
+    #[must_use]
+    pub fn window(&self) -> f32 {
+        self.timer.duration().as_secs_f32()
+    }
+
+    /// Returns `true` if a press is waiting for the action to fire.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+}
+
+impl InputCondition for Buffer {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let previously_actuated = self.actuated;
+        self.actuated = value.is_actuated(self.actuation);
+
+        if self.actuated && !previously_actuated {
+            self.timer.reset();
+            self.pending = true;
+        } else if self.pending {
+            self.timer.tick(time.delta_kind(self.time_kind));
+            if self.timer.is_finished() {
+                self.pending = false;
+            }
+        }
+
+        if self.pending {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+
+    fn post_evaluate(&mut self, state: TriggerState) {
+        if state == TriggerState::Fired {
+            self.pending = false;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn unblocked() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Buffer::new(1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+        );
+        condition.post_evaluate(TriggerState::Fired);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::None,
+            "should fire only once per press"
+        );
+    }
+
+    #[test]
+    fn blocked() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Buffer::new(1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+        );
+        condition.post_evaluate(TriggerState::None);
+        assert!(condition.is_pending());
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        let (time, actions) = state.get(&world);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            TriggerState::Fired,
+            "should remember the press after release"
+        );
+        condition.post_evaluate(TriggerState::Fired);
+        assert!(!condition.is_pending());
+    }
+
+    #[test]
+    fn expired() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Buffer::new(1.0);
+        condition.evaluate(&actions, &time, true.into());
+        condition.post_evaluate(TriggerState::None);
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::None,
+        );
+        assert!(!condition.is_pending());
+    }
+}
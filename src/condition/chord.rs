@@ -9,9 +9,11 @@ Returns [`TriggerState::Fired`] if all given actions fire, otherwise returns the
 [`TriggerState`], capped at [`TriggerState::Ongoing`].
 
 Useful for defining a composite action that fires only when all listed actions are active.
+Pass multiple entities to [`Chord::new`] to require several actions at the same time.
+For the inverse behavior, when the action should be suppressed while other actions fire, see [`BlockBy`].
 
 Requires using [`SpawnRelated::spawn`] or separate spawning with [`ActionOf`]/[`BindingOf`]
-because you need to pass [`Entity`] for step and cancel actions.
+because you need to pass [`Entity`] for chorded actions.
 
 # Examples
 
@@ -138,6 +140,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn all_fired() {
+        let (mut world, mut state) = context::init_world();
+        let action1 = world
+            .spawn((Action::<Test>::new(), TriggerState::Fired))
+            .id();
+        let action2 = world
+            .spawn((Action::<Test>::new(), TriggerState::Fired))
+            .id();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Chord::new([action1, action2]);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+        );
+    }
+
     #[test]
     fn ongoing() {
         let (mut world, mut state) = context::init_world();
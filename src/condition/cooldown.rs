@@ -52,6 +52,14 @@ impl Cooldown {
     pub fn timer(&self) -> &Timer {
         &self.timer
     }
+
+    /// Returns the time in seconds until the action can trigger again.
+    ///
+    /// Returns 0.0 when the cooldown is over.
+    #[must_use]
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
 }
 
 impl InputCondition for Cooldown {
@@ -139,4 +147,25 @@ mod tests {
             TriggerState::Fired,
         );
     }
+
+    #[test]
+    fn remaining() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Cooldown::new(1.0);
+        assert_eq!(condition.remaining_secs(), 0.0);
+
+        condition.evaluate(&actions, &time, true.into());
+        condition.evaluate(&actions, &time, false.into());
+        assert_eq!(condition.remaining_secs(), 1.0);
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.25));
+        let (time, actions) = state.get(&world);
+
+        condition.evaluate(&actions, &time, false.into());
+        assert_eq!(condition.remaining_secs(), 0.75);
+    }
 }
@@ -8,6 +8,7 @@ use bevy::{
     },
     prelude::*,
 };
+use log::warn;
 
 use crate::prelude::*;
 
@@ -36,10 +37,17 @@ impl InputConditionAppExt for App {
 
 fn register_condition<C: InputCondition + Component<Mutability = Mutable>>(
     add: On<Add, C>,
-    mut conditions: Query<&mut ConditionFns, Allow<Disabled>>,
+    mut unattached: ResMut<UnattachedConditions>,
+    mut conditions: Query<(&mut ConditionFns, Has<Binding>, Has<TriggerState>), Allow<Disabled>>,
 ) {
-    let mut fns = conditions.get_mut(add.entity).unwrap();
-    fns.0.push(get_condition::<C>);
+    let (mut fns, binding, action) = conditions.get_mut(add.entity).unwrap();
+    fns.fns.push(get_condition::<C>);
+    fns.names.push(ShortName::of::<C>());
+
+    if !binding && !action {
+        // Could be inserted before the binding or action component, like in scenes.
+        unattached.push((add.entity, ShortName::of::<C>()));
+    }
 }
 
 fn unregister_condition<C: InputCondition + Component<Mutability = Mutable>>(
@@ -51,9 +59,29 @@ fn unregister_condition<C: InputCondition + Component<Mutability = Mutable>>(
         .iter()
         .position(|&f| ptr::fn_addr_eq(f, get_condition::<C> as GetConditionFn))
         .unwrap();
-    fns.0.remove(index);
+    fns.fns.remove(index);
+    fns.names.remove(index);
+}
+
+/// Warns about conditions that are still neither on an action nor on a binding.
+pub(crate) fn warn_unattached(
+    mut unattached: ResMut<UnattachedConditions>,
+    entities: Query<(Has<Binding>, Has<TriggerState>), Allow<Disabled>>,
+) {
+    for (entity, name) in unattached.drain(..) {
+        if let Ok((false, false)) = entities.get(entity) {
+            warn!("`{entity}` has condition `{name}`, but it is neither an action nor a binding");
+        }
+    }
 }
 
+/// Conditions inserted on entities without an action or binding.
+///
+/// Checked at the end of the frame by [`warn_unattached`], since the action or binding
+/// could be inserted later.
+#[derive(Resource, Deref, DerefMut, Default)]
+pub(crate) struct UnattachedConditions(Vec<(Entity, ShortName<'static>)>);
+
 /// IDs of all registered input conditions.
 ///
 /// Used to dynamically register access for [`FilteredEntityMut`].
@@ -69,7 +97,18 @@ pub(crate) struct ConditionRegistry(Vec<ComponentId>);
 ///
 /// Updated automatically using triggers.
 #[derive(Component, Deref, Default)]
-pub(crate) struct ConditionFns(Vec<GetConditionFn>);
+pub(crate) struct ConditionFns {
+    #[deref]
+    fns: Vec<GetConditionFn>,
+    names: Vec<ShortName<'static>>,
+}
+
+impl ConditionFns {
+    /// Returns names of the conditions in the same order as the functions.
+    pub(crate) fn names(&self) -> &[ShortName<'static>] {
+        &self.names
+    }
+}
 
 type GetConditionFn = for<'a> fn(&'a mut FilteredEntityMut) -> &'a mut dyn InputCondition;
 
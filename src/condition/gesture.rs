@@ -0,0 +1,389 @@
+use core::{f32::consts::FRAC_PI_4, time::Duration};
+
+use bevy::prelude::*;
+use log::warn;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Sequence of directions that the input needs to follow in specific order.
+
+Each input value that exceeds the actuation threshold is matched against the current step.
+If the value is within [`GestureStep::tolerance`] of the step direction, the gesture advances
+to the next step. Values that still match the previous step are accepted, allowing the input
+to keep moving in the same direction. Any other direction resets the gesture.
+
+After the first step, returns [`TriggerState::Ongoing`] until the last step.
+Once all steps are completed, returns [`TriggerState::Fired`] once, then resets.
+
+Intended for [`ActionValue::Axis2D`] inputs, such as mouse motion or gamepad sticks.
+Other values will be converted to it.
+
+With the default tolerance, each step covers a quarter of the circle, so consecutive perpendicular
+directions have no gaps between them. Use a smaller tolerance for diagonal steps.
+
+# Examples
+
+Swipe right then down with the mouse:
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    actions!(Player[(
+        Action::<Slash>::new(),
+        Gesture::default()
+            .with_step(Dir2::X)
+            .with_step(GestureStep::new(Dir2::NEG_Y).with_timeout(0.3)),
+        bindings![Binding::mouse_motion()],
+    )]),
+));
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Slash;
+
+#[derive(Component)]
+struct Player;
+```
+*/
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct Gesture {
+    /// Ordered sequence of directions that define the gesture.
+    pub steps: Vec<GestureStep>,
+
+    /// Minimum input length for the value to be matched against the steps.
+    ///
+    /// Smaller values are ignored.
+    pub actuation: f32,
+
+    /// The type of time used to advance the timer.
+    pub time_kind: TimeKind,
+
+    /// Index of the current step in the gesture.
+    step_index: usize,
+
+    /// Tracks timeout for completing the current step.
+    timer: Timer,
+}
+
+impl Gesture {
+    /// Adds a direction step to the gesture.
+    ///
+    /// If you don't need to configure the step, you can just pass the [`Dir2`].
+    #[must_use]
+    pub fn with_step(mut self, step: impl Into<GestureStep>) -> Self {
+        self.steps.push(step.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the associated timer.
+    #[must_use]
+    pub fn timer(&self) -> &Timer {
+        &self.timer
+    }
+
+    fn reset(&mut self) {
+        self.step_index = 0;
+        self.timer.reset();
+    }
+
+    fn advance(&mut self) {
+        self.step_index += 1;
+        self.timer.reset();
+        if let Some(step) = self.steps.get(self.step_index) {
+            self.timer
+                .set_duration(Duration::from_secs_f32(step.timeout));
+        }
+    }
+}
+
+impl Default for Gesture {
+    fn default() -> Self {
+        Self {
+            steps: Default::default(),
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            step_index: 0,
+            timer: Default::default(),
+        }
+    }
+}
+
+impl InputCondition for Gesture {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        if self.steps.is_empty() {
+            // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+            warn!("gesture has no steps");
+            return TriggerState::None;
+        }
+
+        if self.step_index > 0 {
+            self.timer.tick(time.delta_kind(self.time_kind));
+
+            if self.timer.is_finished() {
+                self.reset();
+            }
+        }
+
+        let value = value.as_axis2d();
+        if value.length() >= self.actuation
+            && let Ok(direction) = Dir2::new(value)
+        {
+            if self.steps[self.step_index].matches(direction) {
+                self.advance();
+            } else if self.step_index == 0 || !self.steps[self.step_index - 1].matches(direction) {
+                self.reset();
+                // The broken gesture could be the start of a new one.
+                if self.steps[0].matches(direction) {
+                    self.advance();
+                }
+            }
+
+            if self.step_index >= self.steps.len() {
+                // Completed all gesture steps.
+                self.reset();
+                return TriggerState::Fired;
+            }
+        }
+
+        if self.step_index > 0 {
+            TriggerState::Ongoing
+        } else {
+            TriggerState::None
+        }
+    }
+}
+
+/// A direction that progresses [`Gesture`].
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug))]
+pub struct GestureStep {
+    /// Expected input direction.
+    pub direction: Dir2,
+
+    /// Maximum angle in radians between the input and [`Self::direction`] to match this step.
+    pub tolerance: f32,
+
+    /// Time in seconds to match [`Self::direction`] before the gesture is cancelled.
+    ///
+    /// Starts once the previous step in the gesture is completed.
+    /// Ignored for the first step in the gesture.
+    pub timeout: f32,
+}
+
+impl GestureStep {
+    /// Creates a new instance with [`Self::tolerance`] set to 45 degrees
+    /// and [`Self::timeout`] set to 0.5.
+    #[must_use]
+    pub fn new(direction: Dir2) -> Self {
+        Self {
+            direction,
+            tolerance: FRAC_PI_4,
+            timeout: 0.5,
+        }
+    }
+
+    /// Sets [`Self::tolerance`].
+    #[must_use]
+    pub fn with_tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Sets [`Self::timeout`].
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: f32) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn matches(&self, direction: Dir2) -> bool {
+        self.direction.angle_to(*direction).abs() <= self.tolerance
+    }
+}
+
+impl From<Dir2> for GestureStep {
+    fn from(direction: Dir2) -> Self {
+        Self::new(direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn empty() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default();
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn sequence() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default().with_step(Dir2::X).with_step(Dir2::Y);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, 0.2).into()),
+            TriggerState::Ongoing,
+            "moving in the direction of the previous step should be allowed"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn wrong_direction() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default().with_step(Dir2::X).with_step(Dir2::Y);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::NEG_X.into()),
+            TriggerState::None
+        );
+        assert_eq!(condition.step_index, 0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn restart() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default()
+            .with_step(Dir2::X)
+            .with_step(Dir2::NEG_X)
+            .with_step(Dir2::Y);
+        condition.evaluate(&actions, &time, Vec2::X.into());
+        condition.evaluate(&actions, &time, Vec2::NEG_X.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing,
+            "broken gesture should restart from the first step"
+        );
+        assert_eq!(condition.step_index, 1);
+    }
+
+    #[test]
+    fn actuation() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default()
+            .with_step(Dir2::X)
+            .with_step(Dir2::Y)
+            .with_actuation(1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, (0.5, 0.0).into()),
+            TriggerState::None
+        );
+        condition.evaluate(&actions, &time, Vec2::X.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, (0.0, -0.5).into()),
+            TriggerState::Ongoing,
+            "small values shouldn't reset the gesture"
+        );
+    }
+
+    #[test]
+    fn timeout() {
+        let (mut world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Gesture::default()
+            .with_step(Dir2::X)
+            .with_step(GestureStep::new(Dir2::Y).with_timeout(0.5));
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::X.into()),
+            TriggerState::Ongoing
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        let (time, actions) = state.get(&world);
+
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::Y.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn tolerance() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition =
+            Gesture::default().with_step(GestureStep::new(Dir2::X).with_tolerance(0.1));
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, 1.0).into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, 0.05).into()),
+            TriggerState::Fired
+        );
+    }
+}
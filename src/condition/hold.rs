@@ -58,6 +58,15 @@ impl Hold {
     pub fn timer(&self) -> &Timer {
         &self.timer
     }
+
+    /// Returns hold progress from 0.0 to 1.0.
+    ///
+    /// Resets to 0.0 when the input stops being actuated.
+    /// Useful for UI, such as progress rings.
+    #[must_use]
+    pub fn progress(&self) -> f32 {
+        self.timer.fraction()
+    }
 }
 
 impl InputCondition for Hold {
@@ -154,4 +163,22 @@ mod tests {
             TriggerState::None
         );
     }
+
+    #[test]
+    fn progress() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.5));
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Hold::new(2.0);
+        assert_eq!(condition.progress(), 0.0);
+
+        condition.evaluate(&actions, &time, 1.0.into());
+        assert_eq!(condition.progress(), 0.25);
+
+        condition.evaluate(&actions, &time, 0.0.into());
+        assert_eq!(condition.progress(), 0.0);
+    }
 }
@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+use log::warn;
+
+use super::DEFAULT_ACTUATION;
+use crate::prelude::*;
+
+/**
+Returns [`TriggerState::Fired`] while the input is actuated, but only if the layer action
+had the expected state when the input was first actuated.
+
+Useful for remapping a set of bindings while a designated input is held, similar to keyboard layers.
+Add [`Layer::active`] to the actions of the layer and [`Layer::inactive`] to the base actions
+bound to the same inputs.
+
+Unlike [`Chord`] and [`BlockBy`], the layer state is captured on press and kept until
+the input is released. So releasing the layer input mid-press won't switch to the base action,
+and pressing it mid-press won't switch to the layered action.
+
+The layer action should be spawned before the actions that use this condition,
+so its state is updated first.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+# let mut world = World::new();
+world.spawn((
+    Player,
+    Actions::<Player>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+        let layer = context
+            .spawn((Action::<AbilityLayer>::new(), bindings![GamepadButton::LeftTrigger2]))
+            .id();
+
+        context.spawn((
+            Action::<Jump>::new(),
+            Layer::inactive(layer),
+            bindings![GamepadButton::South],
+        ));
+        context.spawn((
+            Action::<Dash>::new(),
+            Layer::active(layer),
+            bindings![GamepadButton::South],
+        ));
+    })),
+));
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct AbilityLayer;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Dash;
+```
+*/
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct Layer {
+    /// Action that enables the layer when it fires.
+    pub action: Entity,
+
+    /// Whether the layer action should fire on press to trigger.
+    pub active: bool,
+
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// Whether the layer action was firing when the input was actuated.
+    pressed_in_layer: Option<bool>,
+}
+
+impl Layer {
+    /// Creates a new instance that triggers only for presses made while the layer action fires.
+    #[must_use]
+    pub const fn active(action: Entity) -> Self {
+        Self::new(action, true)
+    }
+
+    /// Creates a new instance that triggers only for presses made while the layer action doesn't fire.
+    #[must_use]
+    pub const fn inactive(action: Entity) -> Self {
+        Self::new(action, false)
+    }
+
+    const fn new(action: Entity, active: bool) -> Self {
+        Self {
+            action,
+            active,
+            actuation: DEFAULT_ACTUATION,
+            pressed_in_layer: None,
+        }
+    }
+}
+
+impl InputCondition for Layer {
+    fn evaluate(
+        &mut self,
+        actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        if !value.is_actuated(self.actuation) {
+            self.pressed_in_layer = None;
+            return TriggerState::None;
+        }
+
+        let pressed_in_layer = *self.pressed_in_layer.get_or_insert_with(|| {
+            let Ok((_, &state, ..)) = actions.get(self.action) else {
+                // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+                warn!("`{}` is not a valid action", self.action);
+                return false;
+            };
+
+            state == TriggerState::Fired
+        });
+
+        if pressed_in_layer == self.active {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Implicit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn active() {
+        let (mut world, mut state) = context::init_world();
+        let layer = world
+            .spawn((Action::<Test>::new(), TriggerState::Fired))
+            .id();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Layer::active(layer);
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+        );
+
+        let mut condition = Layer::inactive(layer);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn latched() {
+        let (mut world, mut state) = context::init_world();
+        let layer = world
+            .spawn((Action::<Test>::new(), TriggerState::Fired))
+            .id();
+
+        let mut condition = Layer::active(layer);
+        {
+            let (time, actions) = state.get(&world);
+            assert_eq!(
+                condition.evaluate(&actions, &time, true.into()),
+                TriggerState::Fired,
+            );
+        }
+
+        *world.get_mut::<TriggerState>(layer).unwrap() = TriggerState::None;
+
+        let (time, actions) = state.get(&world);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+            "should keep the layer state until release"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn missing_action() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Layer::inactive(Entity::PLACEHOLDER);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired,
+        );
+    }
+
+    #[derive(InputAction)]
+    #[action_output(bool)]
+    struct Test;
+}
@@ -0,0 +1,165 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns [`TriggerState::None`] when the lock state of a key from [`LockKeys`] doesn't match.
+///
+/// Keyboard bindings use physical keys, so numpad keys like [`KeyCode::Numpad8`]
+/// trigger regardless of Num Lock. Add this condition to a numpad binding to use
+/// it only as a digit or only as a navigation key.
+///
+/// When added to an action, gates the whole action on the lock state. For example, debug actions
+/// can be made available only while Scroll Lock is enabled.
+///
+/// The state is synchronized with [`LockKeys`] during [`EnhancedInputSystems::Prepare`].
+///
+/// # Examples
+///
+/// Numpad bindings.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Menu,
+///     actions!(Menu[
+///         (
+///             Action::<Up>::new(),
+///             bindings![
+///                 KeyCode::ArrowUp,
+///                 // Navigation key only when Num Lock is off.
+///                 (KeyCode::Numpad8, LockState::disabled(LockKey::NumLock)),
+///             ],
+///         ),
+///         (
+///             // Digit regardless of Num Lock.
+///             Action::<Slot8>::new(),
+///             bindings![KeyCode::Digit8, KeyCode::Numpad8],
+///         ),
+///     ]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Menu;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Up;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Slot8;
+/// ```
+///
+/// Debug action.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     DebugTools,
+///     actions!(DebugTools[(
+///         Action::<ToggleColliders>::new(),
+///         LockState::enabled(LockKey::ScrollLock),
+///         bindings![KeyCode::F1],
+///     )]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct DebugTools;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct ToggleColliders;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct LockState {
+    /// Key whose lock state is checked.
+    pub key: LockKey,
+
+    /// Required lock state.
+    pub enabled: bool,
+
+    /// Current lock state of [`Self::key`].
+    current: bool,
+}
+
+impl LockState {
+    /// Creates a new instance that requires the lock to be enabled.
+    #[must_use]
+    pub const fn enabled(key: LockKey) -> Self {
+        Self::new(key, true)
+    }
+
+    /// Creates a new instance that requires the lock to be disabled.
+    #[must_use]
+    pub const fn disabled(key: LockKey) -> Self {
+        Self::new(key, false)
+    }
+
+    #[must_use]
+    pub const fn new(key: LockKey, enabled: bool) -> Self {
+        Self {
+            key,
+            enabled,
+            current: false,
+        }
+    }
+}
+
+impl InputCondition for LockState {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        _value: ActionValue,
+    ) -> TriggerState {
+        if self.current == self.enabled {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+
+    fn kind(&self) -> ConditionKind {
+        ConditionKind::Blocker
+    }
+}
+
+pub(crate) fn update_lock_states(lock_keys: Res<LockKeys>, mut states: Query<&mut LockState>) {
+    for mut state in &mut states {
+        let current = lock_keys.get(state.key);
+        if state.current != current {
+            state.current = current;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn lock_state() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = LockState::disabled(LockKey::NumLock);
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired
+        );
+
+        condition.current = true;
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::None
+        );
+    }
+}
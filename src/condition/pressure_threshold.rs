@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns [`TriggerState::Fired`] when the input magnitude is within a pressure range.
+///
+/// Intended for analog inputs, such as [`GamepadButton::LeftTrigger2`] and
+/// [`GamepadButton::RightTrigger2`], which report how far they are pulled.
+/// Allows multiple actions to be bound to the same trigger and react to different pull depths.
+///
+/// Returns [`TriggerState::Ongoing`] while the input is actuated but hasn't reached
+/// [`Self::min`] yet, and [`TriggerState::None`] otherwise.
+///
+/// Action-level conditions receive the value converted to the action output, so for
+/// `bool` actions, add this condition to the binding to read the actual pressure.
+///
+/// # Examples
+///
+/// Aim at half pull and shoot at full pull:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Aim>::new(),
+///             bindings![(GamepadButton::LeftTrigger2, PressureThreshold::new(0.3))],
+///         ),
+///         (
+///             Action::<Shoot>::new(),
+///             bindings![(GamepadButton::LeftTrigger2, PressureThreshold::new(0.9))],
+///         ),
+///     ]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Aim;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Shoot;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct PressureThreshold {
+    /// Minimum magnitude to trigger, inclusive.
+    pub min: f32,
+
+    /// Maximum magnitude to trigger, exclusive.
+    ///
+    /// Set it to trigger only on a soft pull.
+    pub max: f32,
+}
+
+impl PressureThreshold {
+    /// Creates a new instance with the given minimum and no maximum.
+    #[must_use]
+    pub const fn new(min: f32) -> Self {
+        Self {
+            min,
+            max: f32::INFINITY,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+}
+
+impl Default for PressureThreshold {
+    /// Creates a new instance that triggers on a full pull.
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputCondition for PressureThreshold {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let pressure = value.as_axis3d().length();
+        if (self.min..self.max).contains(&pressure) {
+            TriggerState::Fired
+        } else if pressure > 0.0 && pressure < self.min {
+            TriggerState::Ongoing
+        } else {
+            TriggerState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn threshold() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = PressureThreshold::new(0.5);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.3.into()),
+            TriggerState::Ongoing
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 1.0.into()),
+            TriggerState::Fired
+        );
+    }
+
+    #[test]
+    fn max() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = PressureThreshold::new(0.3).with_max(0.9);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.9.into()),
+            TriggerState::None
+        );
+    }
+
+    #[test]
+    fn bool() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = PressureThreshold::default();
+        assert_eq!(
+            condition.evaluate(&actions, &time, true.into()),
+            TriggerState::Fired
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, false.into()),
+            TriggerState::None
+        );
+    }
+}
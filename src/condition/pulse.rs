@@ -106,6 +106,14 @@ impl Pulse {
     pub fn timer(&self) -> &Timer {
         &self.timer
     }
+
+    /// Returns the time in seconds until the next trigger while the input is actuated.
+    ///
+    /// Includes [`Self::initial_delay`] before the first trigger.
+    #[must_use]
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
 }
 
 impl InputCondition for Pulse {
@@ -326,4 +334,19 @@ mod tests {
             TriggerState::None
         );
     }
+
+    #[test]
+    fn remaining() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.25));
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Pulse::new(1.0).with_initial_delay(0.5);
+        assert_eq!(condition.remaining_secs(), 0.5);
+
+        condition.evaluate(&actions, &time, true.into());
+        assert_eq!(condition.remaining_secs(), 0.25);
+    }
 }
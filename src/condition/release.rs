@@ -6,8 +6,45 @@ use crate::prelude::*;
 /// Returns [`TriggerState::Ongoing`] when the input exceeds the actuation threshold and
 /// [`TriggerState::Fired`] once when the input drops back below the actuation threshold.
 ///
+/// Since the action stays [`TriggerState::Ongoing`] while held, [`Fire::elapsed_secs`] on release
+/// contains the total hold duration. For the press edge, see [`Press`].
+///
 /// Note that both `bevy::prelude::*` and `bevy_enhanced_input::prelude::*` export a type with this name.
 /// To disambiguate, import `bevy_enhanced_input::prelude::{*, Release}`.
+///
+/// # Examples
+///
+/// Shoot a bow on release with the strength based on how long it was drawn.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::{*, Release};
+/// # let mut app = App::new();
+/// app.add_observer(shoot);
+///
+/// app.world_mut().spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Shoot>::new(),
+///             Release::default(),
+///             bindings![MouseButton::Left],
+///         ),
+///     ])
+/// ));
+///
+/// fn shoot(shoot: On<Fire<Shoot>>) {
+///     let strength = shoot.elapsed_secs.min(MAX_DRAW_SECS) / MAX_DRAW_SECS;
+///     // ..
+/// }
+///
+/// const MAX_DRAW_SECS: f32 = 1.5;
+/// # #[derive(Component)]
+/// # struct Player;
+/// # #[derive(InputAction)]
+/// # #[action_output(bool)]
+/// # struct Shoot;
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
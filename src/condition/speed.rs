@@ -0,0 +1,184 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns [`TriggerState::Fired`] when the speed of the input is within the defined range.
+///
+/// Speed is calculated as the length of the input value divided by the frame delta time,
+/// so it's intended for delta inputs, such as [`Binding::MouseMotion`].
+/// For mouse motion this gives pixels per second.
+///
+/// Returns [`TriggerState::None`] when there is no input or the delta time is zero.
+///
+/// Useful to drive different actions from the same motion source depending on its speed,
+/// such as a slow drag and a fast flick:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Drag;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Flick;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (Action::<Drag>::new(), Speed::below(1000.0), bindings![Binding::mouse_motion()]),
+///         (Action::<Flick>::new(), Speed::above(1000.0), bindings![Binding::mouse_motion()]),
+///     ]),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct Speed {
+    /// Minimum speed in units per second, inclusive.
+    pub min: f32,
+
+    /// Maximum speed in units per second, exclusive.
+    pub max: f32,
+
+    /// The type of time used to calculate the speed.
+    pub time_kind: TimeKind,
+}
+
+impl Speed {
+    /// Creates a new instance that fires when the speed is in `min..max`.
+    #[must_use]
+    pub fn new(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            time_kind: Default::default(),
+        }
+    }
+
+    /// Creates a new instance that fires when the speed is at least `min`.
+    #[must_use]
+    pub fn above(min: f32) -> Self {
+        Self::new(min, f32::INFINITY)
+    }
+
+    /// Creates a new instance that fires when the speed is below `max`.
+    #[must_use]
+    pub fn below(max: f32) -> Self {
+        Self::new(0.0, max)
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+}
+
+impl Default for Speed {
+    fn default() -> Self {
+        Self::new(0.0, f32::INFINITY)
+    }
+}
+
+impl InputCondition for Speed {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let delta = time.delta_kind(self.time_kind).as_secs_f32();
+        let length = value.as_axis3d().length();
+        if delta == 0.0 || length == 0.0 {
+            return TriggerState::None;
+        }
+
+        let speed = length / delta;
+        if (self.min..self.max).contains(&speed) {
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn range() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Speed::new(10.0, 20.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (3.0, 4.0).into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (4.0, 0.0).into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (10.0, 0.0).into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn below() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs(1));
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Speed::below(10.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, Vec2::ZERO.into()),
+            TriggerState::None,
+            "no movement shouldn't be considered as slow"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, 0.0).into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (0.0, 10.0).into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn zero_delta() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Speed::above(1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, (1.0, 0.0).into()),
+            TriggerState::None
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Returns [`TriggerState::Fired`] once for each [`Self::size`] of accumulated input.
+///
+/// Intended for mouse wheel actions like zooming or switching weapons, where each notch should
+/// count once regardless of the platform. Combine with [`Binding::MouseWheelPrecise`], which reports
+/// the delta in lines for both wheels and touchpads. See [`scroll_steps`](crate::binding::scroll_steps)
+/// for details about the normalization.
+///
+/// Input is accumulated until it reaches [`Self::size`]. If multiple steps are accumulated at once,
+/// the remaining steps fire during the next evaluations, one per evaluation. The accumulated fraction
+/// is discarded when the input changes direction.
+///
+/// Since the remaining steps fire without new input, use [`Self::sign`] to get the direction of
+/// the last step.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[(
+///         Action::<SwitchWeapon>::new(),
+///         Step::default(),
+///         bindings![Binding::mouse_wheel_precise()],
+///     )]),
+/// ));
+///
+/// fn switch_weapon(fire: On<Fire<SwitchWeapon>>, steps: Query<&Step>) {
+///     let step = steps.get(fire.action).unwrap();
+///     if step.sign() > 0.0 {
+///         info!("next weapon");
+///     } else {
+///         info!("previous weapon");
+///     }
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct SwitchWeapon;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct Step {
+    /// Amount of input that corresponds to a single step.
+    ///
+    /// By default set to 1.0, which is a single line for [`Binding::MouseWheelPrecise`].
+    pub size: f32,
+
+    remainder: f32,
+
+    sign: f32,
+}
+
+impl Step {
+    /// Creates a new instance with the given step size.
+    #[must_use]
+    pub fn new(size: f32) -> Self {
+        Self {
+            size,
+            remainder: 0.0,
+            sign: 0.0,
+        }
+    }
+
+    /// Returns `1.0` if the last step was positive, `-1.0` if negative, or `0.0` if there were no steps.
+    #[must_use]
+    pub fn sign(&self) -> f32 {
+        self.sign
+    }
+}
+
+impl Default for Step {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputCondition for Step {
+    fn evaluate(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> TriggerState {
+        let delta = value.as_axis1d();
+        if delta * self.remainder < 0.0 {
+            // Discard the fraction from the opposite direction.
+            self.remainder = 0.0;
+        }
+        self.remainder += delta;
+
+        if self.remainder.abs() >= self.size {
+            self.sign = self.remainder.signum();
+            self.remainder -= self.sign * self.size;
+            TriggerState::Fired
+        } else {
+            TriggerState::None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn fractions() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Step::default();
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::None,
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.5.into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(condition.sign(), 1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn multiple_steps() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Step::default();
+        assert_eq!(
+            condition.evaluate(&actions, &time, (-2.0).into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(condition.sign(), -1.0);
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::Fired,
+            "should fire the remaining step"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, 0.0.into()),
+            TriggerState::None,
+        );
+    }
+
+    #[test]
+    fn direction_change() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut condition = Step::default();
+        condition.evaluate(&actions, &time, 0.8.into());
+        assert_eq!(
+            condition.evaluate(&actions, &time, (-0.5).into()),
+            TriggerState::None,
+            "fraction from the opposite direction should be discarded"
+        );
+        assert_eq!(
+            condition.evaluate(&actions, &time, (-0.5).into()),
+            TriggerState::Fired,
+        );
+        assert_eq!(condition.sign(), -1.0);
+    }
+}
@@ -12,6 +12,40 @@ use crate::prelude::*;
 /// This is useful for modes that should persist until toggled off,
 /// like entering a "select target" mode, toggling crouch, or any other
 /// action that represents a persistent state rather than a momentary input.
+///
+/// # Examples
+///
+/// Offer both hold-to-sprint and toggle-to-sprint as an accessibility option.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn spawn_player(mut commands: Commands, settings: Res<AccessibilitySettings>) {
+///     let player = commands.spawn(Player).id();
+///     let mut sprint = commands.spawn((
+///         ActionOf::<Player>::new(player),
+///         Action::<Sprint>::new(),
+///         bindings![KeyCode::ShiftLeft, GamepadButton::LeftThumb],
+///     ));
+///     if settings.toggle_sprint {
+///         // Pressing once keeps the action fired until pressed again.
+///         sprint.insert(Toggle::default());
+///     }
+/// }
+///
+/// #[derive(Resource)]
+/// struct AccessibilitySettings {
+///     toggle_sprint: bool,
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Sprint;
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
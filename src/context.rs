@@ -55,8 +55,22 @@ When an action is despawned, it automatically transitions its state to [`Trigger
 triggering the corresponding events. Depending on your use case, using [`ContextActivity`] might be more convenient than removal.
 */
 
+#[cfg(feature = "window")]
+pub mod cursor;
+pub mod diagnostics;
+#[cfg(feature = "egui")]
+pub mod egui_priority;
+pub mod fixed_rate;
+pub mod gamepad_status;
+pub mod info;
+pub mod input_buffer;
 pub mod input_reader;
 mod instance;
+pub mod latency;
+pub mod pause;
+pub mod seat;
+pub mod stats;
+pub mod switch;
 pub mod time;
 mod trigger_tracker;
 
@@ -80,6 +94,7 @@ use bevy::{
         system::{ParamBuilder, QueryParamBuilder},
         world::{FilteredEntityMut, FilteredEntityRef},
     },
+    platform::time::Instant,
     prelude::*,
 };
 use log::{debug, trace};
@@ -87,15 +102,22 @@ use log::{debug, trace};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    action::fns::ActionFns,
+    action::{ClaimedDevice, conflicts::overlap, fns::ActionFns, group::GroupCanceled},
     binding::FirstActivation,
     condition::fns::{ConditionFns, ConditionRegistry},
-    context::{input_reader::PendingBindings, trigger_tracker::TriggerTracker},
+    context::{
+        fixed_rate::StepInterpolation,
+        input_reader::{DeviceSource, PendingBindings},
+        latency::DelayLine,
+        trigger_tracker::TriggerTracker,
+    },
     modifier::fns::{ModifierFns, ModifierRegistry},
     prelude::*,
 };
 use input_reader::InputReader;
 use instance::ContextInstances;
+use pause::InputPause;
+use stats::{ContextStat, ContextStats};
 
 /// An extension trait for [`App`] to assign input to components.
 pub trait InputContextAppExt {
@@ -110,8 +132,8 @@ pub trait InputContextAppExt {
     ///            The enum variant depends on the input source.
     ///         2. Apply all binding-level [`InputModifier`]s.
     ///         3. Evaluate all input-level [`InputCondition`]s, combining their results based on their [`InputCondition::kind`].
-    ///     2. Select all [`ActionValue`]s with the most significant [`TriggerState`] and combine them using the
-    ///        [`ActionSettings::accumulation`] strategy.
+    ///     2. Select all [`ActionValue`]s with the most significant [`TriggerState`], including the [`InjectedValue`]
+    ///        if present, and combine them using the [`ActionSettings::accumulation`] strategy.
     ///     3. Convert the combined value to [`ActionOutput::DIM`] using [`ActionValue::convert`].
     ///     4. Apply all action-level [`InputModifier`]s.
     ///     5. Evaluate all action-level [`InputCondition`]s, combining their results based on their [`InputCondition::kind`].
@@ -130,7 +152,54 @@ pub trait InputContextAppExt {
     /// For example, if your game logic runs inside [`FixedMain`](bevy::app::FixedMain), you can set the schedule
     /// to [`FixedPreUpdate`]. This way, if the schedule runs multiple times per frame, events like [`Start`] or
     /// [`Complete`] will be triggered only once per schedule run.
+    /// To avoid missing inputs that happen between runs, insert [`FixedInputBuffer`].
+    ///
+    /// To evaluate at a fixed rate without [`FixedMain`](bevy::app::FixedMain), use [`InputFixedUpdate`].
     fn add_input_context_to<S: ScheduleLabel + Default, C: Component>(&mut self) -> &mut Self;
+
+    /// Orders evaluation of contexts registered for [`PreUpdate`] after the given system set.
+    ///
+    /// Useful when modifiers depend on data written by other plugins during [`PreUpdate`],
+    /// like camera transforms. Configures [`EnhancedInputSystems`] in [`PreUpdate`],
+    /// for other schedules use [`App::configure_sets`] directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+    ///     .add_input_context::<Player>()
+    ///     .evaluate_contexts_after(CameraSystems)
+    ///     .add_systems(PreUpdate, follow_player.in_set(CameraSystems));
+    ///
+    /// # fn follow_player() {}
+    /// #[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash)]
+    /// struct CameraSystems;
+    ///
+    /// #[derive(Component)]
+    /// struct Player;
+    /// ```
+    fn evaluate_contexts_after<M>(&mut self, set: impl IntoSystemSet<M>) -> &mut Self;
+
+    /// Orders evaluation of contexts registered for [`PreUpdate`] before the given system set.
+    ///
+    /// Events and [`Action`] values will be available to the set within the same frame.
+    /// See also [`Self::evaluate_contexts_after`].
+    fn evaluate_contexts_before<M>(&mut self, set: impl IntoSystemSet<M>) -> &mut Self;
+
+    /// Assigns user-facing metadata to context `C`, available via [`ContextInfos`].
+    fn set_context_info<C: Component>(&mut self, info: ContextInfo) -> &mut Self;
+
+    /// Assigns cursor requirements to context `C`, applied to the primary window while the context is active.
+    ///
+    /// See [`cursor`] for details.
+    ///
+    /// Requires the `window` feature.
+    #[cfg(feature = "window")]
+    fn set_context_cursor<C: Component>(&mut self, cursor: ContextCursor) -> &mut Self;
 }
 
 impl InputContextAppExt for App {
@@ -143,6 +212,7 @@ impl InputContextAppExt for App {
 
         let actions_id = self.world_mut().register_component::<Actions<C>>();
         let activity_id = self.world_mut().register_component::<ContextActivity<C>>();
+        let sources_id = self.world_mut().register_component::<ContextSources<C>>();
         let mut registry = self.world_mut().resource_mut::<ContextRegistry>();
         if let Some(contexts) = registry
             .iter_mut()
@@ -155,31 +225,95 @@ impl InputContextAppExt for App {
             );
             contexts.actions_ids.push(actions_id);
             contexts.activity_ids.push(activity_id);
+            contexts.sources_ids.push(sources_id);
         } else {
             let mut contexts = ScheduleContexts::new::<S>();
             contexts.actions_ids.push(actions_id);
             contexts.activity_ids.push(activity_id);
+            contexts.sources_ids.push(sources_id);
             registry.push(contexts);
         }
 
         let _ = self.try_register_required_components::<C, ContextPriority<C>>();
         let _ = self.try_register_required_components::<C, ContextActivity<C>>();
+        let _ = self.try_register_required_components::<C, LastInputDevice>();
 
         #[cfg(feature = "reflect")]
         {
+            self.register_type::<ContextSwitchTimer<C>>();
             self.register_type::<ActionOf<C>>();
             self.register_type::<Actions<C>>();
             self.register_type::<ContextActivity<C>>();
             self.register_type::<ContextPriority<C>>();
+            self.register_type::<ContextSources<C>>();
         }
 
+        self.add_systems(
+            PreUpdate,
+            switch::tick_timers::<C>.after(EnhancedInputSystems::Apply),
+        );
+
+        #[cfg(feature = "asset")]
+        self.add_systems(
+            PreUpdate,
+            crate::binding::asset::apply_bindings::<C>
+                .run_if(resource_exists::<Assets<InputBindings>>)
+                .in_set(EnhancedInputSystems::Prepare),
+        );
+
+        #[cfg(feature = "ui")]
+        self.add_systems(PostUpdate, crate::overlay::update_overlays::<C>);
+        #[cfg(feature = "debug")]
+        self.add_systems(
+            PostUpdate,
+            crate::overlay::update_debug_sections::<C>
+                .before(crate::overlay::update_debug_overlays),
+        );
+
         self.add_observer(register::<C, S>)
             .add_observer(unregister::<C, S>)
             .add_observer(deactivate::<C>)
+            .add_observer(deactivate_all::<C>)
             .add_observer(reset_action::<C>);
 
         self
     }
+
+    fn evaluate_contexts_after<M>(&mut self, set: impl IntoSystemSet<M>) -> &mut Self {
+        // Other sets run after it.
+        self.configure_sets(PreUpdate, EnhancedInputSystems::Prepare.after(set))
+    }
+
+    fn evaluate_contexts_before<M>(&mut self, set: impl IntoSystemSet<M>) -> &mut Self {
+        self.configure_sets(
+            PreUpdate,
+            (EnhancedInputSystems::Update, EnhancedInputSystems::Apply).before(set),
+        )
+    }
+
+    fn set_context_info<C: Component>(&mut self, info: ContextInfo) -> &mut Self {
+        self.world_mut()
+            .resource_mut::<ContextInfos>()
+            .insert::<C>(info);
+        self
+    }
+
+    #[cfg(feature = "window")]
+    fn set_context_cursor<C: Component>(&mut self, cursor: ContextCursor) -> &mut Self {
+        let inserted = self
+            .world_mut()
+            .resource_mut::<cursor::ContextCursors>()
+            .insert::<C>(cursor);
+
+        if inserted {
+            self.add_systems(
+                PostUpdate,
+                cursor::request_cursor::<C>.in_set(cursor::RequestCursor),
+            );
+        }
+
+        self
+    }
 }
 
 /// Tracks registered input contexts for each schedule.
@@ -204,6 +338,9 @@ pub(crate) struct ScheduleContexts {
     /// IDs of [`ContextActivity<C>`].
     activity_ids: Vec<ComponentId>,
 
+    /// IDs of [`ContextSources<C>`].
+    sources_ids: Vec<ComponentId>,
+
     /// Configures the app for this schedule.
     setup: fn(&Self, &mut App, &ConditionRegistry, &ModifierRegistry),
 }
@@ -218,6 +355,7 @@ impl ScheduleContexts {
             schedule_id: TypeId::of::<S>(),
             actions_ids: Default::default(),
             activity_ids: Default::default(),
+            sources_ids: Default::default(),
             // Since the type is not present in the function signature, we can store
             // functions for specific type without making the struct generic.
             setup: Self::setup_typed::<S>,
@@ -244,13 +382,25 @@ impl ScheduleContexts {
         debug!("setting up systems for `{}`", ShortName::of::<S>());
 
         let update_fn = (
+            ParamBuilder,
+            ParamBuilder,
+            ParamBuilder,
+            ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             ParamBuilder,
             QueryParamBuilder::new(|builder| {
                 builder
-                    .data::<Option<&GamepadDevice>>()
+                    .data::<(
+                        Option<&GamepadDevice>,
+                        Option<&KeyboardDevice>,
+                        Option<&MouseDevice>,
+                        Option<&ModKeysDisambiguation>,
+                        Option<&mut LastInputDevice>,
+                        Option<&FallbackActive>,
+                        Option<&InputActivity>,
+                    )>()
                     .optional(|builder| {
                         for &id in &self.activity_ids {
                             builder.mut_id(id);
@@ -258,6 +408,9 @@ impl ScheduleContexts {
                         for &id in &self.actions_ids {
                             builder.mut_id(id);
                         }
+                        for &id in &self.sources_ids {
+                            builder.ref_id(id);
+                        }
                     });
             }),
             ParamBuilder,
@@ -273,6 +426,8 @@ impl ScheduleContexts {
                     }
                 });
             }),
+            ParamBuilder,
+            ParamBuilder,
         )
             .build_state(app.world_mut())
             .build_system(update::<S>);
@@ -291,6 +446,9 @@ impl ScheduleContexts {
                 });
             }),
             ParamBuilder,
+            ParamBuilder,
+            ParamBuilder,
+            ParamBuilder,
         )
             .build_state(app.world_mut())
             .build_system(apply::<S>);
@@ -305,7 +463,8 @@ impl ScheduleContexts {
                 (
                     update_fn.in_set(EnhancedInputSystems::Update),
                     trigger_fn.in_set(EnhancedInputSystems::Apply),
-                ),
+                )
+                    .run_if(pause::evaluates),
             );
     }
 }
@@ -339,7 +498,7 @@ fn unregister<C: Component, S: ScheduleLabel>(
 
 fn deactivate<C: Component>(
     insert: On<Insert, ContextActivity<C>>,
-    mut pending: ResMut<PendingBindings>,
+    pending: ResMut<PendingBindings>,
     contexts: Query<(&ContextActivity<C>, &Actions<C>)>,
     actions: Query<(&ActionSettings, &Bindings)>,
     bindings: Query<&Binding>,
@@ -355,10 +514,43 @@ fn deactivate<C: Component>(
     );
 
     if !*active {
-        for (settings, action_bindings) in actions.iter_many(context_actions) {
-            if settings.require_reset {
-                pending.extend(bindings.iter_many(action_bindings).copied());
-            }
+        require_reset(pending, context_actions, actions, bindings);
+    }
+}
+
+fn deactivate_all<C: Component>(
+    insert: On<Insert, InputActivity>,
+    pending: ResMut<PendingBindings>,
+    contexts: Query<(&InputActivity, &Actions<C>)>,
+    actions: Query<(&ActionSettings, &Bindings)>,
+    bindings: Query<&Binding>,
+) {
+    let Ok((&active, context_actions)) = contexts.get(insert.entity) else {
+        return;
+    };
+
+    debug!(
+        "setting input activity of `{}` on `{}` to `{}`",
+        ShortName::of::<C>(),
+        insert.entity,
+        *active,
+    );
+
+    if !*active {
+        require_reset(pending, context_actions, actions, bindings);
+    }
+}
+
+/// Marks bindings of actions with [`ActionSettings::require_reset`] as pending.
+fn require_reset<C: Component>(
+    mut pending: ResMut<PendingBindings>,
+    context_actions: &Actions<C>,
+    actions: Query<(&ActionSettings, &Bindings)>,
+    bindings: Query<&Binding>,
+) {
+    for (settings, action_bindings) in actions.iter_many(context_actions) {
+        if settings.require_reset {
+            pending.extend(bindings.iter_many(action_bindings).copied());
         }
     }
 }
@@ -377,11 +569,21 @@ pub(crate) fn reset_action<C: Component>(
         &mut TriggerState,
         &mut ActionEvents,
         &mut ActionTime,
+        &ActionBinding,
     )>,
     bindings: Query<&Binding>,
 ) {
-    let Ok((action_of, settings, fns, action_bindings, mut value, mut state, mut events, mut time)) =
-        actions.get_mut(remove.entity)
+    let Ok((
+        action_of,
+        settings,
+        fns,
+        action_bindings,
+        mut value,
+        mut state,
+        mut events,
+        mut time,
+        binding,
+    )) = actions.get_mut(remove.entity)
     else {
         trace!("ignoring reset for `{}`", remove.entity);
         return;
@@ -400,6 +602,7 @@ pub(crate) fn reset_action<C: Component>(
         *events,
         *value,
         *time,
+        **binding,
     );
 
     if let Some(action_bindings) = action_bindings
@@ -419,7 +622,11 @@ pub struct ExternallyMocked;
 
 #[allow(clippy::too_many_arguments)]
 fn update<S: ScheduleLabel>(
+    mut commands: Commands,
     mut consume_buffer: Local<Vec<Binding>>, // Consumed inputs during state evaluation.
+    mut pressed_supersets: Local<Vec<Binding>>, // Pressed bindings with modifiers for disambiguation.
+    mut fired_groups: Local<Vec<(ActionGroup, Entity)>>, // Groups with actions that started firing.
+    mut device_usages: Local<Vec<DeviceUsage>>, // Devices used by contexts on each entity.
     time: ContextTime,
     mut reader: InputReader,
     instances: Res<ContextInstances<S>>,
@@ -433,6 +640,12 @@ fn update<S: ScheduleLabel>(
             Option<&ModifierFns>,
             Option<&ConditionFns>,
             &mut ActionMock,
+            &mut InjectedValue,
+            &mut ActionBinding,
+            &mut ClaimedDevice,
+            &ActionFns,
+            Option<&ActionGroup>,
+            &mut GroupCanceled,
         ),
         Without<ExternallyMocked>,
     >,
@@ -447,14 +660,23 @@ fn update<S: ScheduleLabel>(
             Entity,
             &Binding,
             &mut FirstActivation,
+            Option<&mut DelayLine>,
+            &mut StepInterpolation,
             Option<&ModifierFns>,
             Option<&ConditionFns>,
+            Has<FallbackBinding>,
+            Has<NormalizedDirection>,
         ),
         Without<ActionSettings>,
     >,
     mut conds_and_mods: Query<FilteredEntityMut>,
+    mut stats: Option<ResMut<ContextStats>>,
+    pause: Res<InputPause>,
 ) {
     reader.clear_consumed::<S>();
+    if let Some(stats) = &mut stats {
+        stats.clear_schedule::<S>();
+    }
 
     for instance in &**instances {
         let Ok(mut context) = contexts.get_mut(instance.entity) else {
@@ -466,31 +688,78 @@ fn update<S: ScheduleLabel>(
         };
 
         let gamepad = context.get::<GamepadDevice>().copied().unwrap_or_default();
-        let context_active = instance.is_active(&context.as_readonly());
+        let keyboard = context.get::<KeyboardDevice>().copied().unwrap_or_default();
+        let mouse = context.get::<MouseDevice>().copied().unwrap_or_default();
+        let disambiguate = context.get::<ModKeysDisambiguation>().is_some();
+        let fallback_active = context.get::<FallbackActive>().is_some();
+        let last_device = context.get::<LastInputDevice>().and_then(|device| **device);
+        let mut last_device_active = false;
+        let mut new_device = None;
+        let context_active = instance.is_active(&context.as_readonly())
+            && context.get::<InputActivity>().is_none_or(|&active| *active)
+            && !pause.is_paused();
+        let sources = instance.sources(&context.as_readonly());
         let Some(mut context_actions) = instance.actions_mut(&mut context) else {
             continue;
         };
 
-        let mods_count = |action: &Entity| {
-            let Ok((.., action_bindings, _, _, _)) = actions.get(*action) else {
-                return Reverse(0);
+        let start = stats.is_some().then(Instant::now);
+        let mut bindings_count = 0;
+        let mut consumed_count = 0;
+
+        let evaluation_order = |action: &Entity| {
+            let Ok((_, _, settings, action_bindings, ..)) = actions.get(*action) else {
+                return Reverse((0, 0));
             };
 
-            let value = bindings
+            let mods_count = bindings
                 .iter_many(action_bindings.into_iter().flatten())
                 .map(|(_, b, ..)| b.mod_keys_count())
                 .max()
                 .unwrap_or(0);
-            Reverse(value)
+            Reverse((settings.priority, mods_count))
         };
 
-        if !context_actions.is_sorted_by_key(mods_count) {
-            context_actions.sort_by_cached_key(mods_count);
+        if !context_actions.is_sorted_by_key(evaluation_order) {
+            context_actions.sort_by_cached_key(evaluation_order);
         }
 
         trace!("updating `{}` on `{}`", instance.name, instance.entity);
+        #[cfg(feature = "trace")]
+        let _context_span = tracing::info_span!(
+            "evaluate_context",
+            context = %instance.name,
+            entity = %instance.entity,
+        )
+        .entered();
 
         reader.set_gamepad(gamepad);
+        reader.set_seat(keyboard, mouse);
+        reader.set_context_sources(sources);
+        reader.set_buffered(
+            TypeId::of::<S>() == TypeId::of::<FixedPreUpdate>()
+                || TypeId::of::<S>() == TypeId::of::<InputFixedUpdate>(),
+        );
+
+        pressed_supersets.clear();
+        if disambiguate && context_active {
+            for &action in &*context_actions {
+                let Ok((_, _, _, action_bindings, ..)) = actions.get(action) else {
+                    continue;
+                };
+
+                for (_, &binding, .., fallback) in
+                    bindings.iter_many(action_bindings.into_iter().flatten())
+                {
+                    if fallback && !fallback_active {
+                        continue;
+                    }
+                    if !binding.mod_keys().is_empty() && reader.value(binding).as_bool() {
+                        pressed_supersets.push(binding);
+                    }
+                }
+            }
+        }
 
         let mut actions_iter = actions.iter_many_mut(&*context_actions);
         while let Some((
@@ -501,10 +770,24 @@ fn update<S: ScheduleLabel>(
             modifiers,
             conditions,
             mut mock,
+            mut injected,
+            mut action_binding,
+            mut claimed_device,
+            fns,
+            action_group,
+            mut group_canceled,
         )) = actions_iter.fetch_next()
         {
             let action_name = ShortName(action_name);
-            let (new_state, new_value) = if !context_active {
+            #[cfg(feature = "trace")]
+            let _action_span =
+                tracing::info_span!("evaluate_action", action = %action_name).entered();
+            let injected = if injected.is_some() {
+                injected.take()
+            } else {
+                None
+            };
+            let (mut new_state, mut new_value) = if !context_active {
                 trace!("skipping updating `{action_name}` due to inactive context");
                 let dim = actions_data.get(action).map(|(v, ..)| v.dim()).unwrap();
                 (TriggerState::None, ActionValue::zero(dim))
@@ -525,6 +808,7 @@ fn update<S: ScheduleLabel>(
 
                 let new_state = mock.state;
                 let new_value = mock.value;
+                action_binding.set_if_neq(ActionBinding::default());
                 if expired {
                     mock.enabled = false;
                 }
@@ -536,22 +820,96 @@ fn update<S: ScheduleLabel>(
                 let dim = actions_data.get(action).map(|(v, ..)| v.dim()).unwrap();
                 let actions_data = actions_data.as_readonly();
                 let mut tracker = TriggerTracker::new(ActionValue::zero(dim));
+                let mut source = None;
+                let mut directions = Vec::new();
                 let mut bindings_iter =
                     bindings.iter_many_mut(action_bindings.into_iter().flatten());
-                while let Some((
-                    binding_entity,
-                    &binding,
-                    mut first_activation,
-                    modifiers,
-                    conditions,
-                )) = bindings_iter.fetch_next()
-                {
-                    let new_value = reader.value(binding);
+                loop {
+                    let item = bindings_iter.fetch_next();
+                    let normalized = item.as_ref().is_some_and(|&(.., normalized)| normalized);
+                    if !normalized && !directions.is_empty() {
+                        normalize_directions(&mut directions, action_settings.accumulation);
+                        for (current_tracker, binding, device_source) in directions.drain(..) {
+                            merge_binding(
+                                &mut tracker,
+                                &mut source,
+                                current_tracker,
+                                binding,
+                                device_source,
+                                action_settings,
+                                &mut claimed_device,
+                                &mut consume_buffer,
+                            );
+                        }
+                    }
+
+                    let Some((
+                        binding_entity,
+                        &binding,
+                        mut first_activation,
+                        mut delay_line,
+                        mut interpolation,
+                        modifiers,
+                        conditions,
+                        fallback,
+                        normalized,
+                    )) = item
+                    else {
+                        break;
+                    };
+
+                    if fallback && !fallback_active {
+                        trace!("ignoring fallback `{binding:?}`");
+                        continue;
+                    }
+
+                    bindings_count += 1;
+                    let claimed = claimed_device.filter(|_| action_settings.exclusive_device);
+                    if let Some(claimed) = claimed
+                        && binding
+                            .device()
+                            .is_some_and(|device| device != claimed.device)
+                    {
+                        trace!("ignoring `{binding:?}` due to claimed `{claimed:?}`");
+                        continue;
+                    }
+
+                    if let Some(superset) = pressed_supersets
+                        .iter()
+                        .find(|&&superset| overlap(superset, binding) == Some(Ordering::Greater))
+                    {
+                        trace!("ignoring `{binding:?}` due to pressed `{superset:?}`");
+                        continue;
+                    }
+
+                    let (mut new_value, device_source) = match claimed {
+                        Some(claimed) if binding.device() == Some(claimed.device) => {
+                            // Read only from the claimed gamepad, other gamepads are ignored.
+                            let value = match claimed.entity {
+                                Some(gamepad) if claimed.device == InputDevice::Gamepad => {
+                                    reader.gamepad_value(binding, gamepad)
+                                }
+                                _ => reader.value(binding),
+                            };
+                            (value, Some(claimed))
+                        }
+                        _ => (reader.value(binding), reader.device_source(binding)),
+                    };
+                    if let Some(delay) = reader.latency_delay(binding)
+                        && let Some(delay_line) = &mut delay_line
+                    {
+                        new_value =
+                            delay_line.delay(binding, new_value, time.real.elapsed(), delay);
+                    }
+                    if let Some(step) = time.fixed_step() {
+                        new_value = interpolation.interpolate(binding, new_value, step);
+                    }
                     if action_settings.require_reset && **first_activation {
                         // Ignore until we read zero for this mapping.
                         if new_value.as_bool() {
                             // Mark the binding input as consumed regardless of the end action state.
                             reader.consume::<S>(binding);
+                            consumed_count += 1;
                             continue;
                         } else {
                             **first_activation = false;
@@ -587,20 +945,36 @@ fn update<S: ScheduleLabel>(
                         continue;
                     }
 
-                    match current_state.cmp(&tracker.state()) {
+                    if normalized {
+                        // Merge after all consecutive directions are read to clamp their combined value.
+                        directions.push((current_tracker, binding, device_source));
+                    } else {
+                        merge_binding(
+                            &mut tracker,
+                            &mut source,
+                            current_tracker,
+                            binding,
+                            device_source,
+                            action_settings,
+                            &mut claimed_device,
+                            &mut consume_buffer,
+                        );
+                    }
+                }
+
+                if let Some(value) = injected {
+                    trace!("reading injected value `{value:?}`");
+                    let current_tracker = TriggerTracker::new(value);
+                    match current_tracker.state().cmp(&tracker.state()) {
                         Ordering::Less => (),
                         Ordering::Equal => {
-                            tracker.combine(current_tracker, action_settings.accumulation);
-                            if action_settings.consume_input {
-                                consume_buffer.push(binding);
-                            }
+                            tracker.combine(current_tracker, action_settings.accumulation)
                         }
                         Ordering::Greater => {
                             tracker.overwrite(current_tracker);
-                            if action_settings.consume_input {
-                                consume_buffer.clear();
-                                consume_buffer.push(binding);
-                            }
+                            source = None;
+                            action_binding.set_if_neq(ActionBinding::default());
+                            consume_buffer.clear();
                         }
                     }
                 }
@@ -615,12 +989,33 @@ fn update<S: ScheduleLabel>(
 
                 let new_state = tracker.state();
                 let new_value = tracker.value().convert(dim);
+                if let Some((binding, device_source)) = source {
+                    let gamepad = device_source
+                        .filter(|source| source.device == InputDevice::Gamepad)
+                        .and_then(|source| source.entity);
+                    action_binding.set_if_neq(ActionBinding {
+                        binding: Some(binding),
+                        gamepad,
+                    });
+                }
+                let source = source.map(|(binding, _)| binding);
+
+                if new_state != TriggerState::None
+                    && let Some(device) = source.and_then(|binding| binding.device())
+                {
+                    if Some(device) == last_device {
+                        last_device_active = true;
+                    } else {
+                        new_device = Some(device);
+                    }
+                }
 
                 if action_settings.consume_input {
                     if new_state != TriggerState::None {
                         for &binding in &consume_buffer {
                             reader.consume::<S>(binding);
                         }
+                        consumed_count += consume_buffer.len();
                     }
                     consume_buffer.clear();
                 }
@@ -630,17 +1025,203 @@ fn update<S: ScheduleLabel>(
 
             trace!("evaluated to `{new_state:?}` with `{new_value:?}`");
 
+            let group = action_group.copied().or(fns.group());
+            if **group_canceled {
+                if group.is_none() || new_state == TriggerState::None {
+                    **group_canceled = false;
+                } else {
+                    trace!("ignoring `{new_state:?}` due to cancellation by `{group:?}`");
+                    new_state = TriggerState::None;
+                    new_value = ActionValue::zero(new_value.dim());
+                }
+            }
+
+            if new_state == TriggerState::None {
+                **claimed_device = None;
+            }
+
             let (mut value, mut state, mut events, mut action_time) =
                 actions_data.get_mut(action).unwrap();
 
-            action_time.update(time.delta_secs(), *state);
+            if let Some(group) = group
+                && new_state == TriggerState::Fired
+                && *state != TriggerState::Fired
+                && !fired_groups.iter().any(|&(fired, _)| fired == group)
+            {
+                fired_groups.push((group, action));
+            }
+
+            action_time.update(time.delta_kind(TimeKind::Virtual).as_secs_f32(), *state);
             events.set_if_neq(ActionEvents::new(*state, new_state));
             state.set_if_neq(new_state);
             value.set_if_neq(new_value);
         }
+
+        if !fired_groups.is_empty() {
+            let mut actions_iter = actions.iter_many_mut(&*context_actions);
+            while let Some((
+                action,
+                action_name,
+                ..,
+                mut claimed_device,
+                fns,
+                action_group,
+                mut group_canceled,
+            )) = actions_iter.fetch_next()
+            {
+                let Some(group) = action_group.copied().or(fns.group()) else {
+                    continue;
+                };
+                let Some(&(_, winner)) = fired_groups.iter().find(|&&(fired, _)| fired == group)
+                else {
+                    continue;
+                };
+                if winner == action {
+                    continue;
+                }
+
+                let (mut value, mut state, mut events, _) = actions_data.get_mut(action).unwrap();
+                if *state == TriggerState::None {
+                    continue;
+                }
+
+                debug!(
+                    "canceling `{}` due to `{winner}` from `{group:?}`",
+                    ShortName(action_name)
+                );
+                if events.contains(ActionEvents::START) {
+                    // Wasn't active before this frame.
+                    events.set_if_neq(ActionEvents::empty());
+                } else {
+                    events.set_if_neq(ActionEvents::CANCEL);
+                }
+                state.set_if_neq(TriggerState::None);
+                value.set_if_neq(ActionValue::zero(value.dim()));
+                **claimed_device = None;
+                **group_canceled = true;
+            }
+            fired_groups.clear();
+        }
+
+        if let (Some(stats), Some(start)) = (stats.as_deref_mut(), start) {
+            let mut stat = ContextStat::new::<S>(instance.entity, instance.name);
+            stat.actions = context_actions.len();
+            stat.bindings = bindings_count;
+            stat.consumed = consumed_count;
+            stat.duration = start.elapsed();
+            stats.push(stat);
+        }
+
+        // Decide after evaluating all contexts, since the device is shared between them.
+        if let Some(usage) = device_usages
+            .iter_mut()
+            .find(|usage| usage.entity == instance.entity)
+        {
+            usage.last_device_active |= last_device_active;
+            usage.new_device = usage.new_device.or(new_device);
+        } else {
+            device_usages.push(DeviceUsage {
+                entity: instance.entity,
+                last_device_active,
+                new_device,
+            });
+        }
+    }
+
+    for usage in device_usages.drain(..) {
+        if !usage.last_device_active
+            && let Some(device) = usage.new_device
+            && let Ok(mut context) = contexts.get_mut(usage.entity)
+            && let Some(mut last_device) = context.get_mut::<LastInputDevice>()
+        {
+            debug!(
+                "switching last input device on `{}` to `{device:?}`",
+                usage.entity
+            );
+            *last_device = LastInputDevice(Some(device));
+            commands.trigger(InputDeviceChanged {
+                context: usage.entity,
+                device,
+            });
+        }
+    }
+}
+
+/// Merges the tracker of a binding into the tracker of its action.
+///
+/// Updates the binding with the highest state and its device in `source`.
+#[allow(clippy::too_many_arguments)]
+fn merge_binding(
+    tracker: &mut TriggerTracker,
+    source: &mut Option<(Binding, Option<DeviceSource>)>,
+    current_tracker: TriggerTracker,
+    binding: Binding,
+    device_source: Option<DeviceSource>,
+    action_settings: &ActionSettings,
+    claimed_device: &mut ClaimedDevice,
+    consume_buffer: &mut Vec<Binding>,
+) {
+    match current_tracker.state().cmp(&tracker.state()) {
+        Ordering::Less => (),
+        Ordering::Equal => {
+            tracker.combine(current_tracker, action_settings.accumulation);
+            if action_settings.consume_input {
+                consume_buffer.push(binding);
+            }
+        }
+        Ordering::Greater => {
+            tracker.overwrite(current_tracker);
+            *source = Some((binding, device_source));
+            if action_settings.exclusive_device && claimed_device.is_none() {
+                // Claim the device early to ignore other devices during this evaluation.
+                **claimed_device = device_source;
+            }
+            if action_settings.consume_input {
+                consume_buffer.clear();
+                consume_buffer.push(binding);
+            }
+        }
+    }
+}
+
+/// Clamps the combined value of consecutive [`NormalizedDirection`] bindings to a length of 1.0.
+///
+/// Only directions with the highest state are combined, as they would be for the action.
+/// All directions are scaled by the same factor to preserve the direction of the combined value.
+fn normalize_directions(
+    directions: &mut [(TriggerTracker, Binding, Option<DeviceSource>)],
+    accumulation: Accumulation,
+) {
+    let Some(state) = directions.iter().map(|(tracker, ..)| tracker.state()).max() else {
+        return;
+    };
+
+    let mut combined = TriggerTracker::new(ActionValue::Axis3D(Vec3::ZERO));
+    for (tracker, ..) in directions
+        .iter()
+        .filter(|(tracker, ..)| tracker.state() == state)
+    {
+        combined.combine(tracker.clone(), accumulation);
+    }
+
+    let length = combined.value().as_axis3d().length();
+    if length > 1.0 {
+        trace!("clamping directions with length `{length}`");
+        for (tracker, ..) in directions {
+            tracker.scale(length.recip());
+        }
     }
 }
 
+/// Devices used by actions of all contexts on an entity during evaluation.
+struct DeviceUsage {
+    entity: Entity,
+    /// Whether any action is triggered by the current [`LastInputDevice`].
+    last_device_active: bool,
+    /// Device of the first action triggered by a different device.
+    new_device: Option<InputDevice>,
+}
+
 pub type ActionsQuery<'w, 's> = Query<
     'w,
     's,
@@ -657,6 +1238,9 @@ fn apply<S: ScheduleLabel>(
     instances: Res<ContextInstances<S>>,
     contexts: Query<FilteredEntityRef, Without<ActionFns>>,
     mut actions: Query<EntityMut, With<ActionFns>>,
+    bindings: Query<(&Binding, Option<&ConditionFns>), Without<ActionFns>>,
+    real_time: Res<Time<Real>>,
+    mut watchdog: Option<ResMut<ActionWatchdog>>,
 ) {
     for instance in &**instances {
         let Ok(context) = contexts.get(instance.entity) else {
@@ -684,6 +1268,8 @@ fn apply<S: ScheduleLabel>(
             let state = *action.get::<TriggerState>().unwrap();
             let events = *action.get::<ActionEvents>().unwrap();
             let time = *action.get::<ActionTime>().unwrap();
+            let action_binding = *action.get::<ActionBinding>().unwrap();
+            let binding = *action_binding;
             fns.trigger(
                 &mut commands,
                 context.id(),
@@ -692,7 +1278,59 @@ fn apply<S: ScheduleLabel>(
                 events,
                 value,
                 time,
+                binding,
             );
+
+            if let Some(mut history) = action.get_mut::<ActionHistory>() {
+                history.record(value);
+            }
+
+            if let Some(rumble) = action.get::<ActionRumble>()
+                && events.intersects(rumble.events)
+            {
+                if let Some(gamepad) = action_binding.gamepad() {
+                    commands.trigger(
+                        Rumble::new(context.id(), rumble.intensity, rumble.duration)
+                            .with_gamepad(gamepad),
+                    );
+                } else {
+                    trace!(
+                        "ignoring rumble for `{}` not triggered by a gamepad",
+                        action.id()
+                    );
+                }
+            }
+
+            if events.contains(ActionEvents::START)
+                && let Some(mut monitor) = action.get_mut::<InputMonitor>()
+                && let Some(kind) = monitor.record(real_time.elapsed())
+            {
+                commands.trigger(InputAnomaly::new(action.id(), context.id(), kind));
+            }
+
+            if let Some(watchdog) = &mut watchdog
+                && watchdog.check(action.id(), state, time)
+            {
+                let mut conditions = Vec::new();
+                for (_, fns) in bindings
+                    .iter_many(action.get::<Bindings>().into_iter().flatten())
+                    .filter(|&(&action_binding, _)| Some(action_binding) == binding)
+                {
+                    conditions.extend(fns.into_iter().flat_map(|fns| fns.names()));
+                }
+                if let Some(fns) = action.get::<ConditionFns>() {
+                    conditions.extend_from_slice(fns.names());
+                }
+
+                commands.trigger(ActionStuck::new(
+                    action.id(),
+                    context.id(),
+                    state,
+                    time,
+                    binding,
+                    conditions,
+                ));
+            }
         }
     }
 }
@@ -709,6 +1347,7 @@ fn apply<S: ScheduleLabel>(
 /// This is analogous to hiding an entity instead of despawning.
 /// Use this component when you want to toggle quickly, preserve bindings, or keep entity IDs.
 /// Use removal when the context is truly going away and you don't need it back soon.
+/// To toggle all contexts on the entity, use [`InputActivity`].
 ///
 /// Marked as required for `C` on context registration.
 #[derive(Component, Deref)]
@@ -796,6 +1435,72 @@ impl<C: 'static> TypePath for ContextActivity<C> {
     }
 }
 
+/// Enables or disables all input contexts on this entity.
+///
+/// By default, all contexts are active.
+///
+/// Inserting [`Self::INACTIVE`] is similar to inserting [`ContextActivity::INACTIVE`] for each context on the entity.
+/// It transitions all action states to [`TriggerState::None`] with [`ActionValue::zero`], triggering the corresponding
+/// events. Actions, bindings and [`ContextActivity<C>`] are preserved, so inserting [`Self::ACTIVE`] restores the
+/// previous behavior.
+///
+/// A context is evaluated only if both this component and its [`ContextActivity<C>`] are active.
+///
+/// # Examples
+///
+/// Disable all input for a player while a cutscene is playing:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn start_cutscene(mut commands: Commands, player: Single<Entity, With<Player>>) {
+///     commands.entity(*player).insert(InputActivity::INACTIVE);
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Component, Deref, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[component(immutable)]
+pub struct InputActivity(bool);
+
+impl InputActivity {
+    /// Active contexts.
+    pub const ACTIVE: Self = Self(true);
+
+    /// Inactive contexts.
+    pub const INACTIVE: Self = Self(false);
+
+    /// Creates a new instance with the given value.
+    #[must_use]
+    pub const fn new(active: bool) -> Self {
+        Self(active)
+    }
+
+    /// Returns a new instance with the value inverted.
+    #[must_use]
+    pub const fn toggled(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+impl Default for InputActivity {
+    fn default() -> Self {
+        Self::ACTIVE
+    }
+}
+
 /// Determines the evaluation order of the input context `C` on the entity.
 ///
 /// Used to control how contexts are layered, as some [`Action<C>`]s may consume inputs.
@@ -897,6 +1602,113 @@ impl<C: 'static> TypePath for ContextPriority<C> {
     }
 }
 
+/// Input sources visible to actions of context `C`.
+///
+/// Applied in addition to the global [`ActionSources`]: a source is read only if it's enabled in both.
+/// If not present, the context uses only the global sources.
+///
+/// Useful to keep contexts from reacting to unrelated devices without juggling
+/// [`ActionSettings::consume_input`], like a menu that should never read mouse motion
+/// while gameplay on the same entity does.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Menu,
+///     // Allow only keyboard and gamepad.
+///     ContextSources::<Menu>::new(ActionSources {
+///         keyboard: true,
+///         gamepad_button: true,
+///         gamepad_axis: true,
+///         ..ActionSources::NONE
+///     }),
+///     Player,
+///     // Allow everything except the mouse wheel.
+///     ContextSources::<Player>::new(ActionSources {
+///         mouse_wheel: false,
+///         ..ActionSources::ALL
+///     }),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Menu;
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Component, Deref)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Default, type_path = false)
+)]
+pub struct ContextSources<C> {
+    #[deref]
+    sources: ActionSources,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    marker: PhantomData<C>,
+}
+
+impl<C> ContextSources<C> {
+    /// Creates a new instance with the given sources.
+    #[must_use]
+    pub const fn new(sources: ActionSources) -> Self {
+        Self {
+            sources,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Default for ContextSources<C> {
+    fn default() -> Self {
+        Self::new(ActionSources::ALL)
+    }
+}
+
+impl<C> Clone for ContextSources<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for ContextSources<C> {}
+
+#[cfg(feature = "reflect")]
+impl<C: 'static> TypePath for ContextSources<C> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                concat!(module_path!(), "::ContextSources<{}>"),
+                type_name::<C>()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("ContextSources<{}>", type_name::<C>()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("ContextSources")
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some(module_path!())
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some(module_path!().split(':').next().unwrap())
+    }
+}
+
 /// Associated gamepad for all input contexts on this entity.
 ///
 /// If not present, input will be read from all connected gamepads.
@@ -939,6 +1751,305 @@ impl From<Option<Entity>> for GamepadDevice {
     }
 }
 
+/// Suppresses bindings when a binding with more keyboard modifiers for the same input is pressed.
+///
+/// For example, if one action is bound to `Ctrl + S` and another to `S` or `Ctrl`, pressing
+/// `Ctrl + S` activates only the first action. Applies to all input contexts on this entity,
+/// comparing bindings within each context.
+///
+/// Without this component, the subset binding is suppressed only if the action with more modifiers
+/// has [`ActionSettings::consume_input`] enabled, since it's evaluated first. This component doesn't
+/// depend on the evaluation order or the action state, only on the pressed inputs.
+/// See also [`Actions::conflicts`].
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Editor,
+///     ModKeysDisambiguation,
+///     actions!(Editor[
+///         (Action::<Save>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)]),
+///         (Action::<Scale>::new(), bindings![KeyCode::KeyS]),
+///     ]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Editor;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Save;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Scale;
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct ModKeysDisambiguation;
+
+/// Marks an entity as waiting for a gamepad to be assigned.
+///
+/// Once an unassigned gamepad is available, its entity will be assigned to
+/// [`GamepadDevice::Single`] and this component will be removed.
+/// A gamepad is considered unassigned if no entity has it in [`GamepadDevice::Single`].
+/// Already connected gamepads are assigned immediately on insertion,
+/// otherwise assignment happens when a new gamepad connects.
+///
+/// Until assignment, [`GamepadDevice::None`] is used, so the entity ignores all gamepad input.
+///
+/// Useful for local multiplayer, where each player should read only from their own gamepad.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// # let mut world = World::new();
+/// // Each player will receive the next connected gamepad.
+/// world.spawn((Player, AwaitingGamepad));
+/// world.spawn((Player, AwaitingGamepad));
+/// ```
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+#[require(GamepadDevice::None)]
+pub struct AwaitingGamepad;
+
+/// Defines what happens with [`GamepadDevice::Single`] when its gamepad disconnects.
+///
+/// Regardless of the variant, [`GamepadDisconnected`] will be triggered on the entity.
+#[derive(Component, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum GamepadFallback {
+    /// Keeps the assignment.
+    ///
+    /// Input will resume when the same gamepad reconnects, triggering [`GamepadReconnected`].
+    ///
+    /// This is the default behavior if the component is not present.
+    #[default]
+    Keep,
+    /// Switches to [`GamepadDevice::Any`].
+    Any,
+    /// Inserts [`AwaitingGamepad`] to assign a replacement gamepad.
+    Replace,
+}
+
+/// Device that most recently triggered actions on this entity.
+///
+/// Shared between all contexts on the entity, similar to [`GamepadDevice`].
+/// Actions of all contexts evaluated in the same schedule are considered together.
+/// Useful for switching UI prompts between keyboard and gamepad icons.
+///
+/// The device changes when an action is triggered by a different device while no actions are
+/// triggered by the current one. This prevents switching back and forth when multiple devices
+/// are used at the same time. Mocked actions are ignored.
+///
+/// Triggers [`InputDeviceChanged`] when the device changes.
+///
+/// Marked as required for `C` on context registration.
+#[derive(Component, Deref, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+pub struct LastInputDevice(Option<InputDevice>);
+
+/// Triggered on an entity when its [`LastInputDevice`] changes.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn swap_prompts(changed: On<InputDeviceChanged>) {
+///     match changed.device {
+///         InputDevice::Keyboard | InputDevice::Mouse => info!("showing keyboard prompts"),
+///         InputDevice::Gamepad => info!("showing gamepad prompts"),
+///     }
+/// }
+/// ```
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct InputDeviceChanged {
+    /// Entity with [`LastInputDevice`] on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// New device.
+    pub device: InputDevice,
+}
+
+/// Triggered on an entity when the gamepad assigned to it via [`GamepadDevice::Single`] disconnects.
+///
+/// See also [`GamepadFallback`].
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct GamepadDisconnected {
+    /// Entity with [`GamepadDevice`] on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Disconnected gamepad.
+    pub gamepad: Entity,
+}
+
+/// Triggered on an entity when the gamepad assigned to it via [`GamepadDevice::Single`] reconnects.
+///
+/// Triggered only if the assignment was kept, see [`GamepadFallback::Keep`].
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct GamepadReconnected {
+    /// Entity with [`GamepadDevice`] on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Reconnected gamepad.
+    pub gamepad: Entity,
+}
+
+/// Notifies entities about the reconnected gamepad or assigns a new gamepad
+/// to one of the entities with [`AwaitingGamepad`].
+pub(crate) fn handle_connected_gamepad(
+    add: On<Add, Gamepad>,
+    mut commands: Commands,
+    mut devices: Query<(
+        Entity,
+        &mut GamepadDevice,
+        Has<AwaitingGamepad>,
+        Has<FallbackActive>,
+    )>,
+) {
+    let mut assigned = false;
+    for (entity, &device, _, fallback_active) in &devices {
+        if device == GamepadDevice::Single(add.entity) {
+            debug!("gamepad `{}` reconnected to `{entity}`", add.entity);
+            commands.trigger(GamepadReconnected {
+                context: entity,
+                gamepad: add.entity,
+            });
+            if fallback_active {
+                deactivate_fallback(&mut commands, entity);
+            }
+            assigned = true;
+        }
+    }
+
+    if assigned {
+        return;
+    }
+
+    // Mutate the device directly to avoid assigning the same entity
+    // twice before the marker removal is applied.
+    if let Some((entity, mut device, _, fallback_active)) = devices
+        .iter_mut()
+        .find(|(_, device, awaiting, _)| *awaiting && **device == GamepadDevice::None)
+    {
+        debug!("assigning gamepad `{}` to `{entity}`", add.entity);
+        *device = GamepadDevice::Single(add.entity);
+        commands.entity(entity).remove::<AwaitingGamepad>();
+        if fallback_active {
+            deactivate_fallback(&mut commands, entity);
+        }
+    }
+}
+
+/// Notifies entities about the disconnected gamepad and applies their [`GamepadFallback`].
+pub(crate) fn handle_disconnected_gamepad(
+    remove: On<Remove, Gamepad>,
+    mut commands: Commands,
+    mut devices: Query<(
+        Entity,
+        &mut GamepadDevice,
+        Option<&GamepadFallback>,
+        Has<FallbackActive>,
+    )>,
+) {
+    for (entity, mut device, fallback, fallback_active) in &mut devices {
+        if *device != GamepadDevice::Single(remove.entity) {
+            continue;
+        }
+
+        debug!("gamepad `{}` disconnected from `{entity}`", remove.entity);
+        commands.trigger(GamepadDisconnected {
+            context: entity,
+            gamepad: remove.entity,
+        });
+
+        let fallback = fallback.copied().unwrap_or_default();
+        if fallback != GamepadFallback::Any && !fallback_active {
+            debug!("activating fallback bindings for `{entity}`");
+            commands.entity(entity).insert(FallbackActive);
+            commands.trigger(FallbackChanged {
+                context: entity,
+                active: true,
+            });
+        }
+
+        match fallback {
+            GamepadFallback::Keep => (),
+            GamepadFallback::Any => *device = GamepadDevice::Any,
+            GamepadFallback::Replace => {
+                *device = GamepadDevice::None;
+                commands.entity(entity).insert(AwaitingGamepad);
+            }
+        }
+    }
+}
+
+/// Assigns an already connected and unassigned gamepad to an entity that started waiting for it.
+pub(crate) fn assign_awaiting_gamepad(
+    add: On<Add, AwaitingGamepad>,
+    mut commands: Commands,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut devices: Query<(&mut GamepadDevice, Has<FallbackActive>)>,
+) {
+    let Some(gamepad) = gamepads.iter().find(|&gamepad| {
+        !devices
+            .iter()
+            .any(|(&device, _)| device == GamepadDevice::Single(gamepad))
+    }) else {
+        return;
+    };
+
+    let (mut device, fallback_active) = devices.get_mut(add.entity).unwrap();
+    debug!("assigning gamepad `{gamepad}` to `{}`", add.entity);
+    *device = GamepadDevice::Single(gamepad);
+    commands.entity(add.entity).remove::<AwaitingGamepad>();
+    if fallback_active {
+        deactivate_fallback(&mut commands, add.entity);
+    }
+}
+
+fn deactivate_fallback(commands: &mut Commands, entity: Entity) {
+    debug!("deactivating fallback bindings for `{entity}`");
+    commands.entity(entity).remove::<FallbackActive>();
+    commands.trigger(FallbackChanged {
+        context: entity,
+        active: false,
+    });
+}
+
 /// Helper for tests to simplify [`InputTime`] and [`ActionsQuery`] creation.
 #[cfg(test)]
 pub(crate) fn init_world<'w, 's>() -> (World, SystemState<(ContextTime<'w>, ActionsQuery<'w, 's>)>)
@@ -0,0 +1,183 @@
+//! Cursor grab and visibility driven by active contexts.
+//!
+//! Assign requirements with [`InputContextAppExt::set_context_cursor`]. Each frame during [`PostUpdate`],
+//! the requirement of the active context with the highest [`ContextPriority`] is applied to the
+//! [`CursorOptions`] of the primary window. This way the cursor is grabbed while a gameplay context is
+//! active and released once a menu context with a higher priority activates or the gameplay context
+//! deactivates.
+//!
+//! Once no active context has a requirement, the cursor options from before the first requirement
+//! are restored. While any requirement is applied, changes to the cursor options made by other systems
+//! are overwritten only when the requirement changes.
+
+use alloc::vec::Vec;
+use core::any::TypeId;
+
+use bevy::{
+    prelude::*,
+    window::{CursorGrabMode, CursorOptions, PrimaryWindow},
+};
+use log::debug;
+
+use crate::{context::ContextActivity, prelude::*};
+
+/// Cursor state required by an input context while it's active.
+///
+/// Requires the `window` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+///     .add_input_context::<Player>()
+///     .add_input_context::<Menu>()
+///     .set_context_cursor::<Player>(ContextCursor::GRABBED)
+///     .set_context_cursor::<Menu>(ContextCursor::FREE);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// // Spawn with a higher `ContextPriority` to release the cursor while the menu is open.
+/// #[derive(Component)]
+/// struct Menu;
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ContextCursor {
+    /// Whether the cursor can leave the window.
+    pub grab_mode: CursorGrabMode,
+
+    /// Whether the cursor is visible.
+    pub visible: bool,
+}
+
+impl ContextCursor {
+    /// Locked and hidden cursor, for gameplay with mouse look.
+    pub const GRABBED: Self = Self {
+        grab_mode: CursorGrabMode::Locked,
+        visible: false,
+    };
+
+    /// Visible cursor confined to the window, for games that scroll at the window edges.
+    pub const CONFINED: Self = Self {
+        grab_mode: CursorGrabMode::Confined,
+        visible: true,
+    };
+
+    /// Visible cursor that can leave the window, for menus.
+    pub const FREE: Self = Self {
+        grab_mode: CursorGrabMode::None,
+        visible: true,
+    };
+
+    fn from_options(options: &CursorOptions) -> Self {
+        Self {
+            grab_mode: options.grab_mode,
+            visible: options.visible,
+        }
+    }
+}
+
+/// Cursor requirements for each context type and their state.
+#[derive(Resource, Default)]
+pub(crate) struct ContextCursors {
+    /// Requirements in registration order.
+    cursors: Vec<(TypeId, ContextCursor)>,
+
+    /// Requirement of the active context with the highest priority for the current frame.
+    ///
+    /// For equal priorities, the context type registered later wins.
+    requested: Option<((usize, usize), ContextCursor)>,
+
+    /// Requirement applied to the window.
+    applied: Option<ContextCursor>,
+
+    /// Window cursor options before the first requirement was applied.
+    original: Option<ContextCursor>,
+}
+
+impl ContextCursors {
+    /// Assigns the requirement to context `C`.
+    ///
+    /// Returns `true` if the context didn't have a requirement.
+    pub(crate) fn insert<C: Component>(&mut self, cursor: ContextCursor) -> bool {
+        let type_id = TypeId::of::<C>();
+        if let Some((_, existing)) = self.cursors.iter_mut().find(|(id, _)| *id == type_id) {
+            *existing = cursor;
+            false
+        } else {
+            self.cursors.push((type_id, cursor));
+            true
+        }
+    }
+}
+
+/// Systems that collect requirements from active contexts.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub(crate) struct RequestCursor;
+
+pub(crate) fn request_cursor<C: Component>(
+    mut cursors: ResMut<ContextCursors>,
+    contexts: Query<(&ContextActivity<C>, &ContextPriority<C>), With<C>>,
+) {
+    let Some(priority) = contexts
+        .iter()
+        .filter(|(active, _)| ***active)
+        .map(|(_, priority)| **priority)
+        .max()
+    else {
+        return;
+    };
+
+    let type_id = TypeId::of::<C>();
+    let Some((index, &(_, cursor))) = cursors
+        .cursors
+        .iter()
+        .enumerate()
+        .find(|(_, (id, _))| *id == type_id)
+    else {
+        return;
+    };
+
+    let order = (priority, index);
+    if cursors
+        .requested
+        .is_none_or(|(requested_order, _)| requested_order < order)
+    {
+        cursors.requested = Some((order, cursor));
+    }
+}
+
+pub(crate) fn apply_cursor(
+    mut cursors: ResMut<ContextCursors>,
+    mut windows: Query<&mut CursorOptions, With<PrimaryWindow>>,
+) {
+    let requested = cursors.requested.take().map(|(_, cursor)| cursor);
+    if requested == cursors.applied {
+        return;
+    }
+    let Ok(mut options) = windows.single_mut() else {
+        return;
+    };
+
+    let cursor = match requested {
+        Some(cursor) => {
+            if cursors.applied.is_none() {
+                cursors.original = Some(ContextCursor::from_options(&options));
+            }
+            cursor
+        }
+        None => cursors
+            .original
+            .take()
+            .expect("original options should be stored with the applied requirement"),
+    };
+
+    debug!("changing cursor to `{cursor:?}`");
+    options.grab_mode = cursor.grab_mode;
+    options.visible = cursor.visible;
+    cursors.applied = requested;
+}
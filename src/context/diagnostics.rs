@@ -0,0 +1,71 @@
+//! Integration with Bevy diagnostics.
+
+use bevy::{
+    diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic},
+    prelude::*,
+};
+
+use super::stats::ContextStats;
+
+/// Adds [`Diagnostic`]s with totals from [`ContextStats`] across all contexts.
+///
+/// Inserts [`ContextStats`] to collect them. Measurements are added in [`PostUpdate`]
+/// and can be displayed with Bevy's diagnostic tools, like `LogDiagnosticsPlugin`.
+///
+/// For a breakdown per context and action in a profiler, enable the `trace` feature,
+/// which emits a span for each context and action evaluation.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, EnhancedInputPlugin, InputDiagnosticsPlugin));
+/// ```
+pub struct InputDiagnosticsPlugin;
+
+impl InputDiagnosticsPlugin {
+    /// Number of evaluated actions.
+    pub const ACTIONS: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/actions");
+
+    /// Number of evaluated bindings.
+    pub const BINDINGS: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/bindings");
+
+    /// Number of inputs consumed by actions.
+    pub const CONSUMED: DiagnosticPath = DiagnosticPath::const_new("enhanced_input/consumed");
+
+    /// Time spent on context evaluation in milliseconds.
+    pub const EVALUATION_TIME: DiagnosticPath =
+        DiagnosticPath::const_new("enhanced_input/evaluation_time");
+}
+
+impl Plugin for InputDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ContextStats>()
+            .register_diagnostic(Diagnostic::new(Self::ACTIONS))
+            .register_diagnostic(Diagnostic::new(Self::BINDINGS))
+            .register_diagnostic(Diagnostic::new(Self::CONSUMED))
+            .register_diagnostic(Diagnostic::new(Self::EVALUATION_TIME).with_suffix("ms"))
+            .add_systems(PostUpdate, measure);
+    }
+}
+
+fn measure(mut diagnostics: Diagnostics, stats: Res<ContextStats>) {
+    diagnostics.add_measurement(&InputDiagnosticsPlugin::ACTIONS, || {
+        stats.iter().map(|stat| stat.actions).sum::<usize>() as f64
+    });
+    diagnostics.add_measurement(&InputDiagnosticsPlugin::BINDINGS, || {
+        stats.iter().map(|stat| stat.bindings).sum::<usize>() as f64
+    });
+    diagnostics.add_measurement(&InputDiagnosticsPlugin::CONSUMED, || {
+        stats.iter().map(|stat| stat.consumed).sum::<usize>() as f64
+    });
+    diagnostics.add_measurement(&InputDiagnosticsPlugin::EVALUATION_TIME, || {
+        stats
+            .iter()
+            .map(|stat| stat.duration.as_secs_f64() * 1000.0)
+            .sum()
+    });
+}
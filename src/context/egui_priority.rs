@@ -0,0 +1,118 @@
+//! Integration with `bevy_egui`.
+//!
+//! Requires the `egui` feature.
+
+use bevy::prelude::*;
+use bevy_egui::input::EguiWantsInput;
+
+use crate::prelude::*;
+
+/// Blocks input sources for actions while `egui` uses them.
+///
+/// Inserted by [`EnhancedInputPlugin`] when the `egui` feature is enabled.
+/// Prevents dev tools and in-game editors from leaking input into gameplay actions,
+/// like typing into a text field moving the character.
+///
+/// The state is read from [`EguiWantsInput`], which `bevy_egui` updates in [`PostUpdate`],
+/// so actions are blocked based on the previous frame. Does nothing if `EguiPlugin` is not added.
+///
+/// Applied in addition to [`ActionSources`] and [`ContextSources`]. Like with them,
+/// blocked inputs read as zero, so held actions are released while `egui` has focus.
+/// [`InputCapture`] is not affected.
+///
+/// # Examples
+///
+/// Keep keyboard actions working while `egui` has focus:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut app = App::new();
+/// app.insert_resource(EguiPriority {
+///     keyboard: false,
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq, Resource)
+)]
+pub struct EguiPriority {
+    /// Disables [`ActionSources::keyboard`] while `egui` wants keyboard input, like when a text field is focused.
+    ///
+    /// By default set to `true`.
+    pub keyboard: bool,
+
+    /// Disables mouse buttons, motion and wheel while the pointer is over `egui` or `egui` is using it,
+    /// like when dragging a slider.
+    ///
+    /// [`ActionSources::mouse_position`] is not affected.
+    ///
+    /// By default set to `true`.
+    pub pointer: bool,
+}
+
+impl EguiPriority {
+    /// Returns sources that aren't blocked for the given `egui` state.
+    #[must_use]
+    pub(crate) fn sources(self, wants_keyboard: bool, wants_pointer: bool) -> ActionSources {
+        let pointer = !(self.pointer && wants_pointer);
+        ActionSources {
+            keyboard: !(self.keyboard && wants_keyboard),
+            mouse_buttons: pointer,
+            mouse_motion: pointer,
+            mouse_wheel: pointer,
+            ..ActionSources::ALL
+        }
+    }
+
+    /// Returns sources that aren't blocked by `egui`.
+    #[must_use]
+    pub(crate) fn allowed(self, wants_input: &EguiWantsInput) -> ActionSources {
+        self.sources(
+            wants_input.wants_any_keyboard_input(),
+            wants_input.wants_any_pointer_input(),
+        )
+    }
+}
+
+impl Default for EguiPriority {
+    fn default() -> Self {
+        Self {
+            keyboard: true,
+            pointer: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sources() {
+        let priority = EguiPriority::default();
+        assert_eq!(priority.sources(false, false), ActionSources::ALL);
+
+        let sources = priority.sources(true, false);
+        assert!(!sources.keyboard);
+        assert!(sources.mouse_buttons);
+
+        let sources = priority.sources(false, true);
+        assert!(sources.keyboard);
+        assert!(!sources.mouse_buttons);
+        assert!(!sources.mouse_motion);
+        assert!(!sources.mouse_wheel);
+        assert!(sources.mouse_position);
+        assert!(sources.gamepad_button);
+
+        let priority = EguiPriority {
+            keyboard: false,
+            pointer: false,
+        };
+        assert_eq!(priority.sources(true, true), ActionSources::ALL);
+    }
+}
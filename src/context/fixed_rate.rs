@@ -0,0 +1,275 @@
+//! Evaluation of contexts at a fixed rate, independent of the frame rate.
+//!
+//! Contexts registered with [`InputContextAppExt::add_input_context_to`] for [`InputFixedUpdate`]
+//! are evaluated a number of times per frame determined by [`InputFixedRate`], similar to how
+//! [`FixedUpdate`] works, but without tying input to the game simulation rate. Each evaluation
+//! advances time-based [conditions](crate::condition) and [modifiers](crate::modifier) by the
+//! fixed step, so hold durations and pulse rates are the same at any frame rate.
+//!
+//! Since input is read once per frame, all steps within a frame see the same input state.
+//! With [`InputFixedRate::interpolate`], gamepad axis values are interpolated from the previous
+//! frame to the current one across the steps. To accumulate buttons and mouse deltas between
+//! steps, insert [`FixedInputBuffer`]; as with [`FixedPreUpdate`], only the first step of a frame
+//! receives the buffered input. The buffer is shared with [`FixedPreUpdate`]: since
+//! [`InputFixedUpdate`] runs earlier in the frame, its first step clears the buffer before
+//! contexts in [`FixedPreUpdate`] see it. Avoid buffering input for both schedules at once.
+//!
+//! The steps run in [`PreUpdate`] after [`EnhancedInputSystems::Apply`], so inputs consumed by
+//! contexts in [`PreUpdate`] are not visible to them.
+
+use core::time::Duration;
+
+use bevy::{ecs::schedule::ScheduleLabel, prelude::*};
+use log::trace;
+
+use crate::prelude::*;
+
+/// Schedule for contexts evaluated at the rate from [`InputFixedRate`].
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(ScheduleLabel, Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct InputFixedUpdate;
+
+/// Rate at which contexts in [`InputFixedUpdate`] are evaluated.
+///
+/// Not present by default. Insert this resource to run [`InputFixedUpdate`].
+/// Time is accumulated from [`Time<Real>`].
+///
+/// See the [module-level documentation](self) for more details.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(EnhancedInputPlugin)
+///     .insert_resource(InputFixedRate::from_hz(120.0))
+///     .add_input_context_to::<InputFixedUpdate, Player>();
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Resource, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Resource, Debug))]
+pub struct InputFixedRate {
+    /// Maximum number of evaluations per frame.
+    ///
+    /// Protects against long frames, after which the remaining time is discarded.
+    ///
+    /// By default set to 8.
+    pub max_steps: u32,
+
+    /// Whether to interpolate gamepad axis values across the steps of a frame.
+    ///
+    /// By default set to `true`.
+    pub interpolate: bool,
+
+    step: Duration,
+    accumulated: Duration,
+    frame: u32,
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    current: Option<FixedStep>,
+}
+
+impl InputFixedRate {
+    /// Creates a new instance with the given number of evaluations per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hz` is not a finite positive number.
+    #[must_use]
+    pub fn from_hz(hz: f64) -> Self {
+        assert!(
+            hz.is_finite() && hz > 0.0,
+            "rate should be a finite positive number, but got {hz}"
+        );
+        Self::from_duration(Duration::from_secs_f64(1.0 / hz))
+    }
+
+    /// Creates a new instance with the given time between evaluations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    #[must_use]
+    pub fn from_duration(step: Duration) -> Self {
+        assert!(!step.is_zero(), "step should be greater than zero");
+        Self {
+            max_steps: 8,
+            interpolate: true,
+            step,
+            accumulated: Duration::ZERO,
+            frame: 0,
+            current: None,
+        }
+    }
+
+    /// Sets [`Self::max_steps`].
+    #[must_use]
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// Sets [`Self::interpolate`].
+    #[must_use]
+    pub fn with_interpolation(mut self, interpolate: bool) -> Self {
+        self.interpolate = interpolate;
+        self
+    }
+
+    /// Returns the time between evaluations.
+    #[must_use]
+    pub fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Returns the accumulated time that wasn't consumed by steps yet.
+    #[must_use]
+    pub fn overstep(&self) -> Duration {
+        self.accumulated
+    }
+
+    /// Returns the step that is currently running, if any.
+    pub(crate) fn current(&self) -> Option<FixedStep> {
+        self.current
+    }
+}
+
+/// A single evaluation within a frame.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct FixedStep {
+    /// Index of the step within the frame.
+    index: u32,
+
+    /// Number of steps in the frame.
+    count: u32,
+
+    /// Counter that changes each frame.
+    frame: u32,
+
+    /// Copy of [`InputFixedRate::interpolate`].
+    interpolate: bool,
+}
+
+/// Values of a binding from the last two frames, used to interpolate them across steps.
+#[derive(Component, Default)]
+pub(crate) struct StepInterpolation {
+    frame: Option<u32>,
+    previous: f32,
+    current: f32,
+}
+
+impl StepInterpolation {
+    /// Returns the value for the step.
+    ///
+    /// Only gamepad axes are interpolated, other bindings return `value` unchanged.
+    pub(crate) fn interpolate(
+        &mut self,
+        binding: Binding,
+        value: ActionValue,
+        step: FixedStep,
+    ) -> ActionValue {
+        if !step.interpolate || !matches!(binding, Binding::GamepadAxis(_)) {
+            return value;
+        }
+
+        let value = value.as_axis1d();
+        if self.frame != Some(step.frame) {
+            // Start from the current value if there is no history.
+            self.previous = if self.frame.is_some() {
+                self.current
+            } else {
+                value
+            };
+            self.frame = Some(step.frame);
+        }
+        self.current = value;
+
+        let t = (step.index + 1) as f32 / step.count as f32;
+        (self.previous + (self.current - self.previous) * t).into()
+    }
+}
+
+pub(crate) fn run_fixed_rate(world: &mut World) {
+    let delta = world.resource::<Time<Real>>().delta();
+    let mut rate = world.resource_mut::<InputFixedRate>();
+    let step = rate.step;
+    rate.accumulated += delta;
+
+    let mut count = 0;
+    while rate.accumulated >= step && count < rate.max_steps {
+        rate.accumulated -= step;
+        count += 1;
+    }
+    if count == rate.max_steps && rate.accumulated >= step {
+        trace!("discarding {:?} after {count} steps", rate.accumulated);
+        rate.accumulated = Duration::ZERO;
+    }
+    rate.frame = rate.frame.wrapping_add(1);
+
+    for index in 0..count {
+        let mut rate = world.resource_mut::<InputFixedRate>();
+        rate.current = Some(FixedStep {
+            index,
+            count,
+            frame: rate.frame,
+            interpolate: rate.interpolate,
+        });
+
+        trace!("running step {index} of {count}");
+        if world.try_run_schedule(InputFixedUpdate).is_err() {
+            break;
+        }
+    }
+
+    world.resource_mut::<InputFixedRate>().current = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::input::gamepad::GamepadAxis;
+
+    use super::*;
+
+    #[test]
+    fn interpolation() {
+        let binding = Binding::GamepadAxis(GamepadAxis::LeftStickX);
+        let step = |index, frame| FixedStep {
+            index,
+            count: 2,
+            frame,
+            interpolate: true,
+        };
+
+        let mut interpolation = StepInterpolation::default();
+        assert_eq!(
+            interpolation.interpolate(binding, 1.0.into(), step(0, 0)),
+            1.0.into(),
+            "should start from the current value"
+        );
+
+        assert_eq!(
+            interpolation.interpolate(binding, 0.0.into(), step(0, 1)),
+            0.5.into()
+        );
+        assert_eq!(
+            interpolation.interpolate(binding, 0.0.into(), step(1, 1)),
+            0.0.into()
+        );
+
+        let key = Binding::from(KeyCode::Space);
+        assert_eq!(
+            interpolation.interpolate(key, true.into(), step(0, 2)),
+            true.into(),
+            "only axes should be interpolated"
+        );
+    }
+    #[test]
+    #[should_panic(expected = "rate should be a finite positive number")]
+    fn zero_hz() {
+        let _ = InputFixedRate::from_hz(0.0);
+    }
+}
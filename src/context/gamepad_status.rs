@@ -0,0 +1,180 @@
+//! Battery level and supported features of gamepads.
+//!
+//! Bevy doesn't report this information, so [`GamepadBattery`] and [`GamepadCapabilities`]
+//! should be inserted on gamepad entities by the input backend. Re-insert the components
+//! to update them.
+//!
+//! Entities that have the gamepad assigned via [`GamepadDevice::Single`] are notified with
+//! [`GamepadStatusChanged`], which can be used to react to the state of the driving gamepad.
+//!
+//! # Examples
+//!
+//! Activate a context that shows a warning while the player's gamepad is low on battery:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_enhanced_input::prelude::*;
+//!
+//! fn toggle_warning(changed: On<GamepadStatusChanged>, mut commands: Commands) {
+//!     let low = changed.battery.is_some_and(|battery| battery.is_low());
+//!     commands
+//!         .entity(changed.context)
+//!         .insert(ContextActivity::<LowBattery>::new(low));
+//! }
+//!
+//! // Context with actions to dismiss the warning.
+//! #[derive(Component)]
+//! struct LowBattery;
+//! ```
+
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use bevy::prelude::*;
+use bitflags::bitflags;
+use log::debug;
+
+use crate::prelude::*;
+
+/// Battery state of a gamepad.
+///
+/// Should be inserted on the gamepad entity by the backend.
+/// Triggers [`GamepadStatusChanged`] on insertion.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[component(immutable)]
+pub struct GamepadBattery {
+    /// Charge level from 0.0 (empty) to 1.0 (full).
+    pub level: f32,
+
+    /// Whether the gamepad is connected to a power source.
+    pub charging: bool,
+}
+
+impl GamepadBattery {
+    /// Level at or below which the battery is considered low.
+    pub const LOW_LEVEL: f32 = 0.2;
+
+    /// Returns `true` if the battery level is at or below [`Self::LOW_LEVEL`] and the gamepad isn't charging.
+    #[must_use]
+    pub fn is_low(self) -> bool {
+        self.level <= Self::LOW_LEVEL && !self.charging
+    }
+}
+
+/// Features supported by a gamepad.
+///
+/// Should be inserted on the gamepad entity by the backend.
+/// Triggers [`GamepadStatusChanged`] on insertion.
+///
+/// Gamepads without this component are assumed to have no known capabilities.
+#[derive(Component, Default, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Hash, PartialEq)
+)]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+#[component(immutable)]
+pub struct GamepadCapabilities(u8);
+
+bitflags! {
+    impl GamepadCapabilities: u8 {
+        /// Supports [`Rumble`].
+        const RUMBLE = 0b00000001;
+        /// Has a gyroscope.
+        const GYRO = 0b00000010;
+        /// Has an accelerometer.
+        const ACCELEROMETER = 0b00000100;
+        /// Has a touchpad.
+        const TOUCHPAD = 0b00001000;
+        /// Has a controllable light bar or LED.
+        const LIGHT = 0b00010000;
+        /// Has adaptive triggers with configurable resistance.
+        const ADAPTIVE_TRIGGERS = 0b00100000;
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl Serialize for GamepadCapabilities {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bitflags::serde::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'de> Deserialize<'de> for GamepadCapabilities {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bitflags::serde::deserialize(deserializer)
+    }
+}
+
+/// Triggered on an entity when [`GamepadBattery`] or [`GamepadCapabilities`] is inserted
+/// on the gamepad assigned to it via [`GamepadDevice::Single`].
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct GamepadStatusChanged {
+    /// Entity with [`GamepadDevice`] on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Gamepad whose status changed.
+    pub gamepad: Entity,
+
+    /// Current battery state, if reported.
+    pub battery: Option<GamepadBattery>,
+
+    /// Current capabilities.
+    pub capabilities: GamepadCapabilities,
+}
+
+pub(crate) fn battery_changed(
+    insert: On<Insert, GamepadBattery>,
+    commands: Commands,
+    gamepads: Query<(Option<&GamepadBattery>, Option<&GamepadCapabilities>)>,
+    devices: Query<(Entity, &GamepadDevice)>,
+) {
+    notify(insert.entity, commands, gamepads, devices);
+}
+
+pub(crate) fn capabilities_changed(
+    insert: On<Insert, GamepadCapabilities>,
+    commands: Commands,
+    gamepads: Query<(Option<&GamepadBattery>, Option<&GamepadCapabilities>)>,
+    devices: Query<(Entity, &GamepadDevice)>,
+) {
+    notify(insert.entity, commands, gamepads, devices);
+}
+
+fn notify(
+    gamepad: Entity,
+    mut commands: Commands,
+    gamepads: Query<(Option<&GamepadBattery>, Option<&GamepadCapabilities>)>,
+    devices: Query<(Entity, &GamepadDevice)>,
+) {
+    let (battery, capabilities) = gamepads.get(gamepad).unwrap();
+    for (entity, &device) in &devices {
+        if device != GamepadDevice::Single(gamepad) {
+            continue;
+        }
+
+        debug!("status of gamepad `{gamepad}` changed for `{entity}`");
+        commands.trigger(GamepadStatusChanged {
+            context: entity,
+            gamepad,
+            battery: battery.copied(),
+            capabilities: capabilities.copied().unwrap_or_default(),
+        });
+    }
+}
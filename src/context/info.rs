@@ -0,0 +1,74 @@
+//! User-facing metadata for input contexts.
+
+use alloc::borrow::Cow;
+use core::any::TypeId;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+
+/// Display name and description of an input context.
+///
+/// Assigned using [`InputContextAppExt::set_context_info`](super::InputContextAppExt::set_context_info)
+/// and retrieved from [`ContextInfos`]. Useful for settings menus to group and title binding sections.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextInfo {
+    /// Human-readable name of the context, like "On Foot".
+    pub display_name: Cow<'static, str>,
+
+    /// Longer explanation of when the context is active.
+    ///
+    /// Empty by default.
+    pub description: Cow<'static, str>,
+}
+
+impl ContextInfo {
+    #[must_use]
+    pub fn new(display_name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            display_name: display_name.into(),
+            description: Cow::Borrowed(""),
+        }
+    }
+
+    #[must_use]
+    pub fn with_description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
+
+/// Stores [`ContextInfo`] for each context type.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+///     .add_input_context::<OnFoot>()
+///     .set_context_info::<OnFoot>(
+///         ContextInfo::new("On Foot").with_description("Walking around the world."),
+///     );
+///
+/// let infos = app.world().resource::<ContextInfos>();
+/// assert_eq!(infos.get::<OnFoot>().unwrap().display_name, "On Foot");
+///
+/// #[derive(Component)]
+/// struct OnFoot;
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct ContextInfos(HashMap<TypeId, ContextInfo>);
+
+impl ContextInfos {
+    /// Returns the info assigned to context `C`.
+    #[must_use]
+    pub fn get<C: Component>(&self) -> Option<&ContextInfo> {
+        self.0.get(&TypeId::of::<C>())
+    }
+
+    /// Assigns info to context `C`, replacing the previous value.
+    pub fn insert<C: Component>(&mut self, info: ContextInfo) {
+        self.0.insert(TypeId::of::<C>(), info);
+    }
+}
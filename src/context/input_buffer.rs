@@ -0,0 +1,111 @@
+//! Buffering of input between runs of [`FixedPreUpdate`].
+
+use bevy::{
+    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
+    platform::collections::HashSet,
+    prelude::*,
+};
+use log::trace;
+
+use crate::prelude::{ScrollDirection, ScrollSteps};
+
+/// Accumulates input between runs of [`FixedPreUpdate`] for contexts evaluated in it.
+///
+/// Not present by default. Insert this resource to enable buffering.
+///
+/// When the fixed timestep is longer than the frame time, a button can be pressed and
+/// released between two fixed runs, and the press would be missed. With this resource,
+/// buttons held on any frame since the last run are reported as pressed for at least one run,
+/// and mouse motion and wheel deltas are summed across frames instead of reading
+/// only the latest frame.
+///
+/// Inputs are collected every frame during [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare)
+/// and cleared after each [`FixedPreUpdate`] evaluation. If the schedule runs multiple times
+/// per frame, only the first run receives the buffered input and mouse deltas.
+///
+/// Also applies to [`InputFixedUpdate`](super::fixed_rate::InputFixedUpdate), which is cleared after
+/// each of its runs in the same way. The buffer is shared, so when both schedules have contexts,
+/// the first [`InputFixedUpdate`](super::fixed_rate::InputFixedUpdate) step clears it before
+/// [`FixedPreUpdate`] runs later in the frame. Contexts in other schedules are not affected.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(EnhancedInputPlugin)
+///     .init_resource::<FixedInputBuffer>()
+///     .add_input_context_to::<FixedPreUpdate, Player>();
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Resource, Default, Debug)]
+pub struct FixedInputBuffer {
+    pub(crate) keys: HashSet<KeyCode>,
+    pub(crate) mouse_buttons: HashSet<MouseButton>,
+    pub(crate) gamepad_buttons: HashSet<(Entity, GamepadButton)>,
+    pub(crate) mouse_motion: Vec2,
+    pub(crate) mouse_scroll: Vec2,
+    pub(crate) scroll_directions: HashSet<ScrollDirection>,
+    pub(crate) scroll_lines: f32,
+}
+
+impl FixedInputBuffer {
+    fn clear(&mut self) {
+        self.keys.clear();
+        self.mouse_buttons.clear();
+        self.gamepad_buttons.clear();
+        self.mouse_motion = Vec2::ZERO;
+        self.mouse_scroll = Vec2::ZERO;
+        self.scroll_directions.clear();
+        self.scroll_lines = 0.0;
+    }
+}
+
+pub(crate) fn buffer_input(
+    mut buffer: ResMut<FixedInputBuffer>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    mouse_scroll: Option<Res<AccumulatedMouseScroll>>,
+    scroll_steps: Res<ScrollSteps>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    if let Some(keys) = keys {
+        buffer
+            .keys
+            .extend(keys.get_pressed().chain(keys.get_just_pressed()).copied());
+    }
+    if let Some(mouse_buttons) = mouse_buttons {
+        buffer.mouse_buttons.extend(
+            mouse_buttons
+                .get_pressed()
+                .chain(mouse_buttons.get_just_pressed())
+                .copied(),
+        );
+    }
+    if let Some(mouse_motion) = mouse_motion {
+        buffer.mouse_motion += mouse_motion.delta;
+    }
+    if let Some(mouse_scroll) = mouse_scroll {
+        buffer.mouse_scroll += mouse_scroll.delta;
+    }
+    buffer.scroll_directions.extend(scroll_steps.directions());
+    buffer.scroll_lines += scroll_steps.lines().y;
+    for (entity, gamepad) in &gamepads {
+        buffer.gamepad_buttons.extend(
+            gamepad
+                .get_pressed()
+                .chain(gamepad.get_just_pressed())
+                .map(|&button| (entity, button)),
+        );
+    }
+}
+
+pub(crate) fn clear_buffer(mut buffer: ResMut<FixedInputBuffer>) {
+    trace!("clearing fixed input buffer");
+    buffer.clear();
+}
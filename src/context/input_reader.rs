@@ -1,6 +1,8 @@
 use alloc::vec::Vec;
-use core::{any::TypeId, hash::Hash, iter, mem};
+use core::{any::TypeId, hash::Hash, iter, mem, time::Duration};
 
+#[cfg(feature = "window")]
+use bevy::window::{PrimaryWindow, Window};
 use bevy::{
     ecs::{schedule::ScheduleLabel, system::SystemParam},
     input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
@@ -8,9 +10,20 @@ use bevy::{
     prelude::*,
     utils::TypeIdMap,
 };
+#[cfg(feature = "egui")]
+use bevy_egui::input::EguiWantsInput;
 use log::{debug, trace};
 
-use crate::prelude::*;
+use crate::{
+    binding::{
+        capture::ACTUATION_THRESHOLD,
+        logical_keys::{LogicalKeys, fold_case},
+        media_keys::LogicalMediaKeys,
+        ui_buttons::PressedUiButtons,
+        virtual_inputs::VirtualInputs,
+    },
+    prelude::*,
+};
 
 pub(crate) fn update_pending(mut reader: InputReader) {
     reader.update_pending();
@@ -24,13 +37,36 @@ pub(crate) struct InputReader<'w, 's> {
     keys: Option<Res<'w, ButtonInput<KeyCode>>>,
     mouse_buttons: Option<Res<'w, ButtonInput<MouseButton>>>,
     mouse_motion: Option<Res<'w, AccumulatedMouseMotion>>,
+    mouse_drags: Option<Res<'w, MouseDrags>>,
     mouse_scroll: Option<Res<'w, AccumulatedMouseScroll>>,
-    gamepads: Query<'w, 's, &'static Gamepad>,
+    scroll_steps: Option<Res<'w, ScrollSteps>>,
+    cursor_position: Option<Res<'w, CursorPosition>>,
+    lock_keys: Option<Res<'w, LockKeys>>,
+    logical_keys: Option<Res<'w, LogicalKeys>>,
+    media_keys: Option<Res<'w, LogicalMediaKeys>>,
+    ui_buttons: Option<Res<'w, PressedUiButtons>>,
+    virtual_inputs: Option<Res<'w, VirtualInputs>>,
+    #[cfg(feature = "macro_pad")]
+    macro_pad_keys: Option<Res<'w, crate::binding::macro_pad::MacroPadKeys>>,
+    gamepads: Query<'w, 's, (Entity, &'static Gamepad)>,
+    keyboards: Query<'w, 's, &'static SeatKeyboard>,
+    mice: Query<'w, 's, &'static SeatMouse>,
     action_sources: Res<'w, ActionSources>,
+    #[cfg(feature = "egui")]
+    egui_priority: Option<Res<'w, EguiPriority>>,
+    #[cfg(feature = "egui")]
+    egui_wants_input: Option<Res<'w, EguiWantsInput>>,
     consumed: ResMut<'w, ConsumedInputs>,
     pending: ResMut<'w, PendingBindings>,
+    fixed_buffer: Option<Res<'w, FixedInputBuffer>>,
+    latency: Option<Res<'w, InputLatency>>,
     gamepad_device: Local<'s, GamepadDevice>,
+    gamepad_filter: Local<'s, Option<Entity>>,
+    keyboard_device: Local<'s, KeyboardDevice>,
+    mouse_device: Local<'s, MouseDevice>,
+    context_sources: Local<'s, ActionSources>,
     skip_ignore_check: Local<'s, bool>,
+    use_buffer: Local<'s, bool>,
 }
 
 impl InputReader<'_, '_> {
@@ -67,6 +103,151 @@ impl InputReader<'_, '_> {
         *self.gamepad_device = gamepad.into();
     }
 
+    /// Returns the gamepads from which [`Self::value`] reads input.
+    ///
+    /// Same as the assigned device, unless narrowed to a single gamepad by [`Self::gamepad_value`].
+    fn read_gamepad(&self) -> GamepadDevice {
+        match (*self.gamepad_device, *self.gamepad_filter) {
+            (GamepadDevice::Any, Some(entity)) => GamepadDevice::Single(entity),
+            (device, _) => device,
+        }
+    }
+
+    /// Returns the [`ActionValue`] for the given [`Binding`] read only from the given gamepad.
+    ///
+    /// Has an effect only if the assigned device is [`GamepadDevice::Any`].
+    /// Consumed and ignored inputs are still tracked for the assigned device.
+    pub(crate) fn gamepad_value(&mut self, binding: Binding, gamepad: Entity) -> ActionValue {
+        *self.gamepad_filter = Some(gamepad);
+        let value = self.value(binding);
+        *self.gamepad_filter = None;
+        value
+    }
+
+    /// Returns the physical device from which the given [`Binding`] is currently read.
+    ///
+    /// With [`GamepadDevice::Any`], returns the first gamepad that actuates the binding.
+    pub(crate) fn device_source(&self, binding: Binding) -> Option<DeviceSource> {
+        let device = binding.device()?;
+        let entity = match device {
+            InputDevice::Keyboard => match *self.keyboard_device {
+                KeyboardDevice::Single(entity) => Some(entity),
+                KeyboardDevice::Any | KeyboardDevice::None => None,
+            },
+            InputDevice::Mouse => match *self.mouse_device {
+                MouseDevice::Single(entity) => Some(entity),
+                MouseDevice::Any | MouseDevice::None => None,
+            },
+            InputDevice::Gamepad => match self.read_gamepad() {
+                GamepadDevice::Single(entity) => Some(entity),
+                GamepadDevice::Any => self
+                    .gamepads
+                    .iter()
+                    .find(|(_, gamepad)| match binding {
+                        Binding::GamepadButton(button) => gamepad.get(button) != Some(0.0),
+                        Binding::GamepadAxis(axis) => gamepad.get(axis) != Some(0.0),
+                        _ => false,
+                    })
+                    .map(|(entity, _)| entity),
+                GamepadDevice::None => None,
+            },
+        };
+
+        Some(DeviceSource { device, entity })
+    }
+
+    /// Assigns a keyboard and a mouse from which [`Self::value`] should read input.
+    pub(crate) fn set_seat(&mut self, keyboard: KeyboardDevice, mouse: MouseDevice) {
+        *self.keyboard_device = keyboard;
+        *self.mouse_device = mouse;
+    }
+
+    /// Returns pressed keys of the assigned keyboard.
+    fn keys(&self) -> Option<&ButtonInput<KeyCode>> {
+        match *self.keyboard_device {
+            KeyboardDevice::Any => self.keys.as_deref(),
+            KeyboardDevice::Single(entity) => self.keyboards.get(entity).ok().map(|k| &**k),
+            KeyboardDevice::None => None,
+        }
+    }
+
+    /// Returns pressed buttons of the assigned mouse.
+    fn mouse_buttons(&self) -> Option<&ButtonInput<MouseButton>> {
+        match *self.mouse_device {
+            MouseDevice::Any => self.mouse_buttons.as_deref(),
+            MouseDevice::Single(entity) => self.mice.get(entity).ok().map(|m| &m.buttons),
+            MouseDevice::None => None,
+        }
+    }
+
+    /// Returns `false` if the mouse is disabled with [`MouseDevice::None`].
+    ///
+    /// Used for mouse inputs that are read only from the global state.
+    fn global_mouse_enabled(&self) -> bool {
+        *self.mouse_device != MouseDevice::None
+    }
+
+    /// Returns the [`FixedInputBuffer`] if enabled by [`Self::set_buffered`] and the keyboard isn't restricted to a seat.
+    fn keyboard_buffer(&self) -> Option<&FixedInputBuffer> {
+        self.buffer()
+            .filter(|_| *self.keyboard_device == KeyboardDevice::Any)
+    }
+
+    /// Returns the [`FixedInputBuffer`] if enabled by [`Self::set_buffered`] and the mouse isn't restricted to a seat.
+    fn mouse_buffer(&self) -> Option<&FixedInputBuffer> {
+        self.buffer()
+            .filter(|_| *self.mouse_device == MouseDevice::Any)
+    }
+
+    /// Restricts input sources for the currently evaluated context in addition to [`ActionSources`].
+    pub(crate) fn set_context_sources(&mut self, sources: ActionSources) {
+        *self.context_sources = sources;
+    }
+
+    /// Returns input sources enabled both globally and for the current context.
+    ///
+    /// With the `egui` feature, also excludes sources blocked by [`EguiPriority`].
+    fn sources(&self) -> ActionSources {
+        let sources = self.action_sources.intersection(*self.context_sources);
+
+        #[cfg(feature = "egui")]
+        if let Some((priority, wants_input)) = self
+            .egui_priority
+            .as_deref()
+            .zip(self.egui_wants_input.as_deref())
+        {
+            return sources.intersection(priority.allowed(wants_input));
+        }
+
+        sources
+    }
+
+    /// Enables reading from [`FixedInputBuffer`] in addition to the current input state, if the buffer is present.
+    pub(crate) fn set_buffered(&mut self, buffered: bool) {
+        *self.use_buffer = buffered;
+    }
+
+    /// Returns the [`FixedInputBuffer`] if enabled by [`Self::set_buffered`].
+    fn buffer(&self) -> Option<&FixedInputBuffer> {
+        self.fixed_buffer.as_deref().filter(|_| *self.use_buffer)
+    }
+
+    /// Returns the delay from [`InputLatency`] for the given [`Binding`], if the resource is present.
+    pub(crate) fn latency_delay(&self, binding: Binding) -> Option<Duration> {
+        let latency = self.latency.as_deref()?;
+        let source = match binding.device()? {
+            InputDevice::Keyboard => LatencySource::Keyboard,
+            InputDevice::Mouse => LatencySource::Mouse,
+            InputDevice::Gamepad => match *self.gamepad_device {
+                GamepadDevice::Single(entity) => LatencySource::Gamepad(entity),
+                GamepadDevice::Any => LatencySource::AnyGamepad,
+                GamepadDevice::None => return None,
+            },
+        };
+
+        Some(latency.delay(source))
+    }
+
     /// Returns the [`ActionValue`] for the given [`Binding`].
     ///
     /// See also [`Self::consume`] and [`Self::set_gamepad`].
@@ -74,89 +255,228 @@ impl InputReader<'_, '_> {
         let binding = binding.into();
         match binding {
             Binding::Keyboard { key, mod_keys } => {
-                let pressed = self.action_sources.keyboard
-                    && self.keys.as_ref().is_some_and(|k| k.pressed(key))
+                let pressed = self.sources().keyboard
+                    && (self.keys().is_some_and(|k| k.pressed(key))
+                        || (*self.keyboard_device == KeyboardDevice::Any
+                            && self.media_keys.as_ref().is_some_and(|m| m.contains(&key)))
+                        || self
+                            .keyboard_buffer()
+                            .is_some_and(|b| b.keys.contains(&key)))
                     && self.mod_keys_pressed(mod_keys)
                     && !self.ignored(binding);
 
                 pressed.into()
             }
-            Binding::MouseButton { button, mod_keys } => {
-                let pressed = self.action_sources.mouse_buttons
+            Binding::LogicalKey {
+                character,
+                mod_keys,
+            } => {
+                let pressed = self.sources().keyboard
                     && self
-                        .mouse_buttons
+                        .logical_keys
                         .as_ref()
-                        .is_some_and(|b| b.pressed(button))
+                        .is_some_and(|l| l.pressed(character))
+                    && self.mod_keys_pressed(mod_keys)
+                    && !self.ignored(binding);
+
+                pressed.into()
+            }
+            Binding::LockKey(key) => {
+                let enabled = self.sources().keyboard
+                    && self.lock_keys.as_ref().is_some_and(|l| l.get(key))
+                    && !self.ignored(binding);
+
+                enabled.into()
+            }
+            Binding::MouseButton { button, mod_keys } => {
+                let pressed = self.sources().mouse_buttons
+                    && (self.mouse_buttons().is_some_and(|b| b.pressed(button))
+                        || self
+                            .mouse_buffer()
+                            .is_some_and(|b| b.mouse_buttons.contains(&button)))
                     && self.mod_keys_pressed(mod_keys)
                     && !self.ignored(binding);
 
                 pressed.into()
             }
             Binding::MouseMotion { mod_keys } => {
-                if !self.action_sources.mouse_motion
+                if !self.sources().mouse_motion
                     || !self.mod_keys_pressed(mod_keys)
                     || self.ignored(binding)
                 {
                     return Vec2::ZERO.into();
                 }
 
-                self.mouse_motion
+                if let Some(buffer) = self.mouse_buffer() {
+                    return buffer.mouse_motion.into();
+                }
+
+                match *self.mouse_device {
+                    MouseDevice::Any => self.mouse_motion.as_ref().map(|m| m.delta),
+                    MouseDevice::Single(entity) => self.mice.get(entity).ok().map(|m| m.motion),
+                    MouseDevice::None => None,
+                }
+                .unwrap_or_default()
+                .into()
+            }
+            Binding::MouseDrag { button, mod_keys } => {
+                if !self.global_mouse_enabled()
+                    || !self.sources().mouse_buttons
+                    || !self.sources().mouse_motion
+                    || !self.mod_keys_pressed(mod_keys)
+                    || self.ignored(binding)
+                {
+                    return Vec2::ZERO.into();
+                }
+
+                self.mouse_drags
                     .as_ref()
-                    .map(|m| m.delta)
+                    .map(|d| d.displacement(button))
                     .unwrap_or_default()
                     .into()
             }
             Binding::MouseWheel { mod_keys } => {
-                if !self.action_sources.mouse_wheel
+                if !self.sources().mouse_wheel
                     || !self.mod_keys_pressed(mod_keys)
                     || self.ignored(binding)
                 {
                     return Vec2::ZERO.into();
                 }
 
-                self.mouse_scroll
+                if let Some(buffer) = self.mouse_buffer() {
+                    return buffer.mouse_scroll.into();
+                }
+
+                match *self.mouse_device {
+                    MouseDevice::Any => self.mouse_scroll.as_ref().map(|s| s.delta),
+                    MouseDevice::Single(entity) => self.mice.get(entity).ok().map(|m| m.scroll),
+                    MouseDevice::None => None,
+                }
+                .unwrap_or_default()
+                .into()
+            }
+            Binding::MouseWheelStep {
+                direction,
+                mod_keys,
+            } => {
+                let stepped = self.global_mouse_enabled()
+                    && self.sources().mouse_wheel
+                    && (self
+                        .scroll_steps
+                        .as_ref()
+                        .is_some_and(|s| s.stepped(direction))
+                        || self
+                            .buffer()
+                            .is_some_and(|b| b.scroll_directions.contains(&direction)))
+                    && self.mod_keys_pressed(mod_keys)
+                    && !self.ignored(binding);
+
+                stepped.into()
+            }
+            Binding::MouseWheelPrecise { mod_keys } => {
+                if !self.global_mouse_enabled()
+                    || !self.sources().mouse_wheel
+                    || !self.mod_keys_pressed(mod_keys)
+                    || self.ignored(binding)
+                {
+                    return 0.0.into();
+                }
+
+                if let Some(buffer) = self.buffer() {
+                    return buffer.scroll_lines.into();
+                }
+
+                self.scroll_steps
                     .as_ref()
-                    .map(|s| s.delta)
+                    .map(|s| s.lines().y)
                     .unwrap_or_default()
                     .into()
             }
+            Binding::MousePosition { mod_keys } => {
+                if !self.sources().mouse_position
+                    || !self.mod_keys_pressed(mod_keys)
+                    || self.ignored(binding)
+                {
+                    return Vec2::ZERO.into();
+                }
+
+                self.cursor_position
+                    .as_deref()
+                    .and_then(|p| **p)
+                    .unwrap_or_default()
+                    .into()
+            }
+            Binding::UiButton(entity) => {
+                let pressed = self
+                    .ui_buttons
+                    .as_ref()
+                    .is_some_and(|b| b.contains(&entity))
+                    && !self.ignored(binding);
+
+                pressed.into()
+            }
+            Binding::Virtual(input) => {
+                if self.ignored(binding) {
+                    return false.into();
+                }
+
+                self.virtual_inputs
+                    .as_ref()
+                    .and_then(|i| i.get(input))
+                    .unwrap_or(false.into())
+            }
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(key) => {
+                let pressed = self
+                    .macro_pad_keys
+                    .as_ref()
+                    .is_some_and(|keys| keys.pressed(key))
+                    && !self.ignored(binding);
+
+                pressed.into()
+            }
             Binding::GamepadButton(button) => {
-                if !self.action_sources.gamepad_button || self.ignored(binding) {
+                if !self.sources().gamepad_button || self.ignored(binding) {
                     return 0.0.into();
                 }
 
-                let value = match *self.gamepad_device {
+                let value = match self.read_gamepad() {
                     GamepadDevice::Any => self
                         .gamepads
                         .iter()
-                        .filter_map(|gamepad| gamepad.get(button))
+                        .filter_map(|(_, gamepad)| gamepad.get(button))
                         .find(|&value| value != 0.0),
                     GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
                         .ok()
-                        .and_then(|gamepad| gamepad.get(button)),
+                        .and_then(|(_, gamepad)| gamepad.get(button)),
                     GamepadDevice::None => return 0.0.into(),
                 };
 
-                value.unwrap_or_default().into()
+                let value = value.unwrap_or_default();
+                if value == 0.0 && self.buffered_gamepad_button(button) {
+                    return 1.0.into();
+                }
+
+                value.into()
             }
             Binding::GamepadAxis(axis) => {
-                if !self.action_sources.gamepad_axis || self.ignored(binding) {
+                if !self.sources().gamepad_axis || self.ignored(binding) {
                     return 0.0.into();
                 }
 
-                let value = match *self.gamepad_device {
+                let value = match self.read_gamepad() {
                     GamepadDevice::Any => self
                         .gamepads
                         .iter()
-                        .filter_map(|gamepad| gamepad.get_unclamped(axis))
+                        .filter_map(|(_, gamepad)| gamepad.get_unclamped(axis))
                         .reduce(|acc, v| acc + v),
                     GamepadDevice::Single(entity) => self
                         .gamepads
                         .get(entity)
                         .ok()
-                        .and_then(|gamepad| gamepad.get(axis)),
+                        .and_then(|(_, gamepad)| gamepad.get(axis)),
                     GamepadDevice::None => return 0.0.into(),
                 };
 
@@ -168,37 +488,51 @@ impl InputReader<'_, '_> {
                     return false.into();
                 }
 
-                if self.action_sources.keyboard
+                if self.sources().keyboard
                     && self
-                        .keys
-                        .iter()
+                        .keys()
+                        .into_iter()
                         .flat_map(|k| k.get_pressed())
                         .any(|&k| !self.ignored(k))
                 {
                     return true.into();
                 }
 
-                if self.action_sources.mouse_buttons
+                if self.sources().mouse_buttons
                     && self
-                        .mouse_buttons
-                        .iter()
+                        .mouse_buttons()
+                        .into_iter()
                         .flat_map(|b| b.get_pressed())
                         .any(|&b| !self.ignored(b))
                 {
                     return true.into();
                 }
 
-                if self.action_sources.gamepad_button {
+                if let Some(buffer) = self.keyboard_buffer()
+                    && self.sources().keyboard
+                    && buffer.keys.iter().any(|&k| !self.ignored(k))
+                {
+                    return true.into();
+                }
+
+                if let Some(buffer) = self.mouse_buffer()
+                    && self.sources().mouse_buttons
+                    && buffer.mouse_buttons.iter().any(|&b| !self.ignored(b))
+                {
+                    return true.into();
+                }
+
+                if self.sources().gamepad_button {
                     match *self.gamepad_device {
                         GamepadDevice::Single(entity) => {
-                            if let Ok(gamepad) = self.gamepads.get(entity)
+                            if let Ok((_, gamepad)) = self.gamepads.get(entity)
                                 && gamepad.get_pressed().any(|&b| !self.ignored(b))
                             {
                                 return true.into();
                             }
                         }
                         GamepadDevice::Any => {
-                            for gamepad in &self.gamepads {
+                            for (_, gamepad) in &self.gamepads {
                                 if gamepad.get_pressed().any(|&b| !self.ignored(b)) {
                                     return true.into();
                                 }
@@ -210,17 +544,57 @@ impl InputReader<'_, '_> {
 
                 false.into()
             }
+            Binding::AnyInput => {
+                if self.value(Binding::AnyKey).as_bool() {
+                    return true.into();
+                }
+
+                if self.ignored(binding) {
+                    return false.into();
+                }
+
+                let stepped = [
+                    ScrollDirection::Up,
+                    ScrollDirection::Down,
+                    ScrollDirection::Left,
+                    ScrollDirection::Right,
+                ]
+                .into_iter()
+                .any(|direction| self.value(direction).as_bool());
+
+                let tilted = GamepadAxis::all()
+                    .into_iter()
+                    .any(|axis| self.value(axis).as_axis1d().abs() >= ACTUATION_THRESHOLD);
+
+                (stepped || tilted).into()
+            }
             Binding::None => false.into(),
         }
     }
 
+    /// Returns `true` if the button was pressed since the last run on the assigned gamepad.
+    fn buffered_gamepad_button(&self, button: GamepadButton) -> bool {
+        let Some(buffer) = self.buffer() else {
+            return false;
+        };
+
+        buffer
+            .gamepad_buttons
+            .iter()
+            .any(|&(gamepad, buffered)| match self.read_gamepad() {
+                GamepadDevice::Any => buffered == button,
+                GamepadDevice::Single(entity) => entity == gamepad && buffered == button,
+                GamepadDevice::None => false,
+            })
+    }
+
     fn mod_keys_pressed(&self, mod_keys: ModKeys) -> bool {
-        if !mod_keys.is_empty() && !self.action_sources.keyboard {
+        if !mod_keys.is_empty() && !self.sources().keyboard {
             return false;
         }
 
         for keys in mod_keys.iter_keys() {
-            if self.keys.as_ref().is_none_or(|k| !k.any_pressed(keys)) {
+            if self.keys().is_none_or(|k| !k.any_pressed(keys)) {
                 return false;
             }
         }
@@ -238,9 +612,28 @@ impl InputReader<'_, '_> {
         let mut iter = iter::once(&self.pending.ignored).chain(self.consumed.values());
         match binding.into() {
             Binding::Keyboard { key, mod_keys } => {
-                iter.any(|i| i.keys.contains(&key) || i.mod_keys.intersects(mod_keys))
-                    || keys_ignored
+                let character = self.logical_keys.as_ref().and_then(|l| l.character(key));
+                iter.any(|i| {
+                    i.keys.contains(&key)
+                        || character.is_some_and(|c| i.characters.contains(&c))
+                        || i.mod_keys.intersects(mod_keys)
+                }) || keys_ignored
+            }
+            Binding::LogicalKey {
+                character,
+                mod_keys,
+            } => {
+                let character = fold_case(character);
+                iter.any(|i| {
+                    i.characters.contains(&character)
+                        || self
+                            .logical_keys
+                            .as_ref()
+                            .is_some_and(|l| l.keys(character).any(|key| i.keys.contains(&key)))
+                        || i.mod_keys.intersects(mod_keys)
+                }) || keys_ignored
             }
+            Binding::LockKey(key) => iter.any(|inputs| inputs.lock_keys.contains(&key)),
             Binding::MouseButton { button, mod_keys } => {
                 iter.any(|i| i.mouse_buttons.contains(&button) || i.mod_keys.intersects(mod_keys))
                     || keys_ignored
@@ -248,9 +641,22 @@ impl InputReader<'_, '_> {
             Binding::MouseMotion { mod_keys } => {
                 iter.any(|inputs| inputs.mouse_motion || inputs.mod_keys.intersects(mod_keys))
             }
-            Binding::MouseWheel { mod_keys } => {
+            Binding::MouseDrag { button, mod_keys } => {
+                iter.any(|i| i.mouse_buttons.contains(&button) || i.mod_keys.intersects(mod_keys))
+                    || keys_ignored
+            }
+            Binding::MouseWheel { mod_keys }
+            | Binding::MouseWheelStep { mod_keys, .. }
+            | Binding::MouseWheelPrecise { mod_keys } => {
                 iter.any(|inputs| inputs.mouse_wheel || inputs.mod_keys.intersects(mod_keys))
             }
+            Binding::MousePosition { mod_keys } => {
+                iter.any(|inputs| inputs.mouse_position || inputs.mod_keys.intersects(mod_keys))
+            }
+            Binding::UiButton(entity) => iter.any(|inputs| inputs.ui_buttons.contains(&entity)),
+            Binding::Virtual(input) => iter.any(|inputs| inputs.virtual_inputs.contains(&input)),
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(key) => iter.any(|inputs| inputs.macro_pad_keys.contains(&key)),
             Binding::GamepadButton(button) => {
                 let input = GamepadInput {
                     gamepad: *self.gamepad_device,
@@ -265,7 +671,7 @@ impl InputReader<'_, '_> {
                 };
                 iter.any(|inputs| inputs.gamepad_axes.contains(&input))
             }
-            Binding::AnyKey => keys_ignored,
+            Binding::AnyKey | Binding::AnyInput => keys_ignored,
             Binding::None => false,
         }
     }
@@ -281,12 +687,26 @@ impl InputReader<'_, '_> {
     }
 }
 
+/// Physical device from which a [`Binding`] is read.
+///
+/// Returned by [`InputReader::device_source`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DeviceSource {
+    pub(crate) device: InputDevice,
+    /// Gamepad or seat device entity.
+    ///
+    /// `None` if the input is read from the global state, like [`KeyboardDevice::Any`],
+    /// or no gamepad actuates the binding.
+    pub(crate) entity: Option<Entity>,
+}
+
 /// Configures which input sources are visible to actions.
 ///
 /// Defaults to `true` for all values.
 ///
 /// Could be used to prevent actions from being triggered
-/// while interacting with the UI.
+/// while interacting with the UI. To restrict sources only for a specific context,
+/// use [`ContextSources`].
 ///
 /// # Examples
 ///
@@ -310,30 +730,87 @@ impl InputReader<'_, '_> {
 ///     action_sources.mouse_wheel = mouse_unused;
 /// }
 /// ```
-#[derive(Resource)]
-#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, Resource))]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq, Resource)
+)]
 pub struct ActionSources {
     pub keyboard: bool,
     pub mouse_buttons: bool,
     pub mouse_motion: bool,
     pub mouse_wheel: bool,
+    pub mouse_position: bool,
     pub gamepad_button: bool,
     pub gamepad_axis: bool,
 }
 
-impl Default for ActionSources {
-    fn default() -> Self {
+impl ActionSources {
+    /// All sources enabled.
+    pub const ALL: Self = Self {
+        keyboard: true,
+        mouse_buttons: true,
+        mouse_motion: true,
+        mouse_wheel: true,
+        mouse_position: true,
+        gamepad_button: true,
+        gamepad_axis: true,
+    };
+
+    /// All sources disabled.
+    pub const NONE: Self = Self {
+        keyboard: false,
+        mouse_buttons: false,
+        mouse_motion: false,
+        mouse_wheel: false,
+        mouse_position: false,
+        gamepad_button: false,
+        gamepad_axis: false,
+    };
+
+    /// Returns sources that are enabled in both.
+    #[must_use]
+    pub const fn intersection(self, other: Self) -> Self {
         Self {
-            keyboard: true,
-            mouse_buttons: true,
-            mouse_motion: true,
-            mouse_wheel: true,
-            gamepad_button: true,
-            gamepad_axis: true,
+            keyboard: self.keyboard && other.keyboard,
+            mouse_buttons: self.mouse_buttons && other.mouse_buttons,
+            mouse_motion: self.mouse_motion && other.mouse_motion,
+            mouse_wheel: self.mouse_wheel && other.mouse_wheel,
+            mouse_position: self.mouse_position && other.mouse_position,
+            gamepad_button: self.gamepad_button && other.gamepad_button,
+            gamepad_axis: self.gamepad_axis && other.gamepad_axis,
         }
     }
 }
 
+impl Default for ActionSources {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+/// Cursor position in logical pixels for [`Binding::MousePosition`].
+///
+/// With the `window` feature, updated from the primary window during
+/// [`EnhancedInputSystems::Prepare`].
+/// Without it, or if there is no primary window, it can be set manually.
+///
+/// [`None`] if the cursor is outside the window.
+#[derive(Resource, Default, Debug, Clone, Copy, Deref, DerefMut)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Default, Resource))]
+pub struct CursorPosition(pub Option<Vec2>);
+
+#[cfg(feature = "window")]
+pub(crate) fn update_cursor_position(
+    mut cursor_position: ResMut<CursorPosition>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+) {
+    if let Ok(window) = windows.single() {
+        **cursor_position = window.cursor_position();
+    }
+}
+
 /// Inputs consumed by actions in each schedule.
 ///
 /// These inputs will be ignored by [`InputReader::value`] in all schedules.
@@ -367,10 +844,17 @@ impl PendingBindings {
 #[derive(Default)]
 pub(crate) struct IgnoredInputs {
     keys: HashSet<KeyCode>,
+    characters: HashSet<char>,
     mod_keys: ModKeys,
+    lock_keys: HashSet<LockKey>,
     mouse_buttons: HashSet<MouseButton>,
     mouse_motion: bool,
     mouse_wheel: bool,
+    mouse_position: bool,
+    ui_buttons: HashSet<Entity>,
+    virtual_inputs: HashSet<VirtualInput>,
+    #[cfg(feature = "macro_pad")]
+    macro_pad_keys: HashSet<MacroPadKey>,
     gamepad_buttons: HashSet<GamepadInput<GamepadButton>>,
     gamepad_axes: HashSet<GamepadInput<GamepadAxis>>,
     any_key: bool,
@@ -383,6 +867,16 @@ impl IgnoredInputs {
                 self.keys.insert(key);
                 self.mod_keys.insert(mod_keys);
             }
+            Binding::LogicalKey {
+                character,
+                mod_keys,
+            } => {
+                self.characters.insert(fold_case(character));
+                self.mod_keys.insert(mod_keys);
+            }
+            Binding::LockKey(key) => {
+                self.lock_keys.insert(key);
+            }
             Binding::MouseButton { button, mod_keys } => {
                 self.mouse_buttons.insert(button);
                 self.mod_keys.insert(mod_keys);
@@ -391,10 +885,30 @@ impl IgnoredInputs {
                 self.mouse_motion = true;
                 self.mod_keys.insert(mod_keys);
             }
-            Binding::MouseWheel { mod_keys } => {
+            Binding::MouseDrag { button, mod_keys } => {
+                self.mouse_buttons.insert(button);
+                self.mod_keys.insert(mod_keys);
+            }
+            Binding::MouseWheel { mod_keys }
+            | Binding::MouseWheelStep { mod_keys, .. }
+            | Binding::MouseWheelPrecise { mod_keys } => {
                 self.mouse_wheel = true;
                 self.mod_keys.insert(mod_keys);
             }
+            Binding::MousePosition { mod_keys } => {
+                self.mouse_position = true;
+                self.mod_keys.insert(mod_keys);
+            }
+            Binding::UiButton(entity) => {
+                self.ui_buttons.insert(entity);
+            }
+            Binding::Virtual(input) => {
+                self.virtual_inputs.insert(input);
+            }
+            #[cfg(feature = "macro_pad")]
+            Binding::MacroPadKey(key) => {
+                self.macro_pad_keys.insert(key);
+            }
             Binding::GamepadButton(button) => {
                 let input = GamepadInput {
                     gamepad,
@@ -411,17 +925,24 @@ impl IgnoredInputs {
 
                 self.gamepad_axes.insert(input);
             }
-            Binding::AnyKey => self.any_key = true,
+            Binding::AnyKey | Binding::AnyInput => self.any_key = true,
             Binding::None => (),
         }
     }
 
     fn clear(&mut self) {
         self.keys.clear();
+        self.characters.clear();
         self.mod_keys = ModKeys::empty();
+        self.lock_keys.clear();
         self.mouse_buttons.clear();
         self.mouse_motion = false;
         self.mouse_wheel = false;
+        self.mouse_position = false;
+        self.ui_buttons.clear();
+        self.virtual_inputs.clear();
+        #[cfg(feature = "macro_pad")]
+        self.macro_pad_keys.clear();
         self.gamepad_buttons.clear();
         self.gamepad_axes.clear();
         self.any_key = false;
@@ -462,6 +983,41 @@ mod tests {
         assert_eq!(reader.value(Binding::AnyKey), false.into());
     }
 
+    #[test]
+    fn lock_key() {
+        let (mut world, mut state) = init_world();
+
+        world.resource_mut::<LockKeys>().num_lock = true;
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(LockKey::NumLock), true.into());
+        assert_eq!(reader.value(LockKey::CapsLock), false.into());
+        assert_eq!(reader.value(KeyCode::NumLock), false.into());
+
+        reader.consume::<PreUpdate>(LockKey::NumLock);
+        assert_eq!(reader.value(LockKey::NumLock), false.into());
+    }
+
+    #[test]
+    fn logical_key() {
+        let (mut world, mut state) = init_world();
+
+        world.resource_mut::<LogicalKeys>().mocked.insert('z');
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value('z'), true.into());
+        assert_eq!(reader.value('Z'), true.into());
+        assert_eq!(reader.value('w'), false.into());
+        assert_eq!(reader.value(KeyCode::KeyZ), false.into());
+        assert_eq!(
+            reader.value('z'.with_mod_keys(ModKeys::CONTROL)),
+            false.into()
+        );
+
+        reader.consume::<PreUpdate>('Z');
+        assert_eq!(reader.value('z'), false.into());
+    }
+
     #[test]
     fn mouse_button() {
         let (mut world, mut state) = init_world();
@@ -528,6 +1084,56 @@ mod tests {
         assert_eq!(reader.value(binding), Vec2::ZERO.into());
     }
 
+    #[test]
+    fn ui_button() {
+        let (mut world, mut state) = init_world();
+
+        let button = world.spawn_empty().id();
+        world.resource_mut::<PressedUiButtons>().insert(button);
+
+        let mut reader = state.get_mut(&mut world);
+        assert_eq!(reader.value(Binding::UiButton(button)), true.into());
+        assert_eq!(
+            reader.value(Binding::UiButton(Entity::PLACEHOLDER)),
+            false.into()
+        );
+        assert_eq!(
+            reader.value(Binding::AnyKey),
+            false.into(),
+            "UI buttons aren't keys"
+        );
+
+        reader.consume::<PreUpdate>(Binding::UiButton(button));
+        assert_eq!(reader.value(Binding::UiButton(button)), false.into());
+    }
+
+    #[test]
+    fn mouse_position() {
+        let (mut world, mut state) = init_world();
+
+        let binding = Binding::mouse_position();
+        let reader = state.get_mut(&mut world);
+        assert_eq!(
+            reader.value(binding),
+            Vec2::ZERO.into(),
+            "should be zero outside the window"
+        );
+
+        let value = Vec2::new(100.0, 50.0);
+        world.insert_resource(CursorPosition(Some(value)));
+
+        let mut reader = state.get_mut(&mut world);
+        reader.clear_consumed::<PreUpdate>();
+        assert_eq!(reader.value(binding), value.into());
+        assert_eq!(
+            reader.value(binding.with_mod_keys(ModKeys::SHIFT)),
+            Vec2::ZERO.into()
+        );
+
+        reader.consume::<PreUpdate>(binding);
+        assert_eq!(reader.value(binding), Vec2::ZERO.into());
+    }
+
     #[test]
     fn gamepad_button() {
         let (mut world, mut state) = init_world();
@@ -881,6 +1487,9 @@ mod tests {
         world.init_resource::<ConsumedInputs>();
         world.init_resource::<PendingBindings>();
         world.init_resource::<ActionSources>();
+        world.init_resource::<LockKeys>();
+        world.init_resource::<LogicalKeys>();
+        world.init_resource::<PressedUiButtons>();
 
         let state = SystemState::<InputReader>::new(&mut world);
 
@@ -44,6 +44,7 @@ pub(crate) struct ContextInstance {
     type_id: TypeId,
     priority: usize,
     is_active: fn(&Self, &FilteredEntityRef) -> bool,
+    sources: fn(&Self, &FilteredEntityRef) -> ActionSources,
     actions: for<'a> fn(&Self, &'a FilteredEntityRef) -> Option<&'a [Entity]>,
     actions_mut: for<'a> fn(&Self, &'a mut FilteredEntityMut) -> Option<Mut<'a, [Entity]>>,
 }
@@ -58,6 +59,7 @@ impl ContextInstance {
             type_id: TypeId::of::<C>(),
             priority,
             is_active: Self::is_active_typed::<C>,
+            sources: Self::sources_typed::<C>,
             actions: Self::actions_typed::<C>,
             actions_mut: Self::actions_mut_typed::<C>,
         }
@@ -68,6 +70,11 @@ impl ContextInstance {
         (self.is_active)(self, context)
     }
 
+    /// Returns the value from [`ContextSources<C>`] or all sources if it's not present.
+    pub(super) fn sources(&self, context: &FilteredEntityRef) -> ActionSources {
+        (self.sources)(self, context)
+    }
+
     /// Returns a reference to entities from [`Actions<C>`], for which this instance was created.
     pub(super) fn actions<'a>(&self, context: &'a FilteredEntityRef) -> Option<&'a [Entity]> {
         (self.actions)(self, context)
@@ -89,6 +96,13 @@ impl ContextInstance {
             .is_some_and(|&active| *active)
     }
 
+    fn sources_typed<C: Component>(&self, context: &FilteredEntityRef) -> ActionSources {
+        context
+            .get::<ContextSources<C>>()
+            .map(|sources| **sources)
+            .unwrap_or_default()
+    }
+
     fn actions_typed<'a, C: Component>(
         &self,
         context: &'a FilteredEntityRef,
@@ -0,0 +1,260 @@
+//! Input latency equalization between devices.
+
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy::{platform::collections::HashMap, prelude::*};
+
+use crate::prelude::*;
+
+/// Delays input from faster devices, so all devices are applied with the same latency.
+///
+/// Not present by default. Insert this resource to enable equalization.
+///
+/// Intended for competitive local multiplayer, where players use devices with different latencies.
+/// Each binding value is delayed by [`Self::target`] minus the latency measured for its device,
+/// so a device with the measured latency equal to the target is not delayed at all.
+///
+/// Bevy doesn't provide timestamps for input events, so the latency of each device needs to be
+/// measured by the game, for example with a calibration screen, and added to [`Self::measured`].
+/// Devices without a measurement are delayed by the full target.
+///
+/// Gamepad bindings are matched by the [`GamepadDevice`] of their context. Contexts with
+/// [`GamepadDevice::Any`] can't be matched to a specific gamepad and use [`LatencySource::AnyGamepad`].
+///
+/// The delay is applied to binding values before modifiers and conditions.
+/// Input consumption and [`ActionSettings::require_reset`] still operate on the current input.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// let gamepad = world.spawn(Gamepad::default()).id();
+/// world.insert_resource(
+///     InputLatency::new(Duration::from_millis(30))
+///         .with_measured(LatencySource::Keyboard, Duration::from_millis(10))
+///         .with_measured(LatencySource::Gamepad(gamepad), Duration::from_millis(30)),
+/// );
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct InputLatency {
+    /// Latency that all devices should have.
+    pub target: Duration,
+
+    /// Latency measured for each device.
+    pub measured: HashMap<LatencySource, Duration>,
+}
+
+impl InputLatency {
+    /// Creates a new instance with the given target latency and no measurements.
+    #[must_use]
+    pub fn new(target: Duration) -> Self {
+        Self {
+            target,
+            measured: Default::default(),
+        }
+    }
+
+    /// Sets the measured latency for the device.
+    #[must_use]
+    pub fn with_measured(mut self, source: LatencySource, latency: Duration) -> Self {
+        self.measured.insert(source, latency);
+        self
+    }
+
+    /// Returns the delay that will be applied to input from the device.
+    #[must_use]
+    pub fn delay(&self, source: LatencySource) -> Duration {
+        let measured = self.measured.get(&source).copied().unwrap_or_default();
+        self.target.saturating_sub(measured)
+    }
+}
+
+/// Device with a measured latency for [`InputLatency`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Hash, PartialEq)
+)]
+pub enum LatencySource {
+    Keyboard,
+    Mouse,
+    /// A specific gamepad entity.
+    Gamepad(Entity),
+    /// Gamepad input in contexts with [`GamepadDevice::Any`].
+    AnyGamepad,
+}
+
+/// Inserts [`DelayLine`] on bindings that don't have it yet.
+pub(crate) fn insert_delay_lines(
+    mut commands: Commands,
+    bindings: Query<Entity, (With<Binding>, Without<DelayLine>)>,
+) {
+    for entity in &bindings {
+        commands.entity(entity).insert(DelayLine::default());
+    }
+}
+
+/// Recent values of a binding, used to apply the delay from [`InputLatency`].
+///
+/// Inserted on bindings only while [`InputLatency`] is present.
+#[derive(Component, Default)]
+pub(crate) struct DelayLine(VecDeque<(Duration, ActionValue)>);
+
+impl DelayLine {
+    /// Records the value at the given time and returns the value from `delay` ago.
+    ///
+    /// For bindings that report deltas, like [`Binding::MouseMotion`], returns the sum of all values
+    /// that became old enough since the last call, so the total motion is preserved regardless
+    /// of the frame spacing.
+    ///
+    /// Returns zero if there is no value old enough.
+    pub(crate) fn delay(
+        &mut self,
+        binding: Binding,
+        value: ActionValue,
+        now: Duration,
+        delay: Duration,
+    ) -> ActionValue {
+        if delay.is_zero() {
+            self.0.clear();
+            return value;
+        }
+
+        self.0.push_back((now, value));
+
+        let Some(cutoff) = now.checked_sub(delay) else {
+            return ActionValue::zero(value.dim());
+        };
+
+        if matches!(
+            binding,
+            Binding::MouseMotion { .. }
+                | Binding::MouseWheel { .. }
+                | Binding::MouseWheelPrecise { .. }
+        ) {
+            let mut sum = Vec3::ZERO;
+            while let Some(&(time, delta)) = self.0.front()
+                && time <= cutoff
+            {
+                sum += delta.as_axis3d();
+                self.0.pop_front();
+            }
+            return ActionValue::Axis3D(sum).convert(value.dim());
+        }
+
+        // Keep only the latest value that is old enough.
+        while self.0.get(1).is_some_and(|&(time, _)| time <= cutoff) {
+            self.0.pop_front();
+        }
+
+        match self.0.front() {
+            Some(&(time, delayed)) if time <= cutoff => delayed.convert(value.dim()),
+            _ => ActionValue::zero(value.dim()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: Binding = Binding::Keyboard {
+        key: KeyCode::Space,
+        mod_keys: ModKeys::empty(),
+    };
+
+    #[test]
+    fn delay() {
+        let mut line = DelayLine::default();
+        let delay = Duration::from_millis(20);
+
+        assert_eq!(
+            line.delay(KEY, true.into(), Duration::from_millis(10), delay),
+            false.into()
+        );
+        assert_eq!(
+            line.delay(KEY, false.into(), Duration::from_millis(20), delay),
+            false.into()
+        );
+        assert_eq!(
+            line.delay(KEY, false.into(), Duration::from_millis(30), delay),
+            true.into()
+        );
+        assert_eq!(
+            line.delay(KEY, false.into(), Duration::from_millis(40), delay),
+            false.into()
+        );
+    }
+
+    #[test]
+    fn delta() {
+        let mut line = DelayLine::default();
+        let binding = Binding::mouse_motion();
+        let delay = Duration::from_millis(20);
+
+        for (millis, delta) in [(0, 1.0), (5, 2.0), (15, 4.0)] {
+            line.delay(
+                binding,
+                Vec2::splat(delta).into(),
+                Duration::from_millis(millis),
+                delay,
+            );
+        }
+
+        assert_eq!(
+            line.delay(
+                binding,
+                Vec2::splat(8.0).into(),
+                Duration::from_millis(30),
+                delay
+            ),
+            Vec2::splat(3.0).into(),
+        );
+        assert_eq!(
+            line.delay(binding, Vec2::ZERO.into(), Duration::from_millis(40), delay),
+            Vec2::splat(4.0).into(),
+            "should include only deltas that crossed the cutoff since the last call"
+        );
+    }
+
+    #[test]
+    fn no_delay() {
+        let mut line = DelayLine::default();
+        line.delay(KEY, 1.0.into(), Duration::ZERO, Duration::from_millis(10));
+
+        assert_eq!(
+            line.delay(KEY, 0.5.into(), Duration::from_millis(5), Duration::ZERO),
+            0.5.into()
+        );
+        assert!(line.0.is_empty());
+    }
+
+    #[test]
+    fn source_delay() {
+        let latency = InputLatency::new(Duration::from_millis(30))
+            .with_measured(LatencySource::Keyboard, Duration::from_millis(10))
+            .with_measured(LatencySource::Mouse, Duration::from_millis(40));
+
+        assert_eq!(
+            latency.delay(LatencySource::Keyboard),
+            Duration::from_millis(20)
+        );
+        assert_eq!(latency.delay(LatencySource::Mouse), Duration::ZERO);
+        assert_eq!(
+            latency.delay(LatencySource::AnyGamepad),
+            Duration::from_millis(30)
+        );
+    }
+}
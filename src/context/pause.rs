@@ -0,0 +1,87 @@
+//! Global pause of input evaluation.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use log::debug;
+
+/// System parameter to pause and resume input evaluation for all contexts.
+///
+/// Useful for modal OS dialogs and screenshot modes, where the world should keep running
+/// but inputs should be inert.
+///
+/// While paused, [`InputCapture`](crate::prelude::InputCapture) reports nothing and actions
+/// ignore inputs. There are two ways to pause:
+/// - [`Self::pause`] freezes all actions in their current states. No events are triggered
+///   and [`ActionTime`](crate::prelude::ActionTime) doesn't advance until resumed.
+/// - [`Self::pause_canceling`] transitions all actions to
+///   [`TriggerState::None`](crate::prelude::TriggerState::None), triggering the corresponding events,
+///   similar to [`ContextActivity::INACTIVE`](crate::prelude::ContextActivity::INACTIVE).
+///
+/// After resuming, actions are evaluated from the current inputs, so inputs that were held
+/// through the pause are picked up again.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn toggle_screenshot_mode(mut input: EnhancedInput, keys: Res<ButtonInput<KeyCode>>) {
+///     if keys.just_pressed(KeyCode::F12) {
+///         if input.is_paused() {
+///             input.resume();
+///         } else {
+///             input.pause();
+///         }
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct EnhancedInput<'w> {
+    pause: ResMut<'w, InputPause>,
+}
+
+impl EnhancedInput<'_> {
+    /// Stops reading inputs, keeping actions in their current states.
+    pub fn pause(&mut self) {
+        debug!("pausing input with preserved states");
+        *self.pause = InputPause::Preserve;
+    }
+
+    /// Stops reading inputs, transitioning all actions to [`TriggerState::None`](crate::prelude::TriggerState::None).
+    pub fn pause_canceling(&mut self) {
+        debug!("pausing input with canceled states");
+        *self.pause = InputPause::Cancel;
+    }
+
+    /// Resumes reading inputs.
+    pub fn resume(&mut self) {
+        debug!("resuming input");
+        *self.pause = InputPause::Running;
+    }
+
+    /// Returns `true` if paused by [`Self::pause`] or [`Self::pause_canceling`].
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.pause.is_paused()
+    }
+}
+
+/// Pause state for [`EnhancedInput`].
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum InputPause {
+    #[default]
+    Running,
+    Preserve,
+    Cancel,
+}
+
+impl InputPause {
+    pub(crate) fn is_paused(self) -> bool {
+        self != Self::Running
+    }
+}
+
+/// Run condition that skips context evaluation while states are preserved.
+pub(crate) fn evaluates(pause: Res<InputPause>) -> bool {
+    *pause != InputPause::Preserve
+}
@@ -0,0 +1,143 @@
+//! Per-device keyboards and mice for local multiplayer.
+//!
+//! Bevy merges input from all keyboards and mice into global resources, like
+//! [`ButtonInput<KeyCode>`]. Some platforms expose the identity of each physical device, like
+//! raw input on Windows or libinput on Linux. An integration that reads such input can spawn
+//! an entity per device with [`SeatKeyboard`] or [`SeatMouse`] and update its state every frame
+//! before [`EnhancedInputSystems::Update`](crate::EnhancedInputSystems::Update). Then contexts
+//! can be bound to a specific device with [`KeyboardDevice`] and [`MouseDevice`], similar to
+//! [`GamepadDevice`](crate::context::GamepadDevice).
+//!
+//! Only physical keys, mouse buttons, mouse motion and wheel are read per device. Other keyboard
+//! and mouse inputs, like [`Binding::LogicalKey`](crate::binding::Binding::LogicalKey),
+//! [`Binding::MousePosition`](crate::binding::Binding::MousePosition),
+//! [`Binding::MouseWheelStep`](crate::binding::Binding::MouseWheelStep),
+//! [`Binding::MouseWheelPrecise`](crate::binding::Binding::MouseWheelPrecise) or
+//! [`Binding::MouseDrag`](crate::binding::Binding::MouseDrag), are always read from
+//! the global state. Wheel steps and drags are still ignored with [`MouseDevice::None`].
+//! Consumed keys and mouse buttons are shared between devices.
+//!
+//! # Examples
+//!
+//! Two players with their own mice:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_enhanced_input::prelude::*;
+//!
+//! # let mut world = World::new();
+//! let first_mouse = world.spawn(SeatMouse::default()).id();
+//! let second_mouse = world.spawn(SeatMouse::default()).id();
+//!
+//! world.spawn((Player, MouseDevice::Single(first_mouse)));
+//! world.spawn((Player, MouseDevice::Single(second_mouse)));
+//!
+//! /// Updates devices from a platform-specific source.
+//! fn update_mice(mut mice: Query<&mut SeatMouse>) {
+//!     for mut mouse in &mut mice {
+//!         // Read from the device...
+//!         mouse.motion = Vec2::new(1.0, 0.0);
+//!         mouse.buttons.press(MouseButton::Left);
+//!     }
+//! }
+//!
+//! #[derive(Component)]
+//! struct Player;
+//! ```
+
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// State of a single physical keyboard.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Component, Debug, Default, Clone, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct SeatKeyboard(pub ButtonInput<KeyCode>);
+
+/// State of a single physical mouse.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Component, Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct SeatMouse {
+    /// Pressed buttons.
+    pub buttons: ButtonInput<MouseButton>,
+
+    /// Movement since the last frame, like [`AccumulatedMouseMotion`](bevy::input::mouse::AccumulatedMouseMotion).
+    pub motion: Vec2,
+
+    /// Wheel scroll since the last frame, like [`AccumulatedMouseScroll`](bevy::input::mouse::AccumulatedMouseScroll).
+    pub scroll: Vec2,
+}
+
+/// Associated keyboard for all input contexts on this entity.
+///
+/// If not present, input will be read from all keyboards.
+#[derive(Component, Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum KeyboardDevice {
+    /// Matches input from any keyboard using [`ButtonInput<KeyCode>`].
+    #[default]
+    Any,
+    /// Matches input from an entity with [`SeatKeyboard`].
+    Single(Entity),
+    /// Ignores all physical keys.
+    None,
+}
+
+impl From<Entity> for KeyboardDevice {
+    fn from(value: Entity) -> Self {
+        Self::Single(value)
+    }
+}
+
+/// Associated mouse for all input contexts on this entity.
+///
+/// If not present, input will be read from all mice.
+#[derive(Component, Debug, Default, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, Hash, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub enum MouseDevice {
+    /// Matches input from any mouse using [`ButtonInput<MouseButton>`],
+    /// [`AccumulatedMouseMotion`](bevy::input::mouse::AccumulatedMouseMotion) and
+    /// [`AccumulatedMouseScroll`](bevy::input::mouse::AccumulatedMouseScroll).
+    #[default]
+    Any,
+    /// Matches input from an entity with [`SeatMouse`].
+    Single(Entity),
+    /// Ignores all mouse buttons, motion, wheel and drags.
+    None,
+}
+
+impl From<Entity> for MouseDevice {
+    fn from(value: Entity) -> Self {
+        Self::Single(value)
+    }
+}
@@ -0,0 +1,114 @@
+//! Per-entity evaluation statistics for input contexts.
+
+use alloc::vec::Vec;
+use core::{any::TypeId, time::Duration};
+
+use bevy::prelude::*;
+
+/// Evaluation statistics for each context instance.
+///
+/// Not present by default. Insert this resource to collect statistics during
+/// [`EnhancedInputSystems::Update`](crate::EnhancedInputSystems::Update).
+/// Entries are replaced on each evaluation of their schedule.
+///
+/// Useful for debug overlays to find which specific context on which entity is expensive.
+/// For totals in Bevy diagnostics, add [`InputDiagnosticsPlugin`](super::diagnostics::InputDiagnosticsPlugin).
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{input::InputPlugin, prelude::*};
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+///     .init_resource::<ContextStats>()
+///     .add_input_context::<Player>()
+///     .finish();
+///
+/// let player = app
+///     .world_mut()
+///     .spawn((
+///         Player,
+///         actions!(Player[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+///     ))
+///     .id();
+///
+/// app.update();
+///
+/// let stats = app.world().resource::<ContextStats>();
+/// let stat = stats.iter_entity(player).next().unwrap();
+/// assert_eq!(stat.context.to_string(), "Player");
+/// assert_eq!(stat.actions, 1);
+/// assert_eq!(stat.bindings, 1);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(Resource, Default, Debug, Deref)]
+pub struct ContextStats(Vec<ContextStat>);
+
+impl ContextStats {
+    /// Returns statistics for all contexts on the given entity.
+    pub fn iter_entity(&self, entity: Entity) -> impl Iterator<Item = &ContextStat> {
+        self.iter().filter(move |stat| stat.entity == entity)
+    }
+
+    /// Removes all entries for schedule `S`.
+    pub(super) fn clear_schedule<S: 'static>(&mut self) {
+        self.0.retain(|stat| stat.schedule_id != TypeId::of::<S>());
+    }
+
+    pub(super) fn push(&mut self, stat: ContextStat) {
+        self.0.push(stat);
+    }
+}
+
+/// Statistics for a single context evaluation on an entity.
+#[derive(Debug, Clone)]
+pub struct ContextStat {
+    /// Entity with the context component.
+    pub entity: Entity,
+
+    /// Name of the context type.
+    pub context: ShortName<'static>,
+
+    /// Name of the schedule in which the context was evaluated.
+    pub schedule: ShortName<'static>,
+
+    /// Number of evaluated actions.
+    pub actions: usize,
+
+    /// Number of evaluated bindings across all actions.
+    pub bindings: usize,
+
+    /// Number of inputs consumed by actions.
+    ///
+    /// See [`ActionSettings::consume_input`](crate::action::ActionSettings::consume_input).
+    pub consumed: usize,
+
+    /// Time spent on evaluation.
+    pub duration: Duration,
+
+    schedule_id: TypeId,
+}
+
+impl ContextStat {
+    #[must_use]
+    pub(super) fn new<S: 'static>(entity: Entity, context: ShortName<'static>) -> Self {
+        Self {
+            entity,
+            context,
+            schedule: ShortName::of::<S>(),
+            actions: 0,
+            bindings: 0,
+            consumed: 0,
+            duration: Duration::ZERO,
+            schedule_id: TypeId::of::<S>(),
+        }
+    }
+}
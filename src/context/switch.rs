@@ -0,0 +1,352 @@
+//! Automatic switching of [`ContextActivity`] on timers and application events.
+//!
+//! Useful for app-level automation of control schemes, like enabling simplified controls
+//! when a "streamer mode" setting is toggled or disabling gameplay controls for the duration
+//! of a cutscene.
+//!
+//! [`ContextSwitchAppExt::switch_context_on`], [`ContextSwitchTimer`] and the commands from
+//! [`SwitchEntityCommandsExt`] trigger [`ContextSwitched`] for each context they change,
+//! so UI and game logic can react to the transition.
+
+#[cfg(feature = "reflect")]
+use core::any::type_name;
+use core::{
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+};
+
+use bevy::prelude::*;
+#[cfg(feature = "reflect")]
+use bevy::reflect::utility::GenericTypePathCell;
+use log::debug;
+
+use crate::prelude::*;
+
+/// An extension trait for [`App`] to switch contexts on application events.
+pub trait ContextSwitchAppExt {
+    /// Switches context `C` on all entities when event `E` is triggered.
+    ///
+    /// Each time `E` is triggered, `activity` is called with it. If it returns a value,
+    /// [`ContextActivity<C>`] is set to it. Returning [`None`] ignores the event.
+    ///
+    /// Can be called multiple times to switch different contexts by the same event.
+    ///
+    /// # Examples
+    ///
+    /// Swap to a simplified control scheme in streamer mode:
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// let mut app = App::new();
+    /// app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+    ///     .add_input_context::<FullControls>()
+    ///     .add_input_context::<SimpleControls>()
+    ///     .switch_context_on::<StreamerMode, FullControls>(|mode| Some(!mode.enabled))
+    ///     .switch_context_on::<StreamerMode, SimpleControls>(|mode| Some(mode.enabled))
+    ///     .add_observer(announce);
+    ///
+    /// fn announce(switched: On<ContextSwitched<SimpleControls>>) {
+    ///     if switched.active {
+    ///         info!("simplified controls enabled");
+    ///     }
+    /// }
+    ///
+    /// #[derive(Event)]
+    /// struct StreamerMode {
+    ///     enabled: bool,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct FullControls;
+    ///
+    /// #[derive(Component)]
+    /// struct SimpleControls;
+    /// ```
+    fn switch_context_on<E: Event, C: Component>(
+        &mut self,
+        activity: impl Fn(&E) -> Option<bool> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl ContextSwitchAppExt for App {
+    fn switch_context_on<E: Event, C: Component>(
+        &mut self,
+        activity: impl Fn(&E) -> Option<bool> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.add_observer(
+            move |event: On<E>,
+                  mut commands: Commands,
+                  contexts: Query<(Entity, &ContextActivity<C>)>| {
+                let Some(active) = activity(&event) else {
+                    return;
+                };
+
+                for (context, &current) in &contexts {
+                    switch(&mut commands, context, current, active);
+                }
+            },
+        )
+    }
+}
+
+/// Extension trait for [`EntityWorldMut`] that provides methods for switching contexts.
+pub trait SwitchEntityWorldMutExt {
+    /// Activates context `C` on the entity.
+    ///
+    /// See [`SwitchEntityCommandsExt::activate_context`] for more details.
+    fn activate_context<C: Component>(&mut self) -> &mut Self;
+
+    /// Deactivates context `C` on the entity.
+    ///
+    /// See [`SwitchEntityCommandsExt::deactivate_context`] for more details.
+    fn deactivate_context<C: Component>(&mut self) -> &mut Self;
+}
+
+impl SwitchEntityWorldMutExt for EntityWorldMut<'_> {
+    fn activate_context<C: Component>(&mut self) -> &mut Self {
+        self.insert(ContextSwitchTimer::<C>::new(0.0, true))
+    }
+
+    fn deactivate_context<C: Component>(&mut self) -> &mut Self {
+        self.insert(ContextSwitchTimer::<C>::new(0.0, false))
+    }
+}
+
+/// Extension trait for [`EntityCommands`] that provides methods for switching contexts.
+pub trait SwitchEntityCommandsExt {
+    /// Activates context `C` on the entity.
+    ///
+    /// Unlike inserting [`ContextActivity<C>`] directly, the switch is deferred until timers are advanced
+    /// after [`EnhancedInputSystems::Apply`], so all contexts observe the same state during the current
+    /// evaluation. Triggers [`ContextSwitched`] if the activity changes.
+    ///
+    /// Replaces any pending [`ContextSwitchTimer<C>`].
+    ///
+    /// # Examples
+    ///
+    /// Switch from walking to driving when entering a vehicle:
+    ///
+    /// ```
+    /// use bevy::prelude::*;
+    /// use bevy_enhanced_input::prelude::*;
+    ///
+    /// fn enter_vehicle(mut commands: Commands, player: Single<Entity, With<OnFoot>>) {
+    ///     commands
+    ///         .entity(*player)
+    ///         .deactivate_context::<OnFoot>()
+    ///         .activate_context::<Driving>();
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct OnFoot;
+    ///
+    /// #[derive(Component)]
+    /// struct Driving;
+    /// ```
+    fn activate_context<C: Component>(&mut self) -> &mut Self;
+
+    /// Deactivates context `C` on the entity.
+    ///
+    /// Like [`Self::activate_context`], but sets the activity to inactive.
+    fn deactivate_context<C: Component>(&mut self) -> &mut Self;
+}
+
+impl SwitchEntityCommandsExt for EntityCommands<'_> {
+    fn activate_context<C: Component>(&mut self) -> &mut Self {
+        self.insert(ContextSwitchTimer::<C>::new(0.0, true))
+    }
+
+    fn deactivate_context<C: Component>(&mut self) -> &mut Self {
+        self.insert(ContextSwitchTimer::<C>::new(0.0, false))
+    }
+}
+
+/// Sets [`ContextActivity<C>`] on the entity after a delay.
+///
+/// Once the timer finishes, the activity is set to [`Self::active`] and the component
+/// is removed. Insert it again to schedule another switch.
+///
+/// Timers are advanced after [`EnhancedInputSystems::Apply`] for all registered contexts,
+/// so the new activity takes effect on the next evaluation.
+///
+/// # Examples
+///
+/// Suggest a break by switching to a menu-only context after a long session:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     ContextSwitchTimer::<Player>::new(2.0 * 60.0 * 60.0, false).with_time_kind(TimeKind::Real),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+/// ```
+#[derive(Component)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, type_path = false)
+)]
+pub struct ContextSwitchTimer<C> {
+    /// Activity to set once the timer finishes.
+    pub active: bool,
+
+    /// The type of time used to advance the timer.
+    pub time_kind: TimeKind,
+
+    timer: Timer,
+
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    marker: PhantomData<C>,
+}
+
+impl<C> ContextSwitchTimer<C> {
+    /// Creates a new instance that sets the activity after the given time in seconds.
+    #[must_use]
+    pub fn new(delay: f32, active: bool) -> Self {
+        Self {
+            active,
+            time_kind: Default::default(),
+            timer: Timer::from_seconds(delay, TimerMode::Once),
+            marker: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the time in seconds until the switch.
+    #[must_use]
+    pub fn remaining_secs(&self) -> f32 {
+        self.timer.remaining_secs()
+    }
+}
+
+impl<C> Clone for ContextSwitchTimer<C> {
+    fn clone(&self) -> Self {
+        Self {
+            active: self.active,
+            time_kind: self.time_kind,
+            timer: self.timer.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Debug for ContextSwitchTimer<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ContextSwitchTimer")
+            .field("active", &self.active)
+            .field("time_kind", &self.time_kind)
+            .field("timer", &self.timer)
+            .finish()
+    }
+}
+
+#[cfg(feature = "reflect")]
+impl<C: 'static> TypePath for ContextSwitchTimer<C> {
+    fn type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| {
+            format!(
+                concat!(module_path!(), "::ContextSwitchTimer<{}>"),
+                type_name::<C>()
+            )
+        })
+    }
+
+    fn short_type_path() -> &'static str {
+        static CELL: GenericTypePathCell = GenericTypePathCell::new();
+        CELL.get_or_insert::<Self, _>(|| format!("ContextSwitchTimer<{}>", type_name::<C>()))
+    }
+
+    fn type_ident() -> Option<&'static str> {
+        Some("ContextSwitchTimer")
+    }
+
+    fn crate_name() -> Option<&'static str> {
+        Some(module_path!().split(':').next().unwrap())
+    }
+
+    fn module_path() -> Option<&'static str> {
+        Some(module_path!())
+    }
+}
+
+pub(crate) fn tick_timers<C: Component>(
+    mut commands: Commands,
+    time: Res<Time>,
+    real_time: Res<Time<Real>>,
+    mut timers: Query<(Entity, &mut ContextSwitchTimer<C>, &ContextActivity<C>)>,
+) {
+    for (context, mut timer, &current) in &mut timers {
+        let delta = match timer.time_kind {
+            TimeKind::Virtual => time.delta(),
+            TimeKind::Real => real_time.delta(),
+        };
+        timer.timer.tick(delta);
+        if timer.timer.is_finished() {
+            commands.entity(context).remove::<ContextSwitchTimer<C>>();
+            switch(&mut commands, context, current, timer.active);
+        }
+    }
+}
+
+fn switch<C: Component>(
+    commands: &mut Commands,
+    context: Entity,
+    current: ContextActivity<C>,
+    active: bool,
+) {
+    if *current == active {
+        return;
+    }
+
+    debug!(
+        "switching `{}` on `{context}` to `{active}`",
+        ShortName::of::<C>(),
+    );
+    commands
+        .entity(context)
+        .insert(ContextActivity::<C>::new(active));
+    commands.trigger(ContextSwitched::<C> {
+        context,
+        active,
+        marker: PhantomData,
+    });
+}
+
+/// Triggered when [`ContextActivity<C>`] is changed by [`ContextSwitchAppExt::switch_context_on`],
+/// [`ContextSwitchTimer`] or [`SwitchEntityCommandsExt`].
+///
+/// Not triggered for manual changes.
+#[derive(EntityEvent)]
+pub struct ContextSwitched<C: Component> {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// New activity of the context.
+    pub active: bool,
+
+    marker: PhantomData<C>,
+}
+
+impl<C: Component> Debug for ContextSwitched<C> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("ContextSwitched")
+            .field("context", &self.context)
+            .field("active", &self.active)
+            .finish()
+    }
+}
@@ -2,6 +2,9 @@ use core::time::Duration;
 
 use bevy::{ecs::system::SystemParam, prelude::*};
 
+use super::fixed_rate::{FixedStep, InputFixedRate};
+use crate::recording::InputPlayback;
+
 /// Time resources used for input conditions and modifier evaluation.
 ///
 /// Dereferences to [`Self::virt`], which is the default time resource
@@ -12,19 +15,79 @@ pub struct ContextTime<'w> {
     #[deref]
     pub virt: Res<'w, Time>,
     pub real: Res<'w, Time<Real>>,
+    pub max_delta: Option<Res<'w, MaxInputDelta>>,
+    pub playback: Option<Res<'w, InputPlayback>>,
+    pub fixed_rate: Option<Res<'w, InputFixedRate>>,
 }
 
 impl ContextTime<'_> {
     /// Returns the delta of the time resource corresponding to the given [`TimeKind`].
+    ///
+    /// Limited by [`MaxInputDelta`] and scaled by [`InputPlayback::speed`] during playback.
+    /// During [`InputFixedUpdate`](super::fixed_rate::InputFixedUpdate), returns [`InputFixedRate::step`]
+    /// for any kind instead.
     #[must_use]
     pub fn delta_kind(&self, kind: TimeKind) -> Duration {
-        match kind {
-            TimeKind::Virtual => self.virt.delta(),
-            TimeKind::Real => self.real.delta(),
+        let delta = match (
+            self.fixed_rate
+                .as_deref()
+                .filter(|rate| rate.current().is_some()),
+            kind,
+        ) {
+            (Some(rate), _) => rate.step(),
+            (None, TimeKind::Virtual) => self.virt.delta(),
+            (None, TimeKind::Real) => self.real.delta(),
+        };
+
+        let delta = match self.max_delta.as_deref().and_then(|max| **max) {
+            Some(max) => delta.min(max),
+            None => delta,
+        };
+
+        match &self.playback {
+            Some(playback) => delta.mul_f32(playback.time_scale()),
+            None => delta,
         }
     }
+
+    /// Returns the currently running step of [`InputFixedRate`].
+    pub(crate) fn fixed_step(&self) -> Option<FixedStep> {
+        self.fixed_rate.as_ref().and_then(|rate| rate.current())
+    }
 }
 
+/// Upper limit for the time delta used by time-based [modifiers](crate::modifier),
+/// [conditions](crate::condition) and [`ActionTime`](crate::action::ActionTime).
+///
+/// Protects against frame hitches: without a limit, a single long frame could produce
+/// a huge [`DeltaScale`](crate::modifier::delta_scale::DeltaScale) value or instantly
+/// complete a [`Hold`](crate::condition::hold::Hold).
+///
+/// Bevy already limits [`Time<Virtual>`] delta via [`Time::<Virtual>::set_max_delta`],
+/// but [`Time<Real>`], which is the default [`TimeKind`], isn't limited.
+///
+/// Initialized by [`crate::EnhancedInputPlugin`] without a limit.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_plugins(EnhancedInputPlugin)
+///     .insert_resource(MaxInputDelta(Some(Duration::from_millis(100))));
+/// ```
+#[derive(Resource, Debug, Default, Clone, Copy, Deref, DerefMut)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct MaxInputDelta(pub Option<Duration>);
+
 /// Type of the [`Time`] resource to use.
 ///
 /// Used to configure time-based [modifiers](crate::modifier) and [conditions](crate::condition).
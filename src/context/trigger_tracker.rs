@@ -6,6 +6,7 @@ use crate::{condition::fns::ConditionFns, modifier::fns::ModifierFns, prelude::*
 /// Helper for computing [`TriggerState`] and [`ActionValue`] based on modifiers and conditions.
 ///
 /// Can be used at both the input level and the action level.
+#[derive(Clone)]
 pub(super) struct TriggerTracker {
     value: ActionValue,
     found_explicit: bool,
@@ -78,6 +79,11 @@ impl TriggerTracker {
                 }
             }
         }
+
+        let state = self.state();
+        for get_condition in &**conditions {
+            get_condition(entity).post_evaluate(state);
+        }
     }
 
     pub(super) fn state(&self) -> TriggerState {
@@ -106,6 +112,13 @@ impl TriggerTracker {
         self.value
     }
 
+    /// Multiplies the value by `factor`.
+    ///
+    /// Converts the value into [`ActionValue::Axis3D`] to preserve fractional values.
+    pub(super) fn scale(&mut self, factor: f32) {
+        self.value = ActionValue::Axis3D(self.value.as_axis3d() * factor);
+    }
+
     /// Replaces the state with `other`.
     ///
     /// Preserves the value dimension.
@@ -131,6 +144,16 @@ impl TriggerTracker {
                 value.into()
             }
             Accumulation::Cumulative => self.value.as_axis3d() + other.value.as_axis3d(),
+            Accumulation::First => {
+                let mut value = self.value.as_axis3d().to_array();
+                let other_value = other.value.as_axis3d().to_array();
+                for (axis, other_axis) in value.iter_mut().zip(other_value) {
+                    if *axis == 0.0 {
+                        *axis = other_axis;
+                    }
+                }
+                value.into()
+            }
         };
 
         self.value = ActionValue::Axis3D(accumulated).convert(self.value.dim());
@@ -0,0 +1,304 @@
+/*!
+Virtual mouse cursor driven by a gamepad stick.
+
+Insert [`GamepadCursor`] on an action with [`Vec2`] output to move the cursor by its value,
+and [`GamepadCursorClick`] on an action with [`bool`] output to press a mouse button while it fires.
+This way pointer-based menus can be navigated with a gamepad without game-side cursor code.
+
+The cursor position is written to [`CursorPosition`], so it's available via [`Binding::MousePosition`]
+for all contexts. With the `window` feature, the cursor of the primary window is moved as well,
+so UI interactions and the real mouse keep working alongside the emulated cursor.
+Clicks are sent as [`MouseButtonInput`] messages, so they are visible in [`ButtonInput<MouseButton>`]
+on the next frame, just like physical clicks.
+
+Both components are updated in [`PreUpdate`] after [`EnhancedInputSystems::Apply`].
+If [`GamepadCursorClick`] is removed while its button is pressed, the button is released.
+Only a single [`GamepadCursor`] is expected to be active at a time.
+
+# Examples
+
+```
+use bevy::prelude::*;
+use bevy_enhanced_input::prelude::*;
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct MoveCursor;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Click;
+
+# let mut world = World::new();
+world.spawn((
+    Menu,
+    actions!(Menu[
+        (
+            Action::<MoveCursor>::new(),
+            GamepadCursor::default().with_acceleration(2000.0),
+            DeadZone::default(),
+            Bindings::spawn(Axial::left_stick()),
+        ),
+        (
+            Action::<Click>::new(),
+            GamepadCursorClick::new(MouseButton::Left),
+            bindings![GamepadButton::South],
+        ),
+    ]),
+));
+```
+*/
+
+#[cfg(feature = "window")]
+use bevy::window::PrimaryWindow;
+use bevy::{
+    input::{ButtonState, mouse::MouseButtonInput},
+    prelude::*,
+};
+
+use crate::prelude::*;
+
+/// Moves the cursor by the value of the action.
+///
+/// The value is interpreted as a stick deflection: positive Y moves the cursor up.
+/// The speed starts at [`Self::speed`] and increases by [`Self::acceleration`] while the value
+/// is non-zero, up to [`Self::max_speed`].
+///
+/// Does nothing while the value is zero, so the regular mouse can be used in the meantime.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct GamepadCursor {
+    /// Initial speed in logical pixels per second at full deflection.
+    pub speed: f32,
+
+    /// Upper limit for the speed in logical pixels per second.
+    pub max_speed: f32,
+
+    /// Speed increase in logical pixels per second squared while the cursor moves.
+    pub acceleration: f32,
+
+    /// Distance in logical pixels from the bounds at which [`Self::edge_scroll`] activates.
+    pub edge_margin: f32,
+
+    /// Area to confine the cursor to.
+    ///
+    /// If [`None`], the primary window area is used with the `window` feature.
+    /// Without the feature or a window, the cursor is not confined.
+    pub bounds: Option<Rect>,
+
+    /// The type of time used to advance the cursor.
+    pub time_kind: TimeKind,
+
+    /// Time in seconds since the cursor started moving.
+    moving_secs: f32,
+    edge_scroll: Vec2,
+}
+
+impl GamepadCursor {
+    /// Sets [`Self::speed`] and [`Self::max_speed`].
+    #[must_use]
+    pub fn with_speed(mut self, speed: f32, max_speed: f32) -> Self {
+        self.speed = speed;
+        self.max_speed = max_speed;
+        self
+    }
+
+    /// Sets [`Self::acceleration`].
+    #[must_use]
+    pub fn with_acceleration(mut self, acceleration: f32) -> Self {
+        self.acceleration = acceleration;
+        self
+    }
+
+    /// Sets [`Self::edge_margin`].
+    #[must_use]
+    pub fn with_edge_margin(mut self, edge_margin: f32) -> Self {
+        self.edge_margin = edge_margin;
+        self
+    }
+
+    /// Sets [`Self::bounds`].
+    #[must_use]
+    pub fn with_bounds(mut self, bounds: Rect) -> Self {
+        self.bounds = Some(bounds);
+        self
+    }
+
+    /// Sets [`Self::time_kind`].
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the direction in which the cursor pushes against the bounds.
+    ///
+    /// Non-zero on an axis while the cursor is within [`Self::edge_margin`] of the bounds
+    /// and the value points towards that edge. Uses the same orientation as the action value
+    /// and has the same magnitude, which is convenient for panning a camera.
+    #[must_use]
+    pub fn edge_scroll(&self) -> Vec2 {
+        self.edge_scroll
+    }
+}
+
+impl Default for GamepadCursor {
+    fn default() -> Self {
+        Self {
+            speed: 600.0,
+            max_speed: 1500.0,
+            acceleration: 0.0,
+            edge_margin: 20.0,
+            bounds: None,
+            time_kind: Default::default(),
+            moving_secs: 0.0,
+            edge_scroll: Vec2::ZERO,
+        }
+    }
+}
+
+/// Presses a mouse button while the action is in [`TriggerState::Fired`].
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct GamepadCursorClick {
+    /// Button to press.
+    pub button: MouseButton,
+
+    pressed: bool,
+}
+
+impl GamepadCursorClick {
+    /// Creates a new instance that presses the given button.
+    #[must_use]
+    pub fn new(button: MouseButton) -> Self {
+        Self {
+            button,
+            pressed: false,
+        }
+    }
+}
+
+pub(crate) fn move_cursor(
+    time: ContextTime,
+    mut cursor_position: ResMut<CursorPosition>,
+    #[cfg(feature = "window")] mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut cursors: Query<(&mut GamepadCursor, &ActionValue)>,
+) {
+    for (mut cursor, &value) in &mut cursors {
+        let input = value.as_axis2d();
+        if input == Vec2::ZERO {
+            cursor.moving_secs = 0.0;
+            cursor.edge_scroll = Vec2::ZERO;
+            continue;
+        }
+
+        #[cfg(feature = "window")]
+        let window = windows.single_mut().ok();
+        #[cfg(feature = "window")]
+        let bounds = cursor.bounds.or_else(|| {
+            window
+                .as_ref()
+                .map(|w| Rect::new(0.0, 0.0, w.width(), w.height()))
+        });
+        #[cfg(not(feature = "window"))]
+        let bounds = cursor.bounds;
+
+        let delta = time.delta_kind(cursor.time_kind).as_secs_f32();
+        let start = cursor_position
+            .or_else(|| bounds.map(|b| b.center()))
+            .unwrap_or_default();
+        let speed = (cursor.speed + cursor.acceleration * cursor.moving_secs).min(cursor.max_speed);
+        cursor.moving_secs += delta;
+
+        // Screen coordinates grow downwards.
+        let mut position = start + Vec2::new(input.x, -input.y) * speed * delta;
+
+        cursor.edge_scroll = Vec2::ZERO;
+        if let Some(bounds) = bounds {
+            position = position.clamp(bounds.min, bounds.max);
+            let margin = cursor.edge_margin;
+            if (position.x <= bounds.min.x + margin && input.x < 0.0)
+                || (position.x >= bounds.max.x - margin && input.x > 0.0)
+            {
+                cursor.edge_scroll.x = input.x;
+            }
+            if (position.y <= bounds.min.y + margin && input.y > 0.0)
+                || (position.y >= bounds.max.y - margin && input.y < 0.0)
+            {
+                cursor.edge_scroll.y = input.y;
+            }
+        }
+
+        **cursor_position = Some(position);
+        #[cfg(feature = "window")]
+        if let Some(mut window) = window {
+            window.set_cursor_position(Some(position));
+        }
+    }
+}
+
+pub(crate) fn click_cursor(
+    mut mouse_input: MessageWriter<MouseButtonInput>,
+    #[cfg(feature = "window")] windows: Query<Entity, With<PrimaryWindow>>,
+    mut clicks: Query<(&mut GamepadCursorClick, &TriggerState)>,
+) {
+    #[cfg(feature = "window")]
+    let window = windows.single().unwrap_or(Entity::PLACEHOLDER);
+    #[cfg(not(feature = "window"))]
+    let window = Entity::PLACEHOLDER;
+
+    for (mut click, &state) in &mut clicks {
+        let pressed = state == TriggerState::Fired;
+        if click.pressed == pressed {
+            continue;
+        }
+
+        click.pressed = pressed;
+        mouse_input.write(MouseButtonInput {
+            button: click.button,
+            state: if pressed {
+                ButtonState::Pressed
+            } else {
+                ButtonState::Released
+            },
+            window,
+        });
+    }
+}
+
+/// Releases the button if [`GamepadCursorClick`] is removed while it's pressed.
+pub(crate) fn release_click(
+    remove: On<Remove, GamepadCursorClick>,
+    mut mouse_input: MessageWriter<MouseButtonInput>,
+    #[cfg(feature = "window")] windows: Query<Entity, With<PrimaryWindow>>,
+    clicks: Query<&GamepadCursorClick>,
+) {
+    let Ok(click) = clicks.get(remove.entity) else {
+        return;
+    };
+    if !click.pressed {
+        return;
+    }
+
+    #[cfg(feature = "window")]
+    let window = windows.single().unwrap_or(Entity::PLACEHOLDER);
+    #[cfg(not(feature = "window"))]
+    let window = Entity::PLACEHOLDER;
+
+    mouse_input.write(MouseButtonInput {
+        button: click.button,
+        state: ButtonState::Released,
+        window,
+    });
+}
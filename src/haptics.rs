@@ -0,0 +1,193 @@
+/*!
+Gamepad force feedback tied to the same device assignment as input.
+
+Trigger [`Rumble`] on a context entity to rumble its gamepads,
+or insert [`ActionRumble`] on an action to rumble automatically when specific [`ActionEvents`] occur.
+
+[`ActionRumble`] rumbles only the gamepad that triggered the action, see [`ActionBinding::gamepad`].
+Actions triggered by other devices don't rumble.
+
+For [`Rumble`], gamepads are resolved from [`GamepadDevice`] on the context entity,
+unless [`Rumble::gamepad`] is set:
+
+- [`GamepadDevice::Any`] rumbles all connected gamepads.
+- [`GamepadDevice::Single`] rumbles only the assigned gamepad.
+- [`GamepadDevice::None`] doesn't rumble.
+
+Requests are sent as [`GamepadRumbleRequest`] messages, so the actual output is handled by Bevy's gamepad backend.
+Each request has a constant intensity for its whole duration. Envelopes, like attack and decay, aren't supported:
+[`GamepadRumbleRequest::Add`] stacks with other active effects on the gamepad, so changing the intensity over time
+would require stopping all of them.
+
+# Examples
+
+```
+use bevy::{input::gamepad::GamepadRumbleIntensity, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+# let mut app = App::new();
+app.add_observer(land);
+
+app.world_mut().spawn((
+    Player,
+    actions!(Player[
+        (
+            Action::<Fire>::new(),
+            // Rumble each time the action starts.
+            ActionRumble::new(GamepadRumbleIntensity::weak_motor(0.5), 0.1),
+            bindings![GamepadButton::RightTrigger2],
+        ),
+        (Action::<Jump>::new(), bindings![GamepadButton::South]),
+    ]),
+));
+
+/// Rumble manually from game logic.
+fn land(complete: On<Complete<Jump>>, mut commands: Commands) {
+    commands.trigger(Rumble::new(
+        complete.context,
+        GamepadRumbleIntensity::STRONG_MAX,
+        0.2,
+    ));
+}
+```
+*/
+
+use core::time::Duration;
+
+use bevy::{
+    input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest},
+    prelude::*,
+};
+use log::{debug, warn};
+
+use crate::prelude::*;
+
+/// Rumbles gamepads associated with the context entity.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct Rumble {
+    /// Entity with the context component on which this event was triggered.
+    #[event_target]
+    pub context: Entity,
+
+    /// Rumble intensity.
+    pub intensity: GamepadRumbleIntensity,
+
+    /// Rumble duration in seconds.
+    pub duration: f32,
+
+    /// Gamepad to rumble instead of the ones resolved from [`GamepadDevice`].
+    pub gamepad: Option<Entity>,
+}
+
+impl Rumble {
+    /// Creates a new instance that rumbles gamepads resolved from [`GamepadDevice`].
+    #[must_use]
+    pub fn new(context: Entity, intensity: GamepadRumbleIntensity, duration: f32) -> Self {
+        Self {
+            context,
+            intensity,
+            duration,
+            gamepad: None,
+        }
+    }
+
+    /// Sets [`Self::gamepad`].
+    #[must_use]
+    pub fn with_gamepad(mut self, gamepad: Entity) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+}
+
+/// Triggers [`Rumble`] on the context entity when the action has any of [`Self::events`].
+///
+/// Rumbles only the gamepad that triggered the action, see [`ActionBinding::gamepad`].
+///
+/// Should be inserted on an action entity.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct ActionRumble {
+    /// Rumble intensity.
+    pub intensity: GamepadRumbleIntensity,
+
+    /// Rumble duration in seconds.
+    pub duration: f32,
+
+    /// Events that trigger the rumble.
+    pub events: ActionEvents,
+}
+
+impl ActionRumble {
+    /// Creates a new instance with [`Self::events`] set to [`ActionEvents::START`].
+    #[must_use]
+    pub fn new(intensity: GamepadRumbleIntensity, duration: f32) -> Self {
+        Self {
+            intensity,
+            duration,
+            events: ActionEvents::START,
+        }
+    }
+
+    /// Sets [`Self::events`].
+    #[must_use]
+    pub fn with_events(mut self, events: ActionEvents) -> Self {
+        self.events = events;
+        self
+    }
+}
+
+pub(crate) fn rumble(
+    rumble: On<Rumble>,
+    devices: Query<&GamepadDevice>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    let duration = match Duration::try_from_secs_f32(rumble.duration) {
+        Ok(duration) => duration,
+        Err(e) => {
+            warn!(
+                "ignoring rumble for `{}` with invalid duration `{}`: {e}",
+                rumble.context, rumble.duration
+            );
+            return;
+        }
+    };
+
+    let device = match rumble.gamepad {
+        Some(gamepad) => GamepadDevice::Single(gamepad),
+        None => devices.get(rumble.context).copied().unwrap_or_default(),
+    };
+    debug!("rumbling `{device:?}` for `{}`", rumble.context);
+    match device {
+        GamepadDevice::Any => {
+            for gamepad in &gamepads {
+                requests.write(GamepadRumbleRequest::Add {
+                    duration,
+                    intensity: rumble.intensity,
+                    gamepad,
+                });
+            }
+        }
+        GamepadDevice::Single(gamepad) => {
+            requests.write(GamepadRumbleRequest::Add {
+                duration,
+                intensity: rumble.intensity,
+                gamepad,
+            });
+        }
+        GamepadDevice::None => (),
+    }
+}
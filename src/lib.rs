@@ -47,7 +47,13 @@ Bindings associated with actions via [`BindingOf`] relationship. Similar to [`ac
 we provide the [`bindings!`] macro to spawn related bindings.
 
 By default, input is read from all connected gamepads. You can customize this by adding the [`GamepadDevice`] component to the
-context entity.
+context entity. For local multiplayer, [`AwaitingGamepad`] can be used to assign gamepads to players as they connect
+and [`GamepadFallback`] to handle disconnects. Bindings marked with [`FallbackBinding`] are used only while the assigned
+gamepad is disconnected. The same assignment is used for [haptics].
+Contexts are also notified about battery and capability changes of their gamepad, see [`gamepad_status`](context::gamepad_status).
+On platforms that expose individual keyboards and mice, contexts can be bound to them with [`KeyboardDevice`]
+and [`MouseDevice`], see [`seat`](context::seat).
+To navigate pointer-based menus with a gamepad, see [`gamepad_cursor`].
 
 ## [Contexts](context)
 
@@ -61,6 +67,7 @@ Contexts are stored using regular components, commonly on an entity for which th
 Contexts can be activated or deactivated using the [`ContextActivity`] component.
 By default, contexts are active when the component is present.
 When active, all actions associated with the context are evaluated.
+To toggle all contexts on an entity at once, use [`InputActivity`].
 
 By default, contexts are evaluated in reverse spawn order, meaning the most recently spawned context is evaluated first.
 This behavior can be controlled with [`ContextPriority`].
@@ -314,11 +321,59 @@ Each of these is complex to deserve their own section:
 - [mocking](crate::action::mock) for simulating input in tests, cutscenes or as part of replicated network state
 - [the details of working with contexts](crate::context) (e.g. managing multiple players or gameplay states)
 
+# Scenes
+
+Components for actions, bindings, modifiers and conditions implement `Reflect` (behind the `reflect` feature),
+so contexts with their actions, bindings, modifiers and conditions can be declared in scene files. Actions, bindings and
+modifiers are regular entities, connected using [`ActionOf<C>`] and [`BindingOf`] relationships.
+Scenes don't run relationship hooks, so [`Actions<C>`] and [`Bindings`] need to be listed as well,
+and [`BindingOf`] should come before [`Binding`] to avoid a warning about a binding without an action:
+
+```ron
+(
+  resources: {},
+  entities: {
+    4294967295: (
+      components: {
+        "my_game::Player": (),
+        "bevy_enhanced_input::action::relationship::Actions<my_game::Player>": (entities: [4294967294]),
+      },
+    ),
+    4294967294: (
+      components: {
+        "bevy_enhanced_input::action::Action<my_game::Jump>": (false),
+        "bevy_enhanced_input::action::relationship::ActionOf<my_game::Player>": (entity: 4294967295),
+        "bevy_enhanced_input::binding::relationship::Bindings": ([4294967293]),
+        "bevy_enhanced_input::condition::press::Press": (actuation: 0.5),
+      },
+    ),
+    4294967293: (
+      components: {
+        "bevy_enhanced_input::binding::relationship::BindingOf": (4294967294),
+        "bevy_enhanced_input::binding::Binding": Keyboard(key: Space, mod_keys: ""),
+      },
+    ),
+  },
+)
+```
+
+Since [`Action<C>`] is generic, it's registered for reflection only after the first insertion.
+Register your actions with `app.register_type::<Action<Jump>>()` to load them from scenes.
+Built-in modifiers, conditions and binding components are registered by [`EnhancedInputPlugin`],
+so they can also be inspected and tweaked at runtime with reflection-based tools like `bevy-inspector-egui`.
+
+Invalid hierarchies, such as bindings without an action, are reported as warnings when spawned.
+Modifiers and conditions attached to an entity that is neither an action nor a binding are reported
+at the end of the frame, so components can be inserted in any order.
+
 # Input and UI
 
 Currently, we don't integrate `bevy_input_focus` directly. But we provide [`ActionSources`] resource
 that could be used to prevents actions from triggering during UI interactions. See its docs for details.
 
+With the `egui` feature, keyboard and mouse input is blocked for actions while `egui` uses it.
+See `EguiPriority` for details.
+
 # Troubleshooting
 
 If you face any issue, try to enable logging to see what is going on.
@@ -345,63 +400,150 @@ extern crate self as bevy_enhanced_input;
 
 pub mod action;
 pub mod binding;
+pub mod cheat_sheet;
 pub mod condition;
 pub mod context;
+pub mod gamepad_cursor;
+pub mod haptics;
 pub mod modifier;
+#[cfg(feature = "ui")]
+pub mod overlay;
 pub mod preset;
+pub mod recording;
 #[cfg(feature = "state")]
 pub mod state;
+#[cfg(feature = "ui")]
+pub mod virtual_gamepad;
 
 pub mod prelude {
+    #[cfg(feature = "asset")]
+    pub use super::binding::asset::{ContextBindings, InputBindings, InputBindingsLoader};
+    #[cfg(feature = "macro_pad")]
+    pub use super::binding::macro_pad::{MacroPadGlyphs, MacroPadKey, MacroPadKeys};
+    #[cfg(feature = "window")]
+    pub use super::context::cursor::ContextCursor;
+    #[cfg(feature = "egui")]
+    pub use super::context::egui_priority::EguiPriority;
+    #[cfg(feature = "camera")]
+    pub use super::modifier::screen_to_world::ScreenToWorld;
+    #[cfg(feature = "debug")]
+    pub use super::overlay::InputDebugOverlay;
     #[cfg(feature = "state")]
     pub use super::state::{ActiveInStates, StateContextAppExt};
     pub use super::{
         EnhancedInputPlugin, EnhancedInputSystems,
         action::{
-            Accumulation, Action, ActionOutput, ActionSettings, ActionTime, InputAction,
-            TriggerState,
+            Accumulation, Action, ActionBinding, ActionOutput, ActionSettings, ActionTime,
+            InputAction, TriggerState,
+            conflicts::{BindingConflict, ConflictSeverity},
+            dynamic::{DynAction, DynActionEvent},
             events::*,
+            group::ActionGroup,
+            history::{ActionHistory, HistoryEntry, NumpadDirection},
+            inject::{InjectEntityCommandsExt, InjectEntityWorldMutExt, InjectedValue},
             mock::{ActionMock, MockEntityCommandsExt, MockEntityWorldMutExt, MockSpan},
+            monitor::{AnomalyKind, InputAnomaly, InputMonitor},
             relationship::{ActionOf, ActionSpawner, ActionSpawnerCommands, Actions},
+            timeline::ActionTimelineExt,
             value::{ActionValue, ActionValueDim},
+            watchdog::{ActionStuck, ActionWatchdog},
         },
         actions,
         binding::{
-            Binding, InputModKeys,
+            Binding, InputDevice, InputModKeys,
+            capture::InputCapture,
+            default_profile::{
+                DefaultProfileAppExt, DefaultProfileSelector, DeviceProfile, FirstInputSelector,
+                InputHardware, KeyboardLayout, SelectedProfile,
+            },
+            display_map::{BindingDisplay, InputDisplayMap},
+            fallback::{FallbackActive, FallbackBinding, FallbackChanged},
+            glyphs::{GamepadGlyphs, GamepadKind, Glyph},
+            group::{BindingGroup, GroupAsymmetry},
+            history::BindingHistory,
+            lock_keys::{LockKey, LockKeys},
+            mock_input::MockInputExt,
             mod_keys::ModKeys,
+            mouse_drag::MouseDrags,
+            patch::{BindingChange, BindingPatch, BindingSet, PatchError},
+            rebind::{RebindEntityCommandsExt, RebindEntityWorldMutExt},
             relationship::{
                 BindingOf, BindingSpawner, BindingSpawnerCommands, Bindings, IntoBindingBundle,
             },
+            scroll_steps::{ScrollDirection, ScrollSteps},
+            virtual_inputs::{VirtualInput, VirtualInputAppExt},
         },
         bindings,
+        cheat_sheet::{CheatSheet, CheatSheetAction, CheatSheetBinding, CheatSheets},
         condition::{
-            ConditionKind, InputCondition, block_by::*, chord::*, combo::*, cooldown::*, down::*,
-            fns::InputConditionAppExt, hold::*, hold_and_release::*, press::*, pulse::*,
-            release::*, tap::*, toggle::*,
+            ConditionKind, InputCondition, block_by::*, buffer::*, chord::*, combo::*, cooldown::*,
+            down::*, fns::InputConditionAppExt, gesture::*, hold::*, hold_and_release::*, layer::*,
+            lock_state::*, press::*, pressure_threshold::*, pulse::*, release::*, speed::*,
+            step::*, tap::*, toggle::*,
         },
         context::{
-            ActionsQuery, ContextActivity, ContextPriority, GamepadDevice, InputContextAppExt,
-            input_reader::ActionSources,
-            time::{ContextTime, TimeKind},
+            ActionsQuery, AwaitingGamepad, ContextActivity, ContextPriority, ContextSources,
+            GamepadDevice, GamepadDisconnected, GamepadFallback, GamepadReconnected, InputActivity,
+            InputContextAppExt, InputDeviceChanged, LastInputDevice, ModKeysDisambiguation,
+            diagnostics::InputDiagnosticsPlugin,
+            fixed_rate::{InputFixedRate, InputFixedUpdate},
+            gamepad_status::{GamepadBattery, GamepadCapabilities, GamepadStatusChanged},
+            info::{ContextInfo, ContextInfos},
+            input_buffer::FixedInputBuffer,
+            input_reader::{ActionSources, CursorPosition},
+            latency::{InputLatency, LatencySource},
+            pause::EnhancedInput,
+            seat::{KeyboardDevice, MouseDevice, SeatKeyboard, SeatMouse},
+            stats::{ContextStat, ContextStats},
+            switch::{
+                ContextSwitchAppExt, ContextSwitchTimer, ContextSwitched, SwitchEntityCommandsExt,
+                SwitchEntityWorldMutExt,
+            },
+            time::{ContextTime, MaxInputDelta, TimeKind},
         },
+        gamepad_cursor::{GamepadCursor, GamepadCursorClick},
+        haptics::{ActionRumble, Rumble},
         modifier::{
-            InputModifier, accumulate_by::*, clamp::*, dead_zone::*, delta_scale::*,
-            exponential_curve::*, fns::InputModifierAppExt, linear_step::*, negate::*, scale::*,
-            smooth_nudge::*, swizzle_axis::*,
+            InputModifier, accumulate_by::*, axis_calibration::*, axis_lock::*, clamp::*,
+            clamp_length::*, dead_zone::*, debounce::*, delta_scale::*, detent::*, digitize::*,
+            exponential_curve::*, fns::InputModifierAppExt, global_settings::*, linear_step::*,
+            moving_average::*, negate::*, ramp::*, scale::*, smooth_nudge::*, swizzle_axis::*,
+        },
+        preset::{
+            WithBundle, axial::*, bidirectional::*, cardinal::*, ordinal::*, six_dof::*,
+            spatial::*, twin_stick::*,
         },
-        preset::{WithBundle, axial::*, bidirectional::*, cardinal::*, ordinal::*, spatial::*},
+        recording::{GamepadFrame, InputFrame, InputPlayback, InputRecorder, InputRecording},
+    };
+    #[cfg(feature = "voice")]
+    pub use super::{
+        binding::voice::{RecognizedPhrase, VoiceAppExt},
+        modifier::min_confidence::MinConfidence,
+    };
+    #[cfg(feature = "ui")]
+    pub use super::{
+        overlay::InputOverlay,
+        virtual_gamepad::{VirtualButton, VirtualGamepad, VirtualStick},
     };
     pub use bevy_enhanced_input_macros::InputAction;
 }
 
-use bevy::{input::InputSystems, prelude::*};
+use bevy::{
+    input::{InputSystems, mouse::MouseButtonInput},
+    prelude::*,
+};
 
-use condition::fns::ConditionRegistry;
+use binding::{
+    capture::CapturedInputs, logical_keys::LogicalKeys, media_keys::LogicalMediaKeys,
+    ui_buttons::PressedUiButtons, virtual_inputs::VirtualInputs,
+};
+use condition::fns::{ConditionRegistry, UnattachedConditions};
 use context::{
-    ContextRegistry,
+    ContextRegistry, fixed_rate, input_buffer,
     input_reader::{self, ConsumedInputs, PendingBindings},
+    pause::InputPause,
 };
-use modifier::fns::ModifierRegistry;
+use modifier::fns::{ModifierRegistry, UnattachedModifiers};
 use prelude::{Press, Release, *};
 
 /// Initializes contexts and feeds inputs to them.
@@ -413,32 +555,77 @@ impl Plugin for EnhancedInputPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<ContextRegistry>()
             .init_resource::<ConsumedInputs>()
+            .init_resource::<InputPause>()
             .init_resource::<PendingBindings>()
             .init_resource::<ActionSources>()
+            .init_resource::<CursorPosition>()
+            .init_resource::<ContextInfos>()
             .init_resource::<ConditionRegistry>()
             .init_resource::<ModifierRegistry>()
+            .init_resource::<UnattachedConditions>()
+            .init_resource::<UnattachedModifiers>()
+            .init_resource::<MaxInputDelta>()
+            .init_resource::<GlobalInputSettings>()
+            .init_resource::<InputDisplayMap>()
+            .init_resource::<GamepadGlyphs>()
+            .init_resource::<LockKeys>()
+            .init_resource::<LogicalKeys>()
+            .init_resource::<CapturedInputs>()
+            .init_resource::<LogicalMediaKeys>()
+            .init_resource::<ScrollSteps>()
+            .init_resource::<MouseDrags>()
+            .init_resource::<PressedUiButtons>()
+            .init_resource::<VirtualInputs>()
+            .init_resource::<InputHardware>()
             .add_input_condition::<BlockBy>()
+            .add_input_condition::<Buffer>()
             .add_input_condition::<Chord>()
             .add_input_condition::<Combo>()
             .add_input_condition::<Down>()
+            .add_input_condition::<Gesture>()
             .add_input_condition::<Hold>()
             .add_input_condition::<HoldAndRelease>()
+            .add_input_condition::<Layer>()
+            .add_input_condition::<LockState>()
             .add_input_condition::<Press>()
+            .add_input_condition::<PressureThreshold>()
             .add_input_condition::<Pulse>()
             .add_input_condition::<Release>()
+            .add_input_condition::<Speed>()
+            .add_input_condition::<Step>()
             .add_input_condition::<Tap>()
             .add_input_condition::<Cooldown>()
             .add_input_condition::<Toggle>()
             .add_input_modifier::<AccumulateBy>()
+            .add_input_modifier::<AxisCalibration>()
+            .add_input_modifier::<AxisLock>()
             .add_input_modifier::<Clamp>()
+            .add_input_modifier::<ClampLength>()
             .add_input_modifier::<DeadZone>()
+            .add_input_modifier::<Debounce>()
             .add_input_modifier::<DeltaScale>()
+            .add_input_modifier::<Detent>()
+            .add_input_modifier::<Digitize>()
             .add_input_modifier::<ExponentialCurve>()
             .add_input_modifier::<LinearStep>()
+            .add_input_modifier::<InvertY>()
+            .add_input_modifier::<MouseSensitivity>()
+            .add_input_modifier::<MovingAverage>()
             .add_input_modifier::<Negate>()
+            .add_input_modifier::<Ramp>()
             .add_input_modifier::<Scale>()
             .add_input_modifier::<SmoothNudge>()
+            .add_input_modifier::<StickDeadZone>()
             .add_input_modifier::<SwizzleAxis>()
+            .add_observer(context::handle_connected_gamepad)
+            .add_observer(binding::glyphs::detect_kind)
+            .add_observer(context::handle_disconnected_gamepad)
+            .add_observer(context::gamepad_status::battery_changed)
+            .add_observer(context::gamepad_status::capabilities_changed)
+            .add_observer(haptics::rumble)
+            .add_observer(action::watchdog::forget_action)
+            .add_observer(gamepad_cursor::release_click)
+            .add_observer(context::assign_awaiting_gamepad)
             .configure_sets(
                 PreUpdate,
                 (EnhancedInputSystems::Prepare, EnhancedInputSystems::Update)
@@ -447,8 +634,178 @@ impl Plugin for EnhancedInputPlugin {
             )
             .add_systems(
                 PreUpdate,
-                input_reader::update_pending.in_set(EnhancedInputSystems::Prepare),
+                (
+                    recording::playback.run_if(resource_exists::<InputPlayback>),
+                    recording::record.run_if(resource_exists::<InputRecorder>),
+                    binding::scroll_steps::update_scroll_steps,
+                    binding::mouse_drag::update_mouse_drags,
+                    binding::default_profile::update_hardware,
+                    input_buffer::buffer_input.run_if(resource_exists::<FixedInputBuffer>),
+                    binding::lock_keys::update_lock_keys,
+                    binding::media_keys::update_media_keys,
+                    binding::logical_keys::update_logical_keys,
+                    binding::virtual_inputs::update_virtual_inputs,
+                    binding::capture::capture_inputs,
+                    input_reader::update_pending,
+                    context::latency::insert_delay_lines.run_if(resource_exists::<InputLatency>),
+                    modifier::global_settings::calibrate_mouse
+                        .run_if(resource_exists::<MouseCalibration>),
+                    modifier::axis_calibration::calibrate_gamepads
+                        .run_if(resource_exists::<GamepadCalibration>),
+                    modifier::global_settings::update_settings,
+                    condition::lock_state::update_lock_states,
+                )
+                    .chain()
+                    .in_set(EnhancedInputSystems::Prepare),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    gamepad_cursor::move_cursor,
+                    gamepad_cursor::click_cursor
+                        .run_if(resource_exists::<Messages<MouseButtonInput>>),
+                )
+                    .after(EnhancedInputSystems::Apply),
+            )
+            .add_systems(
+                PreUpdate,
+                fixed_rate::run_fixed_rate
+                    .run_if(resource_exists::<InputFixedRate>)
+                    .after(EnhancedInputSystems::Apply),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                input_buffer::clear_buffer
+                    .run_if(resource_exists::<FixedInputBuffer>)
+                    .after(EnhancedInputSystems::Apply),
+            )
+            .add_systems(
+                InputFixedUpdate,
+                input_buffer::clear_buffer
+                    .run_if(resource_exists::<FixedInputBuffer>)
+                    .after(EnhancedInputSystems::Apply),
+            )
+            .add_systems(
+                Last,
+                (
+                    condition::fns::warn_unattached,
+                    modifier::fns::warn_unattached,
+                ),
+            );
+
+        #[cfg(feature = "reflect")]
+        app.register_type::<Binding>()
+            .register_type::<BindingOf>()
+            .register_type::<Bindings>()
+            .register_type::<ActionSettings>()
+            .register_type::<ActionValue>()
+            .register_type::<TriggerState>()
+            .register_type::<ActionEvents>()
+            .register_type::<ActionTime>()
+            .register_type::<ActionMock>()
+            .register_type::<ActionGroup>()
+            .register_type::<BindingGroup>()
+            .register_type::<GamepadDevice>()
+            .register_type::<KeyboardDevice>()
+            .register_type::<MouseDevice>()
+            .register_type::<SeatKeyboard>()
+            .register_type::<SeatMouse>()
+            .register_type::<NormalizedDirection>()
+            .register_type::<BlockBy>()
+            .register_type::<Buffer>()
+            .register_type::<Chord>()
+            .register_type::<Combo>()
+            .register_type::<Cooldown>()
+            .register_type::<Down>()
+            .register_type::<Gesture>()
+            .register_type::<Hold>()
+            .register_type::<HoldAndRelease>()
+            .register_type::<Layer>()
+            .register_type::<LockState>()
+            .register_type::<Press>()
+            .register_type::<PressureThreshold>()
+            .register_type::<Pulse>()
+            .register_type::<Release>()
+            .register_type::<Speed>()
+            .register_type::<Step>()
+            .register_type::<Tap>()
+            .register_type::<Toggle>()
+            .register_type::<AccumulateBy>()
+            .register_type::<AxisCalibration>()
+            .register_type::<AxisLock>()
+            .register_type::<Clamp>()
+            .register_type::<ClampLength>()
+            .register_type::<DeadZone>()
+            .register_type::<Debounce>()
+            .register_type::<DeltaScale>()
+            .register_type::<Detent>()
+            .register_type::<Digitize>()
+            .register_type::<ExponentialCurve>()
+            .register_type::<InvertY>()
+            .register_type::<LinearStep>()
+            .register_type::<MouseSensitivity>()
+            .register_type::<MovingAverage>()
+            .register_type::<Negate>()
+            .register_type::<Ramp>()
+            .register_type::<Scale>()
+            .register_type::<SmoothNudge>()
+            .register_type::<StickDeadZone>()
+            .register_type::<SwizzleAxis>();
+
+        // Requires `AssetPlugin`, so the plugin can still be used without it, like in tests.
+        #[cfg(feature = "asset")]
+        if app.world().contains_resource::<bevy::asset::AssetServer>() {
+            app.init_asset::<InputBindings>()
+                .init_asset_loader::<InputBindingsLoader>();
+        }
+
+        #[cfg(feature = "window")]
+        app.init_resource::<context::cursor::ContextCursors>()
+            .add_systems(
+                PreUpdate,
+                input_reader::update_cursor_position.in_set(EnhancedInputSystems::Prepare),
+            )
+            .add_systems(
+                PostUpdate,
+                context::cursor::apply_cursor.after(context::cursor::RequestCursor),
             );
+
+        #[cfg(feature = "ui")]
+        app.add_systems(
+            PreUpdate,
+            (
+                binding::ui_buttons::update_ui_buttons,
+                virtual_gamepad::update_virtual_gamepad,
+            )
+                .after(bevy::ui::UiSystems::Focus)
+                .before(input_reader::update_pending)
+                .in_set(EnhancedInputSystems::Prepare),
+        );
+        #[cfg(feature = "debug")]
+        app.add_systems(PostUpdate, overlay::update_debug_overlays);
+
+        #[cfg(feature = "egui")]
+        app.init_resource::<EguiPriority>();
+        #[cfg(all(feature = "egui", feature = "reflect"))]
+        app.register_type::<EguiPriority>();
+
+        #[cfg(feature = "macro_pad")]
+        app.init_resource::<MacroPadKeys>()
+            .init_resource::<MacroPadGlyphs>();
+
+        #[cfg(feature = "voice")]
+        app.add_input_modifier::<MinConfidence>();
+        #[cfg(all(feature = "voice", feature = "reflect"))]
+        app.register_type::<MinConfidence>();
+
+        #[cfg(all(feature = "camera", feature = "reflect"))]
+        app.register_type::<ScreenToWorld>();
+
+        #[cfg(feature = "camera")]
+        app.add_input_modifier::<ScreenToWorld>().add_systems(
+            PreUpdate,
+            modifier::screen_to_world::update_cameras.in_set(EnhancedInputSystems::Prepare),
+        );
     }
 
     fn finish(&self, app: &mut App) {
@@ -476,7 +833,7 @@ impl Plugin for EnhancedInputPlugin {
 /// Label for the system that updates input context instances.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub enum EnhancedInputSystems {
-    /// Updates list of pending inputs to ignore.
+    /// Updates list of pending inputs to ignore and runs [input recording and playback](recording).
     ///
     /// Runs in [`PreUpdate`].
     Prepare,
@@ -62,14 +62,27 @@ world.spawn((
 */
 
 pub mod accumulate_by;
+pub mod axis_calibration;
+pub mod axis_lock;
 pub mod clamp;
+pub mod clamp_length;
 pub mod dead_zone;
+pub mod debounce;
 pub mod delta_scale;
+pub mod detent;
+pub mod digitize;
 pub mod exponential_curve;
 pub mod fns;
+pub mod global_settings;
 pub mod linear_step;
+#[cfg(feature = "voice")]
+pub mod min_confidence;
+pub mod moving_average;
 pub mod negate;
+pub mod ramp;
 pub mod scale;
+#[cfg(feature = "camera")]
+pub mod screen_to_world;
 pub mod smooth_nudge;
 pub mod swizzle_axis;
 
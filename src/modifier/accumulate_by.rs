@@ -8,6 +8,10 @@ use crate::prelude::*;
 /// Continuously adds input values together as long as action `A` is [`TriggerState::Fired`].
 /// When the action is inactive, it resets the accumulation with the current frame's input value.
 ///
+/// Optionally, the accumulated value can drain to zero when the input stays at zero
+/// for some time while accumulating, see [`Self::with_decay`].
+/// Useful to automatically reset stale offsets or meters.
+///
 /// # Examples
 ///
 /// To get action entities during spawning, you could use [`SpawnWith`].
@@ -43,14 +47,37 @@ use crate::prelude::*;
 /// #[action_output(f32)]
 /// struct Movement;
 /// ```
+///
+/// Drain the accumulated value by 2 units per second after 0.5 seconds of no input:
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_enhanced_input::prelude::*;
+/// # let accelerate = Entity::PLACEHOLDER;
+/// let modifier = AccumulateBy::new(accelerate).with_decay(0.5, 2.0);
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
 pub struct AccumulateBy {
     /// Action that activates accumulation.
     action: Entity,
 
+    /// Time in seconds without input before the accumulated value starts to decay.
+    pub decay_delay: f32,
+
+    /// Speed in units per second at which the accumulated value decays to zero.
+    ///
+    /// Decay is disabled when set to zero, which is the default.
+    pub decay_rate: f32,
+
+    /// The type of time used to advance the idle timer and decay.
+    pub time_kind: TimeKind,
+
     /// The accumulated value across frames.
     value: Vec3,
+
+    /// Time in seconds since the last non-zero input during accumulation.
+    idle_secs: f32,
 }
 
 impl AccumulateBy {
@@ -58,7 +85,40 @@ impl AccumulateBy {
     pub const fn new(action: Entity) -> Self {
         Self {
             action,
+            decay_delay: 0.0,
+            decay_rate: 0.0,
+            time_kind: TimeKind::Real,
             value: Vec3::ZERO,
+            idle_secs: 0.0,
+        }
+    }
+
+    /// Sets [`Self::decay_delay`] and [`Self::decay_rate`].
+    #[must_use]
+    pub const fn with_decay(mut self, delay: f32, rate: f32) -> Self {
+        self.decay_delay = delay;
+        self.decay_rate = rate;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    fn decay(&mut self, delta_secs: f32) {
+        self.idle_secs += delta_secs;
+        if self.decay_rate <= 0.0 || self.idle_secs < self.decay_delay {
+            return;
+        }
+
+        let step = self.decay_rate * delta_secs;
+        let length = self.value.length();
+        if length <= step {
+            self.value = Vec3::ZERO;
+        } else {
+            self.value -= self.value / length * step;
         }
     }
 }
@@ -67,13 +127,20 @@ impl InputModifier for AccumulateBy {
     fn transform(
         &mut self,
         actions: &ActionsQuery,
-        _time: &ContextTime,
+        time: &ContextTime,
         value: ActionValue,
     ) -> ActionValue {
         if let Ok((_, &state, ..)) = actions.get(self.action) {
             if state == TriggerState::Fired {
-                self.value += value.as_axis3d();
+                let value = value.as_axis3d();
+                if value == Vec3::ZERO {
+                    self.decay(time.delta_kind(self.time_kind).as_secs_f32());
+                } else {
+                    self.idle_secs = 0.0;
+                    self.value += value;
+                }
             } else {
+                self.idle_secs = 0.0;
                 self.value = value.as_axis3d();
             }
             ActionValue::Axis3D(self.value).convert(value.dim())
@@ -87,6 +154,8 @@ impl InputModifier for AccumulateBy {
 
 #[cfg(test)]
 mod tests {
+    use core::time::Duration;
+
     use bevy_enhanced_input_macros::InputAction;
 
     use super::*;
@@ -120,6 +189,41 @@ mod tests {
         assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
     }
 
+    #[test]
+    fn decay() {
+        let (mut world, mut state) = context::init_world();
+        let action = world
+            .spawn((
+                Action::<Test>::new(),
+                TriggerState::Fired,
+                ActionValue::from(true),
+            ))
+            .id();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(500));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AccumulateBy::new(action).with_decay(1.0, 2.0);
+        assert_eq!(modifier.transform(&actions, &time, 3.0.into()), 3.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.0.into()),
+            3.0.into(),
+            "shouldn't decay before the delay"
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 2.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 2.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.0.into()),
+            2.0.into(),
+            "input should reset the delay"
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+    }
+
     #[test]
     fn missing_action() {
         let (world, mut state) = context::init_world();
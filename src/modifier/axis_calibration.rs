@@ -0,0 +1,253 @@
+//! Correction of off-center or short-throw hardware axes.
+//!
+//! Also contains [`GamepadCalibration`] to measure the ranges for the modifier.
+
+use bevy::{platform::collections::HashMap, prelude::*};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Remaps raw axis values using the measured minimum, center and maximum of the hardware axis.
+///
+/// Values from [`Self::center`] to [`Self::max`] are remapped from 0 to 1 and values from
+/// [`Self::min`] to [`Self::center`] from -1 to 0. Values outside of the range are clamped.
+/// For single-direction axes, like triggers or pedals, set the center equal to the minimum,
+/// so the resting position maps to 0.
+///
+/// Applied to each axis of the value independently, so use a separate binding for each
+/// hardware axis with its own calibration. Should be placed before [`DeadZone`], since dead
+/// zones expect the resting position at 0 and full deflection at 1.
+///
+/// The values can be measured with [`GamepadCalibration`] and stored in the player settings.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// A pedal that rests at 0.1 and reaches only 0.9 when fully pressed:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Car[(
+///     Action::<Brake>::new(),
+///     bindings![(GamepadAxis::LeftZ, AxisCalibration::new(0.1, 0.1, 0.9))],
+/// )]);
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct Brake;
+///
+/// #[derive(Component)]
+/// struct Car;
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct AxisCalibration {
+    /// Raw value at the full negative deflection.
+    pub min: f32,
+
+    /// Raw value at the resting position.
+    pub center: f32,
+
+    /// Raw value at the full positive deflection.
+    pub max: f32,
+}
+
+impl AxisCalibration {
+    #[must_use]
+    pub const fn new(min: f32, center: f32, max: f32) -> Self {
+        Self { min, center, max }
+    }
+
+    fn remap(self, value: f32) -> f32 {
+        let offset = value - self.center;
+        let range = if offset >= 0.0 {
+            self.max - self.center
+        } else {
+            self.center - self.min
+        };
+
+        if range > 0.0 {
+            (offset / range).clamp(-1.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for AxisCalibration {
+    /// Creates a new instance that doesn't change values in the range from -1 to 1.
+    fn default() -> Self {
+        Self::new(-1.0, 0.0, 1.0)
+    }
+}
+
+impl InputModifier for AxisCalibration {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(value) => {
+                let value = if value { 1.0 } else { 0.0 };
+                self.remap(value).into()
+            }
+            ActionValue::Axis1D(value) => self.remap(value).into(),
+            ActionValue::Axis2D(value) => value.map(|axis| self.remap(axis)).into(),
+            ActionValue::Axis3D(value) => value.map(|axis| self.remap(axis)).into(),
+        }
+    }
+}
+
+/// Measures the ranges of gamepad axes for [`AxisCalibration`].
+///
+/// Insert this resource to start the calibration, ask the player to leave all controls at rest
+/// and then move each axis to its extremes. The first value read from each axis is recorded as
+/// its center, so the controls should be at rest when the resource is inserted.
+/// Axes are read during [`EnhancedInputSystems::Prepare`] while the resource exists.
+/// Remove the resource to stop.
+///
+/// Values are read after Bevy's [`GamepadSettings`], so for accurate measurements
+/// its dead zones should be disabled for the calibrated axes.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn start_calibration(mut commands: Commands) {
+///     // Show a prompt to move all axes to their extremes.
+///     commands.init_resource::<GamepadCalibration>();
+/// }
+///
+/// fn finish_calibration(
+///     mut commands: Commands,
+///     calibration: Res<GamepadCalibration>,
+///     gamepad: Single<Entity, With<Gamepad>>,
+///     mut bindings: Query<(&Binding, &mut AxisCalibration)>,
+/// ) {
+///     for (&binding, mut axis_calibration) in &mut bindings {
+///         if let Binding::GamepadAxis(axis) = binding
+///             && let Some(measured) = calibration.get(*gamepad, axis)
+///         {
+///             *axis_calibration = measured;
+///         }
+///     }
+///     commands.remove_resource::<GamepadCalibration>();
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct GamepadCalibration {
+    axes: HashMap<(Entity, GamepadAxis), AxisCalibration>,
+}
+
+impl GamepadCalibration {
+    /// Returns the measured range of the axis on the gamepad entity.
+    ///
+    /// Returns [`None`] if the axis didn't report any value since the calibration started.
+    #[must_use]
+    pub fn get(&self, gamepad: Entity, axis: GamepadAxis) -> Option<AxisCalibration> {
+        self.axes.get(&(gamepad, axis)).copied()
+    }
+
+    /// Returns all measured axes with their gamepad entities.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, GamepadAxis, AxisCalibration)> + '_ {
+        self.axes
+            .iter()
+            .map(|(&(gamepad, axis), &calibration)| (gamepad, axis, calibration))
+    }
+}
+
+pub(crate) fn calibrate_gamepads(
+    mut calibration: ResMut<GamepadCalibration>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    for (entity, gamepad) in &gamepads {
+        for axis in GamepadAxis::all() {
+            let Some(value) = gamepad.get(axis) else {
+                continue;
+            };
+
+            calibration
+                .axes
+                .entry((entity, axis))
+                .and_modify(|range| {
+                    range.min = range.min.min(value);
+                    range.max = range.max.max(value);
+                })
+                .or_insert(AxisCalibration::new(value, value, value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn centered() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisCalibration::new(-0.75, 0.25, 0.75);
+        assert_eq!(modifier.transform(&actions, &time, 0.25.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.75.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.75).into()),
+            (-1.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.8.into()),
+            1.0.into(),
+            "should clamp values outside of the range"
+        );
+    }
+
+    #[test]
+    fn single_direction() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisCalibration::new(0.25, 0.25, 0.75);
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.5.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.75, 0.25).into()),
+            Vec2::new(1.0, 0.0).into()
+        );
+    }
+
+    #[test]
+    fn identity() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisCalibration::default();
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(0.5, -0.25, 0.0).into()),
+            Vec3::new(0.5, -0.25, 0.0).into()
+        );
+    }
+}
@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+use log::warn;
+
+use crate::prelude::*;
+
+/// Keeps only one axis of the input while another action is [`TriggerState::Fired`].
+///
+/// Useful for axis-locked dragging, like holding Shift in image editors.
+///
+/// With [`LockedAxis::Dominant`], the input is suppressed until the accumulated movement
+/// exceeds [`Self::threshold`]. After that, the axis with the largest movement is kept
+/// until the action is no longer fired.
+///
+/// [`ActionValue::Bool`] and [`ActionValue::Axis1D`] are passed through unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::{ecs::spawn::SpawnWith, prelude::*};
+/// use bevy_enhanced_input::prelude::*;
+///
+/// Actions::<Editor>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+///     let lock = context
+///         .spawn((Action::<Lock>::new(), bindings![KeyCode::ShiftLeft]))
+///         .id();
+///
+///     context.spawn((
+///         Action::<Drag>::new(),
+///         AxisLock::new(lock),
+///         bindings![Binding::mouse_motion()],
+///     ));
+/// }));
+///
+/// #[derive(Component)]
+/// struct Editor;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Lock;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Drag;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct AxisLock {
+    /// Action that activates the lock.
+    action: Entity,
+
+    /// Axis to keep.
+    ///
+    /// By default set to [`LockedAxis::Dominant`].
+    pub axis: LockedAxis,
+
+    /// Movement required to select the dominant axis.
+    ///
+    /// Used only with [`LockedAxis::Dominant`].
+    ///
+    /// By default set to 0.0, which selects the axis on the first non-zero input.
+    pub threshold: f32,
+
+    /// Movement since the lock was activated.
+    accumulated: Vec3,
+
+    /// Index of the selected dominant axis.
+    dominant: Option<usize>,
+}
+
+impl AxisLock {
+    #[must_use]
+    pub const fn new(action: Entity) -> Self {
+        Self {
+            action,
+            axis: LockedAxis::Dominant,
+            threshold: 0.0,
+            accumulated: Vec3::ZERO,
+            dominant: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_axis(mut self, axis: LockedAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    #[must_use]
+    pub const fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn dominant_axis(&mut self, value: Vec3) -> Option<usize> {
+        if self.dominant.is_none() {
+            self.accumulated += value;
+            let abs = self.accumulated.abs();
+            if abs != Vec3::ZERO && abs.length() >= self.threshold {
+                let index = if abs.x >= abs.y && abs.x >= abs.z {
+                    0
+                } else if abs.y >= abs.z {
+                    1
+                } else {
+                    2
+                };
+                self.dominant = Some(index);
+            }
+        }
+
+        self.dominant
+    }
+}
+
+impl InputModifier for AxisLock {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let Ok((_, &state, ..)) = actions.get(self.action) else {
+            // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+            warn!("`{}` is not a valid action", self.action);
+            return value;
+        };
+
+        if state != TriggerState::Fired {
+            self.accumulated = Vec3::ZERO;
+            self.dominant = None;
+            return value;
+        }
+
+        if matches!(value, ActionValue::Bool(_) | ActionValue::Axis1D(_)) {
+            return value;
+        }
+
+        let value3 = value.as_axis3d();
+        let index = match self.axis {
+            LockedAxis::Dominant => match self.dominant_axis(value3) {
+                Some(index) => index,
+                None => return ActionValue::zero(value.dim()),
+            },
+            LockedAxis::X => 0,
+            LockedAxis::Y => 1,
+            LockedAxis::Z => 2,
+        };
+
+        let mut locked = Vec3::ZERO;
+        locked[index] = value3[index];
+        ActionValue::Axis3D(locked).convert(value.dim())
+    }
+}
+
+/// Axis preserved by [`AxisLock`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+pub enum LockedAxis {
+    /// Select the axis with the largest movement after activation.
+    #[default]
+    Dominant,
+    /// Keep only the X axis.
+    X,
+    /// Keep only the Y axis.
+    Y,
+    /// Keep only the Z axis.
+    Z,
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_enhanced_input_macros::InputAction;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn dominant() {
+        let (mut world, mut state) = context::init_world();
+        let action = world
+            .spawn((
+                Action::<Test>::new(),
+                TriggerState::Fired,
+                ActionValue::from(true),
+            ))
+            .id();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisLock::new(action).with_threshold(1.0);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.2, 0.5).into()),
+            Vec2::ZERO.into(),
+            "should be suppressed until the threshold"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.1, 0.5).into()),
+            Vec2::new(0.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(2.0, 0.5).into()),
+            Vec2::new(0.0, 0.5).into(),
+            "selected axis shouldn't change"
+        );
+    }
+
+    #[test]
+    fn fixed() {
+        let (mut world, mut state) = context::init_world();
+        let action = world
+            .spawn((
+                Action::<Test>::new(),
+                TriggerState::Fired,
+                ActionValue::from(true),
+            ))
+            .id();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisLock::new(action).with_axis(LockedAxis::X);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.2, 0.5).into()),
+            Vec2::new(0.2, 0.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(0.2, 0.5, 1.0).into()),
+            Vec3::new(0.2, 0.0, 0.0).into()
+        );
+    }
+
+    #[test]
+    fn inactive() {
+        let (mut world, mut state) = context::init_world();
+        let action = world.spawn(Action::<Test>::new()).id();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisLock::new(action);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.2, 0.5).into()),
+            Vec2::new(0.2, 0.5).into()
+        );
+    }
+
+    #[test]
+    fn missing_action() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = AxisLock::new(Entity::PLACEHOLDER);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into()
+        );
+    }
+
+    #[derive(InputAction)]
+    #[action_output(bool)]
+    struct Test;
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Restricts the length of the input value, preserving its direction.
+///
+/// Unlike [`Clamp`], which works independently along each axis, diagonal values are scaled
+/// down as a whole. Useful at the action level when multiple bindings are accumulated,
+/// such as WASD keys together with a gamepad stick, to prevent faster diagonal movement.
+///
+/// [`ActionValue::Bool`] will be converted into [`ActionValue::Axis1D`] before clamping.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Player[
+///     (
+///         Action::<Movement>::new(),
+///         ClampLength::default(),
+///         Bindings::spawn((Cardinal::wasd_keys(), Axial::left_stick())),
+///     ),
+/// ]);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Movement;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct ClampLength {
+    /// Maximum length.
+    ///
+    /// The sign is ignored. If NaN, the value is passed through unchanged.
+    ///
+    /// By default set to 1.0.
+    pub max: f32,
+}
+
+impl ClampLength {
+    /// Creates a new instance with the given maximum length.
+    #[must_use]
+    pub const fn new(max: f32) -> Self {
+        Self { max }
+    }
+}
+
+impl Default for ClampLength {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl InputModifier for ClampLength {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let max = self.max.abs();
+        if max.is_nan() {
+            return value;
+        }
+
+        match value {
+            ActionValue::Bool(value) => {
+                let value: f32 = if value { 1.0 } else { 0.0 };
+                value.min(max).into()
+            }
+            ActionValue::Axis1D(value) => value.clamp(-max, max).into(),
+            ActionValue::Axis2D(value) => value.clamp_length_max(max).into(),
+            ActionValue::Axis3D(value) => value.clamp_length_max(max).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn clamping() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = ClampLength::new(0.5);
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            0.0.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-2.0).into()),
+            (-0.5).into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.3.into()), 0.3.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.0, 2.0).into()),
+            Vec2::new(0.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.1, 0.1).into()),
+            Vec2::new(0.1, 0.1).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(-3.0, 0.0, 0.0).into()),
+            Vec3::new(-0.5, 0.0, 0.0).into()
+        );
+    }
+    #[test]
+    fn negative_and_nan() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = ClampLength::new(-0.5);
+        assert_eq!(
+            modifier.transform(&actions, &time, (-2.0).into()),
+            (-0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.0, 2.0).into()),
+            Vec2::new(0.0, 0.5).into()
+        );
+
+        let mut modifier = ClampLength::new(f32::NAN);
+        assert_eq!(modifier.transform(&actions, &time, 2.0.into()), 2.0.into());
+    }
+}
@@ -0,0 +1,173 @@
+use core::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{condition::DEFAULT_ACTUATION, prelude::*};
+
+/// Discards input for a duration after it actuates, except while the actuating input is held.
+///
+/// Once actuated, the value passes through until the input is released. After that, the value
+/// is replaced with zero until the duration since the actuation has elapsed. This filters out
+/// double activations from flaky buttons, like arcade or IR remote buttons that bounce on press.
+///
+/// Unlike [`Cooldown`], which only prevents the action from firing, the discarded input never reaches
+/// the following modifiers and conditions. So place it first on a binding to prevent modifiers like
+/// [`AccumulateBy`] or conditions like [`Tap`] from registering the bounce.
+///
+/// # Examples
+///
+/// Ignore presses within 50 ms of an accepted press:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Cabinet[(
+///     Action::<InsertCoin>::new(),
+///     bindings![(KeyCode::Digit5, Debounce::new(0.05))],
+/// )]);
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct InsertCoin;
+///
+/// #[derive(Component)]
+/// struct Cabinet;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct Debounce {
+    /// Trigger threshold.
+    pub actuation: f32,
+
+    /// The type of time used to advance the timer.
+    pub time_kind: TimeKind,
+
+    timer: Timer,
+
+    held: bool,
+}
+
+impl Debounce {
+    /// Creates a new instance with the given duration in seconds.
+    #[must_use]
+    pub fn new(duration: f32) -> Self {
+        let mut timer = Timer::from_seconds(duration, TimerMode::Once);
+        timer.tick(Duration::from_secs_f32(duration)); // Accept the first actuation immediately.
+        Self {
+            actuation: DEFAULT_ACTUATION,
+            time_kind: Default::default(),
+            timer,
+            held: false,
+        }
+    }
+
+    #[must_use]
+    pub fn with_actuation(mut self, actuation: f32) -> Self {
+        self.actuation = actuation;
+        self
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Returns the associated timer.
+    #[must_use]
+    pub fn timer(&self) -> &Timer {
+        &self.timer
+    }
+}
+
+impl InputModifier for Debounce {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        self.timer.tick(time.delta_kind(self.time_kind));
+
+        let actuated = value.is_actuated(self.actuation);
+        if self.held && actuated {
+            return value;
+        }
+        self.held = false;
+
+        if actuated && self.timer.is_finished() {
+            self.held = true;
+            self.timer.reset();
+            return value;
+        }
+
+        ActionValue::zero(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn bounce() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(10));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Debounce::new(0.05);
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            true.into(),
+            "should accept the first actuation"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            true.into(),
+            "should pass through while held"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            false.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            false.into(),
+            "should discard the bounce"
+        );
+
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(50));
+        let (time, actions) = state.get(&world);
+
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            true.into(),
+            "should accept after the duration"
+        );
+    }
+
+    #[test]
+    fn held_longer() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Debounce::new(0.05);
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.8.into()), 0.8.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 1.0.into()),
+            1.0.into(),
+            "should accept if the duration elapsed while held"
+        );
+    }
+}
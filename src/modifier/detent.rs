@@ -0,0 +1,200 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Snaps input near an arbitrary center to that center, independently along each axis.
+///
+/// Unlike [`DeadZone`], which always removes input around zero, this modifier keeps the input in its
+/// original range. Values within [`Self::width`] of [`Self::center`] become exactly the center, and the
+/// remaining values are remapped linearly so that [`Self::min`] and [`Self::max`] are still reachable.
+/// Values outside [`Self::min`]..=[`Self::max`] are clamped.
+///
+/// Useful for hardware whose axes don't rest at zero, like throttle levers with a detent.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// Throttle axis that reports 0.0..=1.0 and has a detent at half thrust:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Aircraft[(
+///     Action::<Throttle>::new(),
+///     bindings![(GamepadAxis::RightZ, Detent::new(0.5, 0.05).with_range(0.0, 1.0))],
+/// )]);
+///
+/// #[derive(Component)]
+/// struct Aircraft;
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct Throttle;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct Detent {
+    /// Value to snap to.
+    pub center: f32,
+
+    /// Distance from the center within which input snaps to it.
+    pub width: f32,
+
+    /// Lowest value the axis reports.
+    ///
+    /// By default set to -1.0.
+    pub min: f32,
+
+    /// Highest value the axis reports.
+    ///
+    /// By default set to 1.0.
+    pub max: f32,
+}
+
+impl Detent {
+    /// Creates a new instance for axes in the -1.0..=1.0 range.
+    #[must_use]
+    pub const fn new(center: f32, width: f32) -> Self {
+        Self {
+            center,
+            width,
+            min: -1.0,
+            max: 1.0,
+        }
+    }
+
+    /// Returns a new instance for axes in the `min..=max` range.
+    #[must_use]
+    pub const fn with_range(mut self, min: f32, max: f32) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    fn detent(self, axis_value: f32) -> f32 {
+        let value = axis_value.clamp(self.min, self.max);
+        let upper = self.center + self.width;
+        let lower = self.center - self.width;
+        if value > upper {
+            // Remap upper..=max to center..=max.
+            let range = self.max - upper;
+            if range <= 0.0 {
+                return self.max;
+            }
+            self.center + (value - upper) / range * (self.max - self.center)
+        } else if value < lower {
+            // Remap min..=lower to min..=center.
+            let range = lower - self.min;
+            if range <= 0.0 {
+                return self.min;
+            }
+            self.center - (lower - value) / range * (self.center - self.min)
+        } else {
+            self.center
+        }
+    }
+}
+
+impl InputModifier for Detent {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        match value {
+            ActionValue::Bool(value) => {
+                let value = if value { 1.0 } else { 0.0 };
+                self.detent(value).into()
+            }
+            ActionValue::Axis1D(value) => self.detent(value).into(),
+            ActionValue::Axis2D(value) => value.map(|axis| self.detent(axis)).into(),
+            ActionValue::Axis3D(value) => value.map(|axis| self.detent(axis)).into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn symmetric() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Detent::new(0.0, 0.25);
+
+        assert_eq!(modifier.transform(&actions, &time, 0.1.into()), 0.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.625.into()),
+            0.5.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.625).into()),
+            (-0.5).into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 2.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            0.0.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.1, 0.625).into()),
+            Vec2::new(0.0, 0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(0.1, 0.625, -1.0).into()),
+            Vec3::new(0.0, 0.5, -1.0).into()
+        );
+    }
+
+    #[test]
+    fn throttle() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Detent::new(0.5, 0.125).with_range(0.0, 1.0);
+
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.1875.into()),
+            0.25.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.45.into()), 0.5.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.55.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.8125.into()),
+            0.75.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            0.0.into()
+        );
+    }
+
+    #[test]
+    fn center_at_bound() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Detent::new(1.0, 0.5);
+
+        assert_eq!(modifier.transform(&actions, &time, 0.6.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-0.25).into()),
+            0.0.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            (-1.0).into()
+        );
+    }
+}
@@ -0,0 +1,228 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Snaps an analog input to digital directions, making a stick behave like a D-pad.
+///
+/// Each axis becomes `-1.0`, `0.0` or `1.0`. An axis is engaged once its magnitude reaches [`Self::press`]
+/// and stays engaged until it drops below [`Self::release`]. This hysteresis prevents flickering
+/// when the stick rests near the threshold.
+///
+/// Without [`Self::diagonals`], only one axis can be engaged at a time. The engaged axis is kept
+/// while it remains the largest, so rolling the stick between directions switches only after
+/// passing the diagonal.
+///
+/// Pair it with [`Pulse`] for key-repeat style navigation or with [`Press`] to react only to the initial press.
+///
+/// [`ActionValue::Bool`] is passed through unchanged. [`ActionValue::Axis3D`] ignores the Z axis.
+///
+/// # Examples
+///
+/// Menu navigation that repeats while the stick is held:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Menu,
+///     actions!(Menu[(
+///         Action::<Navigate>::new(),
+///         // Added to the action because stick axes are separate bindings.
+///         Digitize::default(),
+///         Pulse::new(0.1).with_initial_delay(0.4),
+///         Bindings::spawn((Cardinal::dpad(), Axial::left_stick())),
+///     )]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Menu;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Navigate;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct Digitize {
+    /// Magnitude at which an axis becomes engaged.
+    pub press: f32,
+
+    /// Magnitude below which an engaged axis is released.
+    ///
+    /// Should be less than or equal to [`Self::press`].
+    pub release: f32,
+
+    /// Whether multiple axes can be engaged at the same time.
+    pub diagonals: bool,
+
+    /// Currently engaged direction.
+    current: Vec2,
+}
+
+impl Digitize {
+    /// Creates a new instance with the given thresholds.
+    #[must_use]
+    pub fn new(press: f32, release: f32) -> Self {
+        Self {
+            press,
+            release,
+            ..Default::default()
+        }
+    }
+
+    /// Sets [`Self::diagonals`].
+    #[must_use]
+    pub fn with_diagonals(mut self, diagonals: bool) -> Self {
+        self.diagonals = diagonals;
+        self
+    }
+
+    fn axis(&self, current: f32, value: f32) -> f32 {
+        if current != 0.0 && current.signum() == value.signum() && value.abs() >= self.release {
+            current
+        } else if value.abs() >= self.press {
+            value.signum()
+        } else {
+            0.0
+        }
+    }
+}
+
+impl Default for Digitize {
+    /// Creates a new instance with [`Self::press`] set to 0.6 and [`Self::release`] set to 0.4.
+    fn default() -> Self {
+        Self {
+            press: 0.6,
+            release: 0.4,
+            diagonals: false,
+            current: Vec2::ZERO,
+        }
+    }
+}
+
+impl InputModifier for Digitize {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if let ActionValue::Bool(_) = value {
+            return value;
+        }
+
+        let value2 = value.as_axis2d();
+        let mut direction = Vec2::new(
+            self.axis(self.current.x, value2.x),
+            self.axis(self.current.y, value2.y),
+        );
+
+        if !self.diagonals && direction.x != 0.0 && direction.y != 0.0 {
+            let keep_x = if self.current.y != 0.0 && self.current.x == 0.0 {
+                value2.x.abs() > value2.y.abs()
+            } else {
+                value2.x.abs() >= value2.y.abs()
+            };
+
+            if keep_x {
+                direction.y = 0.0;
+            } else {
+                direction.x = 0.0;
+            }
+        }
+
+        self.current = direction;
+        ActionValue::Axis2D(direction).convert(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn hysteresis() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Digitize::default();
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.5, 0.0).into()),
+            Vec2::ZERO.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.7, 0.0).into()),
+            Vec2::X.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.5, 0.0).into()),
+            Vec2::X.into(),
+            "should stay engaged above the release threshold"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.3, 0.0).into()),
+            Vec2::ZERO.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(-0.7, 0.0).into()),
+            Vec2::NEG_X.into()
+        );
+    }
+
+    #[test]
+    fn cardinal() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Digitize::default();
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.0, 0.8).into()),
+            Vec2::Y.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.7, 0.7).into()),
+            Vec2::Y.into(),
+            "should keep the engaged axis on the diagonal"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.8, 0.6).into()),
+            Vec2::X.into()
+        );
+    }
+
+    #[test]
+    fn diagonals() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Digitize::default().with_diagonals(true);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(0.7, -0.7).into()),
+            Vec2::new(1.0, -1.0).into()
+        );
+    }
+
+    #[test]
+    fn dims() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Digitize::default();
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            true.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, 0.7.into()), 1.0.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec3::new(0.0, -0.7, 1.0).into()),
+            Vec3::NEG_Y.into()
+        );
+    }
+}
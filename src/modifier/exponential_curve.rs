@@ -6,7 +6,33 @@ use crate::prelude::*;
 ///
 /// Apply a simple exponential response curve to input values, per axis.
 ///
+/// Exponents above 1.0 give finer control in the low range of a stick while preserving the sign.
+/// Place it after [`DeadZone`] so the curve is applied to the already remapped range.
+///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Player[
+///     (
+///         Action::<Aim>::new(),
+///         DeadZone::default(),
+///         ExponentialCurve::splat(2.0),
+///         Bindings::spawn(Axial::right_stick()),
+///     ),
+/// ]);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Aim;
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
 pub struct ExponentialCurve {
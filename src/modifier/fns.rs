@@ -8,6 +8,7 @@ use bevy::{
     },
     prelude::*,
 };
+use log::warn;
 
 use crate::prelude::*;
 
@@ -36,10 +37,16 @@ impl InputModifierAppExt for App {
 
 fn register_modifier<M: InputModifier + Component<Mutability = Mutable>>(
     add: On<Add, M>,
-    mut modifiers: Query<&mut ModifierFns, Allow<Disabled>>,
+    mut unattached: ResMut<UnattachedModifiers>,
+    mut modifiers: Query<(&mut ModifierFns, Has<Binding>, Has<TriggerState>), Allow<Disabled>>,
 ) {
-    let mut fns = modifiers.get_mut(add.entity).unwrap();
+    let (mut fns, binding, action) = modifiers.get_mut(add.entity).unwrap();
     fns.0.push(get_modifier::<M>);
+
+    if !binding && !action {
+        // Could be inserted before the binding or action component, like in scenes.
+        unattached.push((add.entity, ShortName::of::<M>()));
+    }
 }
 
 fn unregister_modifier<M: InputModifier + Component<Mutability = Mutable>>(
@@ -54,6 +61,25 @@ fn unregister_modifier<M: InputModifier + Component<Mutability = Mutable>>(
     fns.0.remove(index);
 }
 
+/// Warns about modifiers that are still neither on an action nor on a binding.
+pub(crate) fn warn_unattached(
+    mut unattached: ResMut<UnattachedModifiers>,
+    entities: Query<(Has<Binding>, Has<TriggerState>), Allow<Disabled>>,
+) {
+    for (entity, name) in unattached.drain(..) {
+        if let Ok((false, false)) = entities.get(entity) {
+            warn!("`{entity}` has modifier `{name}`, but it is neither an action nor a binding");
+        }
+    }
+}
+
+/// Modifiers inserted on entities without an action or binding.
+///
+/// Checked at the end of the frame by [`warn_unattached`], since the action or binding
+/// could be inserted later.
+#[derive(Resource, Deref, DerefMut, Default)]
+pub(crate) struct UnattachedModifiers(Vec<(Entity, ShortName<'static>)>);
+
 /// IDs of all registered input modifiers.
 ///
 /// Used to dynamically register access for [`FilteredEntityMut`].
@@ -0,0 +1,299 @@
+//! Modifiers that read their parameters from [`GlobalInputSettings`].
+//!
+//! Also contains [`MouseCalibration`] to measure the mouse resolution for the settings.
+
+use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*};
+
+use crate::prelude::*;
+
+/// Player-facing input settings shared across all actions.
+///
+/// Read by [`MouseSensitivity`], [`StickDeadZone`] and [`InvertY`], so a settings menu can change
+/// these values at runtime without touching bindings. Changes are applied during
+/// [`EnhancedInputSystems::Prepare`].
+///
+/// Initialized by [`crate::EnhancedInputPlugin`] with neutral values.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[
+///         (
+///             Action::<Look>::new(),
+///             bindings![(Binding::mouse_motion(), MouseSensitivity::default(), InvertY::default())],
+///         ),
+///         (
+///             Action::<Move>::new(),
+///             StickDeadZone::default(),
+///             Bindings::spawn(Axial::left_stick()),
+///         ),
+///     ]),
+/// ));
+///
+/// fn apply_settings(mut settings: ResMut<GlobalInputSettings>) {
+///     settings.mouse_sensitivity = 0.5;
+///     settings.invert_y = true;
+/// }
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Look;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Move;
+/// ```
+#[derive(Resource, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct GlobalInputSettings {
+    /// Factor applied by [`MouseSensitivity`].
+    ///
+    /// By default set to 1.0.
+    pub mouse_sensitivity: f32,
+
+    /// Resolution of the mouse in counts per inch, used by [`MouseSensitivity`] to normalize deltas.
+    ///
+    /// When set, deltas are additionally scaled by [`Self::REFERENCE_DPI`] divided by this value,
+    /// so the same physical hand movement produces the same value on any mouse and
+    /// [`Self::mouse_sensitivity`] transfers between mice.
+    ///
+    /// Bevy doesn't expose the DPI or pointer speed reported by the OS, so it needs to be provided
+    /// by the player or measured with [`MouseCalibration`].
+    ///
+    /// By default set to `None`, which disables normalization.
+    pub mouse_dpi: Option<f32>,
+
+    /// Dead zone applied by [`StickDeadZone`].
+    ///
+    /// By default set to [`DeadZone::default`].
+    pub stick_dead_zone: DeadZone,
+
+    /// Whether [`InvertY`] should negate the Y axis.
+    ///
+    /// By default set to `false`.
+    pub invert_y: bool,
+}
+
+impl GlobalInputSettings {
+    /// Resolution to which mouse deltas are normalized when [`Self::mouse_dpi`] is set.
+    ///
+    /// Matches a common default for mice, so normalized values are close to raw deltas.
+    pub const REFERENCE_DPI: f32 = 800.0;
+
+    /// Returns the factor applied by [`MouseSensitivity`], including DPI normalization.
+    #[must_use]
+    pub fn mouse_scale(&self) -> f32 {
+        match self.mouse_dpi {
+            Some(dpi) if dpi > 0.0 => self.mouse_sensitivity * Self::REFERENCE_DPI / dpi,
+            _ => self.mouse_sensitivity,
+        }
+    }
+}
+
+impl Default for GlobalInputSettings {
+    fn default() -> Self {
+        Self {
+            mouse_sensitivity: 1.0,
+            mouse_dpi: None,
+            stick_dead_zone: Default::default(),
+            invert_y: false,
+        }
+    }
+}
+
+/// Scales input by [`GlobalInputSettings::mouse_sensitivity`], normalized by
+/// [`GlobalInputSettings::mouse_dpi`] if set.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct MouseSensitivity {
+    scale: Scale,
+}
+
+impl Default for MouseSensitivity {
+    fn default() -> Self {
+        Self {
+            scale: Scale::splat(GlobalInputSettings::default().mouse_scale()),
+        }
+    }
+}
+
+impl InputModifier for MouseSensitivity {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        self.scale.transform(actions, time, value)
+    }
+}
+
+/// Applies [`GlobalInputSettings::stick_dead_zone`].
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct StickDeadZone {
+    dead_zone: DeadZone,
+}
+
+impl Default for StickDeadZone {
+    fn default() -> Self {
+        Self {
+            dead_zone: GlobalInputSettings::default().stick_dead_zone,
+        }
+    }
+}
+
+impl InputModifier for StickDeadZone {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        self.dead_zone.transform(actions, time, value)
+    }
+}
+
+/// Negates the Y axis if [`GlobalInputSettings::invert_y`] is set.
+///
+/// Since [`ActionValue::Bool`] and [`ActionValue::Axis1D`] have no Y axis, they are passed through unchanged.
+/// To invert a single axis, add this modifier before [`SwizzleAxis`].
+#[derive(Component, Default, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct InvertY {
+    invert: bool,
+}
+
+impl InputModifier for InvertY {
+    fn transform(
+        &mut self,
+        actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if !self.invert || matches!(value, ActionValue::Bool(_) | ActionValue::Axis1D(_)) {
+            return value;
+        }
+
+        Negate::y().transform(actions, time, value)
+    }
+}
+
+pub(crate) fn update_settings(
+    settings: Res<GlobalInputSettings>,
+    mut sensitivities: Query<&mut MouseSensitivity>,
+    mut dead_zones: Query<&mut StickDeadZone>,
+    mut inversions: Query<&mut InvertY>,
+) {
+    for mut sensitivity in &mut sensitivities {
+        if settings.is_changed() || sensitivity.is_added() {
+            sensitivity.scale = Scale::splat(settings.mouse_scale());
+        }
+    }
+    for mut dead_zone in &mut dead_zones {
+        if settings.is_changed() || dead_zone.is_added() {
+            dead_zone.dead_zone = settings.stick_dead_zone;
+        }
+    }
+    for mut inversion in &mut inversions {
+        if settings.is_changed() || inversion.is_added() {
+            inversion.invert = settings.invert_y;
+        }
+    }
+}
+
+/// Measures the mouse resolution for [`GlobalInputSettings::mouse_dpi`].
+///
+/// Insert this resource to start the calibration, ask the player to move the mouse in a straight
+/// line over a known physical distance, and then pass the distance to [`Self::dpi`].
+/// Mouse motion is accumulated during [`EnhancedInputSystems::Prepare`] while the resource exists.
+/// Remove the resource to stop.
+///
+/// The result depends on the OS pointer acceleration, which should be disabled for accurate
+/// measurements. Bevy reads raw motion on most platforms, so usually it's not applied.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// fn start_calibration(mut commands: Commands) {
+///     // Show a prompt to move the mouse by 4 inches to the right.
+///     commands.init_resource::<MouseCalibration>();
+/// }
+///
+/// fn finish_calibration(
+///     mut commands: Commands,
+///     calibration: Res<MouseCalibration>,
+///     mut settings: ResMut<GlobalInputSettings>,
+/// ) {
+///     settings.mouse_dpi = Some(calibration.dpi(4.0));
+///     commands.remove_resource::<MouseCalibration>();
+/// }
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Resource, Debug, Default)
+)]
+pub struct MouseCalibration {
+    delta: Vec2,
+}
+
+impl MouseCalibration {
+    /// Returns the total mouse delta since the calibration started.
+    #[must_use]
+    pub fn delta(&self) -> Vec2 {
+        self.delta
+    }
+
+    /// Returns the resolution in counts per inch for the given physical distance in inches.
+    ///
+    /// Only the straight-line distance between the start and end positions is measured.
+    #[must_use]
+    pub fn dpi(&self, inches: f32) -> f32 {
+        self.delta.length() / inches
+    }
+}
+
+pub(crate) fn calibrate_mouse(
+    mut calibration: ResMut<MouseCalibration>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+) {
+    if let Some(mouse_motion) = mouse_motion
+        && mouse_motion.delta != Vec2::ZERO
+    {
+        calibration.delta += mouse_motion.delta;
+    }
+}
@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// Converts a recognition confidence into a button press if it reaches the threshold.
+///
+/// Intended for [voice commands](crate::binding::voice), whose inputs report the
+/// recognition confidence as their value. Values below [`Self::threshold`] become `false`,
+/// so uncertain recognitions don't trigger the action.
+///
+/// Always produces [`ActionValue::Bool`]. The magnitude of the value is used
+/// for multi-dimensional values.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// const FIRE: VirtualInput = VirtualInput(0);
+///
+/// actions!(Player[(
+///     Action::<Fire>::new(),
+///     // Firing by mistake is worse than repeating the command.
+///     bindings![(FIRE, MinConfidence::new(0.9))],
+/// )]);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Fire;
+/// ```
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default, PartialEq)
+)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    all(feature = "reflect", feature = "serialize"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct MinConfidence {
+    /// Minimum confidence to produce `true`.
+    pub threshold: f32,
+}
+
+impl MinConfidence {
+    #[must_use]
+    pub const fn new(threshold: f32) -> Self {
+        Self { threshold }
+    }
+}
+
+impl Default for MinConfidence {
+    fn default() -> Self {
+        Self::new(0.5)
+    }
+}
+
+impl InputModifier for MinConfidence {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let confidence = match value {
+            ActionValue::Bool(value) => {
+                if value {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            ActionValue::Axis1D(value) => value,
+            ActionValue::Axis2D(value) => value.length(),
+            ActionValue::Axis3D(value) => value.length(),
+        };
+
+        (confidence > 0.0 && confidence >= self.threshold).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn threshold() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = MinConfidence::new(0.75);
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.5.into()),
+            false.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.75.into()),
+            true.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            true.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            false.into()
+        );
+    }
+
+    #[test]
+    fn zero_threshold() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = MinConfidence::new(0.0);
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.0.into()),
+            false.into(),
+            "missing input shouldn't pass"
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use alloc::collections::VecDeque;
+use core::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Averages the input values over a sliding window of frames or time.
+///
+/// Useful for very noisy analog sources, like sim racing pedals or DIY hall-effect sensors,
+/// where [`SmoothNudge`] or [`DeadZone`] are not enough. Larger windows produce smoother values,
+/// but delay the response to changes.
+///
+/// Samples are stored in a ring buffer. For [`AverageWindow::Frames`] it's allocated upfront,
+/// and for [`AverageWindow::Seconds`] it grows until it fits the samples within the window,
+/// so no allocations happen per frame after that.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// Average the throttle pedal over the last 8 frames:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Car[(
+///     Action::<Throttle>::new(),
+///     bindings![(GamepadAxis::RightZ, MovingAverage::frames(8))],
+/// )]);
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct Throttle;
+///
+/// #[derive(Component)]
+/// struct Car;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct MovingAverage {
+    /// Size of the window to average over.
+    pub window: AverageWindow,
+
+    /// The type of time used for [`AverageWindow::Seconds`].
+    pub time_kind: TimeKind,
+
+    /// Samples with the time they were recorded at.
+    samples: VecDeque<(Duration, Vec3)>,
+
+    elapsed: Duration,
+}
+
+impl MovingAverage {
+    /// Creates a new instance that averages over the given number of frames.
+    ///
+    /// Zero is treated as 1, which passes the value through.
+    #[must_use]
+    pub fn frames(count: usize) -> Self {
+        Self::new(AverageWindow::Frames(count))
+    }
+
+    /// Creates a new instance that averages over the given duration in seconds.
+    #[must_use]
+    pub fn seconds(duration: f32) -> Self {
+        Self::new(AverageWindow::Seconds(duration))
+    }
+
+    /// Creates a new instance with the given window.
+    #[must_use]
+    pub fn new(window: AverageWindow) -> Self {
+        let capacity = match window {
+            AverageWindow::Frames(count) => count.max(1),
+            AverageWindow::Seconds(_) => 0,
+        };
+
+        Self {
+            window,
+            time_kind: Default::default(),
+            samples: VecDeque::with_capacity(capacity),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    /// Discards all collected samples.
+    pub fn reset(&mut self) {
+        self.samples.clear();
+    }
+}
+
+impl Default for MovingAverage {
+    /// Creates a new instance that averages over 5 frames.
+    fn default() -> Self {
+        Self::frames(5)
+    }
+}
+
+impl InputModifier for MovingAverage {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let value = match value {
+            ActionValue::Bool(value) => ActionValue::Axis1D(if value { 1.0 } else { 0.0 }),
+            _ => value,
+        };
+
+        self.elapsed += time.delta_kind(self.time_kind);
+        match self.window {
+            AverageWindow::Frames(count) => {
+                while self.samples.len() >= count.max(1) {
+                    self.samples.pop_front();
+                }
+            }
+            AverageWindow::Seconds(duration) => {
+                let window = Duration::from_secs_f32(duration.max(0.0));
+                while self
+                    .samples
+                    .front()
+                    .is_some_and(|&(recorded, _)| self.elapsed - recorded >= window)
+                {
+                    self.samples.pop_front();
+                }
+            }
+        }
+        self.samples.push_back((self.elapsed, value.as_axis3d()));
+
+        // Summed each time instead of keeping a running total to avoid accumulating float errors.
+        let sum: Vec3 = self.samples.iter().map(|&(_, sample)| sample).sum();
+        let average = sum / self.samples.len() as f32;
+
+        ActionValue::Axis3D(average).convert(value.dim())
+    }
+}
+
+/// Window size for [`MovingAverage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Debug))]
+pub enum AverageWindow {
+    /// Number of the most recent frames.
+    Frames(usize),
+
+    /// Duration in seconds.
+    ///
+    /// Includes samples recorded within this duration from the current frame.
+    Seconds(f32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn frames() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = MovingAverage::frames(2);
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.0.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            (-0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            0.0.into(),
+            "should convert bool into axis"
+        );
+    }
+
+    #[test]
+    fn seconds() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = MovingAverage::seconds(0.25);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ONE.into()),
+            Vec2::ONE.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            (Vec2::ONE / 2.0).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::splat(2.0).into()),
+            Vec2::ONE.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            (Vec2::splat(2.0) / 3.0).into(),
+            "should discard samples outside of the window"
+        );
+    }
+
+    #[test]
+    fn zero_frames() {
+        let (world, mut state) = context::init_world();
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = MovingAverage::frames(0);
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 1.0.into());
+        assert_eq!(modifier.transform(&actions, &time, 0.5.into()), 0.5.into());
+    }
+}
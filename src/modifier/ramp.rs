@@ -0,0 +1,237 @@
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Linearly moves the value toward the input value over time.
+///
+/// Each axis moves away from zero at the rate defined by [`Self::rise_time`]
+/// and toward zero at the rate defined by [`Self::fall_time`]. When the input
+/// changes direction, the value first falls to zero and then rises in the new direction.
+///
+/// Useful for giving digital inputs, like keyboard keys, a smooth analog response
+/// comparable to gamepad sticks, for example for steering or throttle.
+/// Unlike [`LinearStep`], the rate depends on time rather than frame count.
+///
+/// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// Keyboard steering that reaches full lock in 0.3 seconds and recenters in 0.2 seconds:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Car[
+///     (
+///         Action::<Steer>::new(),
+///         Bindings::spawn((
+///             Bidirectional::new(KeyCode::KeyD, KeyCode::KeyA).with(Ramp::new(0.3, 0.2)),
+///             Spawn(Binding::from(GamepadAxis::LeftStickX)),
+///         )),
+///     )
+/// ]);
+///
+/// #[derive(InputAction)]
+/// #[action_output(f32)]
+/// struct Steer;
+///
+/// #[derive(Component)]
+/// struct Car;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct Ramp {
+    /// Time in seconds to go from 0.0 to 1.0.
+    ///
+    /// Zero means the value changes instantly.
+    pub rise_time: f32,
+
+    /// Time in seconds to go from 1.0 to 0.0.
+    ///
+    /// Zero means the value changes instantly.
+    pub fall_time: f32,
+
+    /// The type of time used to advance the value.
+    pub time_kind: TimeKind,
+
+    current_value: Vec3,
+}
+
+impl Ramp {
+    /// Creates a new instance with the given rise and fall times in seconds.
+    #[must_use]
+    pub fn new(rise_time: f32, fall_time: f32) -> Self {
+        Self {
+            rise_time,
+            fall_time,
+            time_kind: Default::default(),
+            current_value: Vec3::ZERO,
+        }
+    }
+
+    /// Creates a new instance with the same rise and fall time in seconds.
+    #[must_use]
+    pub fn splat(time: f32) -> Self {
+        Self::new(time, time)
+    }
+
+    #[must_use]
+    pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
+        self.time_kind = kind;
+        self
+    }
+
+    fn step(&self, current: f32, target: f32, delta_secs: f32) -> f32 {
+        let rising = target != 0.0
+            && (current == 0.0 || current.signum() == target.signum())
+            && target.abs() > current.abs();
+
+        if rising {
+            let step = rate(delta_secs, self.rise_time);
+            current + (target - current).clamp(-step, step)
+        } else {
+            // When changing direction, fall to zero first.
+            let target = if current.signum() == target.signum() {
+                target
+            } else {
+                0.0
+            };
+            let step = rate(delta_secs, self.fall_time);
+            current + (target - current).clamp(-step, step)
+        }
+    }
+}
+
+/// Returns the maximum change for the given delta time.
+fn rate(delta_secs: f32, time: f32) -> f32 {
+    if time > 0.0 {
+        delta_secs / time
+    } else {
+        f32::INFINITY
+    }
+}
+
+impl Default for Ramp {
+    /// Creates a new instance with 0.2 seconds for both rise and fall.
+    fn default() -> Self {
+        Self::splat(0.2)
+    }
+}
+
+impl InputModifier for Ramp {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        let value = match value {
+            ActionValue::Bool(value) => ActionValue::Axis1D(if value { 1.0 } else { 0.0 }),
+            _ => value,
+        };
+
+        let target_value = value.as_axis3d();
+        let delta_secs = time.delta_kind(self.time_kind).as_secs_f32();
+        self.current_value = Vec3::new(
+            self.step(self.current_value.x, target_value.x, delta_secs),
+            self.step(self.current_value.y, target_value.y, delta_secs),
+            self.step(self.current_value.z, target_value.z, delta_secs),
+        );
+
+        ActionValue::Axis3D(self.current_value).convert(value.dim())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use super::*;
+    use crate::context;
+
+    #[test]
+    fn rise_and_fall() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.1));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Ramp::new(0.4, 0.2);
+        assert_eq!(
+            modifier.transform(&actions, &time, true.into()),
+            0.25.into()
+        );
+        assert_eq!(modifier.transform(&actions, &time, true.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, false.into()),
+            0.0.into()
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.1));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Ramp::new(0.2, 0.4);
+        assert_eq!(modifier.transform(&actions, &time, 1.0.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            0.25.into(),
+            "should fall to zero before changing direction"
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            0.0.into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, (-1.0).into()),
+            (-0.5).into()
+        );
+    }
+
+    #[test]
+    fn instant() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.1));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Ramp::splat(0.0);
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::new(1.0, -0.5).into()),
+            Vec2::new(1.0, -0.5).into()
+        );
+        assert_eq!(
+            modifier.transform(&actions, &time, Vec2::ZERO.into()),
+            Vec2::ZERO.into()
+        );
+    }
+
+    #[test]
+    fn partial() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_secs_f32(0.1));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = Ramp::splat(0.2);
+        assert_eq!(modifier.transform(&actions, &time, 0.5.into()), 0.5.into());
+        assert_eq!(
+            modifier.transform(&actions, &time, 0.25.into()),
+            0.25.into(),
+            "should fall to a smaller value in the same direction"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use bevy::{camera::Camera, prelude::*};
+use log::{trace, warn};
+
+use crate::prelude::*;
+
+/// Converts a screen position into a 2D world position using the specified camera.
+///
+/// Intended for [`Binding::MousePosition`] to implement "aim at cursor" actions.
+/// The input is interpreted as a viewport position in logical pixels.
+///
+/// Camera data is captured during [`EnhancedInputSystems::Prepare`], so it reflects
+/// the previous frame, since camera transforms are propagated later in the frame.
+///
+/// Returns zero if the camera doesn't exist or the position can't be converted.
+/// [`ActionValue::Bool`], [`ActionValue::Axis1D`] and zero values, which [`Binding::MousePosition`]
+/// reports when the cursor is outside the window, are passed through unchanged.
+///
+/// Requires the `camera` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// let camera = world.spawn(Camera2d).id();
+/// world.spawn((
+///     Player,
+///     actions!(Player[(
+///         Action::<Aim>::new(),
+///         ScreenToWorld::new(camera),
+///         bindings![Binding::mouse_position()],
+///     )]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Aim;
+/// ```
+#[derive(Component, Debug, Clone)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+pub struct ScreenToWorld {
+    /// Camera entity used for the conversion.
+    pub camera: Entity,
+
+    /// Camera state captured from the last frame.
+    #[cfg_attr(feature = "reflect", reflect(ignore))]
+    view: Option<(Camera, GlobalTransform)>,
+}
+
+impl ScreenToWorld {
+    /// Creates a new instance that converts positions using the given camera.
+    #[must_use]
+    pub fn new(camera: Entity) -> Self {
+        Self { camera, view: None }
+    }
+}
+
+impl InputModifier for ScreenToWorld {
+    fn transform(
+        &mut self,
+        _actions: &ActionsQuery,
+        _time: &ContextTime,
+        value: ActionValue,
+    ) -> ActionValue {
+        if matches!(value, ActionValue::Bool(_) | ActionValue::Axis1D(_))
+            || value.as_axis2d() == Vec2::ZERO
+        {
+            return value;
+        }
+
+        let Some((camera, transform)) = &self.view else {
+            // TODO: use `warn_once` when `bevy_log` becomes `no_std` compatible.
+            warn!("`{}` is not a valid camera", self.camera);
+            return ActionValue::zero(value.dim());
+        };
+
+        match camera.viewport_to_world_2d(transform, value.as_axis2d()) {
+            Ok(position) => ActionValue::Axis2D(position).convert(value.dim()),
+            Err(e) => {
+                trace!("unable to convert `{value:?}` to world: {e}");
+                ActionValue::zero(value.dim())
+            }
+        }
+    }
+}
+
+pub(crate) fn update_cameras(
+    mut modifiers: Query<&mut ScreenToWorld>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+) {
+    for mut modifier in &mut modifiers {
+        modifier.view = cameras
+            .get(modifier.camera)
+            .ok()
+            .map(|(camera, &transform)| (camera.clone(), transform));
+    }
+}
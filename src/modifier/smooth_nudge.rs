@@ -7,6 +7,32 @@ use crate::prelude::*;
 /// See [`StableInterpolate::smooth_nudge`] for details.
 ///
 /// [`ActionValue::Bool`] will be transformed into [`ActionValue::Axis1D`].
+///
+/// # Examples
+///
+/// Smooth camera look driven by mouse motion:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(PlayerCam[
+///     (
+///         Action::<Look>::new(),
+///         bindings![(
+///             Binding::mouse_motion(),
+///             SmoothNudge::from_half_life(0.02).with_time_kind(TimeKind::Virtual),
+///         )],
+///     )
+/// ]);
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Look;
+///
+/// #[derive(Component)]
+/// struct PlayerCam;
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(
     feature = "reflect",
@@ -35,6 +61,14 @@ impl SmoothNudge {
         }
     }
 
+    /// Creates a new instance that halves the remaining distance to the input value every `half_life` seconds.
+    ///
+    /// Useful for mouse smoothing, where the half-life is easier to reason about than the decay rate.
+    #[must_use]
+    pub fn from_half_life(half_life: f32) -> Self {
+        Self::new(core::f32::consts::LN_2 / half_life)
+    }
+
     #[must_use]
     pub fn with_time_kind(mut self, kind: TimeKind) -> Self {
         self.time_kind = kind;
@@ -103,6 +137,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn half_life() {
+        let (mut world, mut state) = context::init_world();
+        world
+            .resource_mut::<Time<Real>>()
+            .advance_by(Duration::from_millis(100));
+        let (time, actions) = state.get(&world);
+
+        let mut modifier = SmoothNudge::from_half_life(0.1);
+        for expected in [0.5, 0.75] {
+            let value = modifier.transform(&actions, &time, 1.0.into()).as_axis1d();
+            assert!(
+                (value - expected).abs() < 1e-6,
+                "`{value}` should be close to `{expected}`"
+            );
+        }
+    }
+
     #[test]
     fn bool_as_axis1d() {
         let (mut world, mut state) = context::init_world();
@@ -12,6 +12,32 @@ use crate::prelude::*;
 /// For example, [`ActionValue::Bool`] will remain unchanged for [`Self::XZY`] (X in the first place).
 /// But for variants like [`Self::YXZ`] (where X becomes the second component), it will be
 /// converted into [`ActionValue::Axis2D`] with Y set to the value.
+///
+/// # Examples
+///
+/// Map the left stick to movement on the XZ plane. Combined with [`Negate`]
+/// since pushing the stick up should move towards negative Z in Bevy.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// actions!(Player[
+///     (
+///         Action::<Movement>::new(),
+///         SwizzleAxis::XZY,
+///         Negate::z(),
+///         Bindings::spawn(Axial::left_stick()),
+///     ),
+/// ]);
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec3)]
+/// struct Movement;
+/// ```
 #[derive(Component, Debug, Clone, Copy)]
 #[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
 pub enum SwizzleAxis {
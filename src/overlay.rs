@@ -0,0 +1,249 @@
+//! Live display of action state for streaming, replay analysis and debugging.
+
+#[cfg(feature = "debug")]
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+#[cfg(feature = "debug")]
+use core::any;
+use core::{
+    fmt::{self, Write},
+    marker::PhantomData,
+};
+
+use bevy::prelude::*;
+
+use crate::prelude::*;
+
+/// Text widget that displays the live state of actions from context `C` on an entity.
+///
+/// Each line shows the action name, its value and state, and the binding that triggered it.
+/// Binding names are taken from [`InputDisplayMap`].
+///
+/// Updated in [`PostUpdate`] for each context registered with
+/// [`InputContextAppExt::add_input_context`]. Use [`Node`] to position the widget.
+///
+/// Requires the `ui` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// let player = world
+///     .spawn((
+///         Player,
+///         actions!(Player[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+///     ))
+///     .id();
+///
+/// world.spawn((
+///     InputOverlay::<Player>::new(player),
+///     Node {
+///         position_type: PositionType::Absolute,
+///         bottom: px(10),
+///         left: px(10),
+///         ..Default::default()
+///     },
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Jump;
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[require(Text)]
+pub struct InputOverlay<C: Component> {
+    /// Entity with context `C` to display.
+    pub context: Entity,
+    marker: PhantomData<C>,
+}
+
+impl<C: Component> InputOverlay<C> {
+    #[must_use]
+    pub fn new(context: Entity) -> Self {
+        Self {
+            context,
+            marker: PhantomData,
+        }
+    }
+}
+
+/// Text widget that displays the live state of all contexts and their actions.
+///
+/// Lists each entity with a context, whether the context is active, and its actions
+/// in the same format as [`InputOverlay`]. Useful to diagnose why an action doesn't fire.
+/// Context names are taken from [`ContextInfos`] if assigned.
+///
+/// Updated in [`PostUpdate`] for each context registered with
+/// [`InputContextAppExt::add_input_context`]. Use [`Node`] to position the widget.
+///
+/// Requires the `debug` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// let mut app = App::new();
+/// app.add_systems(Update, toggle_overlay);
+///
+/// fn toggle_overlay(
+///     mut commands: Commands,
+///     keys: Res<ButtonInput<KeyCode>>,
+///     overlay: Option<Single<Entity, With<InputDebugOverlay>>>,
+/// ) {
+///     if !keys.just_pressed(KeyCode::F3) {
+///         return;
+///     }
+///
+///     match overlay {
+///         Some(overlay) => commands.entity(*overlay).despawn(),
+///         None => {
+///             commands.spawn(InputDebugOverlay::default());
+///         }
+///     }
+/// }
+/// ```
+#[cfg(feature = "debug")]
+#[derive(Component, Debug, Default, Clone)]
+#[require(Text)]
+pub struct InputDebugOverlay {
+    /// Content for each context type, sorted by type name for a stable order.
+    sections: BTreeMap<&'static str, String>,
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn update_debug_sections<C: Component>(
+    display_map: Res<InputDisplayMap>,
+    infos: Res<ContextInfos>,
+    mut overlays: Query<&mut InputDebugOverlay>,
+    contexts: Query<(
+        Entity,
+        &Actions<C>,
+        &ContextActivity<C>,
+        Option<&InputActivity>,
+    )>,
+    actions: Query<(&Name, &ActionValue, &TriggerState, &ActionBinding)>,
+) {
+    if overlays.is_empty() {
+        return;
+    }
+
+    let mut section = String::new();
+    for (context, context_actions, activity, input_activity) in &contexts {
+        let active = **activity && input_activity.is_none_or(|&active| *active);
+        write_context::<C>(&mut section, &infos, context, active)
+            .expect("writing to a string shouldn't fail");
+        for (name, &value, &state, &binding) in actions.iter_many(context_actions) {
+            section.push('\n');
+            section.push_str("  ");
+            write_action(&mut section, &display_map, name, value, state, *binding)
+                .expect("writing to a string shouldn't fail");
+        }
+    }
+
+    for mut overlay in &mut overlays {
+        overlay
+            .sections
+            .insert(any::type_name::<C>(), section.clone());
+    }
+}
+
+#[cfg(feature = "debug")]
+pub(crate) fn update_debug_overlays(
+    mut overlays: Query<(&InputDebugOverlay, &mut Text), Changed<InputDebugOverlay>>,
+) {
+    for (overlay, mut text) in &mut overlays {
+        let mut content = String::new();
+        for section in overlay.sections.values().filter(|s| !s.is_empty()) {
+            if !content.is_empty() {
+                content.push('\n');
+            }
+            content.push_str(section);
+        }
+
+        if text.0 != content {
+            text.0 = content;
+        }
+    }
+}
+
+pub(crate) fn update_overlays<C: Component>(
+    display_map: Res<InputDisplayMap>,
+    mut overlays: Query<(&InputOverlay<C>, &mut Text)>,
+    contexts: Query<&Actions<C>>,
+    actions: Query<(&Name, &ActionValue, &TriggerState, &ActionBinding)>,
+) {
+    for (overlay, mut text) in &mut overlays {
+        let mut content = String::new();
+        if let Ok(context_actions) = contexts.get(overlay.context) {
+            for (name, &value, &state, &binding) in actions.iter_many(context_actions) {
+                if !content.is_empty() {
+                    content.push('\n');
+                }
+                write_action(&mut content, &display_map, name, value, state, *binding)
+                    .expect("writing to a string shouldn't fail");
+            }
+        }
+
+        if text.0 != content {
+            text.0 = content;
+        }
+    }
+}
+
+#[cfg(feature = "debug")]
+fn write_context<C: Component>(
+    content: &mut String,
+    infos: &ContextInfos,
+    context: Entity,
+    active: bool,
+) -> fmt::Result {
+    if !content.is_empty() {
+        content.push('\n');
+    }
+
+    match infos.get::<C>() {
+        Some(info) => write!(content, "{}", info.display_name)?,
+        None => write!(content, "{}", ShortName::of::<C>())?,
+    }
+    write!(
+        content,
+        " on {context} [{}]",
+        if active { "active" } else { "inactive" }
+    )
+}
+
+fn write_action(
+    content: &mut String,
+    display_map: &InputDisplayMap,
+    name: &Name,
+    value: ActionValue,
+    state: TriggerState,
+    binding: Option<Binding>,
+) -> fmt::Result {
+    write!(content, "{}: ", ShortName(name))?;
+    match value {
+        ActionValue::Bool(value) => write!(content, "{}", if value { "on" } else { "off" })?,
+        ActionValue::Axis1D(value) => write!(content, "{value:.2}")?,
+        ActionValue::Axis2D(value) => write!(content, "({:.2}, {:.2})", value.x, value.y)?,
+        ActionValue::Axis3D(value) => {
+            write!(content, "({:.2}, {:.2}, {:.2})", value.x, value.y, value.z)?
+        }
+    }
+    write!(content, " [{state:?}]")?;
+
+    if state != TriggerState::None
+        && let Some(binding) = binding
+    {
+        write!(content, " via {}", display_map.display(binding))?;
+    }
+
+    Ok(())
+}
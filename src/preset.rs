@@ -168,7 +168,9 @@ pub mod axial;
 pub mod bidirectional;
 pub mod cardinal;
 pub mod ordinal;
+pub mod six_dof;
 pub mod spatial;
+pub mod twin_stick;
 
 /// Helper trait for attaching a bundle to a preset.
 ///
@@ -11,6 +11,34 @@ use crate::prelude::*;
 /// In Bevy's 3D space, the -Z axis points forward and the +Z axis points
 /// toward the camera. To map movement correctly in 3D space for [`Transform::translation`],
 /// you will need to invert Y and apply it to Z inside your observer.
+///
+/// Use [`WithBundle::with`] to attach components to all directions, or methods like
+/// [`Self::with_south`] to attach them to a single direction.
+///
+/// # Examples
+///
+/// Slower backward movement.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[(
+///         Action::<Move>::new(),
+///         Bindings::spawn(Cardinal::wasd_keys().with_south(Scale::splat(0.5))),
+///     )]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Move;
+/// ```
 #[derive(Debug, Clone, Copy)]
 pub struct Cardinal<N, E, S, W> {
     pub north: N,
@@ -32,6 +60,69 @@ impl<N, E, S, W, T: Clone> WithBundle<T> for Cardinal<N, E, S, W> {
     }
 }
 
+impl<N, E, S, W> Cardinal<N, E, S, W> {
+    /// Clamps the combined value of the directions to a length of 1.0, so diagonal movement isn't faster.
+    ///
+    /// Without it, pressing two adjacent directions results in a magnitude of √2.
+    /// See [`NormalizedCardinal`] for details.
+    #[must_use]
+    pub fn normalized(self) -> NormalizedCardinal<N, E, S, W> {
+        NormalizedCardinal { cardinal: self }
+    }
+
+    /// Attaches a bundle only to the north entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_north<T>(self, bundle: T) -> Cardinal<(N, T), E, S, W> {
+        Cardinal {
+            north: (self.north, bundle),
+            east: self.east,
+            south: self.south,
+            west: self.west,
+        }
+    }
+
+    /// Attaches a bundle only to the east entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_east<T>(self, bundle: T) -> Cardinal<N, (E, T), S, W> {
+        Cardinal {
+            north: self.north,
+            east: (self.east, bundle),
+            south: self.south,
+            west: self.west,
+        }
+    }
+
+    /// Attaches a bundle only to the south entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_south<T>(self, bundle: T) -> Cardinal<N, E, (S, T), W> {
+        Cardinal {
+            north: self.north,
+            east: self.east,
+            south: (self.south, bundle),
+            west: self.west,
+        }
+    }
+
+    /// Attaches a bundle only to the west entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_west<T>(self, bundle: T) -> Cardinal<N, E, S, (W, T)> {
+        Cardinal {
+            north: self.north,
+            east: self.east,
+            south: self.south,
+            west: (self.west, bundle),
+        }
+    }
+}
+
 impl Cardinal<Binding, Binding, Binding, Binding> {
     /// Maps WASD keys as 2-dimensional input.
     #[must_use]
@@ -116,3 +207,80 @@ impl<N: Bundle, E: Bundle, S: Bundle, W: Bundle> SpawnableList<BindingOf> for Ca
         4
     }
 }
+
+/// A [`Cardinal`] preset that clamps the combined value of its directions to a length of 1.0.
+///
+/// Created by [`Cardinal::normalized`].
+///
+/// Inserts [`NormalizedDirection`] to each direction binding. The clamp is applied
+/// to the directions before they're combined with other action bindings, so it doesn't
+/// affect a gamepad stick or mouse motion on the same action. Action-level modifiers,
+/// like [`Scale`], are evaluated afterward as usual.
+///
+/// Since the clamp belongs to the direction bindings, it's removed together with them.
+/// After [`RebindEntityCommandsExt::rebind`] replaces the bindings, the new ones aren't clamped.
+/// [`RebindEntityCommandsExt::rebind_group`] keeps the binding entities, so the clamp stays.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     actions!(Player[(
+///         Action::<Move>::new(),
+///         Scale::splat(10.0),
+///         Bindings::spawn((Cardinal::wasd_keys().normalized(), Axial::left_stick())),
+///     )]),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Move;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct NormalizedCardinal<N, E, S, W> {
+    pub cardinal: Cardinal<N, E, S, W>,
+}
+
+/// Marks a binding as a direction of [`NormalizedCardinal`].
+///
+/// Consecutive bindings with this component are combined and clamped to a length of 1.0
+/// before they're combined with other bindings of the action.
+#[derive(Component, Debug, Default, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+pub struct NormalizedDirection;
+
+impl<N, E, S, W, T: Clone> WithBundle<T> for NormalizedCardinal<N, E, S, W> {
+    type Output = NormalizedCardinal<(N, T), (E, T), (S, T), (W, T)>;
+
+    fn with(self, bundle: T) -> Self::Output {
+        NormalizedCardinal {
+            cardinal: self.cardinal.with(bundle),
+        }
+    }
+}
+
+impl<N: Bundle, E: Bundle, S: Bundle, W: Bundle> SpawnableList<BindingOf>
+    for NormalizedCardinal<N, E, S, W>
+{
+    fn spawn(this: MovingPtr<'_, Self>, world: &mut World, entity: Entity) {
+        let cardinal = this.read().cardinal.with(NormalizedDirection);
+        move_as_ptr!(cardinal);
+        SpawnableList::spawn(cardinal, world, entity);
+    }
+
+    fn size_hint(&self) -> usize {
+        4
+    }
+}
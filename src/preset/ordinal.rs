@@ -7,6 +7,9 @@ use bevy::{
 use crate::prelude::*;
 
 /// A preset to 8 map buttons as 2-dimensional input.
+///
+/// Use [`WithBundle::with`] to attach components to all directions, or methods like
+/// [`Self::with_north`] to attach them to a single direction.
 #[derive(Debug, Clone, Copy)]
 pub struct Ordinal<N, NE, E, SE, S, SW, W, NW> {
     pub north: N,
@@ -36,6 +39,144 @@ impl<N, NE, E, SE, S, SW, W, NW, T: Clone> WithBundle<T> for Ordinal<N, NE, E, S
     }
 }
 
+impl<N, NE, E, SE, S, SW, W, NW> Ordinal<N, NE, E, SE, S, SW, W, NW> {
+    /// Attaches a bundle only to the north entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_north<T>(self, bundle: T) -> Ordinal<(N, T), NE, E, SE, S, SW, W, NW> {
+        Ordinal {
+            north: (self.north, bundle),
+            north_east: self.north_east,
+            east: self.east,
+            south_east: self.south_east,
+            south: self.south,
+            south_west: self.south_west,
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the north east entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_north_east<T>(self, bundle: T) -> Ordinal<N, (NE, T), E, SE, S, SW, W, NW> {
+        Ordinal {
+            north: self.north,
+            north_east: (self.north_east, bundle),
+            east: self.east,
+            south_east: self.south_east,
+            south: self.south,
+            south_west: self.south_west,
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the east entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_east<T>(self, bundle: T) -> Ordinal<N, NE, (E, T), SE, S, SW, W, NW> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: (self.east, bundle),
+            south_east: self.south_east,
+            south: self.south,
+            south_west: self.south_west,
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the south east entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_south_east<T>(self, bundle: T) -> Ordinal<N, NE, E, (SE, T), S, SW, W, NW> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: self.east,
+            south_east: (self.south_east, bundle),
+            south: self.south,
+            south_west: self.south_west,
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the south entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_south<T>(self, bundle: T) -> Ordinal<N, NE, E, SE, (S, T), SW, W, NW> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: self.east,
+            south_east: self.south_east,
+            south: (self.south, bundle),
+            south_west: self.south_west,
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the south west entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_south_west<T>(self, bundle: T) -> Ordinal<N, NE, E, SE, S, (SW, T), W, NW> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: self.east,
+            south_east: self.south_east,
+            south: self.south,
+            south_west: (self.south_west, bundle),
+            west: self.west,
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the west entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_west<T>(self, bundle: T) -> Ordinal<N, NE, E, SE, S, SW, (W, T), NW> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: self.east,
+            south_east: self.south_east,
+            south: self.south,
+            south_west: self.south_west,
+            west: (self.west, bundle),
+            north_west: self.north_west,
+        }
+    }
+
+    /// Attaches a bundle only to the north west entity.
+    ///
+    /// See also [`WithBundle::with`] to attach a bundle to every entity.
+    #[must_use]
+    pub fn with_north_west<T>(self, bundle: T) -> Ordinal<N, NE, E, SE, S, SW, W, (NW, T)> {
+        Ordinal {
+            north: self.north,
+            north_east: self.north_east,
+            east: self.east,
+            south_east: self.south_east,
+            south: self.south,
+            south_west: self.south_west,
+            west: self.west,
+            north_west: (self.north_west, bundle),
+        }
+    }
+}
+
 impl Ordinal<Binding, Binding, Binding, Binding, Binding, Binding, Binding, Binding> {
     /// Maps numpad keys as 2-dimensional input.
     #[must_use]
@@ -0,0 +1,178 @@
+use core::marker::PhantomData;
+
+use bevy::{ecs::spawn::SpawnableList, prelude::*, ptr::MovingPtr};
+
+use crate::prelude::*;
+
+/// A preset to spawn translation and rotation actions for six-degree-of-freedom flight.
+///
+/// Like [`TwinStick`], spawns actions instead of bindings, so it needs to be used with
+/// [`Actions::spawn`](bevy::prelude::SpawnRelated::spawn).
+///
+/// Spawns action `T` with [`Self::translation`] bindings and action `R` with [`Self::rotation`] bindings.
+/// Both actions need to output [`Vec3`]:
+/// - Translation uses the same axes as [`Spatial`]: right is +X, up is +Y and backward is +Z.
+/// - Rotation contains angular input around each local axis: pitch around X (nose up is positive),
+///   yaw around Y (left is positive) and roll around Z (left is positive).
+///   It follows the right-hand rule, so it can be passed to [`Quat::from_scaled_axis`].
+///
+/// Use [`Self::keyboard_and_gamepad`] for the common layout or [`Self::new`] to assign your own bindings.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Ship,
+///     Actions::<Ship>::spawn(SixDof::<Thrust, Steer>::keyboard_and_gamepad()),
+/// ));
+///
+/// fn apply_steering(steer: On<Fire<Steer>>, mut transforms: Query<&mut Transform>) {
+///     let mut transform = transforms.get_mut(steer.context).unwrap();
+///     transform.rotate_local(Quat::from_scaled_axis(steer.value * 0.02));
+/// }
+///
+/// #[derive(Component)]
+/// struct Ship;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec3)]
+/// struct Thrust;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec3)]
+/// struct Steer;
+/// ```
+///
+/// Custom bindings:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// SixDof::<Thrust, Steer>::new(
+///     Spatial::new(
+///         KeyCode::KeyI,
+///         KeyCode::KeyK,
+///         KeyCode::KeyJ,
+///         KeyCode::KeyL,
+///         KeyCode::KeyU,
+///         KeyCode::KeyO,
+///     ),
+///     // Yaw only.
+///     Bidirectional::new(KeyCode::KeyA, KeyCode::KeyD).with(SwizzleAxis::YXZ),
+/// );
+/// # #[derive(InputAction)]
+/// # #[action_output(Vec3)]
+/// # struct Thrust;
+/// # #[derive(InputAction)]
+/// # #[action_output(Vec3)]
+/// # struct Steer;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SixDof<T, R, TB = (), RB = ()> {
+    /// Bindings for the translation action.
+    pub translation: TB,
+
+    /// Bindings for the rotation action.
+    pub rotation: RB,
+
+    marker: PhantomData<(T, R)>,
+}
+
+impl<T, R> SixDof<T, R> {
+    /// Creates a new instance with the given binding presets for each action.
+    #[must_use]
+    pub fn new<TB, RB>(translation: TB, rotation: RB) -> SixDof<T, R, TB, RB> {
+        SixDof {
+            translation,
+            rotation,
+            marker: PhantomData,
+        }
+    }
+
+    /// Creates a new instance with the common keyboard and gamepad layout.
+    ///
+    /// Translation:
+    /// - WASD keys for horizontal movement, Space and Left Control for vertical movement.
+    /// - Left stick for horizontal movement, triggers for vertical movement.
+    ///
+    /// Rotation:
+    /// - Arrow keys for pitch and yaw, Q and E for roll.
+    /// - Right stick for pitch and yaw, bumpers for roll.
+    ///
+    /// Stick axes have [`DeadZone::default`] applied.
+    #[must_use]
+    pub fn keyboard_and_gamepad() -> SixDof<
+        T,
+        R,
+        impl SpawnableList<BindingOf> + Send + Sync + 'static,
+        impl SpawnableList<BindingOf> + Send + Sync + 'static,
+    > {
+        Self::new(
+            (
+                Spatial::new(
+                    KeyCode::KeyW,
+                    KeyCode::KeyS,
+                    KeyCode::KeyA,
+                    KeyCode::KeyD,
+                    KeyCode::Space,
+                    KeyCode::ControlLeft,
+                ),
+                Spawn((Binding::from(GamepadAxis::LeftStickX), DeadZone::default())),
+                Spawn((
+                    Binding::from(GamepadAxis::LeftStickY),
+                    DeadZone::default(),
+                    Negate::all(),
+                    SwizzleAxis::ZYX,
+                )),
+                Bidirectional::new(GamepadButton::RightTrigger2, GamepadButton::LeftTrigger2)
+                    .with(SwizzleAxis::YXZ),
+            ),
+            (
+                Bidirectional::new(KeyCode::ArrowUp, KeyCode::ArrowDown),
+                Bidirectional::new(KeyCode::ArrowLeft, KeyCode::ArrowRight).with(SwizzleAxis::YXZ),
+                Bidirectional::new(KeyCode::KeyQ, KeyCode::KeyE).with(SwizzleAxis::ZYX),
+                Spawn((Binding::from(GamepadAxis::RightStickY), DeadZone::default())),
+                Spawn((
+                    Binding::from(GamepadAxis::RightStickX),
+                    DeadZone::default(),
+                    Negate::all(),
+                    SwizzleAxis::YXZ,
+                )),
+                Bidirectional::new(GamepadButton::LeftTrigger, GamepadButton::RightTrigger)
+                    .with(SwizzleAxis::ZYX),
+            ),
+        )
+    }
+}
+
+impl<C, T, R, TB, RB> SpawnableList<ActionOf<C>> for SixDof<T, R, TB, RB>
+where
+    C: Component,
+    T: InputAction<Output = Vec3>,
+    R: InputAction<Output = Vec3>,
+    TB: SpawnableList<BindingOf> + Send + Sync + 'static,
+    RB: SpawnableList<BindingOf> + Send + Sync + 'static,
+{
+    fn spawn(this: MovingPtr<'_, Self>, world: &mut World, entity: Entity) {
+        let six_dof = this.read();
+        world.spawn((
+            ActionOf::<C>::new(entity),
+            Action::<T>::new(),
+            Bindings::spawn(six_dof.translation),
+        ));
+        world.spawn((
+            ActionOf::<C>::new(entity),
+            Action::<R>::new(),
+            Bindings::spawn(six_dof.rotation),
+        ));
+    }
+
+    fn size_hint(&self) -> usize {
+        2
+    }
+}
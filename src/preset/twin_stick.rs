@@ -0,0 +1,112 @@
+use core::marker::PhantomData;
+
+use bevy::{ecs::spawn::SpawnableList, prelude::*, ptr::MovingPtr};
+
+use crate::prelude::*;
+
+/// A preset to spawn movement and aim actions for twin-stick controls.
+///
+/// Unlike other presets, spawns actions instead of bindings, so it needs to be used with
+/// [`Actions::spawn`](bevy::prelude::SpawnRelated::spawn).
+///
+/// Spawns the following actions:
+/// - `M` bound to [`Cardinal::wasd_keys`] and [`Axial::left_stick`] with [`Self::move_dead_zone`]
+///   applied at the action level.
+/// - `A` bound to [`Axial::right_stick`] with [`Self::aim_dead_zone`] applied to each stick axis
+///   and to [`Binding::mouse_motion`] with [`Negate::y`], so up is positive for both devices.
+///
+/// Mouse motion is in pixels, while stick input is in the range from -1.0 to 1.0.
+/// If you only need the aim direction, normalize the value.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     Player,
+///     Actions::<Player>::spawn((
+///         TwinStick::<Move, Aim>::default(),
+///         // Additional actions need to be wrapped into `Spawn`.
+///         Spawn((Action::<Fire>::new(), bindings![MouseButton::Left, GamepadButton::RightTrigger2])),
+///     )),
+/// ));
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Move;
+///
+/// #[derive(InputAction)]
+/// #[action_output(Vec2)]
+/// struct Aim;
+///
+/// #[derive(InputAction)]
+/// #[action_output(bool)]
+/// struct Fire;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TwinStick<M, A> {
+    /// Dead zone for the movement action.
+    ///
+    /// By default set to [`DeadZone::default`].
+    pub move_dead_zone: DeadZone,
+
+    /// Dead zone for each axis of the right stick.
+    ///
+    /// By default set to [`DeadZone::default`].
+    pub aim_dead_zone: DeadZone,
+
+    marker: PhantomData<(M, A)>,
+}
+
+impl<M, A> TwinStick<M, A> {
+    /// Creates a new instance with the given dead zones.
+    #[must_use]
+    pub fn new(move_dead_zone: DeadZone, aim_dead_zone: DeadZone) -> Self {
+        Self {
+            move_dead_zone,
+            aim_dead_zone,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M, A> Default for TwinStick<M, A> {
+    fn default() -> Self {
+        Self::new(Default::default(), Default::default())
+    }
+}
+
+impl<C, M, A> SpawnableList<ActionOf<C>> for TwinStick<M, A>
+where
+    C: Component,
+    M: InputAction<Output = Vec2>,
+    A: InputAction<Output = Vec2>,
+{
+    fn spawn(this: MovingPtr<'_, Self>, world: &mut World, entity: Entity) {
+        let twin_stick = this.read();
+        world.spawn((
+            ActionOf::<C>::new(entity),
+            Action::<M>::new(),
+            twin_stick.move_dead_zone,
+            Bindings::spawn((Cardinal::wasd_keys(), Axial::left_stick())),
+        ));
+        world.spawn((
+            ActionOf::<C>::new(entity),
+            Action::<A>::new(),
+            Bindings::spawn((
+                Axial::right_stick().with(twin_stick.aim_dead_zone),
+                Spawn((Binding::mouse_motion(), Negate::y())),
+            )),
+        ));
+    }
+
+    fn size_hint(&self) -> usize {
+        2
+    }
+}
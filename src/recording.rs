@@ -0,0 +1,296 @@
+/*!
+Recording and playback of raw input for automated tests and replays.
+
+Insert [`InputRecorder`] to capture the state of input devices every frame into an [`InputRecording`].
+Insert [`InputPlayback`] with a recording to replay it: recorded state is written into Bevy input resources
+and [`Gamepad`] components before contexts are evaluated, so actions go through the usual evaluation
+with all modifiers and conditions.
+
+Both run in [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare) once per frame. Time-based conditions and modifiers
+depend on the frame time, so for deterministic results use a fixed frame time during both
+recording and playback, for example via [`TimeUpdateStrategy::ManualDuration`](bevy::time::TimeUpdateStrategy::ManualDuration).
+
+Gamepads are recorded in their query order and replayed into connected gamepads in the same order.
+
+Playback speed can be changed with [`InputPlayback::speed`]. At 2.0, two recorded frames are replayed
+per app frame, and at 0.5, each recorded frame is held for two app frames. Deltas returned by
+[`ContextTime::delta_kind`](crate::context::time::ContextTime::delta_kind) are scaled by the same
+factor during playback, so time-based conditions and modifiers observe the recorded timing.
+
+With the `serialize` feature, recordings can be saved and loaded using any `serde` format.
+
+# Examples
+
+```
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+
+let mut app = App::new();
+app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+    .finish();
+
+app.insert_resource(InputRecorder::default());
+app.world_mut()
+    .resource_mut::<ButtonInput<KeyCode>>()
+    .press(KeyCode::Space);
+app.update();
+
+let recorder = app.world_mut().remove_resource::<InputRecorder>().unwrap();
+assert_eq!(recorder.recording.frames.len(), 1);
+
+// Replay later, possibly in another app.
+app.insert_resource(InputPlayback::new(recorder.recording));
+```
+*/
+
+use alloc::vec::Vec;
+use core::hash::Hash;
+
+use bevy::{
+    input::{
+        gamepad::GamepadInput,
+        mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
+    },
+    prelude::*,
+};
+use log::debug;
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
+
+/// Captures input device state every frame while present.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct InputRecorder {
+    /// Frames captured so far.
+    pub recording: InputRecording,
+}
+
+/// Replays [`InputRecording`] frame by frame, overriding input from devices.
+///
+/// Removed automatically after the last frame, releasing all recorded inputs.
+///
+/// See the [module-level documentation](self) for more details.
+#[derive(Resource, Debug, Clone)]
+pub struct InputPlayback {
+    /// Recording to replay.
+    pub recording: InputRecording,
+
+    /// Index of the next frame to replay.
+    pub frame: usize,
+
+    /// Number of recorded frames to replay per app frame.
+    ///
+    /// When skipping frames, mouse motion and scroll are summed and the pressed inputs from the last
+    /// skipped frame are applied. Set to 0.0 to pause the playback.
+    ///
+    /// By default set to 1.0.
+    pub speed: f32,
+
+    /// Fractional part of the accumulated frames to replay.
+    progress: f32,
+}
+
+impl InputPlayback {
+    /// Creates a new instance that replays the recording from the first frame.
+    #[must_use]
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            frame: 0,
+            speed: 1.0,
+            progress: 0.0,
+        }
+    }
+
+    /// Returns a new instance with the given playback speed.
+    ///
+    /// See [`Self::speed`] for details.
+    #[must_use]
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Returns the speed by which time deltas should be scaled.
+    pub(crate) fn time_scale(&self) -> f32 {
+        self.speed.max(0.0)
+    }
+
+    /// Returns `true` if all frames have been replayed.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.recording.frames.len()
+    }
+}
+
+/// Timeline of input device states.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct InputRecording {
+    /// Recorded states, one per frame.
+    pub frames: Vec<InputFrame>,
+}
+
+/// State of all input devices for a single frame.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct InputFrame {
+    /// Pressed keyboard keys.
+    pub keys: Vec<KeyCode>,
+
+    /// Pressed mouse buttons.
+    pub mouse_buttons: Vec<MouseButton>,
+
+    /// Mouse movement from [`AccumulatedMouseMotion`].
+    pub mouse_motion: Vec2,
+
+    /// Mouse wheel from [`AccumulatedMouseScroll`].
+    pub mouse_scroll: Vec2,
+
+    /// Gamepad states in query order.
+    pub gamepads: Vec<GamepadFrame>,
+}
+
+/// State of a single gamepad for a single frame.
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
+pub struct GamepadFrame {
+    /// Pressed buttons.
+    pub pressed: Vec<GamepadButton>,
+
+    /// Non-zero analog values of buttons.
+    pub buttons: Vec<(GamepadButton, f32)>,
+
+    /// Non-zero values of axes.
+    pub axes: Vec<(GamepadAxis, f32)>,
+}
+
+pub(crate) fn record(
+    mut recorder: ResMut<InputRecorder>,
+    keys: Option<Res<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<Res<ButtonInput<MouseButton>>>,
+    mouse_motion: Option<Res<AccumulatedMouseMotion>>,
+    mouse_scroll: Option<Res<AccumulatedMouseScroll>>,
+    gamepads: Query<&Gamepad>,
+) {
+    let frame = InputFrame {
+        keys: keys.iter().flat_map(|k| k.get_pressed()).copied().collect(),
+        mouse_buttons: mouse_buttons
+            .iter()
+            .flat_map(|b| b.get_pressed())
+            .copied()
+            .collect(),
+        mouse_motion: mouse_motion.map(|m| m.delta).unwrap_or_default(),
+        mouse_scroll: mouse_scroll.map(|s| s.delta).unwrap_or_default(),
+        gamepads: gamepads
+            .iter()
+            .map(|gamepad| {
+                let mut frame = GamepadFrame {
+                    pressed: gamepad.get_pressed().copied().collect(),
+                    ..Default::default()
+                };
+                for (&input, value) in gamepad.analog().all_axes_and_values() {
+                    if value == 0.0 {
+                        continue;
+                    }
+                    match input {
+                        GamepadInput::Button(button) => frame.buttons.push((button, value)),
+                        GamepadInput::Axis(axis) => frame.axes.push((axis, value)),
+                    }
+                }
+                frame
+            })
+            .collect(),
+    };
+
+    recorder.recording.frames.push(frame);
+}
+
+pub(crate) fn playback(
+    mut commands: Commands,
+    mut playback: ResMut<InputPlayback>,
+    keys: Option<ResMut<ButtonInput<KeyCode>>>,
+    mouse_buttons: Option<ResMut<ButtonInput<MouseButton>>>,
+    mouse_motion: Option<ResMut<AccumulatedMouseMotion>>,
+    mouse_scroll: Option<ResMut<AccumulatedMouseScroll>>,
+    mut gamepads: Query<&mut Gamepad>,
+) {
+    playback.progress += playback.time_scale();
+    let count = playback.progress as usize;
+    playback.progress -= count as f32;
+    if count == 0 {
+        // Hold the current frame, but don't repeat relative motion.
+        if let Some(mut mouse_motion) = mouse_motion {
+            mouse_motion.delta = Vec2::ZERO;
+        }
+        if let Some(mut mouse_scroll) = mouse_scroll {
+            mouse_scroll.delta = Vec2::ZERO;
+        }
+        return;
+    }
+
+    let start = playback.frame;
+    let end = start + count;
+    playback.frame = end;
+
+    let frames = &playback.recording.frames;
+    let skipped = &frames[start.min(frames.len())..end.min(frames.len())];
+    let motion: Vec2 = skipped.iter().map(|frame| frame.mouse_motion).sum();
+    let scroll: Vec2 = skipped.iter().map(|frame| frame.mouse_scroll).sum();
+
+    let empty = InputFrame::default();
+    let frame = frames.get(end - 1).unwrap_or_else(|| {
+        debug!("finishing playback after {} frames", frames.len());
+        commands.remove_resource::<InputPlayback>();
+        &empty
+    });
+
+    if let Some(mut keys) = keys {
+        apply_buttons(&mut keys, &frame.keys);
+    }
+    if let Some(mut mouse_buttons) = mouse_buttons {
+        apply_buttons(&mut mouse_buttons, &frame.mouse_buttons);
+    }
+    if let Some(mut mouse_motion) = mouse_motion {
+        mouse_motion.delta = motion;
+    }
+    if let Some(mut mouse_scroll) = mouse_scroll {
+        mouse_scroll.delta = scroll;
+    }
+
+    let empty = GamepadFrame::default();
+    for (index, mut gamepad) in gamepads.iter_mut().enumerate() {
+        let gamepad_frame = frame.gamepads.get(index).unwrap_or(&empty);
+        apply_buttons(gamepad.digital_mut(), &gamepad_frame.pressed);
+
+        let inputs: Vec<_> = gamepad.analog().all_axes().copied().collect();
+        for input in inputs {
+            gamepad.analog_mut().set(input, 0.0);
+        }
+        for &(button, value) in &gamepad_frame.buttons {
+            gamepad.analog_mut().set(button, value);
+        }
+        for &(axis, value) in &gamepad_frame.axes {
+            gamepad.analog_mut().set(axis, value);
+        }
+    }
+}
+
+/// Updates the input to have exactly the given buttons pressed.
+fn apply_buttons<T: Copy + Eq + Hash + Send + Sync + 'static>(
+    input: &mut ButtonInput<T>,
+    pressed: &[T],
+) {
+    let released: Vec<_> = input
+        .get_pressed()
+        .filter(|button| !pressed.contains(button))
+        .copied()
+        .collect();
+    for button in released {
+        input.release(button);
+    }
+    for &button in pressed {
+        input.press(button);
+    }
+}
@@ -0,0 +1,249 @@
+//! On-screen stick and button widgets for touch devices.
+//!
+//! Widgets write their state to a [`Gamepad`] entity marked with [`VirtualGamepad`],
+//! so existing [`Binding::GamepadAxis`](crate::prelude::Binding::GamepadAxis) and
+//! [`Binding::GamepadButton`](crate::prelude::Binding::GamepadButton) bindings work without any changes,
+//! as long as the context reads from [`GamepadDevice::Any`](crate::prelude::GamepadDevice::Any)
+//! or from this entity.
+//!
+//! The gamepad entity is spawned automatically when the first widget appears.
+//!
+//! Widgets are pressed by [`Touches`], so multiple widgets can be held at the same time,
+//! like moving with a stick while pressing a button. Each touch belongs to the widget under its start position
+//! until it ends, even if the finger leaves the widget. Touch positions are expected to be in the same window
+//! that the UI is rendered to, with the UI camera covering the whole window.
+//!
+//! While there are no touches, widgets fall back to [`Interaction`] from `bevy_ui`,
+//! so they can also be clicked with a mouse.
+
+use bevy::{
+    platform::collections::HashMap,
+    prelude::*,
+    ui::{RelativeCursorPosition, UiGlobalTransform},
+};
+use log::debug;
+
+/// Marker for the [`Gamepad`] entity controlled by [`VirtualStick`] and [`VirtualButton`] widgets.
+///
+/// Spawned automatically during [`EnhancedInputSystems::Prepare`](crate::EnhancedInputSystems::Prepare)
+/// when any widget exists. Query it to assign the gamepad to a context with
+/// [`GamepadDevice::Single`](crate::prelude::GamepadDevice::Single).
+///
+/// Requires the `ui` feature.
+#[derive(Component, Default, Debug, Clone, Copy)]
+#[cfg_attr(
+    feature = "reflect",
+    derive(Reflect),
+    reflect(Clone, Component, Debug, Default)
+)]
+#[require(Name::new("Virtual Gamepad"), Gamepad)]
+pub struct VirtualGamepad;
+
+/// On-screen stick that controls a pair of axes on the [`VirtualGamepad`].
+///
+/// While the node is pressed, the offset of the touch or pointer from the node center is mapped to the axes,
+/// with the node edge corresponding to full tilt. Up is positive, like on physical sticks.
+/// When released, the axes are reset to zero.
+///
+/// Requires the `ui` feature.
+///
+/// # Examples
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_enhanced_input::prelude::*;
+///
+/// # let mut world = World::new();
+/// world.spawn((
+///     VirtualStick::left(),
+///     Node {
+///         position_type: PositionType::Absolute,
+///         left: px(40),
+///         bottom: px(40),
+///         width: px(160),
+///         height: px(160),
+///         border_radius: BorderRadius::MAX,
+///         ..Default::default()
+///     },
+///     BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
+/// ));
+/// world.spawn((
+///     VirtualButton::new(GamepadButton::South),
+///     Node {
+///         position_type: PositionType::Absolute,
+///         right: px(40),
+///         bottom: px(40),
+///         width: px(80),
+///         height: px(80),
+///         border_radius: BorderRadius::MAX,
+///         ..Default::default()
+///     },
+///     BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.2)),
+/// ));
+/// ```
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[require(Node, Interaction, RelativeCursorPosition)]
+pub struct VirtualStick {
+    /// Axis controlled by horizontal movement.
+    pub x: GamepadAxis,
+
+    /// Axis controlled by vertical movement.
+    pub y: GamepadAxis,
+}
+
+impl VirtualStick {
+    /// Creates a new instance that controls [`GamepadAxis::LeftStickX`] and [`GamepadAxis::LeftStickY`].
+    #[must_use]
+    pub const fn left() -> Self {
+        Self::new(GamepadAxis::LeftStickX, GamepadAxis::LeftStickY)
+    }
+
+    /// Creates a new instance that controls [`GamepadAxis::RightStickX`] and [`GamepadAxis::RightStickY`].
+    #[must_use]
+    pub const fn right() -> Self {
+        Self::new(GamepadAxis::RightStickX, GamepadAxis::RightStickY)
+    }
+
+    /// Creates a new instance that controls the given axes.
+    #[must_use]
+    pub const fn new(x: GamepadAxis, y: GamepadAxis) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the stick value for a pointer position relative to the node.
+    fn value(position: Vec2) -> Vec2 {
+        // The position is in the range from -0.5 to 0.5 with Y pointing down.
+        (Vec2::new(position.x, -position.y) * 2.0).clamp_length_max(1.0)
+    }
+}
+
+/// On-screen button that controls a button on the [`VirtualGamepad`].
+///
+/// The button is held while the node is pressed.
+///
+/// Requires the `ui` feature.
+///
+/// See [`VirtualStick`] for an example.
+#[derive(Component, Debug, Clone, Copy)]
+#[cfg_attr(feature = "reflect", derive(Reflect), reflect(Clone, Component, Debug))]
+#[require(Node, Interaction)]
+pub struct VirtualButton {
+    /// Button pressed by the widget.
+    pub button: GamepadButton,
+}
+
+impl VirtualButton {
+    /// Creates a new instance that presses the given button.
+    #[must_use]
+    pub const fn new(button: GamepadButton) -> Self {
+        Self { button }
+    }
+}
+
+pub(crate) fn update_virtual_gamepad(
+    mut commands: Commands,
+    mut touch_widgets: Local<HashMap<u64, Entity>>,
+    touches: Option<Res<Touches>>,
+    mut gamepads: Query<&mut Gamepad, With<VirtualGamepad>>,
+    sticks: Query<(
+        Entity,
+        &VirtualStick,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &Interaction,
+        &RelativeCursorPosition,
+    )>,
+    buttons: Query<(
+        Entity,
+        &VirtualButton,
+        &ComputedNode,
+        &UiGlobalTransform,
+        &Interaction,
+    )>,
+) {
+    if sticks.is_empty() && buttons.is_empty() {
+        touch_widgets.clear();
+        return;
+    }
+
+    let Some(mut gamepad) = gamepads.iter_mut().next() else {
+        let entity = commands.spawn(VirtualGamepad).id();
+        debug!("spawning virtual gamepad `{entity}`");
+        return;
+    };
+
+    let touches = touches.as_deref();
+    let mut use_interaction = true;
+    if let Some(touches) = touches {
+        touch_widgets.retain(|&id, _| touches.get_pressed(id).is_some());
+        for touch in touches.iter_just_pressed() {
+            let widget = sticks
+                .iter()
+                .map(|(entity, _, node, transform, ..)| (entity, node, transform))
+                .chain(
+                    buttons
+                        .iter()
+                        .map(|(entity, _, node, transform, _)| (entity, node, transform)),
+                )
+                .find(|&(_, node, &transform)| {
+                    node.contains_point(transform, physical_position(node, touch.start_position()))
+                });
+
+            if let Some((entity, ..)) = widget {
+                debug!("assigning touch {} to `{entity}`", touch.id());
+                touch_widgets.insert(touch.id(), entity);
+            }
+        }
+        use_interaction = touches.iter().next().is_none();
+    }
+
+    let touch_position = |widget| {
+        let (&id, _) = touch_widgets
+            .iter()
+            .find(|&(_, &entity)| entity == widget)?;
+        touches?.get_pressed(id).map(|touch| touch.position())
+    };
+
+    for (entity, stick, node, &transform, &interaction, position) in &sticks {
+        let normalized = match touch_position(entity) {
+            Some(touch_position) => {
+                node.normalize_point(transform, physical_position(node, touch_position))
+            }
+            None if use_interaction && interaction == Interaction::Pressed => position.normalized,
+            None => None,
+        };
+        let value = normalized.map(VirtualStick::value).unwrap_or_default();
+
+        let analog = gamepad.analog_mut();
+        if analog.get(stick.x) != Some(value.x) {
+            analog.set(stick.x, value.x);
+        }
+        if analog.get(stick.y) != Some(value.y) {
+            analog.set(stick.y, value.y);
+        }
+    }
+
+    for (entity, button, _, _, &interaction) in &buttons {
+        let pressed = touch_position(entity).is_some()
+            || (use_interaction && interaction == Interaction::Pressed);
+        if gamepad.pressed(button.button) == pressed {
+            continue;
+        }
+
+        let digital = gamepad.digital_mut();
+        if pressed {
+            digital.press(button.button);
+        } else {
+            digital.release(button.button);
+        }
+        gamepad
+            .analog_mut()
+            .set(button.button, if pressed { 1.0 } else { 0.0 });
+    }
+}
+
+/// Converts a position in logical window pixels into physical pixels used by the node.
+fn physical_position(node: &ComputedNode, position: Vec2) -> Vec2 {
+    position / node.inverse_scale_factor()
+}
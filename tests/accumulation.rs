@@ -70,6 +70,56 @@ fn cumulative() {
     assert_eq!(*action, Vec2::ZERO, "up and down should cancel each other");
 }
 
+#[test]
+fn first() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionSettings {
+                    accumulation: Accumulation::First,
+                    ..Default::default()
+                },
+                Bindings::spawn(Cardinal::wasd_keys())
+            )]
+        ),
+    ));
+
+    app.update();
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::KeyW);
+    keys.press(KeyCode::KeyD);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        *action,
+        Vec2::new(1.0, 1.0),
+        "axes should be taken from different bindings"
+    );
+
+    let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+    keys.press(KeyCode::KeyS);
+
+    app.update();
+
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        *action,
+        Vec2::new(1.0, 1.0),
+        "only the first binding should be taken for each axis"
+    );
+}
+
 #[derive(Component)]
 struct TestContext;
 
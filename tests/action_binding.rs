@@ -0,0 +1,87 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn input() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<Completed>()
+        .add_observer(
+            |complete: On<Complete<Test>>, mut completed: ResMut<Completed>| {
+                completed.0 = Some(complete.binding);
+            },
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![KeyCode::KeyA, GamepadButton::South],
+            )]
+        ),
+    ));
+
+    app.update();
+    assert_eq!(*binding(&mut app), None);
+
+    app.press(GamepadButton::South).update();
+    assert_eq!(*binding(&mut app), Some(GamepadButton::South.into()));
+
+    app.release(GamepadButton::South).update();
+    assert_eq!(
+        *binding(&mut app),
+        Some(GamepadButton::South.into()),
+        "binding should be kept after release"
+    );
+
+    let completed = app.world().resource::<Completed>();
+    assert_eq!(completed.0, Some(Some(GamepadButton::South.into())));
+}
+
+#[test]
+fn mock() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Test>::new(), bindings![KeyCode::KeyA])]),
+        ))
+        .id();
+
+    app.press(KeyCode::KeyA).update();
+    assert_eq!(*binding(&mut app), Some(KeyCode::KeyA.into()));
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Test>(TriggerState::Fired, true)
+        .unwrap();
+    app.update();
+
+    assert_eq!(*binding(&mut app), None);
+}
+
+fn binding(app: &mut App) -> ActionBinding {
+    *app.world_mut()
+        .query::<&ActionBinding>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Resource, Default)]
+struct Completed(Option<Option<Binding>>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
@@ -0,0 +1,148 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn exclusive() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Slot1>::new(), bindings![KeyCode::Digit1]),
+            (Action::<Slot2>::new(), bindings![KeyCode::Digit2]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Digit1).update();
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::Fired);
+
+    app.press(KeyCode::Digit2).update();
+    assert_eq!(state::<Slot2>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::None);
+    assert_eq!(events::<Slot1>(&mut app), ActionEvents::CANCEL);
+
+    app.update();
+    assert_eq!(state::<Slot2>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Slot1>(&mut app),
+        TriggerState::None,
+        "canceled action shouldn't fire until its input is released"
+    );
+
+    app.release(KeyCode::Digit1).update();
+    app.press(KeyCode::Digit1).update();
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Slot2>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn same_frame() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Slot1>::new(), bindings![KeyCode::Digit1]),
+            (Action::<Slot2>::new(), bindings![KeyCode::Digit2]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Digit1).press(KeyCode::Digit2).update();
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Slot2>(&mut app), TriggerState::None);
+    assert_eq!(
+        events::<Slot2>(&mut app),
+        ActionEvents::empty(),
+        "action that wasn't active shouldn't be canceled"
+    );
+}
+
+#[test]
+fn component() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Slot1>::new(), bindings![KeyCode::Digit1]),
+            (Action::<Slot2>::new(), ActionGroup(1), bindings![KeyCode::Digit2]),
+            (Action::<Melee>::new(), SLOTS, bindings![KeyCode::KeyV]),
+            (Action::<Jump>::new(), bindings![KeyCode::Space]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Digit1)
+        .press(KeyCode::Digit2)
+        .press(KeyCode::Space)
+        .update();
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Slot2>(&mut app),
+        TriggerState::Fired,
+        "component should override the group"
+    );
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+
+    app.press(KeyCode::KeyV).update();
+    assert_eq!(state::<Melee>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Slot1>(&mut app), TriggerState::None);
+    assert_eq!(state::<Slot2>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::Fired,
+        "actions without a group shouldn't be affected"
+    );
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn events<A: InputAction>(app: &mut App) -> ActionEvents {
+    *app.world_mut()
+        .query_filtered::<&ActionEvents, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+const SLOTS: ActionGroup = ActionGroup(0);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+#[action_group(SLOTS)]
+struct Slot1;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+#[action_group(SLOTS)]
+struct Slot2;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Melee;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
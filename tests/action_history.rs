@@ -0,0 +1,101 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn numpad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Move>::new(),
+                ActionHistory::default(),
+                Bindings::spawn(Cardinal::wasd_keys()),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::KeyS).update();
+    app.update();
+
+    app.press(KeyCode::KeyD).update();
+
+    app.release(KeyCode::KeyS).update();
+    app.update();
+    app.update();
+
+    let history = app
+        .world_mut()
+        .query::<&ActionHistory>()
+        .single(app.world())
+        .unwrap();
+    let notation: Vec<_> = history
+        .numpad(0.5)
+        .map(|(direction, frames)| format!("{direction}:{frames}"))
+        .collect();
+    assert_eq!(notation, ["6:3", "3:1", "2:2", "5:1"]);
+}
+
+#[test]
+fn inactive_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Punch>::new(),
+                    ActionHistory::default(),
+                    bindings![KeyCode::KeyJ],
+                )]
+            ),
+        ))
+        .id();
+
+    app.press(KeyCode::KeyJ).update();
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(ContextActivity::<TestContext>::INACTIVE);
+
+    app.update();
+    app.update();
+
+    let history = app
+        .world_mut()
+        .query::<&ActionHistory>()
+        .single(app.world())
+        .unwrap();
+    let entries: Vec<_> = history
+        .iter()
+        .map(|entry| (entry.value, entry.frames))
+        .collect();
+    assert_eq!(
+        entries,
+        [(false.into(), 2), (true.into(), 1)],
+        "inactive context should record zero values"
+    );
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Move;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Punch;
@@ -0,0 +1,71 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn gamepad_calibration() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<GamepadCalibration>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Brake>::new(),
+                    bindings![(GamepadAxis::LeftZ, AxisCalibration::default())],
+                )]
+            ),
+        ))
+        .id();
+
+    // Resting position is recorded as the center.
+    app.set_axis(GamepadAxis::LeftZ, 0.25).update();
+    app.set_axis(GamepadAxis::LeftZ, 0.75).update();
+    app.set_axis(GamepadAxis::LeftZ, 0.2).update();
+
+    let gamepad = app
+        .world_mut()
+        .query_filtered::<Entity, With<Gamepad>>()
+        .single(app.world())
+        .unwrap();
+    let calibration = app.world().resource::<GamepadCalibration>();
+    assert_eq!(
+        calibration.get(gamepad, GamepadAxis::LeftZ),
+        Some(AxisCalibration::new(0.2, 0.25, 0.75))
+    );
+    assert_eq!(calibration.get(context, GamepadAxis::LeftZ), None);
+
+    let mut measured = calibration.get(gamepad, GamepadAxis::LeftZ).unwrap();
+    measured.min = measured.center; // Pedal can't go below the resting position.
+    app.world_mut().remove_resource::<GamepadCalibration>();
+    *app.world_mut()
+        .query::<&mut AxisCalibration>()
+        .single_mut(app.world_mut())
+        .unwrap() = measured;
+
+    app.set_axis(GamepadAxis::LeftZ, 0.5).update();
+    assert_eq!(brake(&mut app), 0.5);
+
+    app.set_axis(GamepadAxis::LeftZ, 0.2).update();
+    assert_eq!(brake(&mut app), 0.0);
+}
+
+fn brake(app: &mut App) -> f32 {
+    **app
+        .world_mut()
+        .query::<&Action<Brake>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Brake;
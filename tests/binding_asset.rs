@@ -0,0 +1,78 @@
+#![cfg(feature = "asset")]
+
+use bevy::{asset::AssetPlugin, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn apply_and_reload() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        EnhancedInputPlugin,
+    ))
+    .add_input_context::<TestContext>()
+    .finish();
+
+    let mut bindings = InputBindings::default();
+    bindings.insert("TestAction".into(), vec![KeyCode::KeyA.into()]);
+    let handle = app
+        .world_mut()
+        .resource_mut::<Assets<InputBindings>>()
+        .add(bindings);
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            ContextBindings::<TestContext>::new(handle.clone()),
+            actions!(TestContext[(Action::<TestAction>::new(), bindings![KeyCode::Space])]),
+        ))
+        .id();
+
+    app.update();
+
+    assert_eq!(action_bindings(&mut app, context), [KeyCode::KeyA.into()]);
+
+    app.world_mut()
+        .resource_mut::<Assets<InputBindings>>()
+        .get_mut(&handle)
+        .unwrap()
+        .insert("TestAction".into(), vec![KeyCode::KeyB.into()]);
+
+    // Asset events are emitted at the end of the frame.
+    app.update();
+    app.update();
+
+    assert_eq!(
+        action_bindings(&mut app, context),
+        [KeyCode::KeyB.into()],
+        "bindings should be re-applied after modification"
+    );
+}
+
+#[test]
+fn deserialize() {
+    let bindings: InputBindings =
+        ron::from_str(r#"{ "TestAction": [Keyboard(key: Space, mod_keys: "")] }"#).unwrap();
+    assert_eq!(bindings["TestAction"], [KeyCode::Space.into()]);
+}
+
+fn action_bindings(app: &mut App, context: Entity) -> Vec<Binding> {
+    let actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    let action = actions.iter().next().unwrap();
+    let bindings = app.world().get::<Bindings>(action).unwrap();
+    bindings
+        .iter()
+        .map(|binding| *app.world().get::<Binding>(binding).unwrap())
+        .collect()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct TestAction;
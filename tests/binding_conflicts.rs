@@ -0,0 +1,269 @@
+use bevy::{ecs::system::RunSystemOnce, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn identical() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Jump>::new(), bindings![KeyCode::Space, GamepadButton::South]),
+                (Action::<Fire>::new(), bindings![MouseButton::Left, KeyCode::Space]),
+                (Action::<Save>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)]),
+            ]),
+        ))
+        .id();
+
+    let conflicts = conflicts(&mut app, context);
+    let actions = actions(&mut app, context);
+    assert_eq!(
+        conflicts,
+        [BindingConflict {
+            actions: [actions[0], actions[1]],
+            bindings: [KeyCode::Space.into(), KeyCode::Space.into()],
+            severity: ConflictSeverity::Identical,
+        }]
+    );
+}
+
+#[test]
+fn mod_keys() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let ctrl_s = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL);
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Slide>::new(), bindings![KeyCode::KeyS]),
+                (Action::<Crouch>::new(), bindings![KeyCode::ControlLeft]),
+                (Action::<Save>::new(), bindings![ctrl_s]),
+                (
+                    Action::<Load>::new(),
+                    bindings![KeyCode::KeyL.with_mod_keys(ModKeys::CONTROL)],
+                ),
+                (
+                    Action::<Jump>::new(),
+                    bindings![KeyCode::KeyS.with_mod_keys(ModKeys::ALT)],
+                ),
+            ]),
+        ))
+        .id();
+
+    let conflicts = conflicts(&mut app, context);
+    let actions = actions(&mut app, context);
+    assert_eq!(
+        conflicts,
+        [
+            BindingConflict {
+                actions: [actions[0], actions[2]],
+                bindings: [KeyCode::KeyS.into(), ctrl_s],
+                severity: ConflictSeverity::Overlapping,
+            },
+            BindingConflict {
+                actions: [actions[0], actions[4]],
+                bindings: [
+                    KeyCode::KeyS.into(),
+                    KeyCode::KeyS.with_mod_keys(ModKeys::ALT)
+                ],
+                severity: ConflictSeverity::Overlapping,
+            },
+            BindingConflict {
+                actions: [actions[1], actions[2]],
+                bindings: [KeyCode::ControlLeft.into(), ctrl_s],
+                severity: ConflictSeverity::Overlapping,
+            },
+            BindingConflict {
+                actions: [actions[1], actions[3]],
+                bindings: [
+                    KeyCode::ControlLeft.into(),
+                    KeyCode::KeyL.with_mod_keys(ModKeys::CONTROL)
+                ],
+                severity: ConflictSeverity::Overlapping,
+            },
+        ]
+    );
+}
+
+#[test]
+fn resolved() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let ctrl_s = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL);
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Slide>::new(), bindings![KeyCode::KeyS]),
+                (
+                    Action::<Save>::new(),
+                    ActionSettings {
+                        consume_input: true,
+                        ..Default::default()
+                    },
+                    bindings![ctrl_s],
+                ),
+            ]),
+        ))
+        .id();
+
+    let conflicts = conflicts(&mut app, context);
+    let actions = actions(&mut app, context);
+    assert_eq!(
+        conflicts,
+        [BindingConflict {
+            actions: [actions[0], actions[1]],
+            bindings: [KeyCode::KeyS.into(), ctrl_s],
+            severity: ConflictSeverity::Resolved,
+        }],
+        "action with more modifiers should be evaluated first and consume the input"
+    );
+}
+
+#[test]
+fn other_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_context::<OtherContext>()
+        .finish();
+
+    let ctrl_s = KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL);
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            ContextPriority::<TestContext>::new(1),
+            actions!(TestContext[
+                (Action::<Jump>::new(), bindings![KeyCode::Space]),
+                (
+                    Action::<Save>::new(),
+                    ActionSettings {
+                        consume_input: true,
+                        ..Default::default()
+                    },
+                    bindings![ctrl_s],
+                ),
+                (Action::<Crouch>::new(), bindings![KeyCode::KeyC]),
+            ]),
+            OtherContext,
+            actions!(OtherContext[
+                (Action::<Fire>::new(), bindings![KeyCode::Space]),
+                (Action::<Slide>::new(), bindings![KeyCode::KeyS]),
+                (Action::<Load>::new(), bindings![KeyCode::KeyC.with_mod_keys(ModKeys::CONTROL)]),
+            ]),
+        ))
+        .id();
+
+    let conflicts = app
+        .world_mut()
+        .run_system_once(
+            move |contexts: Query<(&Actions<TestContext>, &Actions<OtherContext>)>,
+                  actions: Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+                  bindings: Query<&Binding>| {
+                let (test_actions, other_actions) = contexts.get(context).unwrap();
+                test_actions.conflicts_with(other_actions, &actions, &bindings)
+            },
+        )
+        .unwrap();
+
+    let actions = actions(&mut app, context);
+    let other_actions: Vec<_> = app
+        .world()
+        .get::<Actions<OtherContext>>(context)
+        .unwrap()
+        .iter()
+        .collect();
+    assert_eq!(
+        conflicts,
+        [
+            BindingConflict {
+                actions: [actions[0], other_actions[0]],
+                bindings: [KeyCode::Space.into(), KeyCode::Space.into()],
+                severity: ConflictSeverity::Identical,
+            },
+            BindingConflict {
+                actions: [actions[1], other_actions[1]],
+                bindings: [ctrl_s, KeyCode::KeyS.into()],
+                severity: ConflictSeverity::Resolved,
+            },
+            BindingConflict {
+                actions: [actions[2], other_actions[2]],
+                bindings: [
+                    KeyCode::KeyC.into(),
+                    KeyCode::KeyC.with_mod_keys(ModKeys::CONTROL)
+                ],
+                severity: ConflictSeverity::Overlapping,
+            },
+        ],
+        "action from the other context is evaluated later and can't consume first"
+    );
+}
+
+fn conflicts(app: &mut App, context: Entity) -> Vec<BindingConflict> {
+    app.world_mut()
+        .run_system_once(
+            move |contexts: Query<&Actions<TestContext>>,
+                  actions: Query<(Option<&Bindings>, Option<&ActionSettings>)>,
+                  bindings: Query<&Binding>| {
+                contexts
+                    .get(context)
+                    .unwrap()
+                    .conflicts(&actions, &bindings)
+            },
+        )
+        .unwrap()
+}
+
+fn actions(app: &mut App, context: Entity) -> Vec<Entity> {
+    app.world()
+        .get::<Actions<TestContext>>(context)
+        .unwrap()
+        .iter()
+        .collect()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(Component)]
+struct OtherContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Slide;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Crouch;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Save;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Load;
@@ -0,0 +1,125 @@
+use core::time::Duration;
+
+use bevy::{ecs::spawn::SpawnWith, input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn blocked() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        Actions::<TestContext>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+            let airborne = context
+                .spawn((
+                    Action::<Airborne>::new(),
+                    ActionMock::new(TriggerState::Fired, true, MockSpan::Manual),
+                ))
+                .id();
+
+            context.spawn((
+                Action::<Jump>::new(),
+                BlockBy::single(airborne),
+                Buffer::new(0.25),
+                bindings![KeyCode::Space],
+            ));
+        })),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+
+    land(&mut app);
+    app.update();
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::Fired,
+        "buffered press should fire after the blocker clears"
+    );
+
+    app.update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn expired() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        Actions::<TestContext>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+            let airborne = context
+                .spawn((
+                    Action::<Airborne>::new(),
+                    ActionMock::new(TriggerState::Fired, true, MockSpan::Manual),
+                ))
+                .id();
+
+            context.spawn((
+                Action::<Jump>::new(),
+                BlockBy::single(airborne),
+                Buffer::new(0.25),
+                bindings![KeyCode::Space],
+            ));
+        })),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+    app.release(KeyCode::Space).update();
+    app.update();
+    app.update();
+
+    land(&mut app);
+    app.update();
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::None,
+        "press should be forgotten after the window"
+    );
+}
+
+fn land(app: &mut App) {
+    app.world_mut()
+        .query_filtered::<&mut ActionMock, With<Action<Airborne>>>()
+        .single_mut(app.world_mut())
+        .unwrap()
+        .enabled = false;
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Airborne;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
@@ -0,0 +1,84 @@
+use bevy::{ecs::system::SystemState, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn current_bindings() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .set_context_info::<TestContext>(ContextInfo::new("On Foot"))
+        .finish();
+
+    let mut display_map = app.world_mut().resource_mut::<InputDisplayMap>();
+    display_map.insert_action::<Jump>("Jump Up");
+    display_map.insert(KeyCode::Space, "Spacebar");
+
+    app.world_mut().resource_mut::<GamepadGlyphs>().insert(
+        GamepadKind::Xbox,
+        GamepadButton::South,
+        "icons/xbox/a.png",
+    );
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[
+                    (
+                        Action::<Jump>::new(),
+                        bindings![KeyCode::Space, GamepadButton::South, Binding::None],
+                    ),
+                    (Action::<Crouch>::new(), bindings![KeyCode::ControlLeft]),
+                ]
+            ),
+        ))
+        .id();
+
+    app.update();
+
+    let other = app.world_mut().spawn_empty().id();
+    let mut state = SystemState::<CheatSheets<TestContext>>::new(app.world_mut());
+    let cheat_sheets = state.get(app.world());
+    let cheat_sheet = cheat_sheets.get(context, GamepadKind::Xbox).unwrap();
+
+    assert_eq!(cheat_sheet.title, "On Foot");
+    assert_eq!(cheat_sheet.actions.len(), 2);
+
+    let jump = &cheat_sheet.actions[0];
+    assert_eq!(jump.name, "Jump Up");
+    assert_eq!(
+        jump.bindings,
+        [
+            CheatSheetBinding {
+                binding: KeyCode::Space.into(),
+                name: "Spacebar".into(),
+                glyph: None,
+            },
+            CheatSheetBinding {
+                binding: GamepadButton::South.into(),
+                name: "South".into(),
+                glyph: Some("icons/xbox/a.png".into()),
+            },
+        ],
+        "`None` binding should be skipped"
+    );
+
+    let crouch = &cheat_sheet.actions[1];
+    assert_eq!(crouch.name, "Crouch");
+    assert_eq!(crouch.bindings[0].name, "ControlLeft");
+
+    assert!(cheat_sheets.get(other, GamepadKind::Xbox).is_none());
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Crouch;
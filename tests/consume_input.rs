@@ -294,6 +294,134 @@ fn modifiers() {
     assert_eq!(second_state, TriggerState::Fired);
 }
 
+#[test]
+fn modifier_as_key() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<First>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![KeyCode::ControlLeft],
+            ),
+            (
+                Action::<Second>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![Binding::Keyboard { key: KEY, mod_keys: MOD }],
+            )
+        ]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ControlLeft);
+
+    app.update();
+
+    let mut first = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<First>>>();
+
+    let first_state = *first.single(app.world()).unwrap();
+    assert_eq!(first_state, TriggerState::Fired);
+
+    let mut second = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Second>>>();
+
+    let second_state = *second.single(app.world()).unwrap();
+    assert_eq!(second_state, TriggerState::None);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let first_state = *first.single(app.world()).unwrap();
+    assert_eq!(
+        first_state,
+        TriggerState::Fired,
+        "modifier used as a key shouldn't be consumed by mod keys"
+    );
+
+    let second_state = *second.single(app.world()).unwrap();
+    assert_eq!(second_state, TriggerState::Fired);
+}
+
+#[test]
+fn priority() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<First>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![Binding::Keyboard { key: KEY, mod_keys: MOD }],
+            ),
+            (
+                Action::<Second>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    priority: 1,
+                    ..Default::default()
+                },
+                bindings![KEY],
+            )
+        ]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::ControlLeft);
+
+    app.update();
+
+    let mut first = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<First>>>();
+
+    let first_state = *first.single(app.world()).unwrap();
+    assert_eq!(
+        first_state,
+        TriggerState::None,
+        "action with lower priority should be consumed despite having more modifiers"
+    );
+
+    let mut second = app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Second>>>();
+
+    let second_state = *second.single(app.world()).unwrap();
+    assert_eq!(second_state, TriggerState::Fired);
+}
+
 #[derive(Component, Clone, Copy)]
 struct TestContext;
 
@@ -0,0 +1,108 @@
+#![cfg(feature = "window")]
+
+use bevy::{
+    input::InputPlugin,
+    prelude::*,
+    window::{CursorGrabMode, CursorOptions, PrimaryWindow},
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn activation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .add_input_context::<Menu>()
+        .set_context_cursor::<Player>(ContextCursor::GRABBED)
+        .set_context_cursor::<Menu>(ContextCursor::FREE)
+        .finish();
+
+    app.world_mut().spawn((Window::default(), PrimaryWindow));
+    let player = app.world_mut().spawn(Player).id();
+
+    app.update();
+
+    assert_eq!(cursor(&mut app), ContextCursor::GRABBED);
+
+    let menu = app
+        .world_mut()
+        .spawn((Menu, ContextPriority::<Menu>::new(1)))
+        .id();
+
+    app.update();
+
+    assert_eq!(
+        cursor(&mut app),
+        ContextCursor::FREE,
+        "context with higher priority should take over"
+    );
+
+    app.world_mut()
+        .entity_mut(menu)
+        .insert(ContextActivity::<Menu>::INACTIVE);
+
+    app.update();
+
+    assert_eq!(cursor(&mut app), ContextCursor::GRABBED);
+
+    app.world_mut().entity_mut(player).despawn();
+
+    app.update();
+
+    assert_eq!(
+        cursor(&mut app),
+        ContextCursor::FREE,
+        "original options should be restored"
+    );
+}
+
+#[test]
+fn original_options() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .set_context_cursor::<Player>(ContextCursor::GRABBED)
+        .finish();
+
+    app.world_mut().spawn((
+        Window::default(),
+        PrimaryWindow,
+        CursorOptions {
+            grab_mode: CursorGrabMode::Confined,
+            ..Default::default()
+        },
+    ));
+    let player = app.world_mut().spawn(Player).id();
+
+    app.update();
+
+    assert_eq!(cursor(&mut app), ContextCursor::GRABBED);
+
+    app.world_mut()
+        .entity_mut(player)
+        .insert(ContextActivity::<Player>::INACTIVE);
+
+    app.update();
+
+    assert_eq!(cursor(&mut app), ContextCursor::CONFINED);
+}
+
+fn cursor(app: &mut App) -> ContextCursor {
+    let options = app
+        .world_mut()
+        .query::<&CursorOptions>()
+        .single(app.world())
+        .unwrap();
+
+    ContextCursor {
+        grab_mode: options.grab_mode,
+        visible: options.visible,
+    }
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Component)]
+struct Menu;
@@ -80,6 +80,203 @@ fn by_id() {
     assert_eq!(state, TriggerState::None);
 }
 
+#[test]
+fn awaiting_connected() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let player1 = app.world_mut().spawn((TestContext, AwaitingGamepad)).id();
+    let player2 = app.world_mut().spawn((TestContext, AwaitingGamepad)).id();
+
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player1).unwrap(),
+        GamepadDevice::None
+    );
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.update();
+
+    let mut players = app
+        .world_mut()
+        .query::<(&GamepadDevice, Has<AwaitingGamepad>)>();
+    let (&device1, awaiting1) = players.get(app.world(), player1).unwrap();
+    let (&device2, awaiting2) = players.get(app.world(), player2).unwrap();
+    assert!(!awaiting1);
+    assert!(!awaiting2);
+    assert_ne!(device1, device2, "each player should get its own gamepad");
+    for device in [device1, device2] {
+        assert!(
+            device == GamepadDevice::Single(gamepad_entity1)
+                || device == GamepadDevice::Single(gamepad_entity2)
+        );
+    }
+}
+
+#[test]
+fn awaiting_existing() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut()
+        .spawn((TestContext, GamepadDevice::Single(gamepad_entity1)));
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            AwaitingGamepad,
+            actions!(TestContext[(Action::<Test>::new(), bindings![Test::BUTTON])]),
+        ))
+        .id();
+
+    app.update();
+
+    assert!(!app.world().entity(player).contains::<AwaitingGamepad>());
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(gamepad_entity2),
+        "already assigned gamepads should be skipped"
+    );
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&TriggerState>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+
+    let mut gamepad2 = app.world_mut().get_mut::<Gamepad>(gamepad_entity2).unwrap();
+    gamepad2.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+}
+
+#[test]
+fn disconnect_keep() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<HotplugEvents>()
+        .add_observer(
+            |disconnected: On<GamepadDisconnected>, mut events: ResMut<HotplugEvents>| {
+                events.disconnected.push(disconnected.gamepad);
+            },
+        )
+        .add_observer(
+            |reconnected: On<GamepadReconnected>, mut events: ResMut<HotplugEvents>| {
+                events.reconnected.push(reconnected.gamepad);
+            },
+        )
+        .finish();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((TestContext, GamepadDevice::Single(gamepad_entity)))
+        .id();
+
+    app.world_mut()
+        .entity_mut(gamepad_entity)
+        .remove::<Gamepad>();
+
+    let events = app.world().resource::<HotplugEvents>();
+    assert_eq!(events.disconnected, [gamepad_entity]);
+    assert!(events.reconnected.is_empty());
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(gamepad_entity)
+    );
+
+    app.world_mut()
+        .entity_mut(gamepad_entity)
+        .insert(Gamepad::default());
+
+    let events = app.world().resource::<HotplugEvents>();
+    assert_eq!(events.reconnected, [gamepad_entity]);
+}
+
+#[test]
+fn disconnect_any() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad_entity),
+            GamepadFallback::Any,
+        ))
+        .id();
+
+    app.world_mut()
+        .entity_mut(gamepad_entity)
+        .remove::<Gamepad>();
+
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Any
+    );
+}
+
+#[test]
+fn disconnect_replace() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad_entity1),
+            GamepadFallback::Replace,
+        ))
+        .id();
+
+    app.world_mut()
+        .entity_mut(gamepad_entity1)
+        .remove::<Gamepad>();
+
+    assert!(app.world().entity(player).contains::<AwaitingGamepad>());
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::None
+    );
+
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    assert!(!app.world().entity(player).contains::<AwaitingGamepad>());
+    assert_eq!(
+        *app.world().get::<GamepadDevice>(player).unwrap(),
+        GamepadDevice::Single(gamepad_entity2)
+    );
+}
+
+#[derive(Resource, Default)]
+struct HotplugEvents {
+    disconnected: Vec<Entity>,
+    reconnected: Vec<Entity>,
+}
+
 #[derive(Component)]
 struct TestContext;
 
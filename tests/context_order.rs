@@ -0,0 +1,82 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn after() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .evaluate_contexts_after(TestSystems)
+        .add_systems(PreUpdate, press.in_set(TestSystems))
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<TestAction>::new(), bindings![KEY])]),
+    ));
+
+    app.update();
+
+    let action = *app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<TestAction>>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(
+        action,
+        TriggerState::Fired,
+        "input written by the set should be evaluated within the same frame"
+    );
+}
+
+#[test]
+fn before() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<ObservedState>()
+        .add_input_context::<TestContext>()
+        .evaluate_contexts_before(TestSystems)
+        .add_systems(PreUpdate, observe.in_set(TestSystems))
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<TestAction>::new(), bindings![KEY])]),
+    ));
+
+    app.update();
+
+    app.press(KEY).update();
+    assert_eq!(
+        **app.world().resource::<ObservedState>(),
+        TriggerState::Fired,
+        "the set should observe the action state from the same frame"
+    );
+}
+
+fn press(mut keys: ResMut<ButtonInput<KeyCode>>) {
+    keys.press(KEY);
+}
+
+fn observe(
+    mut observed: ResMut<ObservedState>,
+    action: Single<&TriggerState, With<Action<TestAction>>>,
+) {
+    **observed = **action;
+}
+
+const KEY: KeyCode = KeyCode::Space;
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ObservedState(TriggerState);
+
+#[derive(SystemSet, Debug, PartialEq, Eq, Clone, Hash)]
+struct TestSystems;
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct TestAction;
@@ -0,0 +1,102 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn per_context() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Menu>()
+        .add_input_context::<Player>()
+        .finish();
+
+    app.world_mut().spawn((
+        Menu,
+        ContextSources::<Menu>::new(ActionSources {
+            keyboard: true,
+            ..ActionSources::NONE
+        }),
+        actions!(
+            Menu[(
+                Action::<Confirm>::new(),
+                bindings![KeyCode::Enter, MouseButton::Left],
+            )]
+        ),
+        Player,
+        actions!(Player[(Action::<Fire>::new(), bindings![MouseButton::Left])]),
+    ));
+
+    app.update();
+
+    app.press(MouseButton::Left).update();
+    assert_eq!(state::<Confirm>(&mut app), TriggerState::None);
+    assert_eq!(
+        state::<Fire>(&mut app),
+        TriggerState::Fired,
+        "other contexts should be unaffected"
+    );
+
+    app.release(MouseButton::Left)
+        .press(KeyCode::Enter)
+        .update();
+    assert_eq!(state::<Confirm>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn combined_with_global() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(ActionSources {
+            keyboard: false,
+            ..Default::default()
+        })
+        .add_input_context::<Menu>()
+        .finish();
+
+    app.world_mut().spawn((
+        Menu,
+        ContextSources::<Menu>::new(ActionSources {
+            mouse_motion: false,
+            ..ActionSources::ALL
+        }),
+        actions!(
+            Menu[(
+                Action::<Confirm>::new(),
+                bindings![KeyCode::Enter, MouseButton::Left],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Enter).update();
+    assert_eq!(
+        state::<Confirm>(&mut app),
+        TriggerState::None,
+        "globally disabled sources should stay disabled"
+    );
+
+    app.press(MouseButton::Left).update();
+    assert_eq!(state::<Confirm>(&mut app), TriggerState::Fired);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct Menu;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Confirm;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
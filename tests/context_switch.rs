@@ -0,0 +1,136 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn event() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Full>()
+        .add_input_context::<Simple>()
+        .switch_context_on::<StreamerMode, Full>(|mode| Some(!mode.enabled))
+        .switch_context_on::<StreamerMode, Simple>(|mode| Some(mode.enabled))
+        .init_resource::<Switches>()
+        .add_observer(record::<Full>)
+        .add_observer(record::<Simple>)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((Full, Simple, ContextActivity::<Simple>::INACTIVE))
+        .id();
+
+    app.update();
+
+    app.world_mut().trigger(StreamerMode { enabled: true });
+    app.update();
+    assert!(!**app.world().get::<ContextActivity<Full>>(context).unwrap());
+    assert!(**app.world().get::<ContextActivity<Simple>>(context).unwrap());
+    assert_eq!(
+        app.world_mut().resource_mut::<Switches>().drain(..).count(),
+        2
+    );
+
+    app.world_mut().trigger(StreamerMode { enabled: true });
+    app.update();
+    assert!(
+        app.world().resource::<Switches>().is_empty(),
+        "shouldn't trigger events without changes"
+    );
+}
+
+#[test]
+fn timer() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<Full>()
+        .init_resource::<Switches>()
+        .add_observer(record::<Full>)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((Full, ContextSwitchTimer::<Full>::new(0.25, false)))
+        .id();
+
+    app.update();
+    app.update();
+    assert!(**app.world().get::<ContextActivity<Full>>(context).unwrap());
+
+    app.update();
+    app.update();
+    assert!(!**app.world().get::<ContextActivity<Full>>(context).unwrap());
+    assert!(
+        !app.world()
+            .entity(context)
+            .contains::<ContextSwitchTimer<Full>>()
+    );
+    assert_eq!(**app.world().resource::<Switches>(), [(context, false)]);
+}
+
+#[test]
+fn commands() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Full>()
+        .add_input_context::<Simple>()
+        .init_resource::<Switches>()
+        .add_observer(record::<Full>)
+        .add_observer(record::<Simple>)
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((Full, Simple, ContextActivity::<Simple>::INACTIVE))
+        .id();
+
+    app.world_mut()
+        .commands()
+        .entity(context)
+        .deactivate_context::<Full>()
+        .activate_context::<Simple>();
+    app.world_mut().flush();
+
+    assert!(
+        **app.world().get::<ContextActivity<Full>>(context).unwrap(),
+        "switch should be deferred until timers are advanced"
+    );
+
+    app.update();
+    assert!(!**app.world().get::<ContextActivity<Full>>(context).unwrap());
+    assert!(**app.world().get::<ContextActivity<Simple>>(context).unwrap());
+    let switches = app.world().resource::<Switches>();
+    assert_eq!(switches.len(), 2);
+    assert!(switches.contains(&(context, false)));
+    assert!(switches.contains(&(context, true)));
+
+    app.world_mut()
+        .entity_mut(context)
+        .activate_context::<Full>();
+
+    app.update();
+    assert!(**app.world().get::<ContextActivity<Full>>(context).unwrap());
+}
+
+fn record<C: Component>(switched: On<ContextSwitched<C>>, mut switches: ResMut<Switches>) {
+    switches.push((switched.context, switched.active));
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Switches(Vec<(Entity, bool)>);
+
+#[derive(Event)]
+struct StreamerMode {
+    enabled: bool,
+}
+
+#[derive(Component)]
+struct Full;
+
+#[derive(Component)]
+struct Simple;
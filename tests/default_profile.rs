@@ -0,0 +1,115 @@
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn keyboard() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .select_default_profile(FirstInputSelector)
+        .finish();
+
+    app.update();
+
+    assert!(
+        !app.world()
+            .contains_resource::<SelectedProfile<DeviceProfile>>(),
+        "selection should wait for the first input"
+    );
+
+    app.world_mut().write_message(KeyboardInput {
+        key_code: KeyCode::KeyQ,
+        logical_key: Key::Character("a".into()),
+        state: ButtonState::Pressed,
+        text: Some("a".into()),
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+
+    app.update();
+
+    let hardware = app.world().resource::<InputHardware>();
+    assert_eq!(hardware.keyboard_layout, Some(KeyboardLayout::Azerty));
+    assert_eq!(hardware.first_device(), Some(InputDevice::Keyboard));
+
+    let profile = app.world().resource::<SelectedProfile<DeviceProfile>>();
+    assert_eq!(
+        **profile,
+        DeviceProfile::KeyboardMouse {
+            layout: KeyboardLayout::Azerty
+        }
+    );
+}
+
+#[test]
+fn gamepad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .select_default_profile(FirstInputSelector)
+        .finish();
+
+    app.update();
+
+    app.press(GamepadButton::South).update();
+
+    let hardware = app.world().resource::<InputHardware>();
+    assert_eq!(hardware.gamepads(), 1);
+
+    let profile = app.world().resource::<SelectedProfile<DeviceProfile>>();
+    assert_eq!(**profile, DeviceProfile::Gamepad);
+}
+
+#[test]
+fn custom_selector() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .select_default_profile(|hardware: &InputHardware| {
+            Some(if hardware.gamepads() == 0 {
+                TestProfile::Keyboard
+            } else {
+                TestProfile::Gamepad
+            })
+        })
+        .finish();
+
+    app.update();
+
+    let profile = app.world().resource::<SelectedProfile<TestProfile>>();
+    assert_eq!(
+        **profile,
+        TestProfile::Keyboard,
+        "should select on the first frame"
+    );
+}
+
+#[test]
+fn saved_profile() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .select_default_profile(FirstInputSelector)
+        .insert_resource(SelectedProfile(DeviceProfile::Gamepad))
+        .finish();
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+
+    let profile = app.world().resource::<SelectedProfile<DeviceProfile>>();
+    assert_eq!(
+        **profile,
+        DeviceProfile::Gamepad,
+        "inserted profile shouldn't be replaced"
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum TestProfile {
+    Keyboard,
+    Gamepad,
+}
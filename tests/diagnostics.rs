@@ -0,0 +1,58 @@
+use bevy::{
+    diagnostic::{DiagnosticPath, DiagnosticsStore},
+    input::InputPlugin,
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn measurements() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        InputPlugin,
+        EnhancedInputPlugin,
+        InputDiagnosticsPlugin,
+    ))
+    .add_input_context::<TestContext>()
+    .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<Jump>::new(),
+                ActionSettings {
+                    consume_input: true,
+                    ..Default::default()
+                },
+                bindings![KeyCode::Space, GamepadButton::South],
+            ),
+            (Action::<Crouch>::new(), bindings![KeyCode::ControlLeft]),
+        ]),
+    ));
+
+    app.press(KeyCode::Space).update();
+
+    let diagnostics = app.world().resource::<DiagnosticsStore>();
+    let value = |path: &DiagnosticPath| diagnostics.get_measurement(path).unwrap().value;
+    assert_eq!(value(&InputDiagnosticsPlugin::ACTIONS), 2.0);
+    assert_eq!(value(&InputDiagnosticsPlugin::BINDINGS), 3.0);
+    assert_eq!(value(&InputDiagnosticsPlugin::CONSUMED), 1.0);
+    assert!(value(&InputDiagnosticsPlugin::EVALUATION_TIME) >= 0.0);
+
+    let stats = app.world().resource::<ContextStats>();
+    assert_eq!(stats[0].consumed, 1);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Crouch;
@@ -0,0 +1,98 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<TriggeredEvents>()
+        .add_observer(
+            |event: On<DynActionEvent>, mut events: ResMut<TriggeredEvents>| {
+                events.push(event.event);
+            },
+        )
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (DynAction::new("jump", ActionValueDim::Bool), bindings![KEY]),
+            (Action::<Test>::new(), bindings![KEY]),
+        ]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query::<(&Name, &DynAction, &TriggerState, &ActionValue)>();
+    let (name, action, &state, &value) = actions.single(app.world()).unwrap();
+    assert_eq!(name.as_str(), "jump");
+    assert_eq!(action.name(), "jump");
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(value, true.into());
+
+    let mut typed = app.world_mut().query::<&Action<Test>>();
+    assert!(
+        **typed.single(app.world()).unwrap(),
+        "should be evaluated alongside typed actions"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(KEY);
+
+    app.update();
+
+    let events = app.world().resource::<TriggeredEvents>();
+    assert_eq!(
+        **events,
+        [
+            ActionEvents::START,
+            ActionEvents::FIRE,
+            ActionEvents::COMPLETE
+        ]
+    );
+}
+
+#[test]
+fn dim() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let action = app
+        .world_mut()
+        .spawn((
+            DynAction::new("move", ActionValueDim::Axis2D),
+            Name::new("Movement"),
+        ))
+        .id();
+
+    let value = *app.world().get::<ActionValue>(action).unwrap();
+    assert_eq!(value, Vec2::ZERO.into());
+
+    app.update();
+
+    let name = app.world().get::<Name>(action).unwrap();
+    assert_eq!(name.as_str(), "Movement", "user name should be preserved");
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct TriggeredEvents(Vec<ActionEvents>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+const KEY: KeyCode = KeyCode::KeyA;
@@ -0,0 +1,169 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn claim() {
+    let (mut app, gamepad_entity) = init_app(true);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<(&Action<Test>, &TriggerState)>();
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(*action, 1.0);
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::AXIS, 0.5);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(
+        *action, 1.0,
+        "gamepad should be ignored while keyboard owns the action"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+    assert_eq!(*action, 0.0);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(
+        *action, 0.5,
+        "gamepad should claim the action after release"
+    );
+}
+
+#[test]
+fn separate_gamepads() {
+    let (mut app, gamepad_entity1) = init_app(true);
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::AXIS, 0.5);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<(&Action<Test>, &TriggerState)>();
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(*action, 0.5);
+
+    let mut gamepad2 = app.world_mut().get_mut::<Gamepad>(gamepad_entity2).unwrap();
+    gamepad2.analog_mut().set(Test::AXIS, 0.3);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(
+        *action, 0.5,
+        "second gamepad should be ignored while the first one owns the action"
+    );
+
+    let mut gamepad1 = app.world_mut().get_mut::<Gamepad>(gamepad_entity1).unwrap();
+    gamepad1.analog_mut().set(Test::AXIS, 0.0);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+    assert_eq!(*action, 0.0);
+
+    app.update();
+
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+    assert_eq!(
+        *action, 0.3,
+        "second gamepad should claim the action after release"
+    );
+}
+
+#[test]
+fn same_frame() {
+    let (mut app, gamepad_entity) = init_app(true);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::AXIS, 0.5);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 1.0, "only the first binding device should be used");
+}
+
+#[test]
+fn shared() {
+    let (mut app, gamepad_entity) = init_app(false);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::AXIS, 0.5);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    let action = *actions.single(app.world()).unwrap();
+    assert_eq!(*action, 1.5);
+}
+
+fn init_app(exclusive_device: bool) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionSettings {
+                    exclusive_device,
+                    ..Default::default()
+                },
+                bindings![Test::KEY, Test::AXIS],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    (app, gamepad_entity)
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+    const AXIS: GamepadAxis = GamepadAxis::LeftStickX;
+}
@@ -0,0 +1,147 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn keep() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<FallbackEvents>()
+        .add_observer(
+            |changed: On<FallbackChanged>, mut events: ResMut<FallbackEvents>| {
+                events.push(changed.active);
+            },
+        )
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad),
+            actions!(
+                TestContext[(
+                    Action::<Test>::new(),
+                    bindings![GamepadButton::South, (Test::KEY, FallbackBinding)],
+                )]
+            ),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&TriggerState>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "fallback should be ignored while the gamepad is connected"
+    );
+
+    app.world_mut().entity_mut(gamepad).remove::<Gamepad>();
+    assert!(app.world().get::<FallbackActive>(player).is_some());
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::Fired);
+
+    app.world_mut()
+        .entity_mut(gamepad)
+        .insert(Gamepad::default());
+    assert!(app.world().get::<FallbackActive>(player).is_none());
+
+    app.update();
+
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(state, TriggerState::None);
+
+    let events = app.world().resource::<FallbackEvents>();
+    assert_eq!(**events, [true, false]);
+}
+
+#[test]
+fn replace() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<FallbackEvents>()
+        .add_observer(
+            |changed: On<FallbackChanged>, mut events: ResMut<FallbackEvents>| {
+                events.push(changed.active);
+            },
+        )
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad),
+            GamepadFallback::Replace,
+        ))
+        .id();
+
+    app.world_mut().entity_mut(gamepad).remove::<Gamepad>();
+    assert!(app.world().get::<FallbackActive>(player).is_some());
+
+    app.world_mut().spawn(Gamepad::default());
+    assert!(
+        app.world().get::<FallbackActive>(player).is_none(),
+        "should be deactivated after assigning a replacement"
+    );
+
+    let events = app.world().resource::<FallbackEvents>();
+    assert_eq!(**events, [true, false]);
+}
+
+#[test]
+fn any() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<FallbackEvents>()
+        .add_observer(
+            |changed: On<FallbackChanged>, mut events: ResMut<FallbackEvents>| {
+                events.push(changed.active);
+            },
+        )
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let player = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad),
+            GamepadFallback::Any,
+        ))
+        .id();
+
+    app.world_mut().entity_mut(gamepad).remove::<Gamepad>();
+    assert!(app.world().get::<FallbackActive>(player).is_none());
+
+    let events = app.world().resource::<FallbackEvents>();
+    assert!(events.is_empty());
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct FallbackEvents(Vec<bool>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyX;
+}
@@ -0,0 +1,115 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn steps() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(InputFixedRate::from_duration(Duration::from_millis(10)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            25,
+        )))
+        .add_input_context_to::<InputFixedUpdate, TestContext>()
+        .init_resource::<Fired>()
+        .add_observer(|_on: On<Fire<Jump>>, mut fired: ResMut<Fired>| {
+            fired.push(0.0);
+        })
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+    assert!(
+        app.world().resource::<Fired>().is_empty(),
+        "first frame shouldn't advance time"
+    );
+
+    app.update();
+    assert_eq!(app.world().resource::<Fired>().len(), 2);
+
+    app.update();
+    assert_eq!(
+        app.world().resource::<Fired>().len(),
+        5,
+        "remaining time should be carried over"
+    );
+    assert!(
+        app.world()
+            .resource::<InputFixedRate>()
+            .overstep()
+            .is_zero()
+    );
+}
+
+#[test]
+fn interpolation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(InputFixedRate::from_duration(Duration::from_millis(10)))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            30,
+        )))
+        .add_input_context_to::<InputFixedUpdate, TestContext>()
+        .init_resource::<Fired>()
+        .add_observer(|fire: On<Fire<Brake>>, mut fired: ResMut<Fired>| {
+            fired.push(fire.value);
+        })
+        .finish();
+
+    app.world_mut().spawn(Gamepad::default());
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Brake>::new(), bindings![Brake::AXIS])]),
+    ));
+
+    app.update();
+    app.update();
+    assert!(app.world().resource::<Fired>().is_empty());
+
+    app.set_axis(Brake::AXIS, 0.9).update();
+    let fired = app.world().resource::<Fired>();
+    assert_eq!(fired.len(), 3);
+    for (&value, expected) in fired.iter().zip([0.3, 0.6, 0.9]) {
+        assert!(
+            (value - expected).abs() < f32::EPSILON,
+            "`{value}` should be close to `{expected}`"
+        );
+    }
+
+    app.world_mut().resource_mut::<Fired>().clear();
+    app.world_mut().resource_mut::<InputFixedRate>().interpolate = false;
+    app.set_axis(Brake::AXIS, 0.3).update();
+    assert_eq!(**app.world().resource::<Fired>(), [0.3; 3]);
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Fired(Vec<f32>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const KEY: KeyCode = KeyCode::Space;
+}
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Brake;
+
+impl Brake {
+    const AXIS: GamepadAxis = GamepadAxis::LeftZ;
+}
@@ -80,6 +80,112 @@ fn twice_in_one_frame() {
     );
 }
 
+#[test]
+fn buffered_tap() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .init_resource::<FixedInputBuffer>()
+        .init_resource::<Started>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .add_observer(|_on: On<Start<Test>>, mut started: ResMut<Started>| {
+            **started += 1;
+        })
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+    app.press(Test::KEY).update();
+    app.release(Test::KEY).update();
+
+    assert_eq!(
+        **app.world().resource::<Started>(),
+        1,
+        "tap between fixed runs should be buffered"
+    );
+
+    app.update();
+    app.update();
+
+    let events = *app
+        .world_mut()
+        .query::<&ActionEvents>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(
+        events,
+        ActionEvents::COMPLETE,
+        "buffer should be cleared after the run"
+    );
+}
+
+#[test]
+fn unbuffered_tap() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .init_resource::<Started>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .add_observer(|_on: On<Start<Test>>, mut started: ResMut<Started>| {
+            **started += 1;
+        })
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+    app.press(Test::KEY).update();
+    app.release(Test::KEY).update();
+
+    assert_eq!(**app.world().resource::<Started>(), 0);
+}
+
+#[test]
+fn buffered_motion() {
+    let time_step = Time::<Fixed>::default().timestep() / 2;
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(time_step))
+        .init_resource::<FixedInputBuffer>()
+        .add_input_context_to::<FixedPreUpdate, TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Motion>::new(), bindings![Binding::mouse_motion()])]),
+    ));
+
+    app.update();
+    app.move_mouse(Vec2::X).update();
+    app.move_mouse(Vec2::X).update();
+
+    let motion = *app
+        .world_mut()
+        .query::<&Action<Motion>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(
+        *motion,
+        Vec2::X * 2.0,
+        "motion should be summed across frames"
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Started(usize);
+
 #[derive(Component)]
 struct TestContext;
 
@@ -90,3 +196,7 @@ struct Test;
 impl Test {
     const KEY: KeyCode = KeyCode::KeyA;
 }
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Motion;
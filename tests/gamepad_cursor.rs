@@ -0,0 +1,190 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn movement() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<MoveCursor>::new(),
+                ActionMock::new(TriggerState::Fired, Vec2::new(1.0, 1.0), MockSpan::Manual),
+                GamepadCursor::default()
+                    .with_speed(100.0, 200.0)
+                    .with_acceleration(500.0)
+                    .with_bounds(Rect::new(0.0, 0.0, 200.0, 200.0)),
+            )]
+        ),
+    ));
+
+    // Time starts advancing from the second update.
+    app.update();
+    let start = position(&app);
+    assert_eq!(start, Vec2::splat(100.0), "should start from the center");
+
+    app.update();
+    let value = position(&app);
+    assert!(
+        value.abs_diff_eq(start + Vec2::new(10.0, -10.0), 1e-3),
+        "`{value}` should move up and right"
+    );
+
+    app.update();
+    let value = position(&app);
+    assert!(
+        value.abs_diff_eq(start + Vec2::new(25.0, -25.0), 1e-3),
+        "`{value}` should accelerate"
+    );
+
+    app.update();
+    let value = position(&app);
+    assert!(
+        value.abs_diff_eq(start + Vec2::new(45.0, -45.0), 1e-3),
+        "`{value}` should be limited by the max speed"
+    );
+}
+
+#[test]
+fn edge_scroll() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_resource(CursorPosition(Some(Vec2::new(190.0, 100.0))))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let action = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<MoveCursor>::new(),
+                    ActionMock::new(TriggerState::Fired, Vec2::X, MockSpan::Manual),
+                    GamepadCursor::default()
+                        .with_speed(1000.0, 1000.0)
+                        .with_bounds(Rect::new(0.0, 0.0, 200.0, 200.0)),
+                )]
+            ),
+        ))
+        .id();
+    let action = app.world().get::<Actions<TestContext>>(action).unwrap()[0];
+
+    app.update();
+    app.update();
+
+    assert_eq!(position(&app), Vec2::new(200.0, 100.0));
+    let cursor = app.world().get::<GamepadCursor>(action).unwrap();
+    assert_eq!(cursor.edge_scroll(), Vec2::X);
+
+    let mut mock = app.world_mut().get_mut::<ActionMock>(action).unwrap();
+    mock.value = Vec2::NEG_X.into();
+
+    app.update();
+
+    assert_eq!(position(&app), Vec2::new(100.0, 100.0));
+    let cursor = app.world().get::<GamepadCursor>(action).unwrap();
+    assert_eq!(cursor.edge_scroll(), Vec2::ZERO);
+}
+
+#[test]
+fn click() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Click>::new(),
+                    GamepadCursorClick::new(MouseButton::Left),
+                    bindings![GamepadButton::South],
+                )]
+            ),
+        ))
+        .id();
+    let action = app.world().get::<Actions<TestContext>>(context).unwrap()[0];
+
+    app.world_mut()
+        .entity_mut(action)
+        .insert(ActionMock::once(TriggerState::Fired, true));
+
+    app.update();
+    app.update();
+
+    let mouse_buttons = app.world().resource::<ButtonInput<MouseButton>>();
+    assert!(mouse_buttons.just_pressed(MouseButton::Left));
+
+    app.update();
+
+    let mouse_buttons = app.world().resource::<ButtonInput<MouseButton>>();
+    assert!(mouse_buttons.just_released(MouseButton::Left));
+}
+
+#[test]
+fn release_on_remove() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Click>::new(),
+                    GamepadCursorClick::new(MouseButton::Left),
+                    ActionMock::new(TriggerState::Fired, true, MockSpan::Manual),
+                )]
+            ),
+        ))
+        .id();
+    let action = app.world().get::<Actions<TestContext>>(context).unwrap()[0];
+
+    app.update();
+    app.update();
+
+    let mouse_buttons = app.world().resource::<ButtonInput<MouseButton>>();
+    assert!(mouse_buttons.pressed(MouseButton::Left));
+
+    app.world_mut().entity_mut(action).despawn();
+
+    app.update();
+
+    let mouse_buttons = app.world().resource::<ButtonInput<MouseButton>>();
+    assert!(mouse_buttons.just_released(MouseButton::Left));
+}
+
+fn position(app: &App) -> Vec2 {
+    app.world().resource::<CursorPosition>().unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct MoveCursor;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Click;
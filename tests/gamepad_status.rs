@@ -0,0 +1,80 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn battery() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_observer(
+            |changed: On<GamepadStatusChanged>, mut changes: ResMut<Changes>| {
+                changes.push(*changed)
+            },
+        )
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let other_gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    let context = app.world_mut().spawn(GamepadDevice::Single(gamepad)).id();
+    app.world_mut().spawn(GamepadDevice::Any);
+
+    let battery = GamepadBattery {
+        level: 0.1,
+        charging: false,
+    };
+    app.world_mut().entity_mut(gamepad).insert(battery);
+    app.world_mut().entity_mut(other_gamepad).insert(battery);
+
+    let changes = app.world_mut().remove_resource::<Changes>().unwrap();
+    assert_eq!(
+        changes.len(),
+        1,
+        "only the assigned entity should be notified"
+    );
+    let changed = changes[0];
+    assert_eq!(changed.context, context);
+    assert_eq!(changed.gamepad, gamepad);
+    assert_eq!(changed.battery, Some(battery));
+    assert!(changed.battery.unwrap().is_low());
+    assert!(changed.capabilities.is_empty());
+}
+
+#[test]
+fn capabilities() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_observer(
+            |changed: On<GamepadStatusChanged>, mut changes: ResMut<Changes>| {
+                changes.push(*changed)
+            },
+        )
+        .finish();
+
+    let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn(GamepadDevice::Single(gamepad));
+
+    let capabilities = GamepadCapabilities::RUMBLE | GamepadCapabilities::GYRO;
+    app.world_mut().entity_mut(gamepad).insert(capabilities);
+    app.world_mut().entity_mut(gamepad).insert(GamepadBattery {
+        level: 0.1,
+        charging: true,
+    });
+
+    let changes = app.world_mut().remove_resource::<Changes>().unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].capabilities, capabilities);
+    assert_eq!(changes[0].battery, None);
+    assert_eq!(
+        changes[1].capabilities, capabilities,
+        "should include the current capabilities"
+    );
+    assert!(
+        !changes[1].battery.unwrap().is_low(),
+        "charging battery shouldn't be low"
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Changes(Vec<GamepadStatusChanged>);
@@ -0,0 +1,131 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn runtime_change() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Look>::new(),
+                bindings![(
+                    Binding::mouse_motion(),
+                    MouseSensitivity::default(),
+                    InvertY::default()
+                )],
+            )]
+        ),
+    ));
+
+    app.move_mouse(Vec2::ONE).update();
+    assert_eq!(look(&mut app), Vec2::ONE);
+
+    let mut settings = app.world_mut().resource_mut::<GlobalInputSettings>();
+    settings.mouse_sensitivity = 0.5;
+    settings.invert_y = true;
+
+    app.move_mouse(Vec2::ONE).update();
+    assert_eq!(look(&mut app), Vec2::new(0.5, -0.5));
+}
+
+#[test]
+fn dead_zone() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Move>::new(),
+                StickDeadZone::default(),
+                bindings![GamepadAxis::LeftStickX],
+            )]
+        ),
+    ));
+
+    app.set_axis(GamepadAxis::LeftStickX, 0.3).update();
+    let value = **app
+        .world_mut()
+        .query::<&Action<Move>>()
+        .single(app.world())
+        .unwrap();
+    assert_ne!(value, 0.0);
+
+    app.world_mut()
+        .resource_mut::<GlobalInputSettings>()
+        .stick_dead_zone
+        .lower_threshold = 0.5;
+
+    app.update();
+    let value = **app
+        .world_mut()
+        .query::<&Action<Move>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(value, 0.0);
+}
+
+#[test]
+fn mouse_dpi() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .init_resource::<MouseCalibration>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Look>::new(),
+                bindings![(Binding::mouse_motion(), MouseSensitivity::default())],
+            )]
+        ),
+    ));
+
+    // Move a 1600 DPI mouse by 2 inches.
+    app.move_mouse(Vec2::new(1600.0, 0.0)).update();
+    app.move_mouse(Vec2::new(1600.0, 0.0)).update();
+
+    let dpi = app.world().resource::<MouseCalibration>().dpi(2.0);
+    assert_eq!(dpi, 1600.0);
+
+    app.world_mut()
+        .resource_mut::<GlobalInputSettings>()
+        .mouse_dpi = Some(dpi);
+
+    app.move_mouse(Vec2::new(1600.0, 0.0)).update();
+    assert_eq!(
+        look(&mut app),
+        Vec2::new(GlobalInputSettings::REFERENCE_DPI, 0.0),
+        "an inch should be normalized to the reference resolution"
+    );
+}
+
+fn look(app: &mut App) -> Vec2 {
+    **app
+        .world_mut()
+        .query::<&Action<Look>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Look;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Move;
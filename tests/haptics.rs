@@ -0,0 +1,176 @@
+use bevy::{
+    input::{
+        InputPlugin,
+        gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn action() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn(Gamepad::default());
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        GamepadDevice::Single(gamepad_entity),
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionRumble::new(GamepadRumbleIntensity::MAX, 0.5),
+                bindings![Test::BUTTON],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    assert!(requests(&mut app).is_empty());
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let gamepads = requests(&mut app);
+    assert_eq!(gamepads, [gamepad_entity]);
+
+    app.update();
+
+    assert!(
+        requests(&mut app).is_empty(),
+        "should rumble only on start by default"
+    );
+}
+
+#[test]
+fn triggering_gamepad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn(Gamepad::default());
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                ActionRumble::new(GamepadRumbleIntensity::MAX, 0.5),
+                bindings![Test::KEY, Test::BUTTON],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+
+    assert!(
+        requests(&mut app).is_empty(),
+        "shouldn't rumble for other devices"
+    );
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+
+    app.update();
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::BUTTON, 1.0);
+
+    app.update();
+
+    let gamepads = requests(&mut app);
+    assert_eq!(
+        gamepads,
+        [gamepad_entity],
+        "should rumble only the gamepad that triggered the action"
+    );
+}
+
+#[test]
+fn manual() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad_entity2 = app.world_mut().spawn(Gamepad::default()).id();
+
+    let any = app.world_mut().spawn(TestContext).id();
+    let none = app
+        .world_mut()
+        .spawn((TestContext, GamepadDevice::None))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .trigger(Rumble::new(any, GamepadRumbleIntensity::MAX, 0.5));
+    app.world_mut()
+        .trigger(Rumble::new(none, GamepadRumbleIntensity::MAX, 0.5));
+
+    let gamepads = requests(&mut app);
+    assert_eq!(gamepads.len(), 2);
+    assert!(gamepads.contains(&gamepad_entity1));
+    assert!(gamepads.contains(&gamepad_entity2));
+}
+
+#[test]
+fn invalid_duration() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn(Gamepad::default());
+    let context = app.world_mut().spawn(TestContext).id();
+
+    app.update();
+
+    for duration in [-1.0, f32::NAN, f32::INFINITY] {
+        app.world_mut()
+            .trigger(Rumble::new(context, GamepadRumbleIntensity::MAX, duration));
+    }
+
+    assert!(requests(&mut app).is_empty());
+}
+
+fn requests(app: &mut App) -> Vec<Entity> {
+    app.world_mut()
+        .resource_mut::<Messages<GamepadRumbleRequest>>()
+        .drain()
+        .map(|request| match request {
+            GamepadRumbleRequest::Add { gamepad, .. } => gamepad,
+            GamepadRumbleRequest::Stop { gamepad } => gamepad,
+        })
+        .collect()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::Space;
+    const BUTTON: GamepadButton = GamepadButton::South;
+}
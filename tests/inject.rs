@@ -0,0 +1,144 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::{Press, *};
+use test_log::test;
+
+#[test]
+fn modifiers() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Movement>::new(),
+                    Scale::splat(2.0),
+                    Bindings::spawn(Cardinal::wasd_keys()),
+                )]
+            ),
+        ))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .commands()
+        .entity(context)
+        .inject::<TestContext, Movement>(Vec2::Y);
+    app.world_mut().flush();
+    app.update();
+    assert_eq!(state::<Movement>(&mut app), TriggerState::Fired);
+    assert_eq!(movement(&mut app), Vec2::Y * 2.0);
+
+    app.update();
+    assert_eq!(
+        state::<Movement>(&mut app),
+        TriggerState::None,
+        "injected value should be used for a single update"
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .inject::<TestContext, Movement>(Vec2::Y)
+        .unwrap();
+    app.press(KeyCode::KeyD).update();
+    assert_eq!(
+        movement(&mut app),
+        Vec2::new(2.0, 2.0),
+        "injected value should be combined with bindings"
+    );
+}
+
+#[test]
+fn conditions() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Jump>::new(), Press::default())]),
+        ))
+        .id();
+
+    app.update();
+
+    for _ in 0..2 {
+        app.world_mut()
+            .entity_mut(context)
+            .inject::<TestContext, Jump>(true)
+            .unwrap();
+        app.update();
+    }
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::None,
+        "conditions should apply to injected values"
+    );
+}
+
+#[test]
+fn mocked() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((TestContext, actions!(TestContext[Action::<Jump>::new()])))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .entity_mut(context)
+        .mock_once::<TestContext, Jump>(TriggerState::None, false)
+        .unwrap();
+    app.world_mut()
+        .entity_mut(context)
+        .inject::<TestContext, Jump>(true)
+        .unwrap();
+    app.update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+
+    app.update();
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::None,
+        "value should be discarded while mocked"
+    );
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn movement(app: &mut App) -> Vec2 {
+    **app
+        .world_mut()
+        .query::<&Action<Movement>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Movement;
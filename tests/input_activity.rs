@@ -0,0 +1,122 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn toggle() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .add_input_context::<Second>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            First,
+            actions!(First[(Action::<OnFirst>::new(), bindings![KEY])]),
+            Second,
+            actions!(Second[(Action::<OnSecond>::new(), bindings![KEY])]),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<(&TriggerState, &ActionEvents)>();
+    for (&state, _) in actions.iter(app.world()) {
+        assert_eq!(state, TriggerState::Fired);
+    }
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(InputActivity::INACTIVE);
+
+    app.update();
+
+    for (&state, &events) in actions.iter(app.world()) {
+        assert_eq!(state, TriggerState::None);
+        assert_eq!(events, ActionEvents::COMPLETE);
+    }
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(InputActivity::INACTIVE.toggled());
+
+    app.update();
+
+    for (&state, &events) in actions.iter(app.world()) {
+        assert_eq!(state, TriggerState::Fired);
+        assert_eq!(events, ActionEvents::START | ActionEvents::FIRE);
+    }
+}
+
+#[test]
+fn require_reset() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<First>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            First,
+            actions!(
+                First[(
+                    Action::<OnFirst>::new(),
+                    ActionSettings {
+                        require_reset: true,
+                        ..Default::default()
+                    },
+                    bindings![KEY]
+                )]
+            ),
+        ))
+        .id();
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY);
+
+    app.update();
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(InputActivity::INACTIVE);
+
+    app.update();
+
+    app.world_mut()
+        .entity_mut(context)
+        .insert(InputActivity::ACTIVE);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&TriggerState>();
+    let state = *actions.single(app.world()).unwrap();
+    assert_eq!(
+        state,
+        TriggerState::None,
+        "input should be released before reactivation"
+    );
+}
+
+const KEY: KeyCode = KeyCode::KeyA;
+
+#[derive(Component)]
+struct First;
+
+#[derive(Component)]
+struct Second;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct OnFirst;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct OnSecond;
@@ -0,0 +1,107 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn capture() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Captured>()
+        .add_systems(Update, capture_inputs)
+        .finish();
+
+    app.update();
+    assert!(app.world().resource::<Captured>().is_empty());
+
+    app.press(KeyCode::KeyA).update();
+    assert_eq!(**app.world().resource::<Captured>(), [KeyCode::KeyA.into()]);
+
+    app.update();
+    assert!(
+        app.world().resource::<Captured>().is_empty(),
+        "held inputs shouldn't be reported again"
+    );
+
+    app.press(MouseButton::Left)
+        .press(GamepadButton::South)
+        .update();
+    assert_eq!(
+        **app.world().resource::<Captured>(),
+        [MouseButton::Left.into(), GamepadButton::South.into()]
+    );
+
+    app.set_axis(GamepadAxis::LeftStickX, 0.2).update();
+    assert!(
+        app.world().resource::<Captured>().is_empty(),
+        "axis below the threshold shouldn't be reported"
+    );
+
+    app.set_axis(GamepadAxis::LeftStickX, -0.8).update();
+    assert_eq!(
+        **app.world().resource::<Captured>(),
+        [GamepadAxis::LeftStickX.into()]
+    );
+
+    app.scroll_mouse(Vec2::Y).update();
+    assert_eq!(
+        **app.world().resource::<Captured>(),
+        [ScrollDirection::Up.into()]
+    );
+
+    app.release(KeyCode::KeyA).update();
+    app.press(KeyCode::KeyA).update();
+    assert_eq!(**app.world().resource::<Captured>(), [KeyCode::KeyA.into()]);
+}
+
+#[test]
+fn any_input() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<TestAction>::new(), bindings![Binding::AnyInput])]),
+    ));
+
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.set_axis(GamepadAxis::RightStickY, 0.9).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.set_axis(GamepadAxis::RightStickY, 0.0).update();
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.scroll_mouse(Vec2::NEG_Y).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.press(KeyCode::Enter).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+}
+
+fn capture_inputs(capture: InputCapture, mut captured: ResMut<Captured>) {
+    captured.clear();
+    captured.extend_from_slice(capture.inputs());
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<TestAction>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Captured(Vec<Binding>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct TestAction;
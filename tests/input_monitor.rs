@@ -0,0 +1,59 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn anomaly() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )))
+        .init_resource::<Anomalies>()
+        .add_observer(
+            |anomaly: On<InputAnomaly>, mut anomalies: ResMut<Anomalies>| {
+                anomalies.push(anomaly.kind);
+            },
+        )
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Fire>::new(),
+                InputMonitor::default().with_samples(3),
+                bindings![KeyCode::Space],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    for _ in 0..2 {
+        app.press(KeyCode::Space).update();
+        app.release(KeyCode::Space).update();
+    }
+    assert!(app.world().resource::<Anomalies>().is_empty());
+
+    app.press(KeyCode::Space).update();
+    let anomalies = app.world().resource::<Anomalies>();
+    assert_eq!(anomalies.len(), 1);
+    assert!(
+        matches!(anomalies[0], AnomalyKind::Rate { .. }),
+        "pressing every 20 ms should exceed the default rate"
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Anomalies(Vec<AnomalyKind>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
@@ -203,11 +203,34 @@ fn same_action_different_bindings() {
     assert_eq!(actions.iter(app.world()).map(|&a| *a).sum::<f32>(), 0.0);
 }
 
+#[test]
+fn default_bindings() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[Action::<Test>::with_default_bindings()]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY2);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    assert_eq!(**actions.single(app.world()).unwrap(), 1.0);
+}
+
 #[derive(Component)]
 struct TestContext;
 
 #[derive(InputAction)]
 #[action_output(f32)]
+#[action_bindings(Test::KEY1, Test::KEY2)]
 struct Test;
 
 impl Test {
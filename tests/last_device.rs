@@ -0,0 +1,122 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn switch() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Changes>()
+        .add_observer(
+            |changed: On<InputDeviceChanged>, mut changes: ResMut<Changes>| {
+                changes.push(changed.device);
+            },
+        )
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Jump>::new(), bindings![KeyCode::Space, GamepadButton::South]),
+                (Action::<Fire>::new(), bindings![MouseButton::Left]),
+            ]),
+        ))
+        .id();
+
+    app.update();
+    assert_eq!(**app.world().get::<LastInputDevice>(context).unwrap(), None);
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Keyboard)
+    );
+
+    app.press(MouseButton::Left).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Keyboard),
+        "shouldn't switch while the current device is active"
+    );
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Mouse)
+    );
+
+    app.release(MouseButton::Left).update();
+    app.press(GamepadButton::South).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Gamepad)
+    );
+
+    let changes = app.world().resource::<Changes>();
+    assert_eq!(
+        **changes,
+        [
+            InputDevice::Keyboard,
+            InputDevice::Mouse,
+            InputDevice::Gamepad
+        ]
+    );
+}
+
+#[test]
+fn multiple_contexts() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_context::<OtherContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            OtherContext,
+            actions!(TestContext[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+            actions!(OtherContext[(Action::<Fire>::new(), bindings![MouseButton::Left])]),
+        ))
+        .id();
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Keyboard)
+    );
+
+    app.press(MouseButton::Left).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Keyboard),
+        "shouldn't switch while the current device is active in another context"
+    );
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(
+        **app.world().get::<LastInputDevice>(context).unwrap(),
+        Some(InputDevice::Mouse)
+    );
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Changes(Vec<InputDevice>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(Component)]
+struct OtherContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Fire;
@@ -0,0 +1,138 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn equalize() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )))
+        .insert_resource(
+            InputLatency::new(Duration::from_millis(30))
+                .with_measured(LatencySource::Keyboard, Duration::from_millis(10))
+                .with_measured(LatencySource::Mouse, Duration::from_millis(30)),
+        )
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Key>::new(), bindings![KeyCode::Space]),
+            (Action::<Click>::new(), bindings![MouseButton::Left]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).press(MouseButton::Left).update();
+    assert_eq!(state::<Key>(&mut app), TriggerState::None);
+    assert_eq!(
+        state::<Click>(&mut app),
+        TriggerState::Fired,
+        "device with the target latency shouldn't be delayed"
+    );
+
+    app.update();
+    assert_eq!(state::<Key>(&mut app), TriggerState::None);
+
+    app.update();
+    assert_eq!(
+        state::<Key>(&mut app),
+        TriggerState::Fired,
+        "faster device should be delayed by the difference"
+    );
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(state::<Key>(&mut app), TriggerState::Fired);
+
+    app.update();
+    app.update();
+    assert_eq!(state::<Key>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn per_gamepad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            10,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad1 = app.world_mut().spawn(Gamepad::default()).id();
+    let gamepad2 = app.world_mut().spawn(Gamepad::default()).id();
+    app.insert_resource(
+        InputLatency::new(Duration::from_millis(20))
+            .with_measured(LatencySource::Gamepad(gamepad1), Duration::from_millis(20)),
+    );
+
+    let player1 = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad1),
+            actions!(TestContext[(Action::<Key>::new(), bindings![GamepadButton::South])]),
+        ))
+        .id();
+    let player2 = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            GamepadDevice::Single(gamepad2),
+            actions!(TestContext[(Action::<Key>::new(), bindings![GamepadButton::South])]),
+        ))
+        .id();
+
+    app.update();
+
+    for gamepad in [gamepad1, gamepad2] {
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .digital_mut()
+            .press(GamepadButton::South);
+        app.world_mut()
+            .get_mut::<Gamepad>(gamepad)
+            .unwrap()
+            .analog_mut()
+            .set(GamepadButton::South, 1.0);
+    }
+
+    app.update();
+    assert_eq!(player_state(&mut app, player1), TriggerState::Fired);
+    assert_eq!(player_state(&mut app, player2), TriggerState::None);
+
+    app.update();
+    app.update();
+    assert_eq!(player_state(&mut app, player2), TriggerState::Fired);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn player_state(app: &mut App, player: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<TestContext>>(player).unwrap();
+    let action = actions.iter().next().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Key;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Click;
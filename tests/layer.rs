@@ -0,0 +1,116 @@
+use bevy::{ecs::spawn::SpawnWith, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn remap() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((TestContext, layered_actions()));
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(state::<Base>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Layered>(&mut app), TriggerState::None);
+
+    app.release(KeyCode::Space)
+        .press(KeyCode::ShiftLeft)
+        .update();
+    app.press(KeyCode::Space).update();
+    assert_eq!(state::<Base>(&mut app), TriggerState::None);
+    assert_eq!(state::<Layered>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn layer_released_mid_press() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((TestContext, layered_actions()));
+
+    app.press(KeyCode::ShiftLeft).update();
+    app.press(KeyCode::Space).update();
+    assert_eq!(state::<Layered>(&mut app), TriggerState::Fired);
+
+    app.release(KeyCode::ShiftLeft).update();
+    assert_eq!(
+        state::<Layered>(&mut app),
+        TriggerState::Fired,
+        "should stay in the layer until release"
+    );
+    assert_eq!(
+        state::<Base>(&mut app),
+        TriggerState::None,
+        "shouldn't switch to the base action mid-press"
+    );
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(state::<Layered>(&mut app), TriggerState::None);
+    assert_eq!(state::<Base>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn layer_pressed_mid_press() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((TestContext, layered_actions()));
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(state::<Base>(&mut app), TriggerState::Fired);
+
+    app.press(KeyCode::ShiftLeft).update();
+    assert_eq!(state::<Base>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Layered>(&mut app),
+        TriggerState::None,
+        "shouldn't switch to the layered action mid-press"
+    );
+}
+
+fn layered_actions() -> impl Bundle {
+    Actions::<TestContext>::spawn(SpawnWith(|context: &mut ActionSpawner<_>| {
+        let layer = context
+            .spawn((Action::<LayerKey>::new(), bindings![KeyCode::ShiftLeft]))
+            .id();
+
+        context.spawn((
+            Action::<Base>::new(),
+            Layer::inactive(layer),
+            bindings![KeyCode::Space],
+        ));
+        context.spawn((
+            Action::<Layered>::new(),
+            Layer::active(layer),
+            bindings![KeyCode::Space],
+        ));
+    }))
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct LayerKey;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Base;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Layered;
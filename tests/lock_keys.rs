@@ -0,0 +1,155 @@
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn toggle() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<NumLock>::new(), bindings![LockKey::NumLock])]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::NumLock).update();
+    assert!(app.world().resource::<LockKeys>().num_lock);
+    assert_eq!(state::<NumLock>(&mut app), TriggerState::Fired);
+
+    app.release(KeyCode::NumLock).update();
+    assert_eq!(
+        state::<NumLock>(&mut app),
+        TriggerState::Fired,
+        "lock should stay enabled after release"
+    );
+
+    app.press(KeyCode::NumLock).update();
+    assert!(!app.world().resource::<LockKeys>().num_lock);
+    assert_eq!(state::<NumLock>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn numpad() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<Up>::new(),
+                bindings![
+                    KeyCode::ArrowUp,
+                    (KeyCode::Numpad8, LockState::disabled(LockKey::NumLock)),
+                ],
+            ),
+            (Action::<Digit>::new(), bindings![KeyCode::Numpad8]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Numpad8).update();
+    assert_eq!(state::<Up>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Digit>(&mut app), TriggerState::Fired);
+
+    app.release(KeyCode::Numpad8).update();
+    app.world_mut().resource_mut::<LockKeys>().num_lock = true;
+    app.press(KeyCode::Numpad8).update();
+    assert_eq!(
+        state::<Up>(&mut app),
+        TriggerState::None,
+        "numpad shouldn't navigate with Num Lock enabled"
+    );
+    assert_eq!(state::<Digit>(&mut app), TriggerState::Fired);
+
+    app.press(KeyCode::ArrowUp).update();
+    assert_eq!(
+        state::<Up>(&mut app),
+        TriggerState::Fired,
+        "other bindings shouldn't be blocked"
+    );
+}
+
+#[test]
+fn platform_state() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .finish();
+
+    app.update();
+
+    write_key(&mut app, KeyCode::KeyA, Key::Character("A".into()));
+    write_key(&mut app, KeyCode::Numpad8, Key::Character("8".into()));
+    app.update();
+
+    let lock_keys = *app.world().resource::<LockKeys>();
+    assert!(
+        lock_keys.caps_lock,
+        "uppercase without Shift means Caps Lock"
+    );
+    assert!(lock_keys.num_lock, "digit from numpad means Num Lock");
+
+    app.press(KeyCode::ShiftLeft);
+    write_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    write_key(&mut app, KeyCode::Numpad8, Key::ArrowUp);
+    app.update();
+
+    let lock_keys = *app.world().resource::<LockKeys>();
+    assert!(lock_keys.caps_lock, "shouldn't infer while Shift is held");
+    assert!(lock_keys.num_lock, "shouldn't infer while Shift is held");
+
+    app.release(KeyCode::ShiftLeft);
+    write_key(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    write_key(&mut app, KeyCode::Numpad8, Key::ArrowUp);
+    app.update();
+
+    let lock_keys = *app.world().resource::<LockKeys>();
+    assert!(!lock_keys.caps_lock);
+    assert!(!lock_keys.num_lock);
+}
+
+fn write_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    app.world_mut().write_message(KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct NumLock;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Up;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Digit;
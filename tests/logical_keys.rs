@@ -0,0 +1,123 @@
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn layout() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (Action::<Undo>::new(), bindings!['z']),
+                (Action::<Forward>::new(), bindings![KeyCode::KeyW]),
+            ]
+        ),
+    ));
+
+    app.update();
+
+    // Key at the QWERTY W location on AZERTY.
+    write_key(&mut app, KeyCode::KeyW, "z", ButtonState::Pressed);
+    app.update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Forward>(&mut app), TriggerState::Fired);
+
+    write_key(&mut app, KeyCode::KeyW, "z", ButtonState::Released);
+    app.update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::None);
+    assert_eq!(state::<Forward>(&mut app), TriggerState::None);
+
+    // Physical Z key types W on AZERTY.
+    write_key(&mut app, KeyCode::KeyZ, "w", ButtonState::Pressed);
+    app.update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::None);
+    assert_eq!(state::<Forward>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn mod_keys() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Undo>::new(),
+                bindings!['z'.with_mod_keys(ModKeys::CONTROL)]
+            )]
+        ),
+    ));
+
+    app.update();
+
+    write_key(&mut app, KeyCode::KeyW, "z", ButtonState::Pressed);
+    app.update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::None);
+
+    app.press(KeyCode::ControlLeft).update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn mocking() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Undo>::new(), bindings!['z'])]),
+    ));
+
+    app.update();
+
+    app.press('Z').update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::Fired);
+
+    app.release('z').update();
+    assert_eq!(state::<Undo>(&mut app), TriggerState::None);
+}
+
+fn write_key(app: &mut App, key_code: KeyCode, character: &str, state: ButtonState) {
+    app.world_mut().write_message(KeyboardInput {
+        key_code,
+        logical_key: Key::Character(character.into()),
+        state,
+        text: state.is_pressed().then(|| character.into()),
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Undo;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Forward;
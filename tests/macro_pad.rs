@@ -0,0 +1,59 @@
+#![cfg(feature = "macro_pad")]
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn keys() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Record>::new(), bindings![KeyCode::KeyR, RECORD])]),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<MacroPadKeys>()
+        .set(RECORD, true);
+    app.update();
+    assert_eq!(state::<Record>(&mut app), TriggerState::Fired);
+
+    app.update();
+    assert_eq!(
+        state::<Record>(&mut app),
+        TriggerState::Fired,
+        "key should stay pressed until released"
+    );
+
+    app.world_mut().resource_mut::<MacroPadKeys>().release_all();
+    app.update();
+    assert_eq!(state::<Record>(&mut app), TriggerState::None);
+
+    app.press(RECORD).update();
+    assert_eq!(state::<Record>(&mut app), TriggerState::Fired);
+
+    app.release(RECORD).update();
+    assert_eq!(state::<Record>(&mut app), TriggerState::None);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+const RECORD: MacroPadKey = MacroPadKey(0);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Record;
@@ -0,0 +1,86 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn hold() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(2)))
+        .insert_resource(MaxInputDelta(Some(Duration::from_millis(250))))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), Hold::new(1.0), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    app.press(Test::KEY).update();
+    assert_eq!(state(&mut app), TriggerState::Ongoing);
+
+    app.update();
+    assert_eq!(
+        state(&mut app),
+        TriggerState::Ongoing,
+        "a single long frame shouldn't complete the hold"
+    );
+
+    for _ in 0..3 {
+        app.update();
+    }
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    let time = app
+        .world_mut()
+        .query::<&ActionTime>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert!(time.elapsed_secs <= 1.25);
+}
+
+#[test]
+fn unlimited() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs(2)))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), Hold::new(1.0), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    app.press(Test::KEY).update();
+    assert_eq!(
+        state(&mut app),
+        TriggerState::Fired,
+        "without a limit, a long frame should complete the hold"
+    );
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query::<&TriggerState>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+}
@@ -0,0 +1,85 @@
+use bevy::{
+    input::{
+        ButtonState, InputPlugin,
+        keyboard::{Key, KeyboardInput, NativeKeyCode},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn logical() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<PlayPause>::new(),
+                bindings![KeyCode::MediaPlayPause]
+            )]
+        ),
+    ));
+
+    app.update();
+
+    write_media_key(&mut app, ButtonState::Pressed);
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    write_media_key(&mut app, ButtonState::Released);
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::None);
+}
+
+#[test]
+fn physical() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<PlayPause>::new(),
+                bindings![KeyCode::MediaPlayPause]
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::MediaPlayPause).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+}
+
+fn write_media_key(app: &mut App, state: ButtonState) {
+    app.world_mut().write_message(KeyboardInput {
+        key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+        logical_key: Key::MediaPlayPause,
+        state,
+        text: None,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<PlayPause>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct PlayPause;
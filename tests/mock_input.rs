@@ -0,0 +1,139 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn buttons() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![
+                    KeyCode::KeyA.with_mod_keys(ModKeys::CONTROL),
+                    MouseButton::Left,
+                    GamepadButton::South,
+                ],
+            )]
+        ),
+    ));
+
+    for binding in [
+        KeyCode::KeyA.with_mod_keys(ModKeys::CONTROL),
+        MouseButton::Left.into(),
+        GamepadButton::South.into(),
+    ] {
+        app.press(binding).update();
+        assert_eq!(
+            state(&mut app),
+            TriggerState::Fired,
+            "`{binding}` should fire"
+        );
+
+        app.release(binding).update();
+        assert_eq!(
+            state(&mut app),
+            TriggerState::None,
+            "`{binding}` should be released"
+        );
+    }
+}
+
+#[test]
+fn axes() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Motion>::new(), bindings![Binding::mouse_motion()]),
+            (Action::<Scroll>::new(), bindings![Binding::mouse_wheel()]),
+            (Action::<Stick>::new(), bindings![GamepadAxis::LeftStickX]),
+        ]),
+    ));
+
+    app.move_mouse(Vec2::ONE)
+        .scroll_mouse(Vec2::Y)
+        .set_axis(GamepadAxis::LeftStickX, 0.7)
+        .update();
+
+    let motion = app
+        .world_mut()
+        .query::<&Action<Motion>>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert_eq!(*motion, Vec2::ONE);
+
+    let scroll = app
+        .world_mut()
+        .query::<&Action<Scroll>>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert_eq!(*scroll, Vec2::Y);
+
+    let stick = app
+        .world_mut()
+        .query::<&Action<Stick>>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert_eq!(*stick, 0.7);
+
+    app.update();
+
+    let motion = app
+        .world_mut()
+        .query::<&Action<Motion>>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert_eq!(
+        *motion,
+        Vec2::ZERO,
+        "mouse motion should be applied only once"
+    );
+
+    let stick = app
+        .world_mut()
+        .query::<&Action<Stick>>()
+        .single(app.world())
+        .copied()
+        .unwrap();
+    assert_eq!(*stick, 0.7, "axis should keep its value");
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Motion;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Scroll;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Stick;
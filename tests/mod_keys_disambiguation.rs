@@ -0,0 +1,119 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn superset() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        ModKeysDisambiguation,
+        actions!(TestContext[
+            (Action::<Slide>::new(), bindings![KeyCode::KeyS]),
+            (Action::<Crouch>::new(), bindings![KeyCode::ControlLeft]),
+            (Action::<Save>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::ControlLeft).update();
+    assert_eq!(state::<Crouch>(&mut app), TriggerState::Fired);
+
+    app.press(KeyCode::KeyS).update();
+    assert_eq!(state::<Save>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Slide>(&mut app),
+        TriggerState::None,
+        "subset binding should be suppressed"
+    );
+    assert_eq!(
+        state::<Crouch>(&mut app),
+        TriggerState::None,
+        "modifier binding should be suppressed"
+    );
+
+    app.release(KeyCode::ControlLeft).update();
+    assert_eq!(state::<Save>(&mut app), TriggerState::None);
+    assert_eq!(state::<Slide>(&mut app), TriggerState::Fired);
+}
+
+#[test]
+fn disjoint() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        ModKeysDisambiguation,
+        actions!(TestContext[
+            (Action::<Slide>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::ALT)]),
+            (Action::<Save>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::ControlLeft)
+        .press(KeyCode::AltLeft)
+        .press(KeyCode::KeyS)
+        .update();
+    assert_eq!(state::<Save>(&mut app), TriggerState::Fired);
+    assert_eq!(
+        state::<Slide>(&mut app),
+        TriggerState::Fired,
+        "bindings with different modifiers shouldn't suppress each other"
+    );
+}
+
+#[test]
+fn without_disambiguation() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Slide>::new(), bindings![KeyCode::KeyS]),
+            (Action::<Save>::new(), bindings![KeyCode::KeyS.with_mod_keys(ModKeys::CONTROL)]),
+        ]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::ControlLeft)
+        .press(KeyCode::KeyS)
+        .update();
+    assert_eq!(state::<Save>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Slide>(&mut app), TriggerState::Fired);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Slide;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Crouch;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Save;
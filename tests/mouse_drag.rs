@@ -0,0 +1,78 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn drag() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Pan>::new(),
+                bindings![Binding::mouse_drag(MouseButton::Middle)],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.move_mouse(Vec2::ONE).update();
+    assert_eq!(
+        value(&mut app),
+        Vec2::ZERO,
+        "should ignore motion without the button"
+    );
+
+    app.press(MouseButton::Middle)
+        .move_mouse(Vec2::ONE)
+        .update();
+    assert_eq!(
+        value(&mut app),
+        Vec2::ZERO,
+        "should ignore motion from the press frame"
+    );
+
+    app.move_mouse(Vec2::new(2.0, -1.0)).update();
+    assert_eq!(value(&mut app), Vec2::new(2.0, -1.0));
+
+    app.move_mouse(Vec2::new(1.0, 3.0)).update();
+    assert_eq!(
+        value(&mut app),
+        Vec2::new(3.0, 2.0),
+        "should accumulate while held"
+    );
+
+    app.update();
+    assert_eq!(value(&mut app), Vec2::new(3.0, 2.0));
+
+    app.release(MouseButton::Middle).update();
+    assert_eq!(value(&mut app), Vec2::ZERO);
+
+    app.press(MouseButton::Middle).update();
+    app.move_mouse(Vec2::ONE).update();
+    assert_eq!(
+        value(&mut app),
+        Vec2::ONE,
+        "should start over after release"
+    );
+}
+
+fn value(app: &mut App) -> Vec2 {
+    **app
+        .world_mut()
+        .query::<&Action<Pan>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Pan;
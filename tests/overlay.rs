@@ -0,0 +1,109 @@
+#![cfg(feature = "ui")]
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn overlay() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Jump>::new(), bindings![KeyCode::Space]),
+                (Action::<Move>::new(), Bindings::spawn(Cardinal::wasd_keys())),
+            ]),
+        ))
+        .id();
+    let overlay = app
+        .world_mut()
+        .spawn(InputOverlay::<TestContext>::new(context))
+        .id();
+
+    app.press(KeyCode::Space).press(KeyCode::KeyD).update();
+
+    let text = app.world().get::<Text>(overlay).unwrap();
+    assert_eq!(
+        text.0,
+        "Jump: on [Fired] via Space\nMove: (1.00, 0.00) [Fired] via KeyD"
+    );
+
+    app.release(KeyCode::Space).release(KeyCode::KeyD).update();
+
+    let text = app.world().get::<Text>(overlay).unwrap();
+    assert_eq!(text.0, "Jump: off [None]\nMove: (0.00, 0.00) [None]");
+}
+
+#[cfg(feature = "debug")]
+#[test]
+fn debug_overlay() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_input_context::<OtherContext>()
+        .set_context_info::<TestContext>(ContextInfo::new("Test"))
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Jump>::new(), bindings![KeyCode::Space])]),
+        ))
+        .id();
+    let other = app
+        .world_mut()
+        .spawn((
+            OtherContext,
+            ContextActivity::<OtherContext>::INACTIVE,
+            actions!(
+                OtherContext[(
+                    Action::<Move>::new(),
+                    Bindings::spawn(Cardinal::wasd_keys())
+                )]
+            ),
+        ))
+        .id();
+    let overlay = app.world_mut().spawn(InputDebugOverlay::default()).id();
+
+    app.press(KeyCode::Space).update();
+
+    let text = app.world().get::<Text>(overlay).unwrap();
+    assert_eq!(
+        text.0,
+        format!(
+            "OtherContext on {other} [inactive]\n  Move: (0.00, 0.00) [None]\n\
+            Test on {context} [active]\n  Jump: on [Fired] via Space"
+        )
+    );
+
+    app.world_mut().entity_mut(context).despawn();
+    app.update();
+
+    let text = app.world().get::<Text>(overlay).unwrap();
+    assert_eq!(
+        text.0,
+        format!("OtherContext on {other} [inactive]\n  Move: (0.00, 0.00) [None]")
+    );
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[cfg(feature = "debug")]
+#[derive(Component)]
+struct OtherContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Move;
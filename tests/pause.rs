@@ -0,0 +1,129 @@
+use bevy::{ecs::system::RunSystemOnce, input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn preserve() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .init_resource::<Fires>()
+        .add_observer(|_: On<Fire<Test>>, mut fires: ResMut<Fires>| **fires += 1)
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![KeyCode::Space])]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.pause())
+        .unwrap();
+    **app.world_mut().resource_mut::<Fires>() = 0;
+
+    app.release(KeyCode::Space).update();
+    assert_eq!(
+        state(&mut app),
+        TriggerState::Fired,
+        "state should be frozen while paused"
+    );
+    assert_eq!(
+        **app.world().resource::<Fires>(),
+        0,
+        "events shouldn't trigger while paused"
+    );
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.resume())
+        .unwrap();
+
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::None);
+}
+
+#[test]
+fn cancel() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![KeyCode::Space])]),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.pause_canceling())
+        .unwrap();
+
+    app.update();
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.resume())
+        .unwrap();
+
+    app.update();
+    assert_eq!(
+        state(&mut app),
+        TriggerState::Fired,
+        "held input should be picked up after resuming"
+    );
+}
+
+#[test]
+fn capture() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .finish();
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.pause())
+        .unwrap();
+
+    app.press(KeyCode::KeyA).update();
+    let empty = app
+        .world_mut()
+        .run_system_once(|capture: InputCapture| capture.is_empty())
+        .unwrap();
+    assert!(empty);
+
+    app.world_mut()
+        .run_system_once(|mut input: EnhancedInput| input.resume())
+        .unwrap();
+
+    app.update();
+    let empty = app
+        .world_mut()
+        .run_system_once(|capture: InputCapture| capture.is_empty())
+        .unwrap();
+    assert!(empty, "inputs held through the pause shouldn't be reported");
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Fires(usize);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
@@ -164,6 +164,253 @@ fn sticks() {
     }
 }
 
+#[test]
+fn twin_stick() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+    app.world_mut().spawn((
+        TestContext,
+        GamepadDevice::Single(gamepad_entity),
+        Actions::<TestContext>::spawn(TwinStick::<Move, Aim>::default()),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::KeyW).update();
+    assert_eq!(action::<Move>(&mut app), Vec2::Y);
+    assert_eq!(action::<Aim>(&mut app), Vec2::ZERO);
+
+    app.release(KeyCode::KeyW)
+        .set_axis(GamepadAxis::LeftStickX, 0.1)
+        .set_axis(GamepadAxis::RightStickX, 1.0)
+        .update();
+
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::ZERO,
+        "small stick input should be within the dead zone"
+    );
+    assert_eq!(action::<Aim>(&mut app), Vec2::X);
+
+    app.set_axis(GamepadAxis::LeftStickX, 0.0)
+        .set_axis(GamepadAxis::RightStickX, 0.0)
+        .move_mouse(Vec2::NEG_Y)
+        .update();
+
+    assert_eq!(
+        action::<Aim>(&mut app),
+        Vec2::Y,
+        "moving the mouse up should aim up"
+    );
+}
+
+#[test]
+fn six_dof() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        Actions::<TestContext>::spawn(SixDof::<Translate, Rotate>::keyboard_and_gamepad()),
+    ));
+
+    app.update();
+
+    let mut translation = app.world_mut().query::<&Action<Translate>>();
+    let mut rotation = app.world_mut().query::<&Action<Rotate>>();
+    for (binding, translation_dir, rotation_dir) in [
+        (Binding::from(KeyCode::KeyW), FORWARD, Vec3::ZERO),
+        (KeyCode::Space.into(), UP, Vec3::ZERO),
+        (GamepadButton::LeftTrigger2.into(), DOWN, Vec3::ZERO),
+        (KeyCode::ArrowUp.into(), Vec3::ZERO, Vec3::X),
+        (KeyCode::ArrowLeft.into(), Vec3::ZERO, Vec3::Y),
+        (KeyCode::KeyE.into(), Vec3::ZERO, Vec3::NEG_Z),
+        (GamepadButton::LeftTrigger.into(), Vec3::ZERO, Vec3::Z),
+    ] {
+        app.press(binding).update();
+
+        assert_eq!(
+            **translation.single(app.world()).unwrap(),
+            translation_dir,
+            "`{binding}` should translate to `{translation_dir}`"
+        );
+        assert_eq!(
+            **rotation.single(app.world()).unwrap(),
+            rotation_dir,
+            "`{binding}` should rotate by `{rotation_dir}`"
+        );
+
+        app.release(binding).update();
+    }
+
+    for (axis, translation_dir, rotation_dir) in [
+        (GamepadAxis::LeftStickY, FORWARD, Vec3::ZERO),
+        (GamepadAxis::LeftStickX, RIGHT, Vec3::ZERO),
+        (GamepadAxis::RightStickY, Vec3::ZERO, Vec3::X),
+        (GamepadAxis::RightStickX, Vec3::ZERO, Vec3::NEG_Y),
+    ] {
+        app.set_axis(axis, 1.0).update();
+
+        assert_eq!(
+            **translation.single(app.world()).unwrap(),
+            translation_dir,
+            "`{axis:?}` should translate to `{translation_dir}`"
+        );
+        assert_eq!(
+            **rotation.single(app.world()).unwrap(),
+            rotation_dir,
+            "`{axis:?}` should rotate by `{rotation_dir}`"
+        );
+
+        app.set_axis(axis, 0.0).update();
+    }
+}
+
+#[test]
+fn per_direction() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Move>::new(),
+                Bindings::spawn((
+                    Cardinal::wasd_keys().with_south(Scale::splat(0.5)),
+                    Ordinal::numpad()
+                        .with_north(Hold::new(1.0))
+                        .with_south_west(Scale::splat(2.0)),
+                )),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::KeyS).update();
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::new(0.0, -0.5),
+        "only south should be scaled"
+    );
+
+    app.release(KeyCode::KeyS).press(KeyCode::KeyW).update();
+    assert_eq!(action::<Move>(&mut app), Vec2::Y);
+
+    app.release(KeyCode::KeyW).press(KeyCode::Numpad8).update();
+    let state = *app
+        .world_mut()
+        .query_filtered::<&TriggerState, With<Action<Move>>>()
+        .single(app.world())
+        .unwrap();
+    assert_eq!(state, TriggerState::Ongoing, "north should require holding");
+
+    app.release(KeyCode::Numpad8)
+        .press(KeyCode::Numpad1)
+        .update();
+    assert_eq!(action::<Move>(&mut app), Vec2::new(-2.0, -2.0));
+}
+
+#[test]
+fn normalized() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Move>::new(),
+                Scale::splat(2.0),
+                Bindings::spawn(Cardinal::wasd_keys().normalized()),
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::KeyW).update();
+    assert_eq!(action::<Move>(&mut app), Vec2::Y * 2.0);
+
+    app.press(KeyCode::KeyD).update();
+    let value = action::<Move>(&mut app);
+    assert!(
+        value.abs_diff_eq(Vec2::splat(2.0_f32.sqrt()), 1e-5),
+        "diagonal `{value}` should be clamped before scaling"
+    );
+}
+
+#[test]
+fn normalized_with_other_bindings() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(
+                TestContext[(
+                    Action::<Move>::new(),
+                    Bindings::spawn((
+                        Cardinal::wasd_keys().normalized(),
+                        Spawn(Binding::mouse_motion()),
+                    )),
+                )]
+            ),
+        ))
+        .id();
+
+    app.update();
+
+    app.move_mouse(Vec2::new(3.0, 4.0)).update();
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::new(3.0, 4.0),
+        "other bindings shouldn't be clamped"
+    );
+
+    app.press(KeyCode::KeyW).press(KeyCode::KeyD).update();
+    let value = action::<Move>(&mut app);
+    assert!(
+        value.abs_diff_eq(Vec2::splat(0.5_f32.sqrt()), 1e-5),
+        "diagonal `{value}` should be clamped"
+    );
+
+    app.world_mut()
+        .entity_mut(context)
+        .rebind::<TestContext, Move>([KeyCode::KeyW.into(), KeyCode::KeyD.into()])
+        .unwrap();
+
+    app.update();
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::X * 2.0,
+        "clamp should be removed with the replaced bindings"
+    );
+}
+
+fn action<A: InputAction<Output = Vec2>>(app: &mut App) -> Vec2 {
+    **app
+        .world_mut()
+        .query::<&Action<A>>()
+        .single(app.world())
+        .unwrap()
+}
+
 const RIGHT: Vec3 = Vec3::X;
 const LEFT: Vec3 = Vec3::NEG_X;
 const BACKWARD: Vec3 = Vec3::Z;
@@ -181,3 +428,19 @@ struct TestContext;
 #[derive(InputAction)]
 #[action_output(Vec3)]
 struct Test;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Move;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Aim;
+
+#[derive(InputAction)]
+#[action_output(Vec3)]
+struct Translate;
+
+#[derive(InputAction)]
+#[action_output(Vec3)]
+struct Rotate;
@@ -0,0 +1,70 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn soft_and_full_pull() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (
+                Action::<Aim>::new(),
+                bindings![(GamepadButton::RightTrigger2, PressureThreshold::new(0.3))],
+            ),
+            (
+                Action::<Shoot>::new(),
+                bindings![(GamepadButton::RightTrigger2, PressureThreshold::new(0.9))],
+            ),
+        ]),
+    ));
+
+    app.set_button_value(GamepadButton::RightTrigger2, 0.5)
+        .update();
+
+    let aim = state::<Aim>(&mut app);
+    assert_eq!(aim, TriggerState::Fired);
+
+    let shoot = state::<Shoot>(&mut app);
+    assert_eq!(shoot, TriggerState::Ongoing);
+
+    app.set_button_value(GamepadButton::RightTrigger2, 1.0)
+        .update();
+
+    let aim = state::<Aim>(&mut app);
+    assert_eq!(aim, TriggerState::Fired);
+
+    let shoot = state::<Shoot>(&mut app);
+    assert_eq!(shoot, TriggerState::Fired);
+
+    app.set_button_value(GamepadButton::RightTrigger2, 0.0)
+        .update();
+
+    let aim = state::<Aim>(&mut app);
+    assert_eq!(aim, TriggerState::None);
+
+    let shoot = state::<Shoot>(&mut app);
+    assert_eq!(shoot, TriggerState::None);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Aim;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Shoot;
@@ -0,0 +1,183 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn from_observer() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_observer(|rebind: On<Start<Rebind>>, mut commands: Commands| {
+            commands
+                .entity(rebind.context)
+                .rebind::<TestContext, Test>([KEY2.into()]);
+        })
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Rebind>::new(), bindings![REBIND_KEY]),
+            (Action::<Test>::new(), bindings![KEY1]),
+        ]),
+    ));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(REBIND_KEY);
+
+    app.update();
+
+    let mut bindings = app.world_mut().query::<(&Binding, &BindingOf)>();
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<Entity, With<Action<Test>>>();
+    let action = actions.single(app.world()).unwrap();
+    let test_bindings: Vec<_> = bindings
+        .iter(app.world())
+        .filter(|(_, binding_of)| binding_of.0 == action)
+        .map(|(&binding, _)| binding)
+        .collect();
+    assert_eq!(test_bindings, [KEY2.into()]);
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KEY2);
+
+    app.update();
+
+    let mut actions = app.world_mut().query::<&Action<Test>>();
+    assert!(**actions.single(app.world()).unwrap());
+}
+
+#[test]
+fn missing_action() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[(Action::<Rebind>::new(), bindings![REBIND_KEY])]),
+        ))
+        .id();
+
+    let result = app
+        .world_mut()
+        .entity_mut(context)
+        .rebind::<TestContext, Test>([KEY1.into()]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn group() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let context = app
+        .world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Rebind>::new(), bindings![(KEY1, GROUP, Scale::splat(2.0))]),
+                (Action::<Test>::new(), bindings![(KEY2, GROUP), REBIND_KEY]),
+            ]),
+        ))
+        .id();
+
+    let result = app
+        .world_mut()
+        .entity_mut(context)
+        .rebind_group::<TestContext>(GROUP, [KEY1.into()]);
+    assert!(result.is_err(), "size should match the group");
+
+    app.world_mut()
+        .entity_mut(context)
+        .rebind_group::<TestContext>(GROUP, [KEY2.into(), KEY1.into()])
+        .unwrap();
+
+    let mut actions = app.world_mut().query::<&Bindings>();
+    let mut bindings = app.world_mut().query::<(&Binding, &BindingGroup)>();
+    let context_actions = app.world().get::<Actions<TestContext>>(context).unwrap();
+    let group: Vec<_> = context_actions
+        .binding_group(
+            GROUP,
+            &actions.query(app.world()),
+            &bindings.query(app.world()),
+        )
+        .into_iter()
+        .map(|(_, binding)| binding)
+        .collect();
+    assert_eq!(group, [KEY2.into(), KEY1.into()]);
+
+    let mut scales = app.world_mut().query::<(&Binding, &Scale)>();
+    let (&binding, _) = scales.single(app.world()).unwrap();
+    assert_eq!(
+        binding,
+        KEY2.into(),
+        "modifiers should be preserved on the binding entity"
+    );
+}
+
+#[test]
+fn defaults() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut()
+        .spawn((
+            TestContext,
+            actions!(TestContext[
+                (Action::<Defaults>::new(), bindings![REBIND_KEY]),
+                (Action::<Test>::new(), bindings![REBIND_KEY]),
+            ]),
+        ))
+        .bind_defaults::<TestContext>()
+        .unwrap();
+
+    let mut actions = app.world_mut().query::<(&Bindings, Has<Action<Test>>)>();
+    let mut bindings = app.world_mut().query::<&Binding>();
+    for (action_bindings, is_test) in actions.iter(app.world()) {
+        let action_bindings: Vec<_> = bindings
+            .iter_many(app.world(), action_bindings)
+            .copied()
+            .collect();
+        if is_test {
+            assert_eq!(
+                action_bindings,
+                [REBIND_KEY.into()],
+                "actions without defaults should be left untouched"
+            );
+        } else {
+            assert_eq!(action_bindings, [KEY1.into(), KEY2.into()]);
+        }
+    }
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+#[action_bindings(KEY1, KEY2)]
+struct Defaults;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Rebind;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+const REBIND_KEY: KeyCode = KeyCode::KeyR;
+const KEY1: KeyCode = KeyCode::KeyA;
+const KEY2: KeyCode = KeyCode::KeyB;
+const GROUP: BindingGroup = BindingGroup(0);
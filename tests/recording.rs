@@ -0,0 +1,198 @@
+use core::time::Duration;
+
+use bevy::{
+    input::{InputPlugin, mouse::AccumulatedMouseMotion},
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn record_and_playback() {
+    let (mut app, gamepad_entity) = init_app();
+    app.insert_resource(InputRecorder::default());
+
+    let mut recorded = Vec::new();
+
+    app.update();
+    recorded.push(action_data(&mut app));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Test::KEY);
+
+    app.update();
+    recorded.push(action_data(&mut app));
+
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::AXIS, 0.5);
+
+    app.update();
+    recorded.push(action_data(&mut app));
+
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .release(Test::KEY);
+    let mut gamepad = app.world_mut().get_mut::<Gamepad>(gamepad_entity).unwrap();
+    gamepad.analog_mut().set(Test::AXIS, 0.0);
+
+    app.update();
+    recorded.push(action_data(&mut app));
+
+    let recorder = app.world_mut().remove_resource::<InputRecorder>().unwrap();
+    assert_eq!(recorder.recording.frames.len(), recorded.len());
+
+    let (mut app, _) = init_app();
+    app.insert_resource(InputPlayback::new(recorder.recording));
+
+    for (frame, &data) in recorded.iter().enumerate() {
+        app.update();
+        assert_eq!(
+            action_data(&mut app),
+            data,
+            "frame {frame} should be replayed"
+        );
+    }
+
+    app.update();
+
+    assert!(!app.world().contains_resource::<InputPlayback>());
+    let keys = app.world().resource::<ButtonInput<KeyCode>>();
+    assert_eq!(keys.get_pressed().len(), 0);
+}
+
+#[test]
+fn release_on_finish() {
+    let (mut app, gamepad_entity) = init_app();
+
+    let recording = InputRecording {
+        frames: vec![InputFrame {
+            keys: vec![Test::KEY],
+            gamepads: vec![GamepadFrame {
+                axes: vec![(Test::AXIS, 1.0)],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }],
+    };
+    app.insert_resource(InputPlayback::new(recording));
+
+    app.update();
+
+    assert_eq!(action_data(&mut app), (2.0, TriggerState::Fired));
+
+    app.update();
+
+    assert_eq!(action_data(&mut app), (0.0, TriggerState::None));
+    let gamepad = app.world().get::<Gamepad>(gamepad_entity).unwrap();
+    assert_eq!(gamepad.get(Test::AXIS), Some(0.0));
+}
+
+#[test]
+fn speed() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Held>::new(), Hold::new(1.0), bindings![Test::KEY])]),
+    ));
+
+    app.update();
+
+    let recording = InputRecording {
+        frames: vec![
+            InputFrame {
+                keys: vec![Test::KEY],
+                mouse_motion: Vec2::X,
+                ..Default::default()
+            };
+            4
+        ],
+    };
+    app.insert_resource(InputPlayback::new(recording).with_speed(2.0));
+
+    app.update();
+
+    let playback = app.world().resource::<InputPlayback>();
+    assert_eq!(playback.frame, 2);
+    let mouse_motion = app.world().resource::<AccumulatedMouseMotion>();
+    assert_eq!(
+        mouse_motion.delta,
+        Vec2::new(2.0, 0.0),
+        "motion of skipped frames should be summed"
+    );
+    assert_eq!(held_state(&mut app), TriggerState::Ongoing);
+
+    app.update();
+
+    assert_eq!(
+        held_state(&mut app),
+        TriggerState::Fired,
+        "time should be scaled by the playback speed"
+    );
+
+    app.world_mut().resource_mut::<InputPlayback>().speed = 0.5;
+
+    app.update();
+
+    let playback = app.world().resource::<InputPlayback>();
+    assert_eq!(playback.frame, 4, "should wait for the accumulated frame");
+    let mouse_motion = app.world().resource::<AccumulatedMouseMotion>();
+    assert_eq!(mouse_motion.delta, Vec2::ZERO);
+
+    app.update();
+
+    assert!(!app.world().contains_resource::<InputPlayback>());
+}
+
+fn init_app() -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let gamepad_entity = app.world_mut().spawn(Gamepad::default()).id();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Test::KEY, Test::AXIS])]),
+    ));
+
+    (app, gamepad_entity)
+}
+
+fn held_state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<Held>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn action_data(app: &mut App) -> (f32, TriggerState) {
+    let mut actions = app.world_mut().query::<(&Action<Test>, &TriggerState)>();
+    let (&action, &state) = actions.single(app.world()).unwrap();
+    (*action, state)
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Test;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Held;
+
+impl Test {
+    const KEY: KeyCode = KeyCode::KeyA;
+    const AXIS: GamepadAxis = GamepadAxis::LeftStickX;
+}
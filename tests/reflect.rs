@@ -0,0 +1,106 @@
+#![cfg(feature = "reflect")]
+
+use core::any::TypeId;
+
+use bevy::{input::InputPlugin, prelude::*, reflect::GetPath};
+use bevy_enhanced_input::prelude::{Press, *};
+use test_log::test;
+
+#[test]
+fn round_trip() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), Press::default(), bindings![KEY])]),
+    ));
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query_filtered::<Entity, With<Action<Test>>>();
+    let action = actions.single(app.world()).unwrap();
+    **app.world_mut().get_mut::<Action<Test>>(action).unwrap() = true;
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    assert!(
+        registry
+            .get_type_data::<ReflectComponent>(TypeId::of::<ActionOf<TestContext>>())
+            .is_some()
+    );
+
+    let reflect_action = registry
+        .get_type_data::<ReflectComponent>(TypeId::of::<Action<Test>>())
+        .unwrap();
+    let value = reflect_action
+        .reflect(app.world().entity(action))
+        .unwrap()
+        .reflect_clone()
+        .unwrap();
+
+    let copy = app.world_mut().spawn_empty().id();
+    reflect_action.insert(
+        &mut app.world_mut().entity_mut(copy),
+        value.as_partial_reflect(),
+        &registry,
+    );
+
+    let copied = app.world().get::<Action<Test>>(copy).unwrap();
+    assert!(**copied);
+}
+
+#[test]
+fn builtin_types() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    for type_id in [
+        TypeId::of::<Binding>(),
+        TypeId::of::<BindingOf>(),
+        TypeId::of::<ActionSettings>(),
+        TypeId::of::<Hold>(),
+        TypeId::of::<Press>(),
+        TypeId::of::<DeadZone>(),
+        TypeId::of::<Scale>(),
+    ] {
+        assert!(
+            registry
+                .get_type_data::<ReflectComponent>(type_id)
+                .is_some(),
+            "`{type_id:?}` should be registered"
+        );
+    }
+
+    let dead_zone = app.world_mut().spawn(DeadZone::default()).id();
+    let reflect_dead_zone = registry
+        .get_type_data::<ReflectComponent>(TypeId::of::<DeadZone>())
+        .unwrap();
+    let mut entity = app.world_mut().entity_mut(dead_zone);
+    let mut reflected = reflect_dead_zone.reflect_mut(&mut entity).unwrap();
+    *reflected
+        .reflect_path_mut("lower_threshold")
+        .unwrap()
+        .try_downcast_mut::<f32>()
+        .unwrap() = 0.5;
+
+    let dead_zone = app.world().get::<DeadZone>(dead_zone).unwrap();
+    assert_eq!(dead_zone.lower_threshold, 0.5);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+const KEY: KeyCode = KeyCode::KeyA;
@@ -0,0 +1,83 @@
+#![cfg(all(feature = "reflect", feature = "serialize"))]
+
+use bevy::{
+    ecs::entity::EntityHashMap, input::InputPlugin, prelude::*, scene::serde::SceneDeserializer,
+};
+use bevy_enhanced_input::prelude::*;
+use serde::de::DeserializeSeed;
+use test_log::test;
+
+#[test]
+fn documented_scene() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .register_type::<Player>()
+        .register_type::<Action<Jump>>()
+        .finish();
+
+    // Scene from the crate-level documentation with types from this test.
+    let scene = SCENE.replace("my_game", module_path!());
+    let registry = app.world().resource::<AppTypeRegistry>().clone();
+    let scene = {
+        let registry = registry.read();
+        let mut deserializer = ron::Deserializer::from_str(&scene).unwrap();
+        SceneDeserializer {
+            type_registry: &registry,
+        }
+        .deserialize(&mut deserializer)
+        .unwrap()
+    };
+
+    let mut entity_map = EntityHashMap::default();
+    scene
+        .write_to_world(app.world_mut(), &mut entity_map)
+        .unwrap();
+
+    app.update();
+
+    let mut actions = app
+        .world_mut()
+        .query::<(&Action<Jump>, &ActionOf<Player>)>();
+    let (&action, _) = actions.single(app.world()).unwrap();
+    assert!(!*action);
+
+    app.press(KeyCode::Space).update();
+
+    let (&action, _) = actions.single(app.world()).unwrap();
+    assert!(*action, "binding from the scene should trigger the action");
+}
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+const SCENE: &str = r#"(
+  resources: {},
+  entities: {
+    4294967295: (
+      components: {
+        "my_game::Player": (),
+        "bevy_enhanced_input::action::relationship::Actions<my_game::Player>": (entities: [4294967294]),
+      },
+    ),
+    4294967294: (
+      components: {
+        "bevy_enhanced_input::action::Action<my_game::Jump>": (false),
+        "bevy_enhanced_input::action::relationship::ActionOf<my_game::Player>": (entity: 4294967295),
+        "bevy_enhanced_input::binding::relationship::Bindings": ([4294967293]),
+        "bevy_enhanced_input::condition::press::Press": (actuation: 0.5),
+      },
+    ),
+    4294967293: (
+      components: {
+        "bevy_enhanced_input::binding::relationship::BindingOf": (4294967294),
+        "bevy_enhanced_input::binding::Binding": Keyboard(key: Space, mod_keys: ""),
+      },
+    ),
+  },
+)"#;
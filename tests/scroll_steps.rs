@@ -0,0 +1,126 @@
+use bevy::{
+    input::{
+        InputPlugin,
+        mouse::{MouseScrollUnit, MouseWheel},
+    },
+    prelude::*,
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn steps() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (
+                    Action::<NextPage>::new(),
+                    bindings![ScrollDirection::Down, ScrollDirection::Right],
+                ),
+                (
+                    Action::<PrevPage>::new(),
+                    bindings![ScrollDirection::Up, ScrollDirection::Left],
+                ),
+            ]
+        ),
+    ));
+
+    app.update();
+
+    app.scroll_mouse(Vec2::NEG_Y).update();
+    assert_eq!(state::<NextPage>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<PrevPage>(&mut app), TriggerState::None);
+
+    app.update();
+    assert_eq!(
+        state::<NextPage>(&mut app),
+        TriggerState::None,
+        "step should be active only for a single frame"
+    );
+
+    app.scroll_mouse(Vec2::NEG_X).update();
+    assert_eq!(
+        state::<PrevPage>(&mut app),
+        TriggerState::Fired,
+        "tilting left should step left"
+    );
+    assert_eq!(state::<NextPage>(&mut app), TriggerState::None);
+}
+
+#[test]
+fn pixels() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (
+                    Action::<PrevPage>::new(),
+                    bindings![ScrollDirection::Up],
+                ),
+                (
+                    Action::<Zoom>::new(),
+                    bindings![Binding::mouse_wheel_precise()],
+                ),
+            ]
+        ),
+    ));
+
+    app.update();
+
+    let pixels_per_line = app.world().resource::<ScrollSteps>().pixels_per_line;
+    let delta = pixels_per_line / 2.0;
+    for (expected_state, message) in [
+        (TriggerState::None, "half a line shouldn't step"),
+        (TriggerState::Fired, "accumulated pixels should step"),
+    ] {
+        app.world_mut().write_message(MouseWheel {
+            unit: MouseScrollUnit::Pixel,
+            x: 0.0,
+            y: delta,
+            window: Entity::PLACEHOLDER,
+        });
+        app.update();
+
+        assert_eq!(state::<PrevPage>(&mut app), expected_state, "{message}");
+
+        let zoom = **app
+            .world_mut()
+            .query::<&Action<Zoom>>()
+            .single(app.world())
+            .unwrap();
+        assert_eq!(zoom, 0.5, "precise delta should be in lines");
+    }
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct NextPage;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct PrevPage;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Zoom;
@@ -0,0 +1,119 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn keyboards() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .finish();
+
+    let first_keyboard = app.world_mut().spawn(SeatKeyboard::default()).id();
+    let second_keyboard = app.world_mut().spawn(SeatKeyboard::default()).id();
+
+    let first = app
+        .world_mut()
+        .spawn((
+            Player,
+            KeyboardDevice::Single(first_keyboard),
+            actions!(Player[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+    let second = app
+        .world_mut()
+        .spawn((
+            Player,
+            KeyboardDevice::Single(second_keyboard),
+            actions!(Player[(Action::<Jump>::new(), bindings![Jump::KEY])]),
+        ))
+        .id();
+
+    app.world_mut()
+        .get_mut::<SeatKeyboard>(first_keyboard)
+        .unwrap()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(state(&mut app, first), TriggerState::Fired);
+    assert_eq!(state(&mut app, second), TriggerState::None);
+
+    app.world_mut()
+        .get_mut::<SeatKeyboard>(first_keyboard)
+        .unwrap()
+        .release(Jump::KEY);
+    app.world_mut()
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(Jump::KEY);
+
+    app.update();
+
+    assert_eq!(
+        state(&mut app, first),
+        TriggerState::None,
+        "global keys shouldn't affect seat keyboards"
+    );
+    assert_eq!(state(&mut app, second), TriggerState::None);
+}
+
+#[test]
+fn mice() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<Player>()
+        .finish();
+
+    let mouse = app.world_mut().spawn(SeatMouse::default()).id();
+
+    let seated = app
+        .world_mut()
+        .spawn((
+            Player,
+            MouseDevice::Single(mouse),
+            actions!(Player[(Action::<Look>::new(), bindings![Binding::mouse_motion()])]),
+        ))
+        .id();
+    let ignoring = app
+        .world_mut()
+        .spawn((
+            Player,
+            MouseDevice::None,
+            actions!(Player[(Action::<Look>::new(), bindings![Binding::mouse_motion()])]),
+        ))
+        .id();
+
+    app.world_mut().get_mut::<SeatMouse>(mouse).unwrap().motion = Vec2::ONE;
+
+    app.update();
+
+    assert_eq!(look(&mut app, seated), Vec2::ONE);
+    assert_eq!(look(&mut app, ignoring), Vec2::ZERO);
+}
+
+fn state(app: &mut App, context: Entity) -> TriggerState {
+    let actions = app.world().get::<Actions<Player>>(context).unwrap();
+    let action = actions.iter().next().unwrap();
+    *app.world().get::<TriggerState>(action).unwrap()
+}
+
+fn look(app: &mut App, context: Entity) -> Vec2 {
+    let actions = app.world().get::<Actions<Player>>(context).unwrap();
+    let action = actions.iter().next().unwrap();
+    **app.world().get::<Action<Look>>(action).unwrap()
+}
+
+#[derive(Component)]
+struct Player;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+impl Jump {
+    const KEY: KeyCode = KeyCode::Space;
+}
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Look;
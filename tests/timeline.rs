@@ -0,0 +1,70 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn tap() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), Tap::new(0.25), bindings![KEY])]),
+    ));
+
+    app.update();
+
+    app.press(KEY)
+        .assert_timeline::<Test>([(0, TriggerState::Ongoing), (2, TriggerState::None)]);
+
+    app.release(KEY)
+        .assert_timeline::<Test>([(0, TriggerState::None)]);
+
+    app.press(KEY)
+        .assert_timeline::<Test>([(0, TriggerState::Ongoing)])
+        .release(KEY)
+        .assert_timeline::<Test>([(0, TriggerState::Fired), (1, TriggerState::None)]);
+}
+
+#[test]
+#[should_panic(expected = "`Test` should be in state `Fired` at frame 2")]
+fn mismatch() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![KEY])]),
+    ));
+
+    app.assert_timeline::<Test>([(0, TriggerState::None), (2, TriggerState::Fired)]);
+}
+
+#[test]
+#[should_panic(expected = "frames should be in ascending order")]
+fn unordered() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.assert_timeline::<Test>([(2, TriggerState::None), (1, TriggerState::Fired)]);
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
+
+const KEY: KeyCode = KeyCode::KeyA;
@@ -0,0 +1,94 @@
+#![cfg(feature = "ui")]
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn interaction() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let button = app.world_mut().spawn(Interaction::None).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![KeyCode::KeyI, Binding::UiButton(button)],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Pressed);
+
+    app.update();
+
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Hovered);
+
+    app.update();
+
+    assert_eq!(state(&mut app), TriggerState::None);
+
+    app.press(KeyCode::KeyI).update();
+
+    assert_eq!(
+        state(&mut app),
+        TriggerState::Fired,
+        "hotkey should trigger the same action"
+    );
+}
+
+#[test]
+fn despawn() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    let button = app.world_mut().spawn(Interaction::Pressed).id();
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Test>::new(), bindings![Binding::UiButton(button)])]),
+    ));
+
+    app.update();
+
+    assert_eq!(state(&mut app), TriggerState::Fired);
+
+    app.world_mut().despawn(button);
+
+    app.update();
+
+    assert_eq!(
+        state(&mut app),
+        TriggerState::None,
+        "despawned button should be released"
+    );
+}
+
+fn state(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<Test>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;
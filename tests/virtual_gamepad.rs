@@ -0,0 +1,204 @@
+#![cfg(feature = "ui")]
+
+use bevy::{
+    input::{
+        InputPlugin,
+        touch::{TouchInput, TouchPhase},
+    },
+    prelude::*,
+    ui::{RelativeCursorPosition, UiGlobalTransform},
+};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn stick() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Move>::new(), Bindings::spawn(Axial::left_stick()))]),
+    ));
+    let stick = app.world_mut().spawn(VirtualStick::left()).id();
+
+    app.update();
+
+    let mut gamepads = app
+        .world_mut()
+        .query_filtered::<Entity, (With<Gamepad>, With<VirtualGamepad>)>();
+    assert_eq!(
+        gamepads.iter(app.world()).count(),
+        1,
+        "gamepad should be spawned for widgets"
+    );
+
+    app.world_mut().entity_mut(stick).insert((
+        Interaction::Pressed,
+        RelativeCursorPosition {
+            cursor_over: true,
+            normalized: Some(Vec2::new(0.0, -0.25)),
+        },
+    ));
+
+    app.update();
+
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::new(0.0, 0.5),
+        "dragging up should tilt the stick up"
+    );
+
+    app.world_mut()
+        .entity_mut(stick)
+        .insert(RelativeCursorPosition {
+            cursor_over: false,
+            normalized: Some(Vec2::new(2.0, 0.0)),
+        });
+
+    app.update();
+
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::X,
+        "should be clamped outside the node"
+    );
+
+    app.world_mut().entity_mut(stick).insert(Interaction::None);
+
+    app.update();
+
+    assert_eq!(action::<Move>(&mut app), Vec2::ZERO);
+}
+
+#[test]
+fn button() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Jump>::new(), bindings![GamepadButton::South])]),
+    ));
+    let button = app
+        .world_mut()
+        .spawn(VirtualButton::new(GamepadButton::South))
+        .id();
+
+    app.update();
+
+    app.world_mut()
+        .entity_mut(button)
+        .insert(Interaction::Pressed);
+
+    app.update();
+
+    assert!(action::<Jump>(&mut app));
+
+    app.world_mut().entity_mut(button).insert(Interaction::None);
+
+    app.update();
+
+    assert!(!action::<Jump>(&mut app));
+}
+
+#[test]
+fn multi_touch() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[
+                (Action::<Move>::new(), Bindings::spawn(Axial::left_stick())),
+                (Action::<Jump>::new(), bindings![GamepadButton::South]),
+            ]
+        ),
+    ));
+    app.world_mut().spawn((
+        VirtualStick::left(),
+        ComputedNode {
+            size: Vec2::splat(100.0),
+            ..Default::default()
+        },
+        UiGlobalTransform::from_xy(50.0, 50.0),
+    ));
+    app.world_mut().spawn((
+        VirtualButton::new(GamepadButton::South),
+        ComputedNode {
+            size: Vec2::splat(50.0),
+            ..Default::default()
+        },
+        UiGlobalTransform::from_xy(200.0, 50.0),
+    ));
+
+    app.update();
+
+    touch(&mut app, TouchPhase::Started, 0, Vec2::new(50.0, 25.0));
+    touch(&mut app, TouchPhase::Started, 1, Vec2::new(200.0, 50.0));
+
+    app.update();
+
+    assert_eq!(action::<Move>(&mut app), Vec2::new(0.0, 0.5));
+    assert!(
+        action::<Jump>(&mut app),
+        "button should be pressed while the stick is held"
+    );
+
+    touch(&mut app, TouchPhase::Ended, 1, Vec2::new(200.0, 50.0));
+    touch(&mut app, TouchPhase::Moved, 0, Vec2::new(200.0, 50.0));
+
+    app.update();
+
+    assert_eq!(
+        action::<Move>(&mut app),
+        Vec2::X,
+        "touch should stay with the stick after leaving it"
+    );
+    assert!(
+        !action::<Jump>(&mut app),
+        "touch that started on the stick shouldn't press the button"
+    );
+
+    touch(&mut app, TouchPhase::Ended, 0, Vec2::new(200.0, 50.0));
+
+    app.update();
+
+    assert_eq!(action::<Move>(&mut app), Vec2::ZERO);
+}
+
+fn touch(app: &mut App, phase: TouchPhase, id: u64, position: Vec2) {
+    app.world_mut().write_message(TouchInput {
+        phase,
+        position,
+        window: Entity::PLACEHOLDER,
+        force: None,
+        id,
+    });
+}
+
+fn action<A: InputAction>(app: &mut App) -> A::Output {
+    **app
+        .world_mut()
+        .query::<&Action<A>>()
+        .single(app.world())
+        .unwrap()
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(Vec2)]
+struct Move;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
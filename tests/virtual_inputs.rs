@@ -0,0 +1,108 @@
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn events() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_virtual_input::<VoiceCommand>(JUMP, |command| {
+            (command.word == "jump").then_some(true.into())
+        })
+        .add_virtual_input::<VoiceCommand>(THROTTLE, |command| {
+            (command.word == "faster").then_some(0.5.into())
+        })
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[
+            (Action::<Jump>::new(), bindings![KeyCode::Space, JUMP]),
+            (Action::<Throttle>::new(), bindings![THROTTLE]),
+        ]),
+    ));
+
+    app.update();
+
+    app.world_mut().trigger(VoiceCommand { word: "jump" });
+    app.update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+    assert_eq!(state::<Throttle>(&mut app), TriggerState::None);
+
+    app.update();
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::None,
+        "should be active for a single update"
+    );
+
+    app.world_mut().trigger(VoiceCommand { word: "faster" });
+    app.world_mut().trigger(VoiceCommand { word: "unknown" });
+    app.update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+    assert_eq!(state::<Throttle>(&mut app), TriggerState::Fired);
+    assert_eq!(throttle(&mut app), 0.5);
+}
+
+#[test]
+fn mocking() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(TestContext[(Action::<Jump>::new(), bindings![JUMP])]),
+    ));
+
+    app.update();
+
+    app.press(JUMP).update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::Fired);
+
+    app.update();
+    assert_eq!(
+        state::<Jump>(&mut app),
+        TriggerState::Fired,
+        "mocked input should stay pressed"
+    );
+
+    app.release(JUMP).update();
+    assert_eq!(state::<Jump>(&mut app), TriggerState::None);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+fn throttle(app: &mut App) -> f32 {
+    **app
+        .world_mut()
+        .query::<&Action<Throttle>>()
+        .single(app.world())
+        .unwrap()
+}
+
+const JUMP: VirtualInput = VirtualInput(0);
+const THROTTLE: VirtualInput = VirtualInput(1);
+
+#[derive(Event)]
+struct VoiceCommand {
+    word: &'static str,
+}
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Jump;
+
+#[derive(InputAction)]
+#[action_output(f32)]
+struct Throttle;
@@ -0,0 +1,64 @@
+#![cfg(feature = "voice")]
+
+use bevy::{input::InputPlugin, prelude::*};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn phrases() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .add_input_context::<TestContext>()
+        .add_voice_phrase("Reload", RELOAD)
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Reload>::new(),
+                bindings![(RELOAD, MinConfidence::new(0.75))],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.world_mut()
+        .trigger(RecognizedPhrase::new(" reload ", 0.8));
+    app.update();
+    assert_eq!(state::<Reload>(&mut app), TriggerState::Fired);
+
+    app.update();
+    assert_eq!(state::<Reload>(&mut app), TriggerState::None);
+
+    app.world_mut()
+        .trigger(RecognizedPhrase::new("reload", 0.5));
+    app.update();
+    assert_eq!(
+        state::<Reload>(&mut app),
+        TriggerState::None,
+        "uncertain recognition should be ignored"
+    );
+
+    app.world_mut()
+        .trigger(RecognizedPhrase::new("unload", 1.0));
+    app.update();
+    assert_eq!(state::<Reload>(&mut app), TriggerState::None);
+}
+
+fn state<A: InputAction>(app: &mut App) -> TriggerState {
+    *app.world_mut()
+        .query_filtered::<&TriggerState, With<Action<A>>>()
+        .single(app.world())
+        .unwrap()
+}
+
+const RELOAD: VirtualInput = VirtualInput(0);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Reload;
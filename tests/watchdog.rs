@@ -0,0 +1,80 @@
+use core::time::Duration;
+
+use bevy::{input::InputPlugin, prelude::*, time::TimeUpdateStrategy};
+use bevy_enhanced_input::prelude::*;
+use test_log::test;
+
+#[test]
+fn stuck() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, InputPlugin, EnhancedInputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .insert_resource(ActionWatchdog::new(0.25))
+        .init_resource::<Reports>()
+        .add_observer(record)
+        .add_input_context::<TestContext>()
+        .finish();
+
+    app.world_mut().spawn((
+        TestContext,
+        actions!(
+            TestContext[(
+                Action::<Test>::new(),
+                bindings![(KeyCode::Space, Down::default())],
+            )]
+        ),
+    ));
+
+    app.update();
+
+    app.press(KeyCode::Space).update();
+    app.update();
+    app.update();
+    assert!(app.world().resource::<Reports>().is_empty());
+
+    app.update();
+    app.update();
+    let reports = app.world().resource::<Reports>();
+    assert_eq!(reports.len(), 1, "should report only once");
+
+    let stuck = &reports[0];
+    assert_eq!(stuck.state, TriggerState::Fired);
+    assert_eq!(stuck.binding, Some(KeyCode::Space.into()));
+    assert_eq!(
+        stuck
+            .conditions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["Down"]
+    );
+
+    app.release(KeyCode::Space).update();
+    app.world_mut().resource_mut::<Reports>().clear();
+
+    app.press(KeyCode::Space).update();
+    for _ in 0..4 {
+        app.update();
+    }
+    assert_eq!(
+        app.world().resource::<Reports>().len(),
+        1,
+        "should report again after release"
+    );
+}
+
+fn record(stuck: On<ActionStuck>, mut reports: ResMut<Reports>) {
+    reports.push(stuck.clone());
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct Reports(Vec<ActionStuck>);
+
+#[derive(Component)]
+struct TestContext;
+
+#[derive(InputAction)]
+#[action_output(bool)]
+struct Test;